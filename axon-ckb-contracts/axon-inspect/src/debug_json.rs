@@ -0,0 +1,207 @@
+use serde_json::{Map, Value};
+
+/*
+converts the Debug output of common-raw types to json, so that every FromRaw impl could be printed without
+mapping each struct by hand:
+    Name { field: value, .. }   -> object
+    Name(value)                 -> value, thus Some(x) is x
+    Name(a, b, ..), (a, b, ..)  -> array
+    [a, b, ..]                  -> array, hex string for 20 and 32 bytes (lock args and hashes)
+    None                        -> null
+    Variant                     -> "Variant"
+ */
+pub fn debug_to_json(debug: &str) -> Result<Value, String> {
+    let mut parser = Parser {
+        input: debug.as_bytes(),
+        pos:   0,
+    };
+    let value = parser.value()?;
+
+    parser.skip_whitespace();
+    if parser.pos != parser.input.len() {
+        return Err(parser.error("trailing characters"));
+    }
+
+    Ok(value)
+}
+
+struct Parser<'a> {
+    input: &'a [u8],
+    pos:   usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self, reason: &str) -> String {
+        format!("unable to convert debug output at {}: {}", self.pos, reason)
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.input.get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().map_or(false, |c| c.is_ascii_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, expected: u8) -> Result<(), String> {
+        self.skip_whitespace();
+        if self.peek() != Some(expected) {
+            return Err(self.error(&format!("expect '{}'", expected as char)));
+        }
+        self.pos += 1;
+        Ok(())
+    }
+
+    // consumes the separator of a sequence, returns true at its end
+    fn end_of_sequence(&mut self, close: u8) -> Result<bool, String> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(b',') => {
+                self.pos += 1;
+                self.skip_whitespace();
+                if self.peek() == Some(close) {
+                    self.pos += 1;
+                    return Ok(true);
+                }
+                Ok(false)
+            }
+            Some(c) if c == close => {
+                self.pos += 1;
+                Ok(true)
+            }
+            _ => Err(self.error(&format!("expect ',' or '{}'", close as char))),
+        }
+    }
+
+    fn take_while<F: Fn(u8) -> bool>(&mut self, f: F) -> &'a str {
+        let start = self.pos;
+        while self.peek().map_or(false, &f) {
+            self.pos += 1;
+        }
+        std::str::from_utf8(&self.input[start..self.pos]).expect("ascii")
+    }
+
+    fn value(&mut self) -> Result<Value, String> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(b'[') => {
+                self.pos += 1;
+                let items = self.sequence(b']')?;
+                Ok(bytes_to_hex(&items).unwrap_or(Value::Array(items)))
+            }
+            Some(b'(') => {
+                self.pos += 1;
+                Ok(Value::Array(self.sequence(b')')?))
+            }
+            Some(b'"') => self.string(),
+            Some(c) if c == b'-' || c.is_ascii_digit() => self.number(),
+            Some(c) if c == b'_' || c.is_ascii_alphabetic() => self.named(),
+            _ => Err(self.error("unexpected character")),
+        }
+    }
+
+    fn sequence(&mut self, close: u8) -> Result<Vec<Value>, String> {
+        let mut items = Vec::new();
+
+        self.skip_whitespace();
+        if self.peek() == Some(close) {
+            self.pos += 1;
+            return Ok(items);
+        }
+
+        loop {
+            items.push(self.value()?);
+            if self.end_of_sequence(close)? {
+                return Ok(items);
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<Value, String> {
+        let start = self.pos;
+        self.pos += 1;
+        loop {
+            match self.peek() {
+                Some(b'\\') => self.pos += 2,
+                Some(b'"') => break,
+                Some(_) => self.pos += 1,
+                None => return Err(self.error("unterminated string")),
+            }
+        }
+        self.pos += 1;
+
+        // debug escapes are a subset of json ones for the ascii strings we print
+        let literal = std::str::from_utf8(&self.input[start..self.pos]).map_err(|_| self.error("invalid utf8"))?;
+        serde_json::from_str(literal).map_err(|_| self.error("invalid string"))
+    }
+
+    fn number(&mut self) -> Result<Value, String> {
+        let literal = self.take_while(|c| c == b'-' || c == b'+' || c == b'.' || c == b'e' || c.is_ascii_digit());
+        serde_json::from_str(literal).map_err(|_| self.error("invalid number"))
+    }
+
+    fn named(&mut self) -> Result<Value, String> {
+        let name = self.take_while(|c| c == b'_' || c == b':' || c.is_ascii_alphanumeric());
+
+        self.skip_whitespace();
+        match self.peek() {
+            Some(b'{') => {
+                self.pos += 1;
+                self.object()
+            }
+            Some(b'(') => {
+                self.pos += 1;
+                let mut items = self.sequence(b')')?;
+                if items.len() == 1 {
+                    return Ok(items.remove(0));
+                }
+                Ok(Value::Array(items))
+            }
+            _ => Ok(match name {
+                "true" => Value::Bool(true),
+                "false" => Value::Bool(false),
+                "None" => Value::Null,
+                _ => Value::String(name.to_owned()),
+            }),
+        }
+    }
+
+    fn object(&mut self) -> Result<Value, String> {
+        let mut fields = Map::new();
+
+        self.skip_whitespace();
+        if self.peek() == Some(b'}') {
+            self.pos += 1;
+            return Ok(Value::Object(fields));
+        }
+
+        loop {
+            self.skip_whitespace();
+            let key = self.take_while(|c| c == b'_' || c.is_ascii_alphanumeric());
+            if key.is_empty() {
+                return Err(self.error("expect field name"));
+            }
+            self.expect(b':')?;
+            fields.insert(key.to_owned(), self.value()?);
+
+            if self.end_of_sequence(b'}')? {
+                return Ok(Value::Object(fields));
+            }
+        }
+    }
+}
+
+fn bytes_to_hex(items: &[Value]) -> Option<Value> {
+    if items.len() != 20 && items.len() != 32 {
+        return None;
+    }
+
+    let bytes = items
+        .iter()
+        .map(|item| item.as_u64().filter(|&byte| byte <= 0xff).map(|byte| byte as u8))
+        .collect::<Option<Vec<u8>>>()?;
+
+    Some(Value::String(crate::input::to_hex(&bytes)))
+}
@@ -0,0 +1,141 @@
+use std::fmt::Debug;
+
+use serde_json::{Map, Value};
+
+use common_raw::{
+    cell::{
+        checker_bond::{CheckerBondCell, CheckerBondCellLockArgs},
+        checker_info::{CheckerInfoCell, CheckerInfoCellTypeArgs},
+        code::CodeCellLockArgs,
+        delegation::{DelegationCell, DelegationCellLockArgs},
+        global_config::GlobalConfigCellData,
+        muse_token::MuseTokenCell,
+        proposal::{ProposalCell, ProposalCellTypeArgs},
+        sidechain_bond::{SidechainBondCell, SidechainBondCellLockArgs},
+        sidechain_config::{SidechainConfigCell, SidechainConfigCellTypeArgs},
+        sidechain_fee::{SidechainFeeCell, SidechainFeeCellLockArgs},
+        sidechain_state::{SidechainStateCell, SidechainStateCellTypeArgs},
+        sudt_token::SudtTokenCell,
+        task::{TaskCell, TaskCellTypeArgs},
+    },
+    pattern::Pattern,
+    witness::{
+        admin_create_sidechain::AdminCreateSidechainWitness, anyone_refresh_task::AnyoneRefreshTaskWitness,
+        anyone_shutdown_sidechain::AnyoneShutdownSidechainWitness, batch::BatchWitness, checker_bond_withdraw::CheckerBondWithdrawWitness,
+        checker_join_sidechain::CheckerJoinSidechainWitness, checker_propose_change::CheckerProposeChangeWitness,
+        checker_publish_challenge::CheckerPublishChallengeWitness, checker_quit_sidechain::CheckerQuitSidechainWitness,
+        checker_take_beneficiary::CheckerTakeBeneficiaryWitness, checker_vote::CheckerVoteWitness,
+        checker_vote_proposal::CheckerVoteProposalWitness, collator_handover::CollatorHandoverWitness,
+        collator_publish_task::CollatorPublishTaskWitness, collator_shutdown_sidechain::CollatorShutdownSidechainWitness,
+        collator_submit_tasks::CollatorSubmitTasksWitness, collator_unlock_bond::CollatorUnlockBondWitness,
+        delegator_claim::DelegatorClaimWitness, delegator_delegate::DelegatorDelegateWitness, execute_proposal::ExecuteProposalWitness,
+    },
+    FromRaw,
+};
+
+use crate::{debug_json::debug_to_json, input::to_hex};
+
+pub type Decoder = fn(&[u8]) -> Result<Value, String>;
+
+pub const CELL_DATA: &[(&str, Decoder)] = &[
+    ("CheckerBondCell", decode::<CheckerBondCell>),
+    ("CheckerInfoCell", decode::<CheckerInfoCell>),
+    ("DelegationCell", decode::<DelegationCell>),
+    ("GlobalConfigCellData", decode::<GlobalConfigCellData>),
+    ("MuseTokenCell", decode::<MuseTokenCell>),
+    ("ProposalCell", decode::<ProposalCell>),
+    ("SidechainBondCell", decode::<SidechainBondCell>),
+    ("SidechainConfigCell", decode::<SidechainConfigCell>),
+    ("SidechainFeeCell", decode::<SidechainFeeCell>),
+    ("SidechainStateCell", decode::<SidechainStateCell>),
+    ("SudtTokenCell", decode::<SudtTokenCell>),
+    ("TaskCell", decode::<TaskCell>),
+];
+
+pub const LOCK_ARGS: &[(&str, Decoder)] = &[
+    ("CheckerBondCellLockArgs", decode::<CheckerBondCellLockArgs>),
+    ("CodeCellLockArgs", decode::<CodeCellLockArgs>),
+    ("DelegationCellLockArgs", decode::<DelegationCellLockArgs>),
+    ("SidechainBondCellLockArgs", decode::<SidechainBondCellLockArgs>),
+    ("SidechainFeeCellLockArgs", decode::<SidechainFeeCellLockArgs>),
+];
+
+pub const TYPE_ARGS: &[(&str, Decoder)] = &[
+    ("CheckerInfoCellTypeArgs", decode::<CheckerInfoCellTypeArgs>),
+    ("ProposalCellTypeArgs", decode::<ProposalCellTypeArgs>),
+    ("SidechainConfigCellTypeArgs", decode::<SidechainConfigCellTypeArgs>),
+    ("SidechainStateCellTypeArgs", decode::<SidechainStateCellTypeArgs>),
+    ("TaskCellTypeArgs", decode::<TaskCellTypeArgs>),
+];
+
+pub fn find_decoder(table: &[(&str, Decoder)], name: &str) -> Result<Decoder, String> {
+    table
+        .iter()
+        .find(|(type_name, _)| *type_name == name)
+        .map(|(_, decoder)| *decoder)
+        .ok_or_else(|| format!("unknown type {}, see `axon-inspect types`", name))
+}
+
+fn decode<T: FromRaw + Debug>(raw: &[u8]) -> Result<Value, String> {
+    let value = T::from_raw(raw).map_err(|err| format!("unable to decode {} bytes as {}: {}", raw.len(), type_name::<T>(), err))?;
+    debug_to_json(&format!("{:?}", value))
+}
+
+fn type_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+// the pattern of a code cell witness is its first byte
+pub fn decode_witness(raw: &[u8]) -> Result<Value, String> {
+    let pattern = Pattern::from_raw(raw.get(0..1).unwrap_or(&[])).map_err(|err| format!("unknown pattern: {}", err))?;
+
+    match pattern {
+        Pattern::AdminCreateSidechain => decode::<AdminCreateSidechainWitness>(raw),
+        Pattern::CheckerBondWithdraw => decode::<CheckerBondWithdrawWitness>(raw),
+        Pattern::CheckerJoinSidechain => decode::<CheckerJoinSidechainWitness>(raw),
+        Pattern::CheckerQuitSidechain => decode::<CheckerQuitSidechainWitness>(raw),
+        Pattern::CheckerVote => decode::<CheckerVoteWitness>(raw),
+        Pattern::CheckerPublishChallenge => decode::<CheckerPublishChallengeWitness>(raw),
+        Pattern::CheckerTakeBeneficiary => decode::<CheckerTakeBeneficiaryWitness>(raw),
+        Pattern::CollatorPublishTask => decode::<CollatorPublishTaskWitness>(raw),
+        Pattern::CollatorSubmitTasks => decode::<CollatorSubmitTasksWitness>(raw),
+        Pattern::AnyoneRefreshTask => decode::<AnyoneRefreshTaskWitness>(raw),
+        Pattern::CollatorUnlockBond => decode::<CollatorUnlockBondWitness>(raw),
+        Pattern::AnyoneShutdownSidechain => decode::<AnyoneShutdownSidechainWitness>(raw),
+        Pattern::CollatorShutdownSidechain => decode::<CollatorShutdownSidechainWitness>(raw),
+        Pattern::Batch => decode_batch(raw),
+        Pattern::CheckerProposeChange => decode::<CheckerProposeChangeWitness>(raw),
+        Pattern::CheckerVoteProposal => decode::<CheckerVoteProposalWitness>(raw),
+        Pattern::ExecuteProposal => decode::<ExecuteProposalWitness>(raw),
+        Pattern::CollatorHandover => decode::<CollatorHandoverWitness>(raw),
+        Pattern::DelegatorDelegate => decode::<DelegatorDelegateWitness>(raw),
+        Pattern::DelegatorClaim => decode::<DelegatorClaimWitness>(raw),
+    }
+}
+
+// entries of a batch carry whole witnesses, which are decoded in place
+fn decode_batch(raw: &[u8]) -> Result<Value, String> {
+    let witness = BatchWitness::from_raw(raw).map_err(|err| format!("unable to decode {} bytes as BatchWitness: {}", raw.len(), err))?;
+
+    let entries = witness
+        .entries
+        .iter()
+        .map(|entry| {
+            let mut fields = Map::new();
+            fields.insert("input_count".to_owned(), Value::from(entry.input_count));
+            fields.insert("output_count".to_owned(), Value::from(entry.output_count));
+            fields.insert(
+                "witness".to_owned(),
+                decode_witness(&entry.witness).unwrap_or_else(|_| Value::String(to_hex(&entry.witness))),
+            );
+            Value::Object(fields)
+        })
+        .collect();
+
+    let mut fields = Map::new();
+    fields.insert("pattern".to_owned(), Value::String(format!("{:?}", Pattern::Batch)));
+    fields.insert("entries".to_owned(), Value::Array(entries));
+
+    Ok(Value::Object(fields))
+}
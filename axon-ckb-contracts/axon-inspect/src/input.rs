@@ -0,0 +1,87 @@
+use std::{fs, path::Path};
+
+use ckb_types::{packed::WitnessArgs, prelude::*};
+use serde_json::Value;
+
+pub fn to_hex(bytes: &[u8]) -> String {
+    let mut hex = String::from("0x");
+    for byte in bytes {
+        hex.push_str(&format!("{:02x}", byte));
+    }
+    hex
+}
+
+pub fn from_hex(hex: &str) -> Result<Vec<u8>, String> {
+    let hex = hex.trim();
+    let hex = hex.strip_prefix("0x").unwrap_or(hex);
+    if hex.len() % 2 != 0 {
+        return Err(format!("odd length hex {}", hex));
+    }
+
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| format!("invalid hex {}", hex)))
+        .collect()
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum Field {
+    Data,
+    LockArgs,
+    TypeArgs,
+    Witness,
+}
+
+/*
+an input is one of
+    0x..            hex bytes
+    @path           a file of raw bytes or hex text
+    tx.json:index   the output (or the witness) at index of a transaction json,
+                    either a transaction or an rpc/ckb-cli object holding it under "transaction"
+ */
+pub fn load(input: &str, field: Field) -> Result<Vec<u8>, String> {
+    if let Some(path) = input.strip_prefix('@') {
+        let raw = fs::read(path).map_err(|err| format!("unable to read {}: {}", path, err))?;
+        return match std::str::from_utf8(&raw).ok().map(from_hex) {
+            Some(Ok(bytes)) => Ok(bytes),
+            _ => Ok(raw),
+        };
+    }
+
+    if let Some(split) = input.rfind(':') {
+        let (path, index) = (&input[..split], &input[split + 1..]);
+        if let (true, Ok(index)) = (Path::new(path).is_file(), index.parse::<usize>()) {
+            return load_from_tx(path, index, field);
+        }
+    }
+
+    from_hex(input)
+}
+
+fn load_from_tx(path: &str, index: usize, field: Field) -> Result<Vec<u8>, String> {
+    let raw = fs::read_to_string(path).map_err(|err| format!("unable to read {}: {}", path, err))?;
+    let json: Value = serde_json::from_str(&raw).map_err(|err| format!("invalid json {}: {}", path, err))?;
+    let tx = json.get("transaction").unwrap_or(&json);
+
+    let hex_at = |pointer: String| {
+        tx.pointer(&pointer)
+            .and_then(Value::as_str)
+            .ok_or_else(|| format!("{} not found in {}", pointer, path))
+            .and_then(from_hex)
+    };
+
+    match field {
+        Field::Data => hex_at(format!("/outputs_data/{}", index)),
+        Field::LockArgs => hex_at(format!("/outputs/{}/lock/args", index)),
+        Field::TypeArgs => hex_at(format!("/outputs/{}/type/args", index)),
+        Field::Witness => hex_at(format!("/witnesses/{}", index)),
+    }
+}
+
+// the code cell witness is the input type of WitnessArgs, bare code cell witnesses are taken as is
+pub fn unwrap_witness(raw: Vec<u8>) -> Vec<u8> {
+    match WitnessArgs::from_slice(&raw).ok().and_then(|args| args.input_type().to_opt()) {
+        Some(input_type) => input_type.raw_data().to_vec(),
+        None => raw,
+    }
+}
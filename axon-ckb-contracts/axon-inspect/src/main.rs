@@ -0,0 +1,75 @@
+//! Decodes axon cells and code cell witnesses to json.
+//!
+//! See `USAGE` for the commands, every decoder is a `FromRaw` impl of `common-raw`.
+
+mod debug_json;
+mod decode;
+mod input;
+
+use std::{env, process};
+
+use serde_json::Value;
+
+use decode::{decode_witness, find_decoder, CELL_DATA, LOCK_ARGS, TYPE_ARGS};
+use input::{load, unwrap_witness, Field};
+
+const USAGE: &str = "USAGE:
+    axon-inspect witness <INPUT>            decode a code cell witness, the pattern is its first byte
+    axon-inspect data <TYPE> <INPUT>        decode cell data
+    axon-inspect lock-args <TYPE> <INPUT>   decode lock script args
+    axon-inspect type-args <TYPE> <INPUT>   decode type script args
+    axon-inspect types                      list the known types
+    axon-inspect error <CODE>               explain an exit code of the code cell
+
+INPUT:
+    0x...                   hex bytes
+    @<path>                 a file holding raw bytes or hex text
+    <tx.json>:<index>       output <index> of a transaction json, witness <index> for `witness`";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match run(&args) {
+        Ok(value) => println!("{}", serde_json::to_string_pretty(&value).expect("json")),
+        Err(err) => {
+            eprintln!("error: {}\n\n{}", err, USAGE);
+            process::exit(1);
+        }
+    }
+}
+
+fn run(args: &[String]) -> Result<Value, String> {
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    match args.as_slice() {
+        ["witness", input] => decode_witness(&unwrap_witness(load(input, Field::Witness)?)),
+        ["data", name, input] => find_decoder(CELL_DATA, name)?(&load(input, Field::Data)?),
+        ["lock-args", name, input] => find_decoder(LOCK_ARGS, name)?(&load(input, Field::LockArgs)?),
+        ["type-args", name, input] => find_decoder(TYPE_ARGS, name)?(&load(input, Field::TypeArgs)?),
+        ["types"] => Ok(types()),
+        ["error", code] => explain_error(code),
+        _ => Err("invalid arguments".to_owned()),
+    }
+}
+
+fn types() -> Value {
+    let names = |table: &[(&str, decode::Decoder)]| Value::from(table.iter().map(|(name, _)| *name).collect::<Vec<_>>());
+
+    let mut types = serde_json::Map::new();
+    types.insert("data".to_owned(), names(CELL_DATA));
+    types.insert("lock-args".to_owned(), names(LOCK_ARGS));
+    types.insert("type-args".to_owned(), names(TYPE_ARGS));
+
+    Value::Object(types)
+}
+
+fn explain_error(code: &str) -> Result<Value, String> {
+    let code: i8 = code.parse().map_err(|_| format!("invalid exit code {}", code))?;
+    let error = common_raw::find_error(code).ok_or(format!("unknown exit code {}", code))?;
+
+    let mut explained = serde_json::Map::new();
+    explained.insert("code".to_owned(), Value::from(error.code));
+    explained.insert("name".to_owned(), Value::from(error.name));
+    explained.insert("message".to_owned(), Value::from(error.message));
+    Ok(Value::Object(explained))
+}
@@ -0,0 +1,34 @@
+use ckb_types::{bytes::Bytes, packed::CellInput};
+
+use common_raw::{layout::admin_create_sidechain as layout, witness::admin_create_sidechain::AdminCreateSidechainWitness};
+
+use crate::{
+    error::Error,
+    skeleton::{witness_bytes, Cell, Environment, PatternTx, TxSkeleton},
+};
+
+/// Admin creates a sidechain from plain ckb, the config and state cells are born here.
+#[derive(Debug)]
+pub struct AdminCreateSidechain {
+    pub env:              Environment,
+    pub ckb:              CellInput,
+    pub sidechain_config: Cell,
+    pub sidechain_state:  Cell,
+    pub witness:          AdminCreateSidechainWitness,
+}
+
+impl PatternTx for AdminCreateSidechain {
+    fn skeleton(&self) -> Result<TxSkeleton, Error> {
+        let mut tx = TxSkeleton::new(&self.env)?;
+        tx.input(layout::CKB_INPUT, &self.ckb)?;
+        tx.output(layout::SIDECHAIN_CONFIG_OUTPUT, &self.sidechain_config)?;
+        tx.output(layout::SIDECHAIN_STATE_OUTPUT, &self.sidechain_state)?;
+        tx.check_counts(layout::INPUT_COUNT, layout::OUTPUT_COUNT)?;
+
+        Ok(tx)
+    }
+
+    fn witness(&self) -> Result<Bytes, Error> {
+        Ok(witness_bytes(&self.witness))
+    }
+}
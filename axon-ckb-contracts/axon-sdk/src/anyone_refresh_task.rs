@@ -0,0 +1,45 @@
+use ckb_types::{bytes::Bytes, packed::Byte32};
+
+use common_raw::{layout::anyone_refresh_task as layout, witness::anyone_refresh_task::AnyoneRefreshTaskWitness};
+
+use crate::{
+    error::Error,
+    skeleton::{witness_bytes, Environment, PatternTx, Transition, TxSkeleton},
+};
+
+/// Anyone reassigns the tasks whose checkers did not respond in time.
+///
+/// `reference_header` becomes header dep 0 and gives the current time, `task_headers` are the
+/// headers of the blocks the task inputs were committed in.
+#[derive(Debug)]
+pub struct AnyoneRefreshTask {
+    pub env:              Environment,
+    pub reference_header: Byte32,
+    pub sidechain_config: Transition,
+    pub sidechain_state:  Transition,
+    pub tasks:            Vec<Transition>,
+    pub task_headers:     Vec<Byte32>,
+    pub witness:          AnyoneRefreshTaskWitness,
+}
+
+impl PatternTx for AnyoneRefreshTask {
+    fn skeleton(&self) -> Result<TxSkeleton, Error> {
+        let mut tx = TxSkeleton::new(&self.env)?;
+        tx.header_dep(&self.reference_header);
+        for header in &self.task_headers {
+            tx.header_dep(header);
+        }
+        tx.transition(layout::CONFIG_INPUT, layout::CONFIG_OUTPUT, &self.sidechain_config)?;
+        tx.transition(layout::STATE_INPUT, layout::STATE_OUTPUT, &self.sidechain_state)?;
+        for (index, task) in self.tasks.iter().enumerate() {
+            tx.transition(layout::FIXED_CELLS + index, layout::FIXED_CELLS + index, task)?;
+        }
+        tx.check_counts(layout::FIXED_CELLS + self.tasks.len(), layout::FIXED_CELLS + self.tasks.len())?;
+
+        Ok(tx)
+    }
+
+    fn witness(&self) -> Result<Bytes, Error> {
+        Ok(witness_bytes(&self.witness))
+    }
+}
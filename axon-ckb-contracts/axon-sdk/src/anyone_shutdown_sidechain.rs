@@ -0,0 +1,40 @@
+use ckb_types::{bytes::Bytes, packed::CellInput};
+
+use common_raw::{layout::anyone_shutdown_sidechain as layout, witness::anyone_shutdown_sidechain::AnyoneShutdownSidechainWitness};
+
+use crate::{
+    error::Error,
+    skeleton::{witness_bytes, Environment, PatternTx, Transition, TxSkeleton},
+};
+
+/// Anyone shuts a sidechain down with the tasks proving the collator misbehaved.
+#[derive(Debug)]
+pub struct AnyoneShutdownSidechain {
+    pub env:              Environment,
+    pub sidechain_config: Transition,
+    pub sidechain_fee:    Transition,
+    pub tasks:            Vec<CellInput>,
+    pub witness:          AnyoneShutdownSidechainWitness,
+}
+
+impl PatternTx for AnyoneShutdownSidechain {
+    fn skeleton(&self) -> Result<TxSkeleton, Error> {
+        let mut tx = TxSkeleton::new(&self.env)?;
+        tx.transition(
+            layout::SIDECHAIN_CONFIG_INPUT,
+            layout::SIDECHAIN_CONFIG_OUTPUT,
+            &self.sidechain_config,
+        )?;
+        tx.transition(layout::SIDECHAIN_FEE_INPUT, layout::SIDECHAIN_FEE_OUTPUT, &self.sidechain_fee)?;
+        for (index, task) in self.tasks.iter().enumerate() {
+            tx.input(layout::FIXED_INPUT_CELLS + index, task)?;
+        }
+        tx.check_counts(layout::FIXED_INPUT_CELLS + self.tasks.len(), layout::OUTPUT_COUNT)?;
+
+        Ok(tx)
+    }
+
+    fn witness(&self) -> Result<Bytes, Error> {
+        Ok(witness_bytes(&self.witness))
+    }
+}
@@ -0,0 +1,58 @@
+use ckb_types::bytes::Bytes;
+
+use common_raw::{
+    pattern::Pattern,
+    witness::batch::{BatchEntry, BatchWitness},
+    FromRaw,
+};
+
+use crate::{
+    error::Error,
+    skeleton::{witness_bytes, Environment, PatternTx, TxSkeleton},
+};
+
+/// Several patterns sharing one code cell, the cells of each entry follow the previous entry.
+///
+/// Entries are expected to be built on the same `env`, their cell deps are merged by index.
+pub struct Batch {
+    pub env:     Environment,
+    pub entries: Vec<Box<dyn PatternTx>>,
+}
+
+impl PatternTx for Batch {
+    fn skeleton(&self) -> Result<TxSkeleton, Error> {
+        let mut tx = TxSkeleton::new(&self.env)?;
+        for entry in &self.entries {
+            is_batch_entry(&entry.witness()?)?;
+            tx.append(entry.skeleton()?)?;
+        }
+
+        Ok(tx)
+    }
+
+    fn witness(&self) -> Result<Bytes, Error> {
+        let mut witness = BatchWitness::default();
+        for entry in &self.entries {
+            let skeleton = entry.skeleton()?;
+            witness.entries.push(BatchEntry {
+                witness:      entry.witness()?.to_vec(),
+                input_count:  skeleton.input_count() - 1,
+                output_count: skeleton.output_count() - 1,
+            });
+        }
+
+        Ok(witness_bytes(&witness))
+    }
+}
+
+// mirrors the patterns rejected by the code cell
+fn is_batch_entry(witness: &[u8]) -> Result<(), Error> {
+    match witness.get(0..1).and_then(|raw| Pattern::from_raw(raw).ok()) {
+        Some(Pattern::AdminCreateSidechain)
+        | Some(Pattern::AnyoneShutdownSidechain)
+        | Some(Pattern::CollatorShutdownSidechain)
+        | Some(Pattern::Batch)
+        | None => Err(Error::BatchEntryInvalid),
+        Some(_) => Ok(()),
+    }
+}
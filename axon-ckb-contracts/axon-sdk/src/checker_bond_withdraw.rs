@@ -0,0 +1,32 @@
+use ckb_types::{bytes::Bytes, packed::CellInput};
+
+use common_raw::{layout::checker_bond_withdraw as layout, witness::checker_bond_withdraw::CheckerBondWithdrawWitness};
+
+use crate::{
+    error::Error,
+    skeleton::{witness_bytes, Cell, Environment, PatternTx, TxSkeleton},
+};
+
+/// A checker takes its bond back as muse tokens.
+#[derive(Debug)]
+pub struct CheckerBondWithdraw {
+    pub env:     Environment,
+    pub bond:    CellInput,
+    pub token:   Cell,
+    pub witness: CheckerBondWithdrawWitness,
+}
+
+impl PatternTx for CheckerBondWithdraw {
+    fn skeleton(&self) -> Result<TxSkeleton, Error> {
+        let mut tx = TxSkeleton::new(&self.env)?;
+        tx.input(layout::BOND_INPUT, &self.bond)?;
+        tx.output(layout::TOKEN_OUTPUT, &self.token)?;
+        tx.check_counts(layout::INPUT_COUNT, layout::OUTPUT_COUNT)?;
+
+        Ok(tx)
+    }
+
+    fn witness(&self) -> Result<Bytes, Error> {
+        Ok(witness_bytes(&self.witness))
+    }
+}
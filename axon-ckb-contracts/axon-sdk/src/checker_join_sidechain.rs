@@ -0,0 +1,34 @@
+use ckb_types::bytes::Bytes;
+
+use common_raw::{layout::checker_join_sidechain as layout, witness::checker_join_sidechain::CheckerJoinSidechainWitness};
+
+use crate::{
+    error::Error,
+    skeleton::{witness_bytes, Cell, Environment, PatternTx, Transition, TxSkeleton},
+};
+
+/// A checker joins a sidechain with its bond, the checker info cell is born here.
+#[derive(Debug)]
+pub struct CheckerJoinSidechain {
+    pub env:              Environment,
+    pub sidechain_config: Transition,
+    pub checker_bond:     Transition,
+    pub checker_info:     Cell,
+    pub witness:          CheckerJoinSidechainWitness,
+}
+
+impl PatternTx for CheckerJoinSidechain {
+    fn skeleton(&self) -> Result<TxSkeleton, Error> {
+        let mut tx = TxSkeleton::new(&self.env)?;
+        tx.transition(layout::CONFIG_INPUT, layout::CONFIG_OUTPUT, &self.sidechain_config)?;
+        tx.transition(layout::CHECKER_BOND_INPUT, layout::CHECKER_BOND_OUTPUT, &self.checker_bond)?;
+        tx.output(layout::CHECKER_INFO_OUTPUT, &self.checker_info)?;
+        tx.check_counts(layout::INPUT_COUNT, layout::OUTPUT_COUNT)?;
+
+        Ok(tx)
+    }
+
+    fn witness(&self) -> Result<Bytes, Error> {
+        Ok(witness_bytes(&self.witness))
+    }
+}
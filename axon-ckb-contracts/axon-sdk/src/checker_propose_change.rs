@@ -0,0 +1,33 @@
+use ckb_types::{bytes::Bytes, packed::CellDep};
+
+use common_raw::{layout::checker_propose_change as layout, witness::checker_propose_change::CheckerProposeChangeWitness};
+
+use crate::{
+    error::Error,
+    skeleton::{witness_bytes, Cell, Environment, PatternTx, TxSkeleton},
+};
+
+/// An activated checker proposes a change of its sidechain, the proposal cell is born with its
+/// vote and the config nonce of the sidechain config dep.
+#[derive(Debug)]
+pub struct CheckerProposeChange {
+    pub env:                  Environment,
+    pub sidechain_config_dep: CellDep,
+    pub proposal:             Cell,
+    pub witness:              CheckerProposeChangeWitness,
+}
+
+impl PatternTx for CheckerProposeChange {
+    fn skeleton(&self) -> Result<TxSkeleton, Error> {
+        let mut tx = TxSkeleton::new(&self.env)?;
+        tx.cell_dep(layout::SIDECHAIN_CONFIG_DEP, &self.sidechain_config_dep)?;
+        tx.output(layout::PROPOSAL_OUTPUT, &self.proposal)?;
+        tx.check_counts(layout::INPUT_COUNT, layout::OUTPUT_COUNT)?;
+
+        Ok(tx)
+    }
+
+    fn witness(&self) -> Result<Bytes, Error> {
+        Ok(witness_bytes(&self.witness))
+    }
+}
@@ -0,0 +1,45 @@
+use ckb_types::{bytes::Bytes, packed::CellDep};
+
+use common_raw::{layout::checker_publish_challenge as layout, witness::checker_publish_challenge::CheckerPublishChallengeWitness};
+
+use crate::{
+    error::Error,
+    skeleton::{witness_bytes, Cell, Environment, PatternTx, Transition, TxSkeleton},
+};
+
+/// A checker challenges a task, the task is copied into `challenges` for the other checkers.
+///
+/// `witness.challenge_count` counts the task output together with the challenges.
+#[derive(Debug)]
+pub struct CheckerPublishChallenge {
+    pub env:                  Environment,
+    pub sidechain_config_dep: CellDep,
+    pub sidechain_state:      Transition,
+    pub checker_info:         Transition,
+    pub task:                 Transition,
+    pub challenges:           Vec<Cell>,
+    pub witness:              CheckerPublishChallengeWitness,
+}
+
+impl PatternTx for CheckerPublishChallenge {
+    fn skeleton(&self) -> Result<TxSkeleton, Error> {
+        let mut tx = TxSkeleton::new(&self.env)?;
+        tx.cell_dep(self.witness.sidechain_config_dep_index, &self.sidechain_config_dep)?;
+        tx.transition(layout::SIDECHAIN_STATE_INPUT, layout::SIDECHAIN_STATE_OUTPUT, &self.sidechain_state)?;
+        tx.transition(layout::CHECKER_INFO_INPUT, layout::CHECKER_INFO_OUTPUT, &self.checker_info)?;
+        tx.transition(layout::FIRST_TASK_INPUT, layout::FIRST_TASK_OUTPUT, &self.task)?;
+        for (index, challenge) in self.challenges.iter().enumerate() {
+            tx.output(layout::FIRST_TASK_OUTPUT + 1 + index, challenge)?;
+        }
+        tx.check_counts(
+            layout::INPUT_COUNT,
+            layout::FIXED_OUTPUT_CELLS + self.witness.challenge_count as usize,
+        )?;
+
+        Ok(tx)
+    }
+
+    fn witness(&self) -> Result<Bytes, Error> {
+        Ok(witness_bytes(&self.witness))
+    }
+}
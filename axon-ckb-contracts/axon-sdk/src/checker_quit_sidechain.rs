@@ -0,0 +1,36 @@
+use ckb_types::{bytes::Bytes, packed::CellDep};
+
+use common_raw::{layout::checker_quit_sidechain as layout, witness::checker_quit_sidechain::CheckerQuitSidechainWitness};
+
+use crate::{
+    error::Error,
+    skeleton::{witness_bytes, Environment, PatternTx, Transition, TxSkeleton},
+};
+
+/// A checker leaves a sidechain, its checker info cell stays until the bond is released.
+#[derive(Debug)]
+pub struct CheckerQuitSidechain {
+    pub env:                 Environment,
+    pub sidechain_state_dep: CellDep,
+    pub sidechain_config:    Transition,
+    pub checker_bond:        Transition,
+    pub checker_info:        Transition,
+    pub witness:             CheckerQuitSidechainWitness,
+}
+
+impl PatternTx for CheckerQuitSidechain {
+    fn skeleton(&self) -> Result<TxSkeleton, Error> {
+        let mut tx = TxSkeleton::new(&self.env)?;
+        tx.cell_dep(layout::STATE_DEP, &self.sidechain_state_dep)?;
+        tx.transition(layout::CONFIG_INPUT, layout::CONFIG_OUTPUT, &self.sidechain_config)?;
+        tx.transition(layout::CHECKER_BOND_INPUT, layout::CHECKER_BOND_OUTPUT, &self.checker_bond)?;
+        tx.transition(layout::CHECKER_INFO_INPUT, layout::CHECKER_INFO_OUTPUT, &self.checker_info)?;
+        tx.check_counts(layout::INPUT_COUNT, layout::OUTPUT_COUNT)?;
+
+        Ok(tx)
+    }
+
+    fn witness(&self) -> Result<Bytes, Error> {
+        Ok(witness_bytes(&self.witness))
+    }
+}
@@ -0,0 +1,46 @@
+use ckb_types::bytes::Bytes;
+
+use common_raw::{layout::checker_take_beneficiary as layout, witness::checker_take_beneficiary::CheckerTakeBeneficiaryWitness};
+
+use crate::{
+    error::Error,
+    skeleton::{witness_bytes, Cell, Environment, PatternTx, Transition, TxSkeleton},
+};
+
+/// A checker takes its unpaid fee from the sidechain fee cell, the share of its delegators is
+/// added to every delegation cell in `delegations`.
+#[derive(Debug)]
+pub struct CheckerTakeBeneficiary {
+    pub env:           Environment,
+    pub checker_info:  Transition,
+    pub sidechain_fee: Transition,
+    pub muse_token:    Cell,
+    pub delegations:   Vec<Transition>,
+    pub witness:       CheckerTakeBeneficiaryWitness,
+}
+
+impl PatternTx for CheckerTakeBeneficiary {
+    fn skeleton(&self) -> Result<TxSkeleton, Error> {
+        let mut tx = TxSkeleton::new(&self.env)?;
+        tx.transition(layout::CHECKER_INFO_INPUT, layout::CHECKER_INFO_OUTPUT, &self.checker_info)?;
+        tx.transition(layout::FEE_INPUT, layout::FEE_OUTPUT, &self.sidechain_fee)?;
+        tx.output(layout::MUSE_OUTPUT, &self.muse_token)?;
+        for (index, delegation) in self.delegations.iter().enumerate() {
+            tx.transition(
+                layout::DELEGATION_INPUTS_START + index,
+                layout::DELEGATION_OUTPUTS_START + index,
+                delegation,
+            )?;
+        }
+        tx.check_counts(
+            layout::INPUT_COUNT + self.delegations.len(),
+            layout::OUTPUT_COUNT + self.delegations.len(),
+        )?;
+
+        Ok(tx)
+    }
+
+    fn witness(&self) -> Result<Bytes, Error> {
+        Ok(witness_bytes(&self.witness))
+    }
+}
@@ -0,0 +1,34 @@
+use ckb_types::{bytes::Bytes, packed::CellDep};
+
+use common_raw::{layout::checker_vote as layout, witness::checker_vote::CheckerVoteWitness};
+
+use crate::{
+    error::Error,
+    skeleton::{witness_bytes, Environment, PatternTx, Transition, TxSkeleton},
+};
+
+/// A checker votes on a task assigned to it.
+#[derive(Debug)]
+pub struct CheckerVote {
+    pub env:                  Environment,
+    pub sidechain_config_dep: CellDep,
+    pub checker_info:         Transition,
+    pub task:                 Transition,
+    pub witness:              CheckerVoteWitness,
+}
+
+impl PatternTx for CheckerVote {
+    fn skeleton(&self) -> Result<TxSkeleton, Error> {
+        let mut tx = TxSkeleton::new(&self.env)?;
+        tx.cell_dep(self.witness.sidechain_config_dep_index, &self.sidechain_config_dep)?;
+        tx.transition(layout::CHECKER_INFO_INPUT, layout::CHECKER_INFO_OUTPUT, &self.checker_info)?;
+        tx.transition(layout::TASK_INPUT, layout::TASK_OUTPUT, &self.task)?;
+        tx.check_counts(layout::INPUT_COUNT, layout::OUTPUT_COUNT)?;
+
+        Ok(tx)
+    }
+
+    fn witness(&self) -> Result<Bytes, Error> {
+        Ok(witness_bytes(&self.witness))
+    }
+}
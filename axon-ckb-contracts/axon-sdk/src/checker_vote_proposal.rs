@@ -0,0 +1,32 @@
+use ckb_types::{bytes::Bytes, packed::CellDep};
+
+use common_raw::{layout::checker_vote_proposal as layout, witness::checker_vote_proposal::CheckerVoteProposalWitness};
+
+use crate::{
+    error::Error,
+    skeleton::{witness_bytes, Environment, PatternTx, Transition, TxSkeleton},
+};
+
+/// An activated checker adds its vote to a proposal.
+#[derive(Debug)]
+pub struct CheckerVoteProposal {
+    pub env:                  Environment,
+    pub sidechain_config_dep: CellDep,
+    pub proposal:             Transition,
+    pub witness:              CheckerVoteProposalWitness,
+}
+
+impl PatternTx for CheckerVoteProposal {
+    fn skeleton(&self) -> Result<TxSkeleton, Error> {
+        let mut tx = TxSkeleton::new(&self.env)?;
+        tx.cell_dep(layout::SIDECHAIN_CONFIG_DEP, &self.sidechain_config_dep)?;
+        tx.transition(layout::PROPOSAL_INPUT, layout::PROPOSAL_OUTPUT, &self.proposal)?;
+        tx.check_counts(layout::INPUT_COUNT, layout::OUTPUT_COUNT)?;
+
+        Ok(tx)
+    }
+
+    fn witness(&self) -> Result<Bytes, Error> {
+        Ok(witness_bytes(&self.witness))
+    }
+}
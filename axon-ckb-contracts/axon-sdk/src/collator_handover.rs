@@ -0,0 +1,39 @@
+use ckb_types::{bytes::Bytes, packed::CellInput};
+
+use common_raw::{layout::collator_handover as layout, witness::collator_handover::CollatorHandoverWitness};
+
+use crate::{
+    error::Error,
+    skeleton::{witness_bytes, Cell, Environment, PatternTx, Transition, TxSkeleton},
+};
+
+/// The collator or the admin hands the collator role over to a new collator, which posts its own
+/// bond in the sudt named by the sidechain config.
+#[derive(Debug)]
+pub struct CollatorHandover {
+    pub env:              Environment,
+    pub sidechain_config: Transition,
+    pub bond_token:       CellInput,
+    pub sidechain_bond:   Cell,
+    pub witness:          CollatorHandoverWitness,
+}
+
+impl PatternTx for CollatorHandover {
+    fn skeleton(&self) -> Result<TxSkeleton, Error> {
+        let mut tx = TxSkeleton::new(&self.env)?;
+        tx.transition(
+            layout::SIDECHAIN_CONFIG_INPUT,
+            layout::SIDECHAIN_CONFIG_OUTPUT,
+            &self.sidechain_config,
+        )?;
+        tx.input(layout::TOKEN_INPUT, &self.bond_token)?;
+        tx.output(layout::SIDECHAIN_BOND_OUTPUT, &self.sidechain_bond)?;
+        tx.check_counts(layout::INPUT_COUNT, layout::OUTPUT_COUNT)?;
+
+        Ok(tx)
+    }
+
+    fn witness(&self) -> Result<Bytes, Error> {
+        Ok(witness_bytes(&self.witness))
+    }
+}
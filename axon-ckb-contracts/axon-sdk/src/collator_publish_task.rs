@@ -0,0 +1,46 @@
+use ckb_types::{
+    bytes::Bytes,
+    packed::{CellDep, CellInput},
+};
+
+use common_raw::{layout::collator_publish_task as layout, witness::collator_publish_task::CollatorPublishTaskWitness};
+
+use crate::{
+    error::Error,
+    skeleton::{witness_bytes, Cell, Environment, PatternTx, Transition, TxSkeleton},
+};
+
+/// The collator publishes a block range for checking, one task for each of `commit_threshold`
+/// checkers.
+#[derive(Debug)]
+pub struct CollatorPublishTask {
+    pub env:                  Environment,
+    pub sidechain_config_dep: CellDep,
+    pub sidechain_bond_dep:   CellDep,
+    pub sidechain_state:      Transition,
+    pub sidechain_fee:        Transition,
+    pub token:                CellInput,
+    pub tasks:                Vec<Cell>,
+    pub witness:              CollatorPublishTaskWitness,
+}
+
+impl PatternTx for CollatorPublishTask {
+    fn skeleton(&self) -> Result<TxSkeleton, Error> {
+        let mut tx = TxSkeleton::new(&self.env)?;
+        tx.cell_dep(layout::SIDECHAIN_CONFIG_DEP, &self.sidechain_config_dep)?;
+        tx.cell_dep(layout::SIDECHAIN_BOND_DEP, &self.sidechain_bond_dep)?;
+        tx.transition(layout::SIDECHAIN_STATE_INPUT, layout::SIDECHAIN_STATE_OUTPUT, &self.sidechain_state)?;
+        tx.transition(layout::SIDECHAIN_FEE_INPUT, layout::SIDECHAIN_FEE_OUTPUT, &self.sidechain_fee)?;
+        tx.input(layout::TOKEN_INPUT, &self.token)?;
+        for (index, task) in self.tasks.iter().enumerate() {
+            tx.output(layout::FIXED_OUTPUT_CELLS + index, task)?;
+        }
+        tx.check_counts(layout::INPUT_COUNT, layout::FIXED_OUTPUT_CELLS + self.tasks.len())?;
+
+        Ok(tx)
+    }
+
+    fn witness(&self) -> Result<Bytes, Error> {
+        Ok(witness_bytes(&self.witness))
+    }
+}
@@ -0,0 +1,44 @@
+use ckb_types::{bytes::Bytes, packed::CellDep};
+
+use common_raw::{layout::collator_shutdown_sidechain as layout, witness::collator_shutdown_sidechain::CollatorShutdownSidechainWitness};
+
+use crate::{
+    error::Error,
+    skeleton::{witness_bytes, Environment, PatternTx, Transition, TxSkeleton},
+};
+
+/// The collators shut their own sidechain down, the signer and the owners of `approvals` count
+/// towards `collator_threshold`.
+#[derive(Debug)]
+pub struct CollatorShutdownSidechain {
+    pub env:                 Environment,
+    pub sidechain_state_dep: CellDep,
+    pub sidechain_config:    Transition,
+    pub approvals:           Vec<Transition>,
+    pub witness:             CollatorShutdownSidechainWitness,
+}
+
+impl PatternTx for CollatorShutdownSidechain {
+    fn skeleton(&self) -> Result<TxSkeleton, Error> {
+        let mut tx = TxSkeleton::new(&self.env)?;
+        tx.cell_dep(layout::SIDECHAIN_STATE_DEP, &self.sidechain_state_dep)?;
+        tx.transition(
+            layout::SIDECHAIN_CONFIG_INPUT,
+            layout::SIDECHAIN_CONFIG_OUTPUT,
+            &self.sidechain_config,
+        )?;
+        for (index, approval) in self.approvals.iter().enumerate() {
+            tx.transition(layout::APPROVAL_INPUTS_START + index, layout::OUTPUT_COUNT + index, approval)?;
+        }
+        tx.check_counts(
+            layout::INPUT_COUNT + self.approvals.len(),
+            layout::OUTPUT_COUNT + self.approvals.len(),
+        )?;
+
+        Ok(tx)
+    }
+
+    fn witness(&self) -> Result<Bytes, Error> {
+        Ok(witness_bytes(&self.witness))
+    }
+}
@@ -0,0 +1,69 @@
+use ckb_types::{bytes::Bytes, packed::CellInput};
+
+use common_raw::{layout::collator_submit_tasks as layout, witness::collator_submit_tasks::CollatorSubmitTasksWitness};
+
+use crate::{
+    error::{Error, Slot},
+    skeleton::{witness_bytes, Environment, PatternTx, Transition, TxSkeleton},
+};
+
+/// The collator collects the voted tasks of a job, all of them or a chunk of at most
+/// `submit_task_limit`, and settles fees of their checkers. Checkers who have not revealed
+/// by then are punished with `reveal_punish_points`. The job has to be waiting in the sidechain
+/// state, the last chunk removes it.
+///
+/// A signed job, with `witness.signed_job` set, has no `tasks`. The `commit_threshold` checkers
+/// drawn for it with `SeedPurpose::SignedJob` each sign the `SignedVote::message` of their vote
+/// off-chain instead, carried in `witness.signatures`, and are paid on their checker info cells.
+/// `witness.signatures` and `checker_infos` are in the order of `witness.commit`.
+#[derive(Debug)]
+pub struct CollatorSubmitTasks {
+    pub env:              Environment,
+    pub sidechain_config: Transition,
+    pub sidechain_state:  Transition,
+    pub sidechain_fee:    Transition,
+    pub tasks:            Vec<CellInput>,
+    pub checker_infos:    Vec<Transition>,
+    pub witness:          CollatorSubmitTasksWitness,
+}
+
+impl PatternTx for CollatorSubmitTasks {
+    fn skeleton(&self) -> Result<TxSkeleton, Error> {
+        let mut tx = TxSkeleton::new(&self.env)?;
+        tx.transition(
+            layout::SIDECHAIN_CONFIG_INPUT,
+            layout::SIDECHAIN_CONFIG_OUTPUT,
+            &self.sidechain_config,
+        )?;
+        tx.transition(layout::SIDECHAIN_STATE_INPUT, layout::SIDECHAIN_STATE_OUTPUT, &self.sidechain_state)?;
+        tx.transition(layout::SIDECHAIN_FEE_INPUT, layout::SIDECHAIN_FEE_OUTPUT, &self.sidechain_fee)?;
+        for (index, task) in self.tasks.iter().enumerate() {
+            tx.input(layout::FIXED_INPUT_CELLS + index, task)?;
+        }
+        // every signature comes with the checker info of its checker
+        if self.checker_infos.len() != self.witness.signatures.len() {
+            return Err(Error::CellCountMismatch {
+                slot:     Slot::Input,
+                expected: self.witness.signatures.len(),
+                actual:   self.checker_infos.len(),
+            });
+        }
+        for (index, checker_info) in self.checker_infos.iter().enumerate() {
+            tx.transition(
+                layout::SIGNED_VOTE_INPUTS_START + index,
+                layout::SIGNED_VOTE_OUTPUTS_START + index,
+                checker_info,
+            )?;
+        }
+        tx.check_counts(
+            layout::FIXED_INPUT_CELLS + self.tasks.len() + self.checker_infos.len(),
+            layout::OUTPUT_COUNT + self.checker_infos.len(),
+        )?;
+
+        Ok(tx)
+    }
+
+    fn witness(&self) -> Result<Bytes, Error> {
+        Ok(witness_bytes(&self.witness))
+    }
+}
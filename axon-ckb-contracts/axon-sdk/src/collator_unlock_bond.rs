@@ -0,0 +1,39 @@
+use ckb_types::{
+    bytes::Bytes,
+    packed::{CellDep, CellInput},
+};
+
+use common_raw::{layout::collator_unlock_bond as layout, witness::collator_unlock_bond::CollatorUnlockBondWitness};
+
+use crate::{
+    error::Error,
+    skeleton::{witness_bytes, Cell, Environment, PatternTx, TxSkeleton},
+};
+
+/// The collator takes its bond back once the sidechain allows it.
+#[derive(Debug)]
+pub struct CollatorUnlockBond {
+    pub env:                  Environment,
+    pub sidechain_config_dep: CellDep,
+    pub sidechain_state_dep:  CellDep,
+    pub sidechain_bond:       CellInput,
+    pub sudt:                 Cell,
+    pub witness:              CollatorUnlockBondWitness,
+}
+
+impl PatternTx for CollatorUnlockBond {
+    fn skeleton(&self) -> Result<TxSkeleton, Error> {
+        let mut tx = TxSkeleton::new(&self.env)?;
+        tx.cell_dep(layout::SIDECHAIN_CONFIG_DEP, &self.sidechain_config_dep)?;
+        tx.cell_dep(layout::SIDECHAIN_STATE_DEP, &self.sidechain_state_dep)?;
+        tx.input(layout::SIDECHAIN_BOND_INPUT, &self.sidechain_bond)?;
+        tx.output(layout::SUDT_OUTPUT, &self.sudt)?;
+        tx.check_counts(layout::INPUT_COUNT, layout::OUTPUT_COUNT)?;
+
+        Ok(tx)
+    }
+
+    fn witness(&self) -> Result<Bytes, Error> {
+        Ok(witness_bytes(&self.witness))
+    }
+}
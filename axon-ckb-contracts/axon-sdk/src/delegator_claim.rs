@@ -0,0 +1,41 @@
+use ckb_types::{bytes::Bytes, packed::CellInput};
+
+use common_raw::{layout::delegator_claim as layout, witness::delegator_claim::DelegatorClaimWitness};
+
+use crate::{
+    error::Error,
+    skeleton::{witness_bytes, Cell, Environment, PatternTx, Transition, TxSkeleton},
+};
+
+/// A delegator takes its delegation back with the fee accrued to it, less the slash if the
+/// checker was jailed.
+#[derive(Debug)]
+pub struct DelegatorClaim {
+    pub env:              Environment,
+    pub sidechain_config: Transition,
+    pub checker_info:     Transition,
+    pub delegation:       CellInput,
+    pub muse_token:       Cell,
+    pub witness:          DelegatorClaimWitness,
+}
+
+impl PatternTx for DelegatorClaim {
+    fn skeleton(&self) -> Result<TxSkeleton, Error> {
+        let mut tx = TxSkeleton::new(&self.env)?;
+        tx.transition(
+            layout::SIDECHAIN_CONFIG_INPUT,
+            layout::SIDECHAIN_CONFIG_OUTPUT,
+            &self.sidechain_config,
+        )?;
+        tx.transition(layout::CHECKER_INFO_INPUT, layout::CHECKER_INFO_OUTPUT, &self.checker_info)?;
+        tx.input(layout::DELEGATION_INPUT, &self.delegation)?;
+        tx.output(layout::TOKEN_OUTPUT, &self.muse_token)?;
+        tx.check_counts(layout::INPUT_COUNT, layout::OUTPUT_COUNT)?;
+
+        Ok(tx)
+    }
+
+    fn witness(&self) -> Result<Bytes, Error> {
+        Ok(witness_bytes(&self.witness))
+    }
+}
@@ -0,0 +1,40 @@
+use ckb_types::{bytes::Bytes, packed::CellInput};
+
+use common_raw::{layout::delegator_delegate as layout, witness::delegator_delegate::DelegatorDelegateWitness};
+
+use crate::{
+    error::Error,
+    skeleton::{witness_bytes, Cell, Environment, PatternTx, Transition, TxSkeleton},
+};
+
+/// A token holder delegates muse tokens to an activated checker, adding them to its bond.
+#[derive(Debug)]
+pub struct DelegatorDelegate {
+    pub env:              Environment,
+    pub sidechain_config: Transition,
+    pub checker_info:     Transition,
+    pub muse_token:       CellInput,
+    pub delegation:       Cell,
+    pub witness:          DelegatorDelegateWitness,
+}
+
+impl PatternTx for DelegatorDelegate {
+    fn skeleton(&self) -> Result<TxSkeleton, Error> {
+        let mut tx = TxSkeleton::new(&self.env)?;
+        tx.transition(
+            layout::SIDECHAIN_CONFIG_INPUT,
+            layout::SIDECHAIN_CONFIG_OUTPUT,
+            &self.sidechain_config,
+        )?;
+        tx.transition(layout::CHECKER_INFO_INPUT, layout::CHECKER_INFO_OUTPUT, &self.checker_info)?;
+        tx.input(layout::TOKEN_INPUT, &self.muse_token)?;
+        tx.output(layout::DELEGATION_OUTPUT, &self.delegation)?;
+        tx.check_counts(layout::INPUT_COUNT, layout::OUTPUT_COUNT)?;
+
+        Ok(tx)
+    }
+
+    fn witness(&self) -> Result<Bytes, Error> {
+        Ok(witness_bytes(&self.witness))
+    }
+}
@@ -0,0 +1,36 @@
+use std::fmt;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Slot {
+    CellDep,
+    Input,
+    Output,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    // a cell was placed twice at the same index
+    SlotOccupied { slot: Slot, index: usize },
+    // an index below the last placed cell was left empty
+    SlotMissing { slot: Slot, index: usize },
+    // the pattern expects a different number of cells than were given
+    CellCountMismatch { slot: Slot, expected: usize, actual: usize },
+    // two batch entries require different cells at the same dep index
+    CellDepConflict(usize),
+    // the entry could not be carried by a batch, see the code cell for the forbidden patterns
+    BatchEntryInvalid,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::SlotOccupied { slot, index } => write!(f, "{:?} {} is placed twice", slot, index),
+            Error::SlotMissing { slot, index } => write!(f, "{:?} {} is missing", slot, index),
+            Error::CellCountMismatch { slot, expected, actual } => write!(f, "expect {} {:?} cells, got {}", expected, slot, actual),
+            Error::CellDepConflict(index) => write!(f, "batch entries require different cell deps at {}", index),
+            Error::BatchEntryInvalid => write!(f, "pattern could not be batched"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,37 @@
+use ckb_types::{bytes::Bytes, packed::CellInput};
+
+use common_raw::{layout::execute_proposal as layout, witness::execute_proposal::ExecuteProposalWitness};
+
+use crate::{
+    error::Error,
+    skeleton::{witness_bytes, Environment, PatternTx, Transition, TxSkeleton},
+};
+
+/// Anyone applies a proposal which reached the checker threshold to the sidechain config, as long
+/// as no other proposal was executed since it was made.
+#[derive(Debug)]
+pub struct ExecuteProposal {
+    pub env:              Environment,
+    pub sidechain_config: Transition,
+    pub proposal:         CellInput,
+    pub witness:          ExecuteProposalWitness,
+}
+
+impl PatternTx for ExecuteProposal {
+    fn skeleton(&self) -> Result<TxSkeleton, Error> {
+        let mut tx = TxSkeleton::new(&self.env)?;
+        tx.transition(
+            layout::SIDECHAIN_CONFIG_INPUT,
+            layout::SIDECHAIN_CONFIG_OUTPUT,
+            &self.sidechain_config,
+        )?;
+        tx.input(layout::PROPOSAL_INPUT, &self.proposal)?;
+        tx.check_counts(layout::INPUT_COUNT, layout::OUTPUT_COUNT)?;
+
+        Ok(tx)
+    }
+
+    fn witness(&self) -> Result<Bytes, Error> {
+        Ok(witness_bytes(&self.witness))
+    }
+}
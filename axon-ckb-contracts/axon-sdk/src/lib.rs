@@ -0,0 +1,34 @@
+//! Off-chain transaction builders for the patterns of the code cell.
+//!
+//! Every builder places its cells with the constants of `common_raw::layout`, which the code cell
+//! checks against as well, and produces the transaction together with the witness bytes of its
+//! pattern.
+
+mod error;
+mod skeleton;
+
+pub mod admin_create_sidechain;
+pub mod anyone_refresh_task;
+pub mod anyone_shutdown_sidechain;
+pub mod batch;
+pub mod checker_bond_withdraw;
+pub mod checker_join_sidechain;
+pub mod checker_propose_change;
+pub mod checker_publish_challenge;
+pub mod checker_quit_sidechain;
+pub mod checker_take_beneficiary;
+pub mod checker_vote;
+pub mod checker_vote_proposal;
+pub mod collator_handover;
+pub mod collator_publish_task;
+pub mod collator_shutdown_sidechain;
+pub mod collator_submit_tasks;
+pub mod collator_unlock_bond;
+pub mod delegator_claim;
+pub mod delegator_delegate;
+pub mod execute_proposal;
+
+pub use common_raw::layout;
+
+pub use error::{Error, Slot};
+pub use skeleton::{witness_bytes, Cell, Environment, PatternTx, Transition, TxSkeleton};
@@ -0,0 +1,240 @@
+use ckb_types::{
+    bytes::Bytes,
+    core::{TransactionBuilder, TransactionView},
+    packed::{Byte32, CellDep, CellInput, CellOutput, WitnessArgs},
+    prelude::*,
+};
+
+use common_raw::{
+    layout::{CODE_CELL, PATTERN_CELL_DEPS_START},
+    witness::checker_proofs::CheckerProofsWitness,
+    Serialize,
+};
+
+use crate::error::{Error, Slot};
+
+/// An output cell with its data.
+#[derive(Debug, Clone)]
+pub struct Cell {
+    pub output: CellOutput,
+    pub data:   Bytes,
+}
+
+/// A live cell consumed by the transaction and the cell it is updated to.
+///
+/// The code cell rejects an output with less capacity or another lock script than its input.
+#[derive(Debug, Clone)]
+pub struct Transition {
+    pub input:  CellInput,
+    pub output: Cell,
+}
+
+/// Cells shared by every pattern: the environment deps and the code cell.
+///
+/// `cell_deps` fill the indices before `PATTERN_CELL_DEPS_START` in order.
+#[derive(Debug, Clone)]
+pub struct Environment {
+    pub cell_deps: Vec<CellDep>,
+    pub code:      Transition,
+}
+
+/// A transaction under construction, where every cell is placed at an explicit index.
+#[derive(Debug, Clone, Default)]
+pub struct TxSkeleton {
+    cell_deps:   Vec<Option<CellDep>>,
+    header_deps: Vec<Byte32>,
+    inputs:      Vec<Option<CellInput>>,
+    outputs:     Vec<Option<Cell>>,
+    proofs:      CheckerProofsWitness,
+}
+
+impl TxSkeleton {
+    pub fn new(env: &Environment) -> Result<Self, Error> {
+        if env.cell_deps.len() != PATTERN_CELL_DEPS_START {
+            return Err(Error::CellCountMismatch {
+                slot:     Slot::CellDep,
+                expected: PATTERN_CELL_DEPS_START,
+                actual:   env.cell_deps.len(),
+            });
+        }
+
+        let mut tx = Self::default();
+        for (index, dep) in env.cell_deps.iter().enumerate() {
+            tx.cell_dep(index, dep)?;
+        }
+        tx.transition(CODE_CELL, CODE_CELL, &env.code)?;
+
+        Ok(tx)
+    }
+
+    pub fn cell_dep(&mut self, index: usize, dep: &CellDep) -> Result<&mut Self, Error> {
+        place(&mut self.cell_deps, Slot::CellDep, index, dep.clone())?;
+        Ok(self)
+    }
+
+    pub fn header_dep(&mut self, header: &Byte32) -> &mut Self {
+        if !self.header_deps.iter().any(|dep| dep.as_slice() == header.as_slice()) {
+            self.header_deps.push(header.clone());
+        }
+        self
+    }
+
+    pub fn input(&mut self, index: usize, input: &CellInput) -> Result<&mut Self, Error> {
+        place(&mut self.inputs, Slot::Input, index, input.clone())?;
+        Ok(self)
+    }
+
+    pub fn output(&mut self, index: usize, output: &Cell) -> Result<&mut Self, Error> {
+        place(&mut self.outputs, Slot::Output, index, output.clone())?;
+        Ok(self)
+    }
+
+    pub fn transition(&mut self, input_index: usize, output_index: usize, transition: &Transition) -> Result<&mut Self, Error> {
+        self.input(input_index, &transition.input)?;
+        self.output(output_index, &transition.output)
+    }
+
+    /// Adds the proofs for a sidechain which commits to its checkers by `checker_set_root`.
+    ///
+    /// They are taken in the order the pattern visits checkers, after the proofs added so far.
+    pub fn checker_proofs(&mut self, proofs: &CheckerProofsWitness) -> &mut Self {
+        self.proofs.record_proofs.extend_from_slice(&proofs.record_proofs);
+        self.proofs.task_record_proofs.extend_from_slice(&proofs.task_record_proofs);
+        self
+    }
+
+    pub fn input_count(&self) -> usize {
+        self.inputs.len()
+    }
+
+    pub fn output_count(&self) -> usize {
+        self.outputs.len()
+    }
+
+    /// Fails if the placed cells do not match the counts the code cell expects.
+    pub fn check_counts(&self, input_count: usize, output_count: usize) -> Result<(), Error> {
+        if self.inputs.len() != input_count {
+            return Err(Error::CellCountMismatch {
+                slot:     Slot::Input,
+                expected: input_count,
+                actual:   self.inputs.len(),
+            });
+        }
+        if self.outputs.len() != output_count {
+            return Err(Error::CellCountMismatch {
+                slot:     Slot::Output,
+                expected: output_count,
+                actual:   self.outputs.len(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Appends the cells of another pattern after the cells placed so far, as a batch entry.
+    ///
+    /// The code cell of `other` is dropped and its deps are merged by index, returns the numbers of
+    /// inputs and outputs appended.
+    pub fn append(&mut self, other: TxSkeleton) -> Result<(usize, usize), Error> {
+        for (index, dep) in other.cell_deps.into_iter().enumerate() {
+            let dep = match dep {
+                Some(dep) => dep,
+                None => continue,
+            };
+            match self.cell_deps.get(index) {
+                Some(Some(existed)) if existed.as_slice() != dep.as_slice() => return Err(Error::CellDepConflict(index)),
+                Some(Some(_)) => {}
+                _ => {
+                    self.cell_dep(index, &dep)?;
+                }
+            }
+        }
+
+        for header in &other.header_deps {
+            self.header_dep(header);
+        }
+        self.checker_proofs(&other.proofs);
+
+        let input_count = other.inputs.len().saturating_sub(1);
+        let output_count = other.outputs.len().saturating_sub(1);
+
+        let input_start = self.inputs.len();
+        for (index, input) in other.inputs.into_iter().enumerate().skip(1) {
+            let input = input.ok_or(Error::SlotMissing { slot: Slot::Input, index })?;
+            self.input(input_start + index - 1, &input)?;
+        }
+
+        let output_start = self.outputs.len();
+        for (index, output) in other.outputs.into_iter().enumerate().skip(1) {
+            let output = output.ok_or(Error::SlotMissing { slot: Slot::Output, index })?;
+            self.output(output_start + index - 1, &output)?;
+        }
+
+        Ok((input_count, output_count))
+    }
+
+    /// Builds the transaction with the code cell witness as the input type of witness 0, and the
+    /// checker proofs, if any, as its output type.
+    ///
+    /// The lock of witness 0 is left empty for the signer to fill.
+    pub fn build(self, witness: Bytes) -> Result<TransactionView, Error> {
+        let cell_deps = collect(self.cell_deps, Slot::CellDep)?;
+        let inputs = collect(self.inputs, Slot::Input)?;
+        let outputs = collect(self.outputs, Slot::Output)?;
+
+        let proofs = if self.proofs == CheckerProofsWitness::default() {
+            None
+        } else {
+            Some(witness_bytes(&self.proofs))
+        };
+        let witness = WitnessArgs::new_builder()
+            .input_type(Some(witness).pack())
+            .output_type(proofs.pack())
+            .build();
+
+        Ok(TransactionBuilder::default()
+            .cell_deps(cell_deps)
+            .header_deps(self.header_deps)
+            .inputs(inputs)
+            .outputs(outputs.iter().map(|cell| cell.output.clone()))
+            .outputs_data(outputs.iter().map(|cell| cell.data.pack()))
+            .witness(witness.as_bytes().pack())
+            .build())
+    }
+}
+
+/// A pattern of the code cell, which knows where its cells go and how its witness looks like.
+pub trait PatternTx {
+    fn skeleton(&self) -> Result<TxSkeleton, Error>;
+
+    fn witness(&self) -> Result<Bytes, Error>;
+
+    fn build(&self) -> Result<TransactionView, Error> {
+        let skeleton = self.skeleton()?;
+        skeleton.build(self.witness()?)
+    }
+}
+
+pub fn witness_bytes<T: Serialize>(witness: &T) -> Bytes {
+    Bytes::from(witness.serialize().as_ref().to_vec())
+}
+
+fn place<T>(slots: &mut Vec<Option<T>>, slot: Slot, index: usize, value: T) -> Result<(), Error> {
+    if slots.len() <= index {
+        slots.resize_with(index + 1, || None);
+    }
+    if slots[index].is_some() {
+        return Err(Error::SlotOccupied { slot, index });
+    }
+    slots[index] = Some(value);
+
+    Ok(())
+}
+
+fn collect<T>(slots: Vec<Option<T>>, slot: Slot) -> Result<Vec<T>, Error> {
+    slots
+        .into_iter()
+        .enumerate()
+        .map(|(index, value)| value.ok_or(Error::SlotMissing { slot, index }))
+        .collect()
+}
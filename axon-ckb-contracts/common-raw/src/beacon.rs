@@ -0,0 +1,56 @@
+use molecule::prelude::*;
+
+use crate::{
+    cell::sidechain_state::SidechainStateCellTypeArgs,
+    common::{ChainId, RandomSeed},
+    molecule::cell::sidechain_state::SidechainStateCellReader,
+    DecodeError, DecodeField, FromRaw,
+};
+
+/*
+The random seed of a sidechain, read from its sidechain state cell taken as a cell dep.
+
+random_seed is folded from the reveals of the checkers of a job and renewed once per submitted job,
+seed_epoch counts the renewals. A dapp reading the beacon must check that the cell dep is the state cell
+of the sidechain, its type script being the one of sidechain state cells in the global config with the
+chain id as args. The cell dep being live, its seed is the latest one of the sidechain.
+
+The state cell is rewritten by other patterns too, so neither a new state cell nor its block tell
+that the seed was renewed. A dapp records the epoch of the beacon in a cell it creates instead, and
+takes the seed for that cell once the epoch moved on, see is_fresh and common::check_beacon_freshness.
+ */
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RandomBeacon {
+    pub chain_id: ChainId,
+    pub epoch:    u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
+    pub seed:     RandomSeed,
+}
+
+impl RandomBeacon {
+    // only the seed and its epoch are decoded, the rest of the state is left alone
+    pub fn from_state_cell(type_args: &[u8], data: &[u8]) -> Result<Self, DecodeError> {
+        let type_args = SidechainStateCellTypeArgs::from_raw(type_args)?;
+        let reader = SidechainStateCellReader::from_slice(data)?;
+
+        let epoch = u64::from_raw(reader.seed_epoch().raw_data()).field("seed_epoch")?;
+        let mut seed = RandomSeed::default();
+        seed.copy_from_slice(reader.random_seed().raw_data());
+
+        Ok(Self {
+            chain_id: type_args.chain_id,
+            epoch,
+            seed,
+        })
+    }
+
+    /*
+    whether the seed was renewed after a cell recording recorded_epoch was created at block created_at,
+    thus unknown to its creator, and at most max_delay blocks later, so no one could wait for a seed of
+    their liking for long. The renewal happened no later than beacon_at, the block of the state cell.
+     */
+    pub fn is_fresh(&self, recorded_epoch: u64, created_at: u64, beacon_at: u64, max_delay: u64) -> bool {
+        self.epoch > recorded_epoch && beacon_at.checked_sub(created_at).map_or(false, |delay| delay <= max_delay)
+    }
+}
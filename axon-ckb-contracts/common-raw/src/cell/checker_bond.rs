@@ -2,12 +2,12 @@ use molecule::prelude::Reader;
 use molecule::prelude::*;
 
 use crate::{
-    common::{ChainId, PubKeyHash},
+    common::{ChainId, Identity},
     molecule::{
         cell::checker_bond::{CheckerBondCellLockArgsBuilder, CheckerBondCellLockArgsReader},
         common::{ChainIdListBuilder, ChainIdReader, PubKeyHashReader},
     },
-    FromRaw, PureSudtTokenCell, Serialize,
+    DecodeError, DecodeField, FromRaw, PureSudtTokenCell, Serialize,
 };
 
 const CHECKER_BOND_LOCK_ARGS_LEN: usize = 52;
@@ -22,11 +22,12 @@ const CHECKER_BOND_LOCK_ARGS_LEN: usize = 52;
     Lock:
         codehash: checker bond cell lockscript
         hashtype: type
-        args: checker public key | chain id bitmap
+        args: checker identity | chain id bitmap
 */
 
 // which is standard sudt
 #[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Ord, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CheckerBondCell {
     pub amount: u128,
 }
@@ -34,14 +35,16 @@ pub struct CheckerBondCell {
 PureSudtTokenCell!(CheckerBondCell);
 
 #[derive(Debug, Clone, PartialOrd, PartialEq, Ord, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CheckerBondCellLockArgs {
-    pub checker_lock_arg:      PubKeyHash,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
+    pub checker_lock_arg:      Identity,
     pub participated_chain_id: Vec<ChainId>,
 }
 
 impl FromRaw for CheckerBondCellLockArgs {
-    fn from_raw(arg_raw_data: &[u8]) -> Option<CheckerBondCellLockArgs> {
-        let reader = CheckerBondCellLockArgsReader::from_slice(arg_raw_data).ok()?;
+    fn from_raw(arg_raw_data: &[u8]) -> Result<CheckerBondCellLockArgs, DecodeError> {
+        let reader = CheckerBondCellLockArgsReader::from_slice(arg_raw_data)?;
 
         let mut checker_lock_arg = [0u8; 20];
         checker_lock_arg.copy_from_slice(reader.checker_lock_arg().raw_data());
@@ -49,14 +52,11 @@ impl FromRaw for CheckerBondCellLockArgs {
         let participated_chain_id = reader
             .participated_chain_id()
             .iter()
-            .fold(Vec::new(), |mut participated_chain_id, chain_id_reader| {
-                participated_chain_id.push(ChainId::from_raw(chain_id_reader.raw_data()));
-                participated_chain_id
-            })
-            .into_iter()
-            .collect::<Option<Vec<ChainId>>>()?;
+            .map(|chain_id_reader| ChainId::from_raw(chain_id_reader.raw_data()))
+            .collect::<Result<Vec<ChainId>, DecodeError>>()
+            .field("participated_chain_id")?;
 
-        Some(CheckerBondCellLockArgs {
+        Ok(CheckerBondCellLockArgs {
             checker_lock_arg,
             participated_chain_id,
         })
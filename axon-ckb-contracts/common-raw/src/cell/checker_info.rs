@@ -1,4 +1,4 @@
-use crate::common::{ChainId, PubKeyHash};
+use crate::common::{ChainId, Identity};
 use core::convert::TryFrom;
 use core::result::Result;
 
@@ -8,8 +8,8 @@ use crate::molecule::cell::checker_info::CheckerInfoCellTypeArgsBuilder;
 use crate::molecule::cell::checker_info::{
     CheckerInfoCellBuilder, CheckerInfoCellReader, CheckerInfoCellTypeArgsReader, CheckerInfoStatusReader,
 };
-use crate::molecule::common::{ChainIdReader, MolStringBuilder, PubKeyHashReader, Uint128Reader};
-use crate::{FromRaw, Serialize};
+use crate::molecule::common::{ChainIdReader, MolStringBuilder, PubKeyHashReader, Uint128Reader, Uint32Reader};
+use crate::{DecodeError, DecodeField, FromRaw, Serialize};
 
 /**
     Checker Info Cell
@@ -17,13 +17,14 @@ use crate::{FromRaw, Serialize};
     Type:
         codehash: typeId
         hashtype: type
-        args: chain_id | lock_arg
+        args: chain_id | checker identity
     Lock:
         codehash: A.S.
         hashtype: type
         args: null
 */
 #[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Ord, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum CheckerInfoStatus {
     Relaying = 0u8,
@@ -43,32 +44,52 @@ impl TryFrom<u8> for CheckerInfoStatus {
 }
 
 #[derive(Debug, Clone, PartialOrd, PartialEq, Ord, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CheckerInfoCell {
-    pub unpaid_fee: u128,
-    pub status:     CheckerInfoStatus,
-    pub rpc_url:    Vec<u8>,
+    pub unpaid_fee:       u128,
+    pub status:           CheckerInfoStatus,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
+    pub rpc_url:          Vec<u8>,
+    // share of the delegators' fee kept by the checker, over RATE_DENOMINATOR
+    pub commission_rate:  u32,
+    // the bond of the checker when it joined and the principals of the delegations to it,
+    // the fee is split between them when it is taken
+    pub bonded_amount:    u128,
+    pub delegated_amount: u128,
 }
 
 impl Default for CheckerInfoCell {
     fn default() -> Self {
         CheckerInfoCell {
-            unpaid_fee: 0,
-            status:     CheckerInfoStatus::Relaying,
-            rpc_url:    Vec::default(),
+            unpaid_fee:       0,
+            status:           CheckerInfoStatus::Relaying,
+            rpc_url:          Vec::default(),
+            commission_rate:  0,
+            bonded_amount:    0,
+            delegated_amount: 0,
         }
     }
 }
 
 impl FromRaw for CheckerInfoCell {
-    fn from_raw(cell_raw_data: &[u8]) -> Option<CheckerInfoCell> {
-        let reader = CheckerInfoCellReader::from_slice(cell_raw_data).expect("reader");
-        let unpaid_fee = u128::from_raw(reader.unpaid_fee().raw_data()).expect("unpaid_fee");
+    fn from_raw(cell_raw_data: &[u8]) -> Result<CheckerInfoCell, DecodeError> {
+        let reader = CheckerInfoCellReader::from_slice(cell_raw_data)?;
+        let unpaid_fee = u128::from_raw(reader.unpaid_fee().raw_data()).field("unpaid_fee")?;
         let rpc_url = reader.rpc_url().raw_data().to_vec();
-        let status = CheckerInfoStatus::try_from(reader.status().raw_data()[0]).ok()?;
-        Some(CheckerInfoCell {
+        let status = reader.status().raw_data()[0];
+        let status = CheckerInfoStatus::try_from(status)
+            .or(Err(DecodeError::unknown_variant(status)))
+            .field("status")?;
+        let commission_rate = u32::from_raw(reader.commission_rate().raw_data()).field("commission_rate")?;
+        let bonded_amount = u128::from_raw(reader.bonded_amount().raw_data()).field("bonded_amount")?;
+        let delegated_amount = u128::from_raw(reader.delegated_amount().raw_data()).field("delegated_amount")?;
+        Ok(CheckerInfoCell {
             unpaid_fee,
             rpc_url,
             status,
+            commission_rate,
+            bonded_amount,
+            delegated_amount,
         })
     }
 }
@@ -79,6 +100,9 @@ impl Serialize for CheckerInfoCell {
     fn serialize(&self) -> Self::RawType {
         let status = CheckerInfoStatusReader::new_unchecked(&(self.status as u8).serialize()).to_entity();
         let unpaid_fee = Uint128Reader::new_unchecked(&self.unpaid_fee.serialize()).to_entity();
+        let commission_rate = Uint32Reader::new_unchecked(&self.commission_rate.serialize()).to_entity();
+        let bonded_amount = Uint128Reader::new_unchecked(&self.bonded_amount.serialize()).to_entity();
+        let delegated_amount = Uint128Reader::new_unchecked(&self.delegated_amount.serialize()).to_entity();
         let mut rpc_url_builder = MolStringBuilder::default();
         for &v in self.rpc_url.iter() {
             rpc_url_builder = rpc_url_builder.push(Byte::new(v));
@@ -86,7 +110,10 @@ impl Serialize for CheckerInfoCell {
         let builder = CheckerInfoCellBuilder::default()
             .rpc_url(rpc_url_builder.build())
             .status(status)
-            .unpaid_fee(unpaid_fee);
+            .unpaid_fee(unpaid_fee)
+            .commission_rate(commission_rate)
+            .bonded_amount(bonded_amount)
+            .delegated_amount(delegated_amount);
         let mut buf = Vec::new();
         builder
             .write(&mut buf)
@@ -96,21 +123,23 @@ impl Serialize for CheckerInfoCell {
 }
 
 #[derive(Debug, Copy, Clone, Default, PartialOrd, PartialEq, Ord, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CheckerInfoCellTypeArgs {
     pub chain_id:         ChainId,
-    pub checker_lock_arg: PubKeyHash,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
+    pub checker_lock_arg: Identity,
 }
 
 impl FromRaw for CheckerInfoCellTypeArgs {
-    fn from_raw(arg_raw_data: &[u8]) -> Option<CheckerInfoCellTypeArgs> {
-        let reader = CheckerInfoCellTypeArgsReader::from_slice(arg_raw_data).ok()?;
+    fn from_raw(arg_raw_data: &[u8]) -> Result<CheckerInfoCellTypeArgs, DecodeError> {
+        let reader = CheckerInfoCellTypeArgsReader::from_slice(arg_raw_data)?;
 
-        let chain_id = ChainId::from_raw(reader.chain_id().raw_data())?;
+        let chain_id = ChainId::from_raw(reader.chain_id().raw_data()).field("chain_id")?;
 
-        let mut checker_lock_arg = PubKeyHash::default();
+        let mut checker_lock_arg = Identity::default();
         checker_lock_arg.copy_from_slice(reader.checker_lock_arg().raw_data());
 
-        Some(CheckerInfoCellTypeArgs {
+        Ok(CheckerInfoCellTypeArgs {
             chain_id,
             checker_lock_arg,
         })
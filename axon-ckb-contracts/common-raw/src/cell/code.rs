@@ -1,6 +1,6 @@
 use molecule::prelude::*;
 
-use crate::{common::*, molecule::cell::code::CodeCellLockArgsReader, FromRaw};
+use crate::{common::*, molecule::cell::code::CodeCellLockArgsReader, DecodeError, FromRaw};
 
 /*
 
@@ -10,27 +10,31 @@ use crate::{common::*, molecule::cell::code::CodeCellLockArgsReader, FromRaw};
         codehash: typeId
         hashtype: type
         args: chain_id(for lumos)
-    Lock:
-        codehash: secp256k1
-        hashtype: type
-        args: public-key
+    Lock: any lock
+        the signer of every pattern is Identity::from_lock_hash of the code input lock,
+        the lock itself is left to unlock the cell
 */
 
 #[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Ord, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CodeCell {}
 
+// the lock args of the secp256k1 and secp256k1 multisig locks, a public key hash or the blake160 of
+// the multisig script, only kept to decode such locks in axon-inspect, no pattern reads them
 #[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Ord, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CodeCellLockArgs {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
     pub lock_arg: PubKeyHash,
 }
 
 impl FromRaw for CodeCellLockArgs {
-    fn from_raw(arg_raw_data: &[u8]) -> Option<CodeCellLockArgs> {
-        let reader = CodeCellLockArgsReader::from_slice(arg_raw_data).ok()?;
+    fn from_raw(arg_raw_data: &[u8]) -> Result<CodeCellLockArgs, DecodeError> {
+        let reader = CodeCellLockArgsReader::from_slice(arg_raw_data)?;
 
         let mut lock_arg = [0u8; 20];
         lock_arg.copy_from_slice(reader.lock_arg().raw_data());
 
-        Some(CodeCellLockArgs { lock_arg })
+        Ok(CodeCellLockArgs { lock_arg })
     }
 }
@@ -0,0 +1,90 @@
+use molecule::prelude::*;
+
+use crate::{
+    common::*,
+    molecule::{
+        cell::delegation::{DelegationCellLockArgsBuilder, DelegationCellLockArgsReader},
+        common::{ChainIdReader, PubKeyHashReader, Uint128Reader},
+    },
+    DecodeError, DecodeField, FromRaw, PureSudtTokenCell, Serialize,
+};
+
+/**
+    Delegation Cell
+    Data:
+    Type:
+        codehash: sudt
+        hashtype: type
+        args: muse_token_admin
+    Lock:
+        codehash: delegation cell lockscript
+        hashtype: type
+        args: chain_id | checker identity | delegator identity | principal
+*/
+
+// which is standard sudt, the amount is the principal plus the fee accrued to the delegator
+#[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Ord, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DelegationCell {
+    pub amount: u128,
+}
+
+PureSudtTokenCell!(DelegationCell);
+
+#[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Ord, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DelegationCellLockArgs {
+    pub chain_id:           ChainId,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
+    pub checker_lock_arg:   Identity,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
+    pub delegator_lock_arg: Identity,
+    // what the delegation adds to the bond of the checker
+    pub principal:          u128,
+}
+
+impl FromRaw for DelegationCellLockArgs {
+    fn from_raw(arg_raw_data: &[u8]) -> Result<Self, DecodeError> {
+        let reader = DelegationCellLockArgsReader::from_slice(arg_raw_data)?;
+
+        let chain_id = ChainId::from_raw(reader.chain_id().raw_data()).field("chain_id")?;
+
+        let mut checker_lock_arg = Identity::default();
+        checker_lock_arg.copy_from_slice(reader.checker_lock_arg().raw_data());
+
+        let mut delegator_lock_arg = Identity::default();
+        delegator_lock_arg.copy_from_slice(reader.delegator_lock_arg().raw_data());
+
+        let principal = u128::from_raw(reader.principal().raw_data()).field("principal")?;
+
+        Ok(Self {
+            chain_id,
+            checker_lock_arg,
+            delegator_lock_arg,
+            principal,
+        })
+    }
+}
+
+impl Serialize for DelegationCellLockArgs {
+    type RawType = Vec<u8>;
+
+    fn serialize(&self) -> Self::RawType {
+        let chain_id = ChainIdReader::new_unchecked(&self.chain_id.serialize()).to_entity();
+        let checker_lock_arg = PubKeyHashReader::new_unchecked(&self.checker_lock_arg).to_entity();
+        let delegator_lock_arg = PubKeyHashReader::new_unchecked(&self.delegator_lock_arg).to_entity();
+        let principal = Uint128Reader::new_unchecked(&self.principal.serialize()).to_entity();
+
+        let builder = DelegationCellLockArgsBuilder::default()
+            .chain_id(chain_id)
+            .checker_lock_arg(checker_lock_arg)
+            .delegator_lock_arg(delegator_lock_arg)
+            .principal(principal);
+
+        let mut buf = Vec::new();
+        builder
+            .write(&mut buf)
+            .expect("Unable to write buffer while serializing DelegationCellLockArgs");
+        buf
+    }
+}
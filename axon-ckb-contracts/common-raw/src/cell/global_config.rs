@@ -1,6 +1,6 @@
-use crate::{check_args_len, FromRaw, Serialize};
+use crate::{check_args_len, common::Identity, DecodeError, DecodeField, FromRaw, Serialize};
 
-const GLOBAL_CONFIG_DATA_LEN: usize = 284;
+const GLOBAL_CONFIG_DATA_LEN: usize = 350;
 
 /**
 
@@ -17,75 +17,101 @@ const GLOBAL_CONFIG_DATA_LEN: usize = 284;
         args: admin
 */
 #[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Ord, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GlobalConfigCellData {
-    pub admin_lock_arg:          [u8; 20],
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
+    pub admin_lock_arg:          Identity,
     /* this is the authenticated admin for
      * sidechain config cell */
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
     pub code_cell_type_codehash: [u8; 32],
     pub code_cell_type_hashtype: u8,
 
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
     pub sidechain_config_cell_type_codehash: [u8; 32],
     pub sidechain_config_cell_type_hashtype: u8,
 
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
     pub sidechain_state_cell_type_codehash: [u8; 32],
     pub sidechain_state_cell_type_hashtype: u8,
 
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
     pub checker_info_cell_type_codehash: [u8; 32],
     pub checker_info_cell_type_hashtype: u8,
 
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
     pub checker_bond_cell_lock_codehash: [u8; 32],
     pub checker_bond_cell_lock_hashtype: u8,
 
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
     pub task_cell_type_codehash: [u8; 32],
     pub task_cell_type_hashtype: u8,
 
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
     pub sidechain_fee_cell_lock_codehash: [u8; 32],
     pub sidechain_fee_cell_lock_hashtype: u8,
 
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
     pub sidechain_bond_cell_lock_codehash: [u8; 32],
     pub sidechain_bond_cell_lock_hashtype: u8,
+
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
+    pub proposal_cell_type_codehash: [u8; 32],
+    pub proposal_cell_type_hashtype: u8,
+
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
+    pub delegation_cell_lock_codehash: [u8; 32],
+    pub delegation_cell_lock_hashtype: u8,
 }
 
 impl FromRaw for GlobalConfigCellData {
-    fn from_raw(cell_raw_data: &[u8]) -> Option<GlobalConfigCellData> {
+    fn from_raw(cell_raw_data: &[u8]) -> Result<GlobalConfigCellData, DecodeError> {
         check_args_len(cell_raw_data.len(), GLOBAL_CONFIG_DATA_LEN)?;
 
-        let mut admin_lock_arg = [0u8; 20];
+        let mut admin_lock_arg = Identity::default();
         admin_lock_arg.copy_from_slice(&cell_raw_data[0..20]);
 
         let mut code_cell_type_codehash = [0u8; 32];
         code_cell_type_codehash.copy_from_slice(&cell_raw_data[20..52]);
-        let code_cell_type_hashtype = u8::from_raw(&cell_raw_data[52..53])?;
+        let code_cell_type_hashtype = u8::from_raw(&cell_raw_data[52..53]).field("code_cell_type_hashtype")?;
 
         let mut sidechain_config_cell_type_codehash = [0u8; 32];
         sidechain_config_cell_type_codehash.copy_from_slice(&cell_raw_data[53..85]);
-        let sidechain_config_cell_type_hashtype = u8::from_raw(&cell_raw_data[85..86])?;
+        let sidechain_config_cell_type_hashtype = u8::from_raw(&cell_raw_data[85..86]).field("sidechain_config_cell_type_hashtype")?;
 
         let mut sidechain_state_cell_type_codehash = [0u8; 32];
         sidechain_state_cell_type_codehash.copy_from_slice(&cell_raw_data[86..118]);
-        let sidechain_state_cell_type_hashtype = u8::from_raw(&cell_raw_data[118..119])?;
+        let sidechain_state_cell_type_hashtype = u8::from_raw(&cell_raw_data[118..119]).field("sidechain_state_cell_type_hashtype")?;
 
         let mut checker_info_cell_type_codehash = [0u8; 32];
         checker_info_cell_type_codehash.copy_from_slice(&cell_raw_data[119..151]);
-        let checker_info_cell_type_hashtype = u8::from_raw(&cell_raw_data[151..152])?;
+        let checker_info_cell_type_hashtype = u8::from_raw(&cell_raw_data[151..152]).field("checker_info_cell_type_hashtype")?;
 
         let mut checker_bond_cell_lock_codehash = [0u8; 32];
         checker_bond_cell_lock_codehash.copy_from_slice(&cell_raw_data[152..184]);
-        let checker_bond_cell_lock_hashtype = u8::from_raw(&cell_raw_data[184..185])?;
+        let checker_bond_cell_lock_hashtype = u8::from_raw(&cell_raw_data[184..185]).field("checker_bond_cell_lock_hashtype")?;
 
         let mut task_cell_type_codehash = [0u8; 32];
         task_cell_type_codehash.copy_from_slice(&cell_raw_data[185..217]);
-        let task_cell_type_hashtype = u8::from_raw(&cell_raw_data[217..218])?;
+        let task_cell_type_hashtype = u8::from_raw(&cell_raw_data[217..218]).field("task_cell_type_hashtype")?;
 
         let mut sidechain_fee_cell_lock_codehash = [0u8; 32];
         sidechain_fee_cell_lock_codehash.copy_from_slice(&cell_raw_data[218..250]);
-        let sidechain_fee_cell_lock_hashtype = u8::from_raw(&cell_raw_data[250..251])?;
+        let sidechain_fee_cell_lock_hashtype = u8::from_raw(&cell_raw_data[250..251]).field("sidechain_fee_cell_lock_hashtype")?;
 
         let mut sidechain_bond_cell_lock_codehash = [0u8; 32];
         sidechain_bond_cell_lock_codehash.copy_from_slice(&cell_raw_data[251..283]);
-        let sidechain_bond_cell_lock_hashtype = u8::from_raw(&cell_raw_data[283..284])?;
+        let sidechain_bond_cell_lock_hashtype = u8::from_raw(&cell_raw_data[283..284]).field("sidechain_bond_cell_lock_hashtype")?;
+
+        let mut proposal_cell_type_codehash = [0u8; 32];
+        proposal_cell_type_codehash.copy_from_slice(&cell_raw_data[284..316]);
+        let proposal_cell_type_hashtype = u8::from_raw(&cell_raw_data[316..317]).field("proposal_cell_type_hashtype")?;
 
-        Some(GlobalConfigCellData {
+        let mut delegation_cell_lock_codehash = [0u8; 32];
+        delegation_cell_lock_codehash.copy_from_slice(&cell_raw_data[317..349]);
+        let delegation_cell_lock_hashtype = u8::from_raw(&cell_raw_data[349..350]).field("delegation_cell_lock_hashtype")?;
+
+        Ok(GlobalConfigCellData {
             admin_lock_arg,
             code_cell_type_codehash,
             code_cell_type_hashtype,
@@ -103,6 +129,10 @@ impl FromRaw for GlobalConfigCellData {
             sidechain_fee_cell_lock_hashtype,
             sidechain_bond_cell_lock_codehash,
             sidechain_bond_cell_lock_hashtype,
+            proposal_cell_type_codehash,
+            proposal_cell_type_hashtype,
+            delegation_cell_lock_codehash,
+            delegation_cell_lock_hashtype,
         })
     }
 }
@@ -140,6 +170,12 @@ impl Serialize for GlobalConfigCellData {
         buf[251..283].copy_from_slice(&self.sidechain_bond_cell_lock_codehash);
         buf[283..284].copy_from_slice(&self.sidechain_bond_cell_lock_hashtype.serialize());
 
+        buf[284..316].copy_from_slice(&self.proposal_cell_type_codehash);
+        buf[316..317].copy_from_slice(&self.proposal_cell_type_hashtype.serialize());
+
+        buf[317..349].copy_from_slice(&self.delegation_cell_lock_codehash);
+        buf[349..350].copy_from_slice(&self.delegation_cell_lock_hashtype.serialize());
+
         buf
     }
 }
@@ -2,6 +2,7 @@ use crate::PureSudtTokenCell;
 
 // which is standard sudt
 #[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Ord, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MuseTokenCell {
     pub amount: u128,
 }
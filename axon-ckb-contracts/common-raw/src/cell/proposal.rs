@@ -0,0 +1,199 @@
+use molecule::prelude::*;
+
+use crate::{
+    cell::sidechain_config::SidechainConfigCell,
+    common::*,
+    molecule::{
+        cell::proposal::{
+            ProposalCellBuilder, ProposalCellReader, ProposalCellTypeArgsBuilder, ProposalCellTypeArgsReader, ProposalKindReader,
+        },
+        common::{ChainIdReader, MolStringBuilder, PubKeyHashListBuilder, PubKeyHashListReader, PubKeyHashReader, Uint64Reader},
+    },
+    DecodeError, DecodeField, FromRaw, Serialize,
+};
+
+/*
+What a passed proposal does to the sidechain config cell.
+UpdateConfig carries the whole proposed config, only its parameters are adopted, the status, the
+nonce, the checker counts and lists, the admin and collators and the bond token stay as they are in
+the config cell. Its thresholds and slash rate have to stay valid, as does the collator threshold
+of a new collator set.
+ReplaceCollators replaces the whole collator set, its payload is a PubKeyHashList.
+ */
+#[derive(Debug, Clone, PartialOrd, PartialEq, Ord, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ProposalChange {
+    UpdateConfig(SidechainConfigCell),
+    ReplaceCollators(#[cfg_attr(feature = "serde", serde(with = "crate::serde_hex::vec"))] Vec<Identity>),
+    Shutdown,
+}
+
+impl ProposalChange {
+    fn kind(&self) -> u8 {
+        match self {
+            Self::UpdateConfig(_) => 0u8,
+            Self::ReplaceCollators(_) => 1u8,
+            Self::Shutdown => 2u8,
+        }
+    }
+
+    fn from_reader(kind: ProposalKindReader, payload: &[u8]) -> Result<Self, DecodeError> {
+        let kind = u8::from_raw(kind.raw_data()).field("kind")?;
+        match kind {
+            0u8 => Ok(Self::UpdateConfig(SidechainConfigCell::from_raw(payload).field("payload")?)),
+            1u8 => {
+                let collators = PubKeyHashListReader::from_slice(payload)
+                    .map_err(DecodeError::from)
+                    .field("payload")?
+                    .iter()
+                    .map(|collator_reader| Identity::from_raw(collator_reader.raw_data()))
+                    .collect::<Result<Vec<Identity>, DecodeError>>()
+                    .field("payload")?;
+                Ok(Self::ReplaceCollators(collators))
+            }
+            2u8 if payload.is_empty() => Ok(Self::Shutdown),
+            2u8 => Err(DecodeError::length(0, payload.len()).within("payload")),
+            _ => Err(DecodeError::unknown_variant(kind).within("kind")),
+        }
+    }
+
+    fn payload(&self) -> Vec<u8> {
+        match self {
+            Self::UpdateConfig(config) => config.serialize(),
+            Self::ReplaceCollators(collators) => {
+                let mut builder = PubKeyHashListBuilder::default();
+                for collator in collators {
+                    builder = builder.push(PubKeyHashReader::new_unchecked(collator).to_entity());
+                }
+                builder.build().as_slice().to_vec()
+            }
+            Self::Shutdown => Vec::new(),
+        }
+    }
+}
+
+impl Default for ProposalChange {
+    fn default() -> Self {
+        Self::UpdateConfig(SidechainConfigCell::default())
+    }
+}
+
+/**
+    Proposal Cell
+    Data:
+    Type:
+        codehash: typeId
+        hashtype: type
+        args: chain_id | proposer identity
+    Lock:
+        codehash: A.S.
+        hashtype: type
+        args: null
+*/
+#[derive(Debug, Clone, PartialOrd, PartialEq, Ord, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProposalCell {
+    pub change:       ProposalChange,
+    // config_nonce of the sidechain config the proposal was made against
+    pub config_nonce: u64,
+    // activated checkers who signed for the proposal, the proposer first
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex::vec"))]
+    pub votes:        Vec<Identity>,
+}
+
+impl FromRaw for ProposalCell {
+    fn from_raw(cell_raw_data: &[u8]) -> Result<ProposalCell, DecodeError> {
+        let reader = ProposalCellReader::from_slice(cell_raw_data)?;
+
+        let change = ProposalChange::from_reader(reader.kind(), reader.payload().raw_data()).field("change")?;
+        let config_nonce = u64::from_raw(reader.config_nonce().raw_data()).field("config_nonce")?;
+
+        let votes = reader
+            .votes()
+            .iter()
+            .map(|vote_reader| Identity::from_raw(vote_reader.raw_data()))
+            .collect::<Result<Vec<Identity>, DecodeError>>()
+            .field("votes")?;
+
+        Ok(ProposalCell {
+            change,
+            config_nonce,
+            votes,
+        })
+    }
+}
+
+impl Serialize for ProposalCell {
+    type RawType = Vec<u8>;
+
+    fn serialize(&self) -> Self::RawType {
+        let kind = ProposalKindReader::new_unchecked(&self.change.kind().serialize()).to_entity();
+
+        let mut payload = MolStringBuilder::default();
+        for v in self.change.payload() {
+            payload = payload.push(Byte::new(v));
+        }
+
+        let config_nonce = Uint64Reader::new_unchecked(&self.config_nonce.serialize()).to_entity();
+
+        let mut votes = PubKeyHashListBuilder::default();
+        for vote in &self.votes {
+            votes = votes.push(PubKeyHashReader::new_unchecked(vote).to_entity());
+        }
+
+        let builder = ProposalCellBuilder::default()
+            .kind(kind)
+            .payload(payload.build())
+            .config_nonce(config_nonce)
+            .votes(votes.build());
+
+        let mut buf = Vec::new();
+        builder
+            .write(&mut buf)
+            .expect("Unable to write buffer while serializing ProposalCell");
+        buf
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Ord, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProposalCellTypeArgs {
+    pub chain_id:          ChainId,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
+    pub proposer_lock_arg: Identity,
+}
+
+impl FromRaw for ProposalCellTypeArgs {
+    fn from_raw(arg_raw_data: &[u8]) -> Result<ProposalCellTypeArgs, DecodeError> {
+        let reader = ProposalCellTypeArgsReader::from_slice(arg_raw_data)?;
+
+        let chain_id = ChainId::from_raw(reader.chain_id().raw_data()).field("chain_id")?;
+
+        let mut proposer_lock_arg = Identity::default();
+        proposer_lock_arg.copy_from_slice(reader.proposer_lock_arg().raw_data());
+
+        Ok(ProposalCellTypeArgs {
+            chain_id,
+            proposer_lock_arg,
+        })
+    }
+}
+
+impl Serialize for ProposalCellTypeArgs {
+    type RawType = Vec<u8>;
+
+    fn serialize(&self) -> Self::RawType {
+        let chain_id = ChainIdReader::new_unchecked(&self.chain_id.serialize()).to_entity();
+        let proposer_lock_arg = PubKeyHashReader::new_unchecked(&self.proposer_lock_arg).to_entity();
+
+        let builder = ProposalCellTypeArgsBuilder::default()
+            .chain_id(chain_id)
+            .proposer_lock_arg(proposer_lock_arg);
+
+        let mut buf = Vec::new();
+        builder
+            .write(&mut buf)
+            .expect("Unable to write buffer while serializing ProposalCellTypeArgs");
+        buf
+    }
+}
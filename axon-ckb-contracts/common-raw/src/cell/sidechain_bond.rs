@@ -1,7 +1,7 @@
 use crate::{
     check_args_len,
-    common::{BlockHeight, ChainId, PubKeyHash},
-    FromRaw, PureSudtTokenCell, Serialize,
+    common::{BlockHeight, ChainId, Identity},
+    DecodeError, DecodeField, FromRaw, PureSudtTokenCell, Serialize,
 };
 
 const SIDECHAIN_BOND_LOCK_ARGS_LEN: usize = 40;
@@ -16,11 +16,12 @@ const SIDECHAIN_BOND_LOCK_ARGS_LEN: usize = 40;
     Lock:
         codehash: sidechain bond cell lockscript
         hashtype: type
-        args: chain_id | collator_public_key | unlock_sidechain_height
+        args: chain_id | collator identity | unlock_sidechain_height
 */
 
 // which is standard sudt
 #[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Ord, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SidechainBondCell {
     pub amount: u128,
 }
@@ -28,24 +29,26 @@ pub struct SidechainBondCell {
 PureSudtTokenCell!(SidechainBondCell);
 
 #[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Ord, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SidechainBondCellLockArgs {
     pub chain_id:                ChainId,
-    pub collator_lock_arg:       PubKeyHash,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
+    pub collator_lock_arg:       Identity,
     pub unlock_sidechain_height: BlockHeight,
 }
 
 impl FromRaw for SidechainBondCellLockArgs {
-    fn from_raw(arg_raw_data: &[u8]) -> Option<SidechainBondCellLockArgs> {
+    fn from_raw(arg_raw_data: &[u8]) -> Result<SidechainBondCellLockArgs, DecodeError> {
         check_args_len(arg_raw_data.len(), SIDECHAIN_BOND_LOCK_ARGS_LEN)?;
 
-        let chain_id = ChainId::from_raw(&arg_raw_data[0..4])?;
+        let chain_id = ChainId::from_raw(&arg_raw_data[0..4]).field("chain_id")?;
 
-        let mut collator_lock_arg = PubKeyHash::default();
+        let mut collator_lock_arg = Identity::default();
         collator_lock_arg.copy_from_slice(&arg_raw_data[4..24]);
 
-        let unlock_sidechain_height = BlockHeight::from_raw(&arg_raw_data[24..40])?;
+        let unlock_sidechain_height = BlockHeight::from_raw(&arg_raw_data[24..40]).field("unlock_sidechain_height")?;
 
-        Some(SidechainBondCellLockArgs {
+        Ok(SidechainBondCellLockArgs {
             chain_id,
             collator_lock_arg,
             unlock_sidechain_height,
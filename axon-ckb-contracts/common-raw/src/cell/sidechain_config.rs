@@ -1,22 +1,27 @@
+use core::cmp::Ordering;
+
 use molecule::prelude::*;
 
 use crate::molecule::common::Uint64Reader;
 use crate::{
     common::*,
+    is_strictly_sorted_by_key,
     molecule::{
         cell::sidechain_config::{
-            SidechainConfigCellBuilder, SidechainConfigCellReader, SidechainConfigCellTypeArgsBuilder, SidechainConfigCellTypeArgsReader,
-            SidechainStatusReader,
+            CheckerRecordBuilder, CheckerRecordReader, CheckerStatusReader, SidechainConfigCellBuilder, SidechainConfigCellReader,
+            SidechainConfigCellTypeArgsBuilder, SidechainConfigCellTypeArgsReader, SidechainStatusReader,
         },
         common::{
-            BlockHeightReader, ChainIdReader, CodeHashReader, HashTypeReader, PubKeyHashListBuilder, PubKeyHashReader, Uint128Reader,
-            Uint32Reader, Uint8Reader,
+            BlockHeightReader, ChainIdReader, CodeHashReader, HashTypeReader, MerkleHashOptBuilder, MerkleHashReader,
+            PubKeyHashListBuilder, PubKeyHashReader, Uint128ListBuilder, Uint128Reader, Uint32Reader, Uint8Reader,
         },
     },
-    FromRaw, Serialize,
+    smt::SmtNode,
+    DecodeError, DecodeField, FromRaw, Hasher, Serialize,
 };
 
 #[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Ord, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum SidechainStatus {
     Relaying,
@@ -24,12 +29,12 @@ pub enum SidechainStatus {
 }
 
 impl SidechainStatus {
-    fn from_reader(reader: SidechainStatusReader) -> Option<Self> {
+    fn from_reader(reader: SidechainStatusReader) -> Result<Self, DecodeError> {
         let status = u8::from_raw(reader.raw_data())?;
         match status {
-            0u8 => Some(Self::Relaying),
-            1u8 => Some(Self::Shutdown),
-            _ => None,
+            0u8 => Ok(Self::Relaying),
+            1u8 => Ok(Self::Shutdown),
+            _ => Err(DecodeError::unknown_variant(status)),
         }
     }
 }
@@ -41,8 +46,8 @@ impl Default for SidechainStatus {
 }
 
 impl FromRaw for SidechainStatus {
-    fn from_raw(raw: &[u8]) -> Option<Self> {
-        let reader = SidechainStatusReader::from_slice(raw).ok()?;
+    fn from_raw(raw: &[u8]) -> Result<Self, DecodeError> {
+        let reader = SidechainStatusReader::from_slice(raw)?;
         Self::from_reader(reader)
     }
 }
@@ -55,6 +60,85 @@ impl Serialize for SidechainStatus {
     }
 }
 
+#[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Ord, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum CheckerStatus {
+    Activated,
+    Jailed,
+}
+
+impl CheckerStatus {
+    fn from_reader(reader: CheckerStatusReader) -> Result<Self, DecodeError> {
+        let status = u8::from_raw(reader.raw_data())?;
+        match status {
+            0u8 => Ok(Self::Activated),
+            1u8 => Ok(Self::Jailed),
+            _ => Err(DecodeError::unknown_variant(status)),
+        }
+    }
+}
+
+impl Default for CheckerStatus {
+    fn default() -> Self {
+        Self::Activated
+    }
+}
+
+/*
+A leaf of checker_set_root, what the three checker lists of a listed sidechain tell about a checker.
+Only activated checkers weigh in the drawing, a jailed one is kept with no weight.
+ */
+#[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Ord, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CheckerRecord {
+    pub status: CheckerStatus,
+    pub weight: u128,
+}
+
+impl CheckerRecord {
+    pub fn is_activated(&self) -> bool {
+        self.status == CheckerStatus::Activated
+    }
+
+    // the leaf of checker, an absent checker is an empty leaf
+    pub fn leaf<H: Hasher>(record: Option<&Self>, checker: &Identity) -> SmtNode {
+        match record {
+            Some(record) if record.is_activated() => SmtNode::leaf::<H>(checker, &record.serialize(), record.weight),
+            Some(record) => SmtNode::leaf::<H>(checker, &record.serialize(), 0),
+            None => SmtNode::default(),
+        }
+    }
+}
+
+impl FromRaw for CheckerRecord {
+    fn from_raw(raw: &[u8]) -> Result<Self, DecodeError> {
+        let reader = CheckerRecordReader::from_slice(raw)?;
+
+        let status = CheckerStatus::from_reader(reader.status()).field("status")?;
+        let weight = u128::from_raw(reader.weight().raw_data()).field("weight")?;
+
+        Ok(CheckerRecord { status, weight })
+    }
+}
+
+impl Serialize for CheckerRecord {
+    type RawType = Vec<u8>;
+
+    fn serialize(&self) -> Self::RawType {
+        let status = CheckerStatusReader::new_unchecked(&(self.status as u8).serialize()).to_entity();
+        let weight = Uint128Reader::new_unchecked(&self.weight.serialize()).to_entity();
+
+        let builder = CheckerRecordBuilder::default().status(status).weight(weight);
+
+        let mut buf = Vec::new();
+        builder
+            .write(&mut buf)
+            .expect("Unable to write buffer while serializing CheckerRecord");
+        buf
+    }
+}
+
 /**
     Sidechain Config Cell
     Data:
@@ -68,93 +152,307 @@ impl Serialize for SidechainStatus {
         args: null
 */
 #[derive(Debug, Clone, PartialOrd, PartialEq, Ord, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SidechainConfigCell {
     pub sidechain_status: SidechainStatus,
+    // proposals executed so far, a proposal is made against one nonce and is stale once it moves on
+    pub config_nonce:     u64,
 
     pub commit_threshold:    u32,
     pub challenge_threshold: u32,
 
-    pub checker_normal_count: u32,
-    pub checker_threshold:    u32,
-    pub checker_total_count:  u32,
-    pub activated_checkers:   Vec<PubKeyHash>,
-    pub jailed_checkers:      Vec<PubKeyHash>,
+    pub checker_normal_count:      u32,
+    pub checker_threshold:         u32,
+    pub checker_total_count:       u32,
+    // both checker lists are sorted by lock arg without duplicates, see has_sorted_checkers
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex::vec"))]
+    pub activated_checkers:        Vec<Identity>,
+    // cumulative bonds of activated_checkers, see checker_weight
+    pub activated_checker_weights: Vec<u128>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex::vec"))]
+    pub jailed_checkers:           Vec<Identity>,
+    // when set, the checkers are committed to by this root of their CheckerRecords and the lists above stay empty
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex::option"))]
+    pub checker_set_root:          Option<MerkleHash>,
 
     pub refresh_punish_points:         u32,
     pub refresh_punish_release_points: u32,
     pub refresh_punish_threshold:      u32,
     pub refresh_interval:              u64,
+    // a checker withholding its reveal until the submit of the job earns these points, which count
+    // against refresh_punish_threshold like unanswered tasks do
+    pub reveal_punish_points:          u32,
+    // real reveals needed in every new random seed, at most commit_threshold. A job with
+    // fewer checkers committed earlier needs each of their reveals
+    pub reveal_quorum:                 u32,
     pub shutdown_timeout:              u64,
 
     pub check_data_size_limit: u128,
     pub check_fee_rate: u32,
     pub minimal_bond: u128,
+    // share of a jailed checker's bond and of the delegations to it forfeited when they leave, over RATE_DENOMINATOR
+    pub slash_rate: u32,
     pub parallel_job_upper_bond: u8,
     pub parallel_job_maximal_height_range: BlockHeight,
-
-    pub admin_lock_arg:    PubKeyHash,
-    pub collator_lock_arg: PubKeyHash,
-
+    // most tasks a refresh or a submit may carry in one transaction, 0 for no limit, so that large
+    // sidechains stay within the cycle limit of a transaction
+    pub refresh_task_limit: u32,
+    pub submit_task_limit: u32,
+
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
+    pub admin_lock_arg:     Identity,
+    // collators take turns to publish and submit jobs, see collator_on_duty
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex::vec"))]
+    pub collators:          Vec<Identity>,
+    // collators needed to shut the sidechain down
+    pub collator_threshold: u32,
+
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
     pub bond_sudt_typescript_codehash: CodeHash,
     pub bond_sudt_typescript_hashtype: HashType,
 }
 
+impl SidechainConfigCell {
+    /*
+    Collators take turns by sidechain block height, the job starting at height h belongs to
+    collators[h % len], both its publishing and its submitting.
+     */
+    pub fn collator_on_duty(&self, height: BlockHeight) -> Option<&Identity> {
+        if self.collators.is_empty() {
+            return None;
+        }
+
+        let index = height % self.collators.len() as BlockHeight;
+        self.collators.get(index as usize)
+    }
+
+    /*
+    Checkers are drawn in proportion to their bonds. activated_checker_weights[i] is the sum of the
+    bonds of activated_checkers[0..=i], so checker i covers the points in
+    [activated_checker_weights[i - 1], activated_checker_weights[i]) of the total weight.
+     */
+    pub fn total_checker_weight(&self) -> u128 {
+        self.activated_checker_weights.last().copied().unwrap_or(0)
+    }
+
+    // the bond of activated_checkers[index]
+    pub fn checker_weight(&self, index: usize) -> Option<u128> {
+        let lower = match index.checked_sub(1) {
+            Some(previous) => *self.activated_checker_weights.get(previous)?,
+            None => 0,
+        };
+
+        self.activated_checker_weights.get(index)?.checked_sub(lower)
+    }
+
+    // index of the checker covering point, None if point is past the total weight
+    pub fn checker_at(&self, point: u128) -> Option<usize> {
+        // the first cumulative weight above point, i.e. partition_point which the toolchain lacks
+        let index = self
+            .activated_checker_weights
+            .binary_search_by(|weight| if *weight <= point { Ordering::Less } else { Ordering::Greater })
+            .unwrap_or_else(|index| index);
+
+        if index < self.activated_checker_weights.len() {
+            Some(index)
+        } else {
+            None
+        }
+    }
+
+    // inserts the checker at its place in the sorted list, None if it is already activated
+    pub fn activate_checker(&mut self, checker: Identity, weight: u128) -> Option<()> {
+        if self.activated_checker_weights.len() != self.activated_checkers.len() {
+            return None;
+        }
+        self.total_checker_weight().checked_add(weight)?;
+
+        let index = self.activated_checkers.binary_search(&checker).err()?;
+        let lower = match index.checked_sub(1) {
+            Some(previous) => self.activated_checker_weights[previous],
+            None => 0,
+        };
+
+        self.activated_checkers.insert(index, checker);
+        self.activated_checker_weights.insert(index, lower);
+        for cumulative_weight in &mut self.activated_checker_weights[index..] {
+            *cumulative_weight += weight;
+        }
+        Some(())
+    }
+
+    // removes the checker and its weight from the table, returns the weight it had
+    pub fn deactivate_checker(&mut self, checker: &Identity) -> Option<u128> {
+        let index = self.checker_index(checker)?;
+        let weight = self.checker_weight(index)?;
+
+        self.activated_checkers.remove(index);
+        self.activated_checker_weights.remove(index);
+        for cumulative_weight in &mut self.activated_checker_weights[index..] {
+            *cumulative_weight -= weight;
+        }
+        Some(weight)
+    }
+
+    // delegations add to the weight of the checker they are bonded to
+    pub fn increase_checker_weight(&mut self, checker: &Identity, weight: u128) -> Option<()> {
+        let index = self.checker_index(checker)?;
+        self.total_checker_weight().checked_add(weight)?;
+
+        for cumulative_weight in &mut self.activated_checker_weights[index..] {
+            *cumulative_weight += weight;
+        }
+        Some(())
+    }
+
+    pub fn decrease_checker_weight(&mut self, checker: &Identity, weight: u128) -> Option<()> {
+        let index = self.checker_index(checker)?;
+        self.checker_weight(index)?.checked_sub(weight)?;
+
+        for cumulative_weight in &mut self.activated_checker_weights[index..] {
+            *cumulative_weight -= weight;
+        }
+        Some(())
+    }
+
+    // the index of an activated checker in activated_checkers
+    pub fn checker_index(&self, checker: &Identity) -> Option<usize> {
+        if self.activated_checker_weights.len() != self.activated_checkers.len() {
+            return None;
+        }
+
+        self.activated_checkers.binary_search(checker).ok()
+    }
+
+    pub fn is_jailed(&self, checker: &Identity) -> bool {
+        self.jailed_checkers.binary_search(checker).is_ok()
+    }
+
+    // None if the checker is already jailed
+    pub fn insert_jailed_checker(&mut self, checker: Identity) -> Option<()> {
+        let index = self.jailed_checkers.binary_search(&checker).err()?;
+        self.jailed_checkers.insert(index, checker);
+        Some(())
+    }
+
+    // None if the checker is not jailed
+    pub fn remove_jailed_checker(&mut self, checker: &Identity) -> Option<()> {
+        let index = self.jailed_checkers.binary_search(checker).ok()?;
+        self.jailed_checkers.remove(index);
+        Some(())
+    }
+
+    /*
+    Lookups in both checker lists are binary searches, so every config output has to keep each list
+    strictly ascending, and no checker may be activated and jailed at once.
+     */
+    pub fn has_sorted_checkers(&self) -> bool {
+        if !is_strictly_sorted_by_key(&self.activated_checkers, |checker| *checker)
+            || !is_strictly_sorted_by_key(&self.jailed_checkers, |checker| *checker)
+        {
+            return false;
+        }
+
+        // both lists are sorted, a merge walk finds any checker in both
+        let (mut activated, mut jailed) = (self.activated_checkers.iter().peekable(), self.jailed_checkers.iter().peekable());
+        while let (Some(a), Some(j)) = (activated.peek(), jailed.peek()) {
+            match a.cmp(j) {
+                Ordering::Less => {
+                    activated.next();
+                }
+                Ordering::Greater => {
+                    jailed.next();
+                }
+                Ordering::Equal => return false,
+            }
+        }
+        true
+    }
+}
+
 impl FromRaw for SidechainConfigCell {
-    fn from_raw(cell_raw_data: &[u8]) -> Option<SidechainConfigCell> {
-        let reader = SidechainConfigCellReader::from_slice(cell_raw_data).ok()?;
+    fn from_raw(cell_raw_data: &[u8]) -> Result<SidechainConfigCell, DecodeError> {
+        let reader = SidechainConfigCellReader::from_slice(cell_raw_data)?;
 
-        let sidechain_status = SidechainStatus::from_reader(reader.sidechain_status())?;
+        let sidechain_status = SidechainStatus::from_reader(reader.sidechain_status()).field("sidechain_status")?;
+        let config_nonce = u64::from_raw(reader.config_nonce().raw_data()).field("config_nonce")?;
 
-        let commit_threshold = u32::from_raw(reader.commit_threshold().raw_data())?;
-        let challenge_threshold = u32::from_raw(reader.challenge_threshold().raw_data())?;
+        let commit_threshold = u32::from_raw(reader.commit_threshold().raw_data()).field("commit_threshold")?;
+        let challenge_threshold = u32::from_raw(reader.challenge_threshold().raw_data()).field("challenge_threshold")?;
 
-        let checker_normal_count = u32::from_raw(reader.checker_normal_count().raw_data())?;
-        let checker_threshold = u32::from_raw(reader.checker_threshold().raw_data())?;
-        let checker_total_count = u32::from_raw(reader.checker_total_count().raw_data())?;
+        let checker_normal_count = u32::from_raw(reader.checker_normal_count().raw_data()).field("checker_normal_count")?;
+        let checker_threshold = u32::from_raw(reader.checker_threshold().raw_data()).field("checker_threshold")?;
+        let checker_total_count = u32::from_raw(reader.checker_total_count().raw_data()).field("checker_total_count")?;
 
         let activated_checkers_reader = reader.activated_checkers();
         let activated_checkers_len = activated_checkers_reader.len();
         let mut activated_checkers = Vec::with_capacity(activated_checkers_len);
 
         for i in 0..activated_checkers_len {
-            let result = PubKeyHash::from_raw(activated_checkers_reader.get_unchecked(i).raw_data())?;
+            let result = Identity::from_raw(activated_checkers_reader.get_unchecked(i).raw_data()).field("activated_checkers")?;
             activated_checkers.push(result);
         }
 
+        let activated_checker_weights = reader
+            .activated_checker_weights()
+            .iter()
+            .map(|weight_reader| u128::from_raw(weight_reader.raw_data()))
+            .collect::<Result<Vec<u128>, DecodeError>>()
+            .field("activated_checker_weights")?;
+
         let jailed_checkers_reader = reader.jailed_checkers();
         let jailed_checkers_len = jailed_checkers_reader.len();
         let mut jailed_checkers = Vec::with_capacity(jailed_checkers_len);
 
         for i in 0..jailed_checkers_len {
-            let result = PubKeyHash::from_raw(jailed_checkers_reader.get_unchecked(i).raw_data())?;
+            let result = Identity::from_raw(jailed_checkers_reader.get_unchecked(i).raw_data()).field("jailed_checkers")?;
             jailed_checkers.push(result);
         }
 
-        let refresh_punish_points = u32::from_raw(reader.refresh_punish_points().raw_data())?;
-        let refresh_punish_release_points = u32::from_raw(reader.refresh_punish_release_points().raw_data())?;
-        let refresh_punish_threshold = u32::from_raw(reader.refresh_punish_threshold().raw_data())?;
-        let refresh_interval = u64::from_raw(reader.refresh_interval().raw_data())?;
-        let shutdown_timeout = u64::from_raw(reader.shutdown_timeout().raw_data())?;
-
-        let check_data_size_limit = u128::from_raw(reader.check_data_size_limit().raw_data())?;
-        let check_fee_rate = u32::from_raw(reader.check_fee_rate().raw_data())?;
-        let minimal_bond = u128::from_raw(reader.minimal_bond().raw_data())?;
-        let parallel_job_upper_bond = u8::from_raw(reader.parallel_job_upper_bond().raw_data())?;
-        let parallel_job_maximal_height_range = BlockHeight::from_raw(reader.parallel_job_maximal_height_range().raw_data())?;
+        let checker_set_root = match reader.checker_set_root().to_opt() {
+            Some(root_reader) => Some(MerkleHash::from_raw(root_reader.raw_data()).field("checker_set_root")?),
+            None => None,
+        };
+
+        let refresh_punish_points = u32::from_raw(reader.refresh_punish_points().raw_data()).field("refresh_punish_points")?;
+        let refresh_punish_release_points =
+            u32::from_raw(reader.refresh_punish_release_points().raw_data()).field("refresh_punish_release_points")?;
+        let refresh_punish_threshold = u32::from_raw(reader.refresh_punish_threshold().raw_data()).field("refresh_punish_threshold")?;
+        let refresh_interval = u64::from_raw(reader.refresh_interval().raw_data()).field("refresh_interval")?;
+        let reveal_punish_points = u32::from_raw(reader.reveal_punish_points().raw_data()).field("reveal_punish_points")?;
+        let reveal_quorum = u32::from_raw(reader.reveal_quorum().raw_data()).field("reveal_quorum")?;
+        let shutdown_timeout = u64::from_raw(reader.shutdown_timeout().raw_data()).field("shutdown_timeout")?;
+
+        let check_data_size_limit = u128::from_raw(reader.check_data_size_limit().raw_data()).field("check_data_size_limit")?;
+        let check_fee_rate = u32::from_raw(reader.check_fee_rate().raw_data()).field("check_fee_rate")?;
+        let minimal_bond = u128::from_raw(reader.minimal_bond().raw_data()).field("minimal_bond")?;
+        let slash_rate = u32::from_raw(reader.slash_rate().raw_data()).field("slash_rate")?;
+        let parallel_job_upper_bond = u8::from_raw(reader.parallel_job_upper_bond().raw_data()).field("parallel_job_upper_bond")?;
+        let parallel_job_maximal_height_range =
+            BlockHeight::from_raw(reader.parallel_job_maximal_height_range().raw_data()).field("parallel_job_maximal_height_range")?;
+        let refresh_task_limit = u32::from_raw(reader.refresh_task_limit().raw_data()).field("refresh_task_limit")?;
+        let submit_task_limit = u32::from_raw(reader.submit_task_limit().raw_data()).field("submit_task_limit")?;
 
-        let mut admin_lock_arg: PubKeyHash = [0u8; 20];
+        let mut admin_lock_arg: Identity = [0u8; 20];
         admin_lock_arg.copy_from_slice(reader.admin_lock_arg().raw_data());
 
-        let mut collator_lock_arg: PubKeyHash = [0u8; 20];
-        collator_lock_arg.copy_from_slice(reader.collator_lock_arg().raw_data());
+        let collators = reader
+            .collators()
+            .iter()
+            .map(|collator_reader| Identity::from_raw(collator_reader.raw_data()))
+            .collect::<Result<Vec<Identity>, DecodeError>>()
+            .field("collators")?;
+        let collator_threshold = u32::from_raw(reader.collator_threshold().raw_data()).field("collator_threshold")?;
 
         let mut bond_sudt_typescript_codehash: CodeHash = [0u8; 32];
         bond_sudt_typescript_codehash.copy_from_slice(reader.bond_sudt_typescript_codehash().raw_data());
 
-        let bond_sudt_typescript_hashtype = HashType::from_raw(reader.bond_sudt_typescript_hashtype().raw_data())?;
+        let bond_sudt_typescript_hashtype =
+            HashType::from_raw(reader.bond_sudt_typescript_hashtype().raw_data()).field("bond_sudt_typescript_hashtype")?;
 
-        Some(SidechainConfigCell {
+        Ok(SidechainConfigCell {
             sidechain_status,
+            config_nonce,
 
             commit_threshold,
             challenge_threshold,
@@ -163,21 +461,29 @@ impl FromRaw for SidechainConfigCell {
             checker_threshold,
             checker_total_count,
             activated_checkers,
+            activated_checker_weights,
             jailed_checkers,
+            checker_set_root,
             refresh_punish_points,
             refresh_punish_release_points,
             refresh_punish_threshold,
             refresh_interval,
+            reveal_punish_points,
+            reveal_quorum,
             shutdown_timeout,
 
             check_data_size_limit,
             check_fee_rate,
             minimal_bond,
+            slash_rate,
             parallel_job_upper_bond,
             parallel_job_maximal_height_range,
+            refresh_task_limit,
+            submit_task_limit,
 
             admin_lock_arg,
-            collator_lock_arg,
+            collators,
+            collator_threshold,
 
             bond_sudt_typescript_codehash,
             bond_sudt_typescript_hashtype,
@@ -190,6 +496,7 @@ impl Serialize for SidechainConfigCell {
 
     fn serialize(&self) -> Self::RawType {
         let sidechain_status = SidechainStatusReader::new_unchecked(&self.sidechain_status.serialize()).to_entity();
+        let config_nonce = Uint64Reader::new_unchecked(&self.config_nonce.serialize()).to_entity();
 
         let commit_threshold = Uint32Reader::new_unchecked(&self.commit_threshold.serialize()).to_entity();
         let challenge_threshold = Uint32Reader::new_unchecked(&self.challenge_threshold.serialize()).to_entity();
@@ -203,51 +510,78 @@ impl Serialize for SidechainConfigCell {
             activated_checkers = activated_checkers.push(PubKeyHashReader::new_unchecked(checker).to_entity());
         }
 
+        let mut activated_checker_weights = Uint128ListBuilder::default();
+        for weight in &self.activated_checker_weights {
+            activated_checker_weights = activated_checker_weights.push(Uint128Reader::new_unchecked(&weight.serialize()).to_entity());
+        }
+
         let mut jailed_checkers = PubKeyHashListBuilder::default();
         for checker in &self.jailed_checkers {
             jailed_checkers = jailed_checkers.push(PubKeyHashReader::new_unchecked(checker).to_entity());
         }
 
+        let checker_set_root = MerkleHashOptBuilder::default()
+            .set(self.checker_set_root.map(|root| MerkleHashReader::new_unchecked(&root).to_entity()))
+            .build();
+
         let refresh_punish_points = Uint32Reader::new_unchecked(&self.refresh_punish_points.serialize()).to_entity();
         let refresh_punish_release_points = Uint32Reader::new_unchecked(&self.refresh_punish_release_points.serialize()).to_entity();
         let refresh_punish_threshold = Uint32Reader::new_unchecked(&self.refresh_punish_threshold.serialize()).to_entity();
         let refresh_interval = Uint64Reader::new_unchecked(&self.refresh_interval.serialize()).to_entity();
+        let reveal_punish_points = Uint32Reader::new_unchecked(&self.reveal_punish_points.serialize()).to_entity();
+        let reveal_quorum = Uint32Reader::new_unchecked(&self.reveal_quorum.serialize()).to_entity();
         let shutdown_timeout = Uint64Reader::new_unchecked(&self.shutdown_timeout.serialize()).to_entity();
 
         let check_data_size_limit = Uint128Reader::new_unchecked(&self.check_data_size_limit.serialize()).to_entity();
         let check_fee_rate = Uint32Reader::new_unchecked(&self.check_fee_rate.serialize()).to_entity();
         let minimal_bond = Uint128Reader::new_unchecked(&self.minimal_bond.serialize()).to_entity();
+        let slash_rate = Uint32Reader::new_unchecked(&self.slash_rate.serialize()).to_entity();
         let parallel_job_upper_bond = Uint8Reader::new_unchecked(&self.parallel_job_upper_bond.serialize()).to_entity();
         let parallel_job_maximal_height_range =
             BlockHeightReader::new_unchecked(&self.parallel_job_maximal_height_range.serialize()).to_entity();
+        let refresh_task_limit = Uint32Reader::new_unchecked(&self.refresh_task_limit.serialize()).to_entity();
+        let submit_task_limit = Uint32Reader::new_unchecked(&self.submit_task_limit.serialize()).to_entity();
 
         let admin_lock_arg = PubKeyHashReader::new_unchecked(&self.admin_lock_arg).to_entity();
-        let collator_lock_arg = PubKeyHashReader::new_unchecked(&self.collator_lock_arg).to_entity();
+        let mut collators = PubKeyHashListBuilder::default();
+        for collator in &self.collators {
+            collators = collators.push(PubKeyHashReader::new_unchecked(collator).to_entity());
+        }
+        let collator_threshold = Uint32Reader::new_unchecked(&self.collator_threshold.serialize()).to_entity();
 
         let bond_sudt_typescript_codehash = CodeHashReader::new_unchecked(&self.bond_sudt_typescript_codehash).to_entity();
         let bond_sudt_typescript_hashtype = HashTypeReader::new_unchecked(&self.bond_sudt_typescript_hashtype.serialize()).to_entity();
 
         let builder = SidechainConfigCellBuilder::default()
             .sidechain_status(sidechain_status)
+            .config_nonce(config_nonce)
             .commit_threshold(commit_threshold)
             .challenge_threshold(challenge_threshold)
             .checker_normal_count(checker_normal_count)
             .checker_threshold(checker_threshold)
             .checker_total_count(checker_total_count)
             .activated_checkers(activated_checkers.build())
+            .activated_checker_weights(activated_checker_weights.build())
             .jailed_checkers(jailed_checkers.build())
+            .checker_set_root(checker_set_root)
             .refresh_punish_points(refresh_punish_points)
             .refresh_punish_release_points(refresh_punish_release_points)
             .refresh_punish_threshold(refresh_punish_threshold)
             .refresh_interval(refresh_interval)
+            .reveal_punish_points(reveal_punish_points)
+            .reveal_quorum(reveal_quorum)
             .shutdown_timeout(shutdown_timeout)
             .check_data_size_limit(check_data_size_limit)
             .check_fee_rate(check_fee_rate)
             .minimal_bond(minimal_bond)
+            .slash_rate(slash_rate)
             .parallel_job_upper_bond(parallel_job_upper_bond)
             .parallel_job_maximal_height_range(parallel_job_maximal_height_range)
+            .refresh_task_limit(refresh_task_limit)
+            .submit_task_limit(submit_task_limit)
             .admin_lock_arg(admin_lock_arg)
-            .collator_lock_arg(collator_lock_arg)
+            .collators(collators.build())
+            .collator_threshold(collator_threshold)
             .bond_sudt_typescript_codehash(bond_sudt_typescript_codehash)
             .bond_sudt_typescript_hashtype(bond_sudt_typescript_hashtype);
 
@@ -260,17 +594,18 @@ impl Serialize for SidechainConfigCell {
 }
 
 #[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Ord, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SidechainConfigCellTypeArgs {
     pub chain_id: ChainId,
 }
 
 impl FromRaw for SidechainConfigCellTypeArgs {
-    fn from_raw(arg_raw_data: &[u8]) -> Option<SidechainConfigCellTypeArgs> {
-        let reader = SidechainConfigCellTypeArgsReader::from_slice(arg_raw_data).ok()?;
+    fn from_raw(arg_raw_data: &[u8]) -> Result<SidechainConfigCellTypeArgs, DecodeError> {
+        let reader = SidechainConfigCellTypeArgsReader::from_slice(arg_raw_data)?;
 
-        let chain_id = ChainId::from_raw(reader.chain_id().raw_data())?;
+        let chain_id = ChainId::from_raw(reader.chain_id().raw_data()).field("chain_id")?;
 
-        Some(SidechainConfigCellTypeArgs { chain_id })
+        Ok(SidechainConfigCellTypeArgs { chain_id })
     }
 }
 
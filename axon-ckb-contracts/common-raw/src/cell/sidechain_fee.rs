@@ -6,7 +6,7 @@ use crate::{
         cell::sidechain_fee::{SidechainFeeCellLockArgsBuilder, SidechainFeeCellLockArgsReader},
         common::{ChainIdReader, Uint128Reader},
     },
-    FromRaw, PureSudtTokenCell, Serialize,
+    DecodeError, DecodeField, FromRaw, PureSudtTokenCell, Serialize,
 };
 
 /**
@@ -22,6 +22,7 @@ use crate::{
         args: chain_id
 */
 #[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Ord, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SidechainFeeCell {
     pub amount: u128,
 }
@@ -29,19 +30,20 @@ pub struct SidechainFeeCell {
 PureSudtTokenCell!(SidechainFeeCell);
 
 #[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Ord, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SidechainFeeCellLockArgs {
     pub chain_id: ChainId,
     pub surplus:  u128,
 }
 
 impl FromRaw for SidechainFeeCellLockArgs {
-    fn from_raw(arg_raw_data: &[u8]) -> Option<Self> {
-        let reader = SidechainFeeCellLockArgsReader::from_slice(arg_raw_data).ok()?;
+    fn from_raw(arg_raw_data: &[u8]) -> Result<Self, DecodeError> {
+        let reader = SidechainFeeCellLockArgsReader::from_slice(arg_raw_data)?;
 
-        let chain_id = ChainId::from_raw(reader.chain_id().raw_data())?;
-        let surplus = u128::from_raw(reader.surplus().raw_data())?;
+        let chain_id = ChainId::from_raw(reader.chain_id().raw_data()).field("chain_id")?;
+        let surplus = u128::from_raw(reader.surplus().raw_data()).field("surplus")?;
 
-        Some(Self { chain_id, surplus })
+        Ok(Self { chain_id, surplus })
     }
 }
 
@@ -6,23 +6,24 @@ use crate::{
         cell::sidechain_registry::{SidechainRegistryCellBuilder, SidechainRegistryCellReader},
         common::{ChainIdListBuilder, ChainIdReader},
     },
-    FromRaw, Serialize,
+    DecodeError, DecodeField, FromRaw, Serialize,
 };
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 struct SidechainRegistryCell {
     chain_ids: Vec<ChainId>,
 }
 
 impl FromRaw for SidechainRegistryCell {
-    fn from_raw(cell_raw_data: &[u8]) -> Option<Self> {
-        let reader = SidechainRegistryCellReader::from_slice(cell_raw_data).ok()?;
+    fn from_raw(cell_raw_data: &[u8]) -> Result<Self, DecodeError> {
+        let reader = SidechainRegistryCellReader::from_slice(cell_raw_data)?;
         let mut chain_ids = Vec::new();
         for chain_id_reader in reader.chain_ids().iter() {
-            let chain_id = ChainId::from_raw(chain_id_reader.raw_data())?;
+            let chain_id = ChainId::from_raw(chain_id_reader.raw_data()).field("chain_ids")?;
             chain_ids.push(chain_id);
         }
-        Some(Self { chain_ids })
+        Ok(Self { chain_ids })
     }
 }
 
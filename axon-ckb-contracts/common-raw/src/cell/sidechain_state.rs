@@ -1,20 +1,23 @@
 use molecule::prelude::*;
 
 use crate::{
-    common::{BlockHeader, BlockHeight, BlockSlice, ChainId, CommittedHash, MerkleHash, PubKeyHash, RandomSeed},
+    common::{BlockHeader, BlockHeight, BlockSlice, ChainId, CommittedHash, Identity, MerkleHash, RandomSeed},
+    is_strictly_sorted_by_key,
     molecule::{
         cell::sidechain_state::{
             BlockHeadersBuilder, CheckerLastAcceptTaskHeightBuilder, CheckerLastAcceptTaskHeightReader,
-            CheckerLastAcceptTaskHeightsBuilder, CommittedCheckerInfoBuilder, CommittedCheckerInfoReader, CommittedCheckerInfosBuilder,
-            JobsBuilder, PunishedCheckerBuilder, PunishedCheckerReader, PunishedCheckersBuilder, SidechainStateCellBuilder,
-            SidechainStateCellReader, SidechainStateCellTypeArgsBuilder, SidechainStateCellTypeArgsReader,
+            CheckerLastAcceptTaskHeightsBuilder, CheckerTaskRecordBuilder, CheckerTaskRecordReader, CommittedCheckerInfoBuilder,
+            CommittedCheckerInfoReader, CommittedCheckerInfosBuilder, JobsBuilder, PunishedCheckerBuilder, PunishedCheckerReader,
+            PunishedCheckersBuilder, SidechainStateCellBuilder, SidechainStateCellReader, SidechainStateCellTypeArgsBuilder,
+            SidechainStateCellTypeArgsReader, SubmitProgressBuilder, SubmitProgressOptBuilder, SubmitProgressReader,
         },
         common::{
             BlockHeaderReader, BlockHeightReader, BlockSliceReader, ChainIdReader, CommittedHashReader, MerkleHashReader, PubKeyHashReader,
-            RandomSeedReader, Uint32Reader, Uint8Reader,
+            RandomSeedReader, Uint32Reader, Uint64Reader, Uint8Reader,
         },
     },
-    FromRaw, Serialize,
+    smt::SmtNode,
+    DecodeError, DecodeField, FromRaw, Hasher, Serialize,
 };
 
 /**
@@ -30,19 +33,22 @@ use crate::{
         args: null
 */
 #[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Ord, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CommittedCheckerInfo {
-    pub checker_lock_arg: PubKeyHash,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
+    pub checker_lock_arg: Identity,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
     pub committed_hash:   CommittedHash,
 }
 
 impl CommittedCheckerInfo {
-    fn from_reader(reader: CommittedCheckerInfoReader) -> Option<Self> {
-        let mut checker_lock_arg = PubKeyHash::default();
+    fn from_reader(reader: CommittedCheckerInfoReader) -> Result<Self, DecodeError> {
+        let mut checker_lock_arg = Identity::default();
         checker_lock_arg.copy_from_slice(reader.checker_lock_arg().raw_data());
         let mut committed_hash = CommittedHash::default();
         committed_hash.copy_from_slice(reader.committed_hash().raw_data());
 
-        Some(Self {
+        Ok(Self {
             checker_lock_arg,
             committed_hash,
         })
@@ -50,14 +56,14 @@ impl CommittedCheckerInfo {
 }
 
 impl FromRaw for CommittedCheckerInfo {
-    fn from_raw(raw_data: &[u8]) -> Option<Self> {
-        let reader = CommittedCheckerInfoReader::from_slice(raw_data).ok()?;
-        let mut checker_lock_arg = PubKeyHash::default();
+    fn from_raw(raw_data: &[u8]) -> Result<Self, DecodeError> {
+        let reader = CommittedCheckerInfoReader::from_slice(raw_data)?;
+        let mut checker_lock_arg = Identity::default();
         checker_lock_arg.copy_from_slice(reader.checker_lock_arg().raw_data());
         let mut committed_hash = CommittedHash::default();
         committed_hash.copy_from_slice(reader.committed_hash().raw_data());
 
-        Some(Self {
+        Ok(Self {
             checker_lock_arg,
             committed_hash,
         })
@@ -83,18 +89,20 @@ impl Serialize for CommittedCheckerInfo {
 }
 
 #[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Ord, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PunishedChecker {
-    pub checker_lock_arg: PubKeyHash,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
+    pub checker_lock_arg: Identity,
     pub punish_points:    u32,
 }
 
 impl PunishedChecker {
-    fn from_reader(reader: PunishedCheckerReader) -> Option<Self> {
-        let mut checker_lock_arg = PubKeyHash::default();
+    fn from_reader(reader: PunishedCheckerReader) -> Result<Self, DecodeError> {
+        let mut checker_lock_arg = Identity::default();
         checker_lock_arg.copy_from_slice(reader.checker_lock_arg().raw_data());
-        let punish_points = u32::from_raw(reader.punish_points().raw_data())?;
+        let punish_points = u32::from_raw(reader.punish_points().raw_data()).field("punish_points")?;
 
-        Some(Self {
+        Ok(Self {
             checker_lock_arg,
             punish_points,
         })
@@ -102,13 +110,13 @@ impl PunishedChecker {
 }
 
 impl FromRaw for PunishedChecker {
-    fn from_raw(cell_raw_data: &[u8]) -> Option<Self> {
-        let reader = PunishedCheckerReader::from_slice(cell_raw_data).ok()?;
-        let mut checker_lock_arg = PubKeyHash::default();
+    fn from_raw(cell_raw_data: &[u8]) -> Result<Self, DecodeError> {
+        let reader = PunishedCheckerReader::from_slice(cell_raw_data)?;
+        let mut checker_lock_arg = Identity::default();
         checker_lock_arg.copy_from_slice(reader.checker_lock_arg().raw_data());
-        let punish_points = u32::from_raw(reader.punish_points().raw_data())?;
+        let punish_points = u32::from_raw(reader.punish_points().raw_data()).field("punish_points")?;
 
-        Some(Self {
+        Ok(Self {
             checker_lock_arg,
             punish_points,
         })
@@ -130,31 +138,33 @@ impl Serialize for PunishedChecker {
 }
 
 #[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Ord, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CheckerLastAcceptTaskHeight {
-    pub checker_lock_arg: PubKeyHash,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
+    pub checker_lock_arg: Identity,
     pub height:           BlockHeight,
 }
 
 impl CheckerLastAcceptTaskHeight {
-    fn from_reader(reader: CheckerLastAcceptTaskHeightReader) -> Option<Self> {
-        let mut checker_lock_arg = PubKeyHash::default();
+    fn from_reader(reader: CheckerLastAcceptTaskHeightReader) -> Result<Self, DecodeError> {
+        let mut checker_lock_arg = Identity::default();
         checker_lock_arg.copy_from_slice(reader.checker_lock_arg().raw_data());
 
-        let height = BlockHeight::from_raw(reader.height().raw_data())?;
+        let height = BlockHeight::from_raw(reader.height().raw_data()).field("height")?;
 
-        Some(Self { checker_lock_arg, height })
+        Ok(Self { checker_lock_arg, height })
     }
 }
 
 impl FromRaw for CheckerLastAcceptTaskHeight {
-    fn from_raw(cell_raw_data: &[u8]) -> Option<Self> {
-        let reader = CheckerLastAcceptTaskHeightReader::from_slice(cell_raw_data).ok()?;
-        let mut checker_lock_arg = PubKeyHash::default();
+    fn from_raw(cell_raw_data: &[u8]) -> Result<Self, DecodeError> {
+        let reader = CheckerLastAcceptTaskHeightReader::from_slice(cell_raw_data)?;
+        let mut checker_lock_arg = Identity::default();
         checker_lock_arg.copy_from_slice(reader.checker_lock_arg().raw_data());
 
-        let height = BlockHeight::from_raw(reader.height().raw_data())?;
+        let height = BlockHeight::from_raw(reader.height().raw_data()).field("height")?;
 
-        Some(Self { checker_lock_arg, height })
+        Ok(Self { checker_lock_arg, height })
     }
 }
 
@@ -172,53 +182,206 @@ impl Serialize for CheckerLastAcceptTaskHeight {
     }
 }
 
+/*
+A leaf of checker_task_root, what punish_checkers and checker_last_task_sidechain_heights tell about
+a checker when the checkers of the sidechain are committed to by a root. A checker with neither
+punish points nor tasks is an empty leaf.
+ */
+#[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Ord, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CheckerTaskRecord {
+    pub punish_points:    u32,
+    pub last_task_height: BlockHeight,
+}
+
+impl CheckerTaskRecord {
+    pub fn leaf<H: Hasher>(&self, checker: &Identity) -> SmtNode {
+        if *self == Self::default() {
+            return SmtNode::default();
+        }
+
+        SmtNode::leaf::<H>(checker, &self.serialize(), 0)
+    }
+}
+
+impl FromRaw for CheckerTaskRecord {
+    fn from_raw(raw: &[u8]) -> Result<Self, DecodeError> {
+        let reader = CheckerTaskRecordReader::from_slice(raw)?;
+
+        let punish_points = u32::from_raw(reader.punish_points().raw_data()).field("punish_points")?;
+        let last_task_height = BlockHeight::from_raw(reader.last_task_height().raw_data()).field("last_task_height")?;
+
+        Ok(Self {
+            punish_points,
+            last_task_height,
+        })
+    }
+}
+
+impl Serialize for CheckerTaskRecord {
+    type RawType = Vec<u8>;
+
+    fn serialize(&self) -> Self::RawType {
+        let mut buf = Vec::new();
+        CheckerTaskRecordBuilder::default()
+            .punish_points(Uint32Reader::new_unchecked(&self.punish_points.serialize()).to_entity())
+            .last_task_height(BlockHeightReader::new_unchecked(&self.last_task_height.serialize()).to_entity())
+            .write(&mut buf)
+            .expect("Unable to write buffer while serializing sidechainState::CheckerTaskRecord");
+        buf
+    }
+}
+
+/*
+A job whose tasks are submitted across several transactions, see collator_submit_tasks. Every
+chunk but the last leaves it in the state, the last one takes random_seed into the state and drops it.
+ */
+#[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Ord, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SubmitProgress {
+    // what the tasks of the job share, so that later chunks can't carry tasks of another job
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
+    pub job_hash:             MerkleHash,
+    pub remaining_tasks:      u32,
+    pub remaining_challenges: u32,
+    pub settle_count:         u32,
+    pub shutdown_count:       u32,
+    // checkers of the job with an earlier commit, thus a reveal to make
+    pub committed_count:      u32,
+    // reveals which went into random_seed, see SidechainConfigCell::reveal_quorum
+    pub reveal_count:         u32,
+    // the seed folded with the reveals of the chunks so far
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
+    pub random_seed:          RandomSeed,
+}
+
+impl FromRaw for SubmitProgress {
+    fn from_raw(raw: &[u8]) -> Result<Self, DecodeError> {
+        let reader = SubmitProgressReader::from_slice(raw)?;
+
+        let job_hash = MerkleHash::from_raw(reader.job_hash().raw_data()).field("job_hash")?;
+        let remaining_tasks = u32::from_raw(reader.remaining_tasks().raw_data()).field("remaining_tasks")?;
+        let remaining_challenges = u32::from_raw(reader.remaining_challenges().raw_data()).field("remaining_challenges")?;
+        let settle_count = u32::from_raw(reader.settle_count().raw_data()).field("settle_count")?;
+        let shutdown_count = u32::from_raw(reader.shutdown_count().raw_data()).field("shutdown_count")?;
+        let committed_count = u32::from_raw(reader.committed_count().raw_data()).field("committed_count")?;
+        let reveal_count = u32::from_raw(reader.reveal_count().raw_data()).field("reveal_count")?;
+        let random_seed = RandomSeed::from_raw(reader.random_seed().raw_data()).field("random_seed")?;
+
+        Ok(Self {
+            job_hash,
+            remaining_tasks,
+            remaining_challenges,
+            settle_count,
+            shutdown_count,
+            committed_count,
+            reveal_count,
+            random_seed,
+        })
+    }
+}
+
+impl Serialize for SubmitProgress {
+    type RawType = Vec<u8>;
+
+    fn serialize(&self) -> Self::RawType {
+        let mut buf = Vec::new();
+        SubmitProgressBuilder::default()
+            .job_hash(MerkleHashReader::new_unchecked(&self.job_hash).to_entity())
+            .remaining_tasks(Uint32Reader::new_unchecked(&self.remaining_tasks.serialize()).to_entity())
+            .remaining_challenges(Uint32Reader::new_unchecked(&self.remaining_challenges.serialize()).to_entity())
+            .settle_count(Uint32Reader::new_unchecked(&self.settle_count.serialize()).to_entity())
+            .shutdown_count(Uint32Reader::new_unchecked(&self.shutdown_count.serialize()).to_entity())
+            .committed_count(Uint32Reader::new_unchecked(&self.committed_count.serialize()).to_entity())
+            .reveal_count(Uint32Reader::new_unchecked(&self.reveal_count.serialize()).to_entity())
+            .random_seed(RandomSeedReader::new_unchecked(&self.random_seed).to_entity())
+            .write(&mut buf)
+            .expect("Unable to write buffer while serializing sidechainState::SubmitProgress");
+        buf
+    }
+}
+
 #[derive(Debug, Clone, PartialOrd, PartialEq, Ord, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SidechainStateCell {
     pub version: u8,
     pub submit_sidechain_block_height: BlockHeight,
     pub waiting_jobs: Vec<BlockSlice>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
     pub random_seed: RandomSeed,
-    pub random_offset: u8,
+    // draws made from random_seed, see seed::derive_seed
+    pub random_offset: u64,
+    // seeds taken into random_seed so far, the epoch of the beacon, see beacon::RandomBeacon
+    pub seed_epoch: u64,
     pub random_commit: Vec<CommittedCheckerInfo>,
+    // sorted by lock arg without duplicates, as is checker_last_task_sidechain_heights
     pub punish_checkers: Vec<PunishedChecker>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex::vec"))]
     pub recent_block_headers: Vec<BlockHeader>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
     pub ancient_block_heard_merkle_root: MerkleHash,
     pub checker_last_task_sidechain_heights: Vec<CheckerLastAcceptTaskHeight>,
+    // the CheckerTaskRecords of a sidechain with checker_set_root, the two lists above stay empty then
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
+    pub checker_task_root: MerkleHash,
+    pub submit_progress: Option<SubmitProgress>,
+}
+
+impl SidechainStateCell {
+    // Ok with the index of the entry of checker in punish_checkers, Err with where it would go
+    pub fn punished_checker_index(&self, checker: &Identity) -> Result<usize, usize> {
+        self.punish_checkers
+            .binary_search_by(|punished| punished.checker_lock_arg.cmp(checker))
+    }
+
+    // as punished_checker_index, in checker_last_task_sidechain_heights
+    pub fn last_task_height_index(&self, checker: &Identity) -> Result<usize, usize> {
+        self.checker_last_task_sidechain_heights
+            .binary_search_by(|last_height| last_height.checker_lock_arg.cmp(checker))
+    }
+
+    pub fn has_sorted_checkers(&self) -> bool {
+        is_strictly_sorted_by_key(&self.punish_checkers, |punished| punished.checker_lock_arg)
+            && is_strictly_sorted_by_key(&self.checker_last_task_sidechain_heights, |last_height| {
+                last_height.checker_lock_arg
+            })
+    }
 }
 
 impl FromRaw for SidechainStateCell {
-    fn from_raw(cell_raw_data: &[u8]) -> Option<SidechainStateCell> {
-        let reader = SidechainStateCellReader::from_slice(cell_raw_data).ok()?;
+    fn from_raw(cell_raw_data: &[u8]) -> Result<SidechainStateCell, DecodeError> {
+        let reader = SidechainStateCellReader::from_slice(cell_raw_data)?;
 
-        let version = u8::from_raw(reader.version().raw_data())?;
-        let submit_sidechain_block_height = BlockHeight::from_raw(reader.submit_sidechain_block_height().raw_data())?;
+        let version = u8::from_raw(reader.version().raw_data()).field("version")?;
+        let submit_sidechain_block_height =
+            BlockHeight::from_raw(reader.submit_sidechain_block_height().raw_data()).field("submit_sidechain_block_height")?;
         let waiting_jobs: Vec<BlockSlice> = reader
             .waiting_jobs()
             .iter()
-            .map(|reader| BlockSlice::from_raw(reader.as_slice()).ok_or(()))
-            .collect::<Result<Vec<BlockSlice>, ()>>()
-            .ok()?;
+            .map(|reader| BlockSlice::from_raw(reader.as_slice()))
+            .collect::<Result<Vec<BlockSlice>, DecodeError>>()
+            .field("waiting_jobs")?;
 
         let mut random_seed = RandomSeed::default();
         random_seed.copy_from_slice(reader.random_seed().raw_data());
 
-        let mut buf = [0u8; 1];
-        buf.copy_from_slice(reader.random_offset().raw_data());
-        let random_offset = u8::from_raw(&buf)?;
+        let random_offset = u64::from_raw(reader.random_offset().raw_data()).field("random_offset")?;
+
+        let seed_epoch = u64::from_raw(reader.seed_epoch().raw_data()).field("seed_epoch")?;
 
         let random_commit = reader
             .random_commit()
             .iter()
-            .map(|reader| CommittedCheckerInfo::from_reader(reader).ok_or(()))
-            .collect::<Result<Vec<CommittedCheckerInfo>, ()>>()
-            .ok()?;
+            .map(|reader| CommittedCheckerInfo::from_reader(reader))
+            .collect::<Result<Vec<CommittedCheckerInfo>, DecodeError>>()
+            .field("random_commit")?;
 
         let punish_checkers = reader
             .punish_checkers()
             .iter()
-            .map(|reader| PunishedChecker::from_reader(reader).ok_or(()))
-            .collect::<Result<Vec<PunishedChecker>, ()>>()
-            .ok()?;
+            .map(|reader| PunishedChecker::from_reader(reader))
+            .collect::<Result<Vec<PunishedChecker>, DecodeError>>()
+            .field("punish_checkers")?;
 
         let recent_block_headers = reader
             .recent_block_headers()
@@ -236,21 +399,32 @@ impl FromRaw for SidechainStateCell {
         let checker_last_task_sidechain_heights = reader
             .checker_last_task_sidechain_heights()
             .iter()
-            .map(|reader| CheckerLastAcceptTaskHeight::from_reader(reader).ok_or(()))
-            .collect::<Result<Vec<CheckerLastAcceptTaskHeight>, ()>>()
-            .ok()?;
+            .map(|reader| CheckerLastAcceptTaskHeight::from_reader(reader))
+            .collect::<Result<Vec<CheckerLastAcceptTaskHeight>, DecodeError>>()
+            .field("checker_last_task_sidechain_heights")?;
+
+        let mut checker_task_root = MerkleHash::default();
+        checker_task_root.copy_from_slice(reader.checker_task_root().raw_data());
+
+        let submit_progress = match reader.submit_progress().to_opt() {
+            Some(progress_reader) => Some(SubmitProgress::from_raw(progress_reader.as_slice()).field("submit_progress")?),
+            None => None,
+        };
 
-        Some(Self {
+        Ok(Self {
             version,
             submit_sidechain_block_height,
             waiting_jobs,
             random_seed,
             random_offset,
+            seed_epoch,
             random_commit,
             punish_checkers,
             recent_block_headers,
             ancient_block_heard_merkle_root,
             checker_last_task_sidechain_heights,
+            checker_task_root,
+            submit_progress,
         })
     }
 }
@@ -271,7 +445,9 @@ impl Serialize for SidechainStateCell {
 
         let random_seed = RandomSeedReader::new_unchecked(&self.random_seed).to_entity();
 
-        let random_offset = Uint8Reader::new_unchecked(&self.random_offset.serialize()).to_entity();
+        let random_offset = Uint64Reader::new_unchecked(&self.random_offset.serialize()).to_entity();
+
+        let seed_epoch = Uint64Reader::new_unchecked(&self.seed_epoch.serialize()).to_entity();
 
         let mut random_commit_builder = CommittedCheckerInfosBuilder::default();
         for committed_checker_info in &self.random_commit {
@@ -303,6 +479,15 @@ impl Serialize for SidechainStateCell {
         }
         let checker_last_task_sidechain_heights = checker_last_task_sidechain_heights_builder.build();
 
+        let checker_task_root = MerkleHashReader::new_unchecked(&self.checker_task_root).to_entity();
+
+        let submit_progress = SubmitProgressOptBuilder::default()
+            .set(
+                self.submit_progress
+                    .map(|progress| SubmitProgressReader::new_unchecked(&progress.serialize()).to_entity()),
+            )
+            .build();
+
         let mut buf = Vec::new();
         SidechainStateCellBuilder::default()
             .version(version)
@@ -310,11 +495,14 @@ impl Serialize for SidechainStateCell {
             .waiting_jobs(waiting_jobs)
             .random_seed(random_seed)
             .random_offset(random_offset)
+            .seed_epoch(seed_epoch)
             .random_commit(random_commit)
             .punish_checkers(punish_checkers)
             .recent_block_headers(recent_block_headers)
             .ancient_block_heard_merkle_root(ancient_block_heard_merkle_root)
             .checker_last_task_sidechain_heights(checker_last_task_sidechain_heights)
+            .checker_task_root(checker_task_root)
+            .submit_progress(submit_progress)
             .write(&mut buf)
             .expect("Unable to write buffer while serializing sidechainState::SidechainStateCell");
         buf
@@ -322,15 +510,16 @@ impl Serialize for SidechainStateCell {
 }
 
 #[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Ord, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SidechainStateCellTypeArgs {
     pub chain_id: ChainId,
 }
 
 impl FromRaw for SidechainStateCellTypeArgs {
-    fn from_raw(arg_raw_data: &[u8]) -> Option<SidechainStateCellTypeArgs> {
-        let reader = SidechainStateCellTypeArgsReader::from_slice(arg_raw_data).ok()?;
-        let chain_id = ChainId::from_raw(reader.chain_id().raw_data())?;
-        Some(SidechainStateCellTypeArgs { chain_id })
+    fn from_raw(arg_raw_data: &[u8]) -> Result<SidechainStateCellTypeArgs, DecodeError> {
+        let reader = SidechainStateCellTypeArgsReader::from_slice(arg_raw_data)?;
+        let chain_id = ChainId::from_raw(reader.chain_id().raw_data()).field("chain_id")?;
+        Ok(SidechainStateCellTypeArgs { chain_id })
     }
 }
 
@@ -12,10 +12,11 @@ use crate::{
             Uint8Reader,
         },
     },
-    FromRaw, Serialize,
+    DecodeError, DecodeField, FromRaw, Serialize,
 };
 
 #[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Ord, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum TaskMode {
     Task,
@@ -23,12 +24,12 @@ pub enum TaskMode {
 }
 
 impl TaskMode {
-    fn from_reader(reader: TaskModeReader) -> Option<Self> {
+    fn from_reader(reader: TaskModeReader) -> Result<Self, DecodeError> {
         let mode = u8::from_raw(reader.raw_data())?;
         match mode {
-            0u8 => Some(Self::Task),
-            1u8 => Some(Self::Challenge),
-            _ => None,
+            0u8 => Ok(Self::Task),
+            1u8 => Ok(Self::Challenge),
+            _ => Err(DecodeError::unknown_variant(mode)),
         }
     }
 }
@@ -40,8 +41,8 @@ impl Default for TaskMode {
 }
 
 impl FromRaw for TaskMode {
-    fn from_raw(raw: &[u8]) -> Option<Self> {
-        let reader = TaskModeReader::from_slice(raw).ok()?;
+    fn from_raw(raw: &[u8]) -> Result<Self, DecodeError> {
+        let reader = TaskModeReader::from_slice(raw)?;
         Self::from_reader(reader)
     }
 }
@@ -55,6 +56,7 @@ impl Serialize for TaskMode {
 }
 
 #[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Ord, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum TaskStatus {
     Idle,
@@ -64,14 +66,14 @@ pub enum TaskStatus {
 }
 
 impl TaskStatus {
-    fn from_reader(reader: TaskStatusReader) -> Option<Self> {
+    fn from_reader(reader: TaskStatusReader) -> Result<Self, DecodeError> {
         let status = u8::from_raw(reader.raw_data())?;
         match status {
-            0u8 => Some(Self::Idle),
-            1u8 => Some(Self::TaskPassed),
-            2u8 => Some(Self::ChallengePassed),
-            3u8 => Some(Self::ChallengeRejected),
-            _ => None,
+            0u8 => Ok(Self::Idle),
+            1u8 => Ok(Self::TaskPassed),
+            2u8 => Ok(Self::ChallengePassed),
+            3u8 => Ok(Self::ChallengeRejected),
+            _ => Err(DecodeError::unknown_variant(status)),
         }
     }
 }
@@ -83,8 +85,8 @@ impl Default for TaskStatus {
 }
 
 impl FromRaw for TaskStatus {
-    fn from_raw(raw: &[u8]) -> Option<Self> {
-        let reader = TaskStatusReader::from_slice(raw).ok()?;
+    fn from_raw(raw: &[u8]) -> Result<Self, DecodeError> {
+        let reader = TaskStatusReader::from_slice(raw)?;
         Self::from_reader(reader)
     }
 }
@@ -110,6 +112,7 @@ impl Serialize for TaskStatus {
         args: null
 */
 #[derive(Debug, Clone, PartialOrd, PartialEq, Ord, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TaskCell {
     pub version: u8,
     pub sidechain_block_height_from: BlockHeight,
@@ -117,21 +120,25 @@ pub struct TaskCell {
     pub check_data_size: u128,
     pub mode: TaskMode,
     pub status: TaskStatus,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
     pub reveal: RandomSeed,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
     pub commit: CommittedHash,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex::vec"))]
     pub sidechain_block_header: Vec<BlockHeader>,
 }
 
 impl FromRaw for TaskCell {
-    fn from_raw(cell_raw_data: &[u8]) -> Option<TaskCell> {
-        let reader = TaskCellReader::from_slice(cell_raw_data).ok()?;
+    fn from_raw(cell_raw_data: &[u8]) -> Result<TaskCell, DecodeError> {
+        let reader = TaskCellReader::from_slice(cell_raw_data)?;
 
-        let version = u8::from_raw(reader.version().raw_data())?;
-        let sidechain_block_height_from = u128::from_raw(reader.sidechain_block_height_from().raw_data())?;
-        let sidechain_block_height_to = u128::from_raw(reader.sidechain_block_height_to().raw_data())?;
-        let check_data_size = u128::from_raw(reader.check_data_size().raw_data())?;
-        let mode = TaskMode::from_reader(reader.mode())?;
-        let status = TaskStatus::from_reader(reader.status())?;
+        let version = u8::from_raw(reader.version().raw_data()).field("version")?;
+        let sidechain_block_height_from =
+            u128::from_raw(reader.sidechain_block_height_from().raw_data()).field("sidechain_block_height_from")?;
+        let sidechain_block_height_to = u128::from_raw(reader.sidechain_block_height_to().raw_data()).field("sidechain_block_height_to")?;
+        let check_data_size = u128::from_raw(reader.check_data_size().raw_data()).field("check_data_size")?;
+        let mode = TaskMode::from_reader(reader.mode()).field("mode")?;
+        let status = TaskStatus::from_reader(reader.status()).field("status")?;
 
         let mut reveal: RandomSeed = [0u8; 32];
         reveal.copy_from_slice(reader.reveal().raw_data());
@@ -152,7 +159,7 @@ impl FromRaw for TaskCell {
             result
         });
 
-        Some(TaskCell {
+        Ok(TaskCell {
             version,
             sidechain_block_height_from,
             sidechain_block_height_to,
@@ -202,21 +209,23 @@ impl Serialize for TaskCell {
 }
 
 #[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Ord, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TaskCellTypeArgs {
     pub chain_id:         ChainId,
-    pub checker_lock_arg: PubKeyHash,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
+    pub checker_lock_arg: Identity,
 }
 
 impl FromRaw for TaskCellTypeArgs {
-    fn from_raw(arg_raw_data: &[u8]) -> Option<TaskCellTypeArgs> {
-        let reader = TaskCellTypeArgsReader::from_slice(arg_raw_data).ok()?;
+    fn from_raw(arg_raw_data: &[u8]) -> Result<TaskCellTypeArgs, DecodeError> {
+        let reader = TaskCellTypeArgsReader::from_slice(arg_raw_data)?;
 
-        let chain_id = ChainId::from_raw(reader.chain_id().raw_data())?;
+        let chain_id = ChainId::from_raw(reader.chain_id().raw_data()).field("chain_id")?;
 
-        let mut checker_lock_arg: PubKeyHash = PubKeyHash::default();
+        let mut checker_lock_arg: Identity = Identity::default();
         checker_lock_arg.copy_from_slice(reader.checker_lock_arg().raw_data());
 
-        Some(TaskCellTypeArgs {
+        Ok(TaskCellTypeArgs {
             chain_id,
             checker_lock_arg,
         })
@@ -1,11 +1,12 @@
 use crate::molecule::common::{BlockHeightReader, BlockSliceBuilder, BlockSliceReader};
-use crate::{FromRaw, Serialize};
+use crate::{DecodeError, DecodeField, FromRaw, Serialize};
 use molecule::prelude::*;
 pub type BlockHeader = [u8; 32];
 pub type BlockHeight = u128;
 pub type CodeHash = [u8; 32];
 
 #[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Ord, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum HashType {
     Data,
@@ -19,12 +20,12 @@ impl Default for HashType {
 }
 
 impl FromRaw for HashType {
-    fn from_raw(raw: &[u8]) -> Option<Self> {
+    fn from_raw(raw: &[u8]) -> Result<Self, DecodeError> {
         let status = u8::from_raw(raw)?;
         match status {
-            0u8 => Some(Self::Data),
-            1u8 => Some(Self::Type),
-            _ => None,
+            0u8 => Ok(Self::Data),
+            1u8 => Ok(Self::Type),
+            _ => Err(DecodeError::unknown_variant(status)),
         }
     }
 }
@@ -40,27 +41,40 @@ impl Serialize for HashType {
 pub type MerkleHash = [u8; 32];
 pub type PubKeyHash = [u8; 20];
 
-impl FromRaw for PubKeyHash {
-    fn from_raw(raw_data: &[u8]) -> Option<Self> {
-        let mut buf = Self::default();
-        buf.copy_from_slice(raw_data);
-        Some(buf)
+pub type ScriptHash = [u8; 32];
+
+/*
+    Identity of a signer, the first 20 bytes of its full lock script hash.
+    It does not depend on which lock the signer uses(secp256k1, multisig, omni-lock, ...),
+    and shares the molecule layout of PubKeyHash.
+*/
+pub type Identity = [u8; 20];
+
+pub trait SignerIdentity {
+    fn from_lock_hash(lock_hash: &ScriptHash) -> Self;
+}
+
+impl SignerIdentity for Identity {
+    fn from_lock_hash(lock_hash: &ScriptHash) -> Self {
+        let mut identity = Self::default();
+        identity.copy_from_slice(&lock_hash[..20]);
+        identity
     }
 }
-pub type ScriptHash = [u8; 32];
 
 #[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Ord, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BlockSlice {
     pub from: BlockHeight,
     pub to:   BlockHeight,
 }
 
 impl FromRaw for BlockSlice {
-    fn from_raw(raw: &[u8]) -> Option<Self> {
-        let reader = BlockSliceReader::from_slice(raw).ok()?;
-        let from = BlockHeight::from_raw(reader.from().raw_data())?;
-        let to = BlockHeight::from_raw(reader.to().raw_data())?;
-        return Some(BlockSlice { from, to });
+    fn from_raw(raw: &[u8]) -> Result<Self, DecodeError> {
+        let reader = BlockSliceReader::from_slice(raw)?;
+        let from = BlockHeight::from_raw(reader.from().raw_data()).field("from")?;
+        let to = BlockHeight::from_raw(reader.to().raw_data()).field("to")?;
+        return Ok(BlockSlice { from, to });
     }
 }
 
@@ -79,6 +93,9 @@ impl Serialize for BlockSlice {
 }
 pub type ChainId = u32;
 
+// commission_rate and slash_rate are fractions over RATE_DENOMINATOR, i.e. in basis points
+pub const RATE_DENOMINATOR: u32 = 10_000;
+
 pub type RandomSeed = [u8; 32];
 pub type CommittedHash = [u8; 32];
 
@@ -86,14 +103,15 @@ pub type CommittedHash = [u8; 32];
 macro_rules! PureSudtTokenCell {
     ($type: ty) => {
         impl crate::FromRaw for $type {
-            fn from_raw(cell_raw_data: &[u8]) -> Option<Self> {
+            fn from_raw(cell_raw_data: &[u8]) -> Result<Self, crate::DecodeError> {
+                use crate::DecodeField;
                 use molecule::prelude::Reader;
 
-                let reader = crate::molecule::cell::sudt_token::SudtTokenCellReader::from_slice(cell_raw_data).ok()?;
+                let reader = crate::molecule::cell::sudt_token::SudtTokenCellReader::from_slice(cell_raw_data)?;
 
-                let amount = crate::FromRaw::from_raw(reader.amount().raw_data())?;
+                let amount = crate::FromRaw::from_raw(reader.amount().raw_data()).field("amount")?;
 
-                Some(Self { amount })
+                Ok(Self { amount })
             }
         }
 
@@ -0,0 +1,146 @@
+use core::fmt;
+
+use molecule::error::VerificationError;
+
+/*
+Path of the field which failed to decode, outermost first.
+std builds keep every level, e.g. punish_checkers.checker_lock_arg, no_std builds only keep the
+innermost field so contracts don't allocate while reporting an error.
+ */
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct FieldPath(Vec<&'static str>);
+
+#[cfg(not(feature = "std"))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct FieldPath(Option<&'static str>);
+
+impl FieldPath {
+    #[cfg(feature = "std")]
+    fn push(&mut self, field: &'static str) {
+        self.0.push(field);
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn push(&mut self, field: &'static str) {
+        self.0.get_or_insert(field);
+    }
+
+    #[cfg(feature = "std")]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    #[cfg(not(feature = "std"))]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_none()
+    }
+}
+
+impl fmt::Display for FieldPath {
+    #[cfg(feature = "std")]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, field) in self.0.iter().rev().enumerate() {
+            if i > 0 {
+                f.write_str(".")?;
+            }
+            f.write_str(field)?;
+        }
+        Ok(())
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0.unwrap_or_default())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    // molecule verification of the entity failed, its header or layout is corrupted
+    Molecule {
+        path: FieldPath,
+    },
+    // a fixed size entity or field has a wrong number of bytes
+    Length {
+        path:     FieldPath,
+        expected: usize,
+        actual:   usize,
+    },
+    // the byte is not a variant of the enum, e.g. an unknown status or pattern
+    UnknownVariant {
+        path:  FieldPath,
+        value: u8,
+    },
+}
+
+impl DecodeError {
+    pub fn molecule() -> Self {
+        Self::Molecule {
+            path: FieldPath::default(),
+        }
+    }
+
+    pub fn length(expected: usize, actual: usize) -> Self {
+        Self::Length {
+            path: FieldPath::default(),
+            expected,
+            actual,
+        }
+    }
+
+    pub fn unknown_variant(value: u8) -> Self {
+        Self::UnknownVariant {
+            path: FieldPath::default(),
+            value,
+        }
+    }
+
+    pub fn path(&self) -> &FieldPath {
+        match self {
+            Self::Molecule { path } | Self::Length { path, .. } | Self::UnknownVariant { path, .. } => path,
+        }
+    }
+
+    // records the field the error happened in, called from the innermost field outwards
+    pub fn within(mut self, field: &'static str) -> Self {
+        match &mut self {
+            Self::Molecule { path } | Self::Length { path, .. } | Self::UnknownVariant { path, .. } => path.push(field),
+        }
+        self
+    }
+}
+
+impl From<VerificationError> for DecodeError {
+    fn from(_: VerificationError) -> Self {
+        Self::molecule()
+    }
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Molecule { .. } => write!(f, "molecule verification failed")?,
+            Self::Length { expected, actual, .. } => write!(f, "expected {} bytes, got {}", expected, actual)?,
+            Self::UnknownVariant { value, .. } => write!(f, "unknown variant {}", value)?,
+        }
+
+        if !self.path().is_empty() {
+            write!(f, " at {}", self.path())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecodeError {}
+
+pub trait DecodeField<T> {
+    fn field(self, field: &'static str) -> Result<T, DecodeError>;
+}
+
+impl<T> DecodeField<T> for Result<T, DecodeError> {
+    fn field(self, field: &'static str) -> Result<T, DecodeError> {
+        self.map_err(|err| err.within(field))
+    }
+}
@@ -0,0 +1,146 @@
+/*
+Exit codes of the code cell and what they mean.
+The contract's `Error` enum and off-chain tools both read their messages from here, so a failed
+transaction can be explained from its exit code alone. Codes are append-only, never renumber one.
+ */
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ErrorCode {
+    pub code:    i8,
+    pub name:    &'static str,
+    pub message: &'static str,
+}
+
+macro_rules! error_codes {
+    ($($code: literal $name: ident => $message: literal,)*) => {
+        pub const CODE_CELL_ERRORS: &[ErrorCode] = &[
+            $(ErrorCode {
+                code:    $code,
+                name:    stringify!($name),
+                message: $message,
+            },)*
+        ];
+    };
+}
+
+error_codes! {
+    1 IndexOutOfBound => "cell index out of bound",
+    2 ItemMissing => "item missing",
+    3 LengthNotEnough => "length not enough",
+    4 Encoding => "invalid encoding of cell data, script args or witness",
+    5 MissingTypeScript => "cell has no type script",
+    6 CodeHashMismatch => "script code hash mismatch",
+    7 HashTypeMismatch => "script hash type or args mismatch",
+    8 CellNumberMismatch => "wrong number of cells for the pattern",
+    9 GlobalConfigMissed => "global config cell not found in cell deps",
+    10 MissingWitness => "code cell witness has no input type",
+    11 TypeScriptMissed => "cell has no type script",
+    12 Wrong => "invalid transaction",
+    13 SidechainBondMismatch => "sidechain bond cell mismatch",
+    14 SidechainConfigMismatch => "sidechain config cell mismatch",
+    15 SidechainFeeMismatch => "sidechain fee cell mismatch",
+    16 SidechainStateMismatch => "sidechain state cell mismatch",
+    17 CheckerBondMismatch => "checker bond cell mismatch",
+    18 CheckerInfoMismatch => "checker info cell mismatch",
+    19 MuseTokenMismatch => "muse token cell mismatch",
+    20 TaskMismatch => "task cell mismatch",
+    21 MissingHeader => "required header dep missing",
+    22 BatchPatternInvalid => "pattern could not be batched",
+
+    23 WitnessEncoding => "invalid encoding of code cell witness",
+    24 GlobalConfigEncoding => "invalid encoding of global config cell data",
+    25 CodeCellLockArgsEncoding => "invalid encoding of code cell lock args",
+    26 CheckerBondEncoding => "invalid encoding of checker bond cell data",
+    27 CheckerBondLockArgsEncoding => "invalid encoding of checker bond cell lock args",
+    28 CheckerInfoEncoding => "invalid encoding of checker info cell data",
+    29 CheckerInfoTypeArgsEncoding => "invalid encoding of checker info cell type args",
+    30 MuseTokenEncoding => "invalid encoding of muse token cell data",
+    31 SidechainBondEncoding => "invalid encoding of sidechain bond cell data",
+    32 SidechainBondLockArgsEncoding => "invalid encoding of sidechain bond cell lock args",
+    33 SidechainConfigEncoding => "invalid encoding of sidechain config cell data",
+    34 SidechainConfigTypeArgsEncoding => "invalid encoding of sidechain config cell type args",
+    35 SidechainFeeEncoding => "invalid encoding of sidechain fee cell data",
+    36 SidechainFeeLockArgsEncoding => "invalid encoding of sidechain fee cell lock args",
+    37 SidechainStateEncoding => "invalid encoding of sidechain state cell data",
+    38 SidechainStateTypeArgsEncoding => "invalid encoding of sidechain state cell type args",
+    39 SudtTokenEncoding => "invalid encoding of sudt token cell data",
+    40 TaskEncoding => "invalid encoding of task cell data",
+    41 TaskTypeArgsEncoding => "invalid encoding of task cell type args",
+
+    42 TaskChainIdMismatch => "task chain id differs from the sidechain",
+    43 TaskCheckDataSizeMismatch => "task check data size differs from the witness",
+    44 TaskNotVoted => "task has not been voted",
+    45 TaskAlreadyVoted => "task has already been voted",
+    46 TaskStatusInconsistentWithVote => "task status inconsistent with vote",
+    47 TaskRevealHashMismatch => "task reveal hash mismatch",
+    48 TaskRevealNotDefault => "task reveal of a newly committed checker is not default",
+    49 TaskCommitHashMismatch => "task commit differs from the new committed hash",
+    50 TaskCheckerMismatch => "task checker differs from the signer or committed checker",
+    51 TaskJailedCheckerMismatch => "task checker differs from the jailed checkers",
+    52 TaskJobInconsistent => "task differs from the first task of the job",
+    53 TaskCommitCountMismatch => "number of tasks and committed checkers differ",
+    54 TaskCountMismatch => "number of tasks or challenges differs from the sidechain config",
+    55 TaskChallengeMajority => "passed challenges are not outnumbered by settled tasks",
+    56 RandomSeedMismatch => "new random seed differs from the revealed tasks",
+    57 SidechainStateRandomSeedMismatch => "origin random seed differs from sidechain state",
+    58 SidechainStateCommitIndexOutOfBound => "committed checker index out of bound of sidechain state",
+    59 SidechainStateCommitMismatch => "committed checker differs from sidechain state",
+    60 SidechainStateCommitDuplicated => "new committed checker already in sidechain state",
+
+    61 ProposalMismatch => "proposal cell mismatch",
+    62 ProposalEncoding => "invalid encoding of proposal cell data",
+    63 ProposalTypeArgsEncoding => "invalid encoding of proposal cell type args",
+    64 CheckerNotActivated => "signer is not an activated checker of the sidechain",
+    65 ProposalAlreadyVoted => "checker has already voted for the proposal",
+    66 ProposalQuorumNotReached => "proposal has fewer votes of activated checkers than checker threshold",
+
+    67 CollatorHandoverNotAllowed => "signer is neither the collator nor the admin of the sidechain",
+    68 CollatorJobsPending => "jobs covered by the bond of the collator are still waiting",
+
+    69 CollatorNotInSet => "signer is not a collator of the sidechain",
+    70 CollatorNotOnDuty => "signer is not the collator on duty for the job",
+    71 CollatorQuorumNotReached => "fewer collators approved than collator threshold",
+
+    72 CheckerWeightsMismatch => "activated checker weights are inconsistent with activated checkers",
+    73 CheckerSampleExhausted => "fewer activated checkers with a bond than checkers to draw",
+
+    74 DelegationMismatch => "delegation cell mismatch",
+    75 DelegationEncoding => "invalid encoding of delegation cell data",
+    76 DelegationLockArgsEncoding => "invalid encoding of delegation cell lock args",
+    77 DelegationsIncomplete => "delegation cells do not add up to the delegated amount of the checker",
+
+    78 CapacityDecreased => "output cell has less capacity than the input cell it continues",
+    79 LockScriptMismatch => "output cell is locked by another script than the input cell it continues",
+
+    80 SidechainBondTokenMismatch => "sidechain bond is not in the sudt configured for the sidechain",
+
+    81 JailedCheckerDelegated => "jailed checker has delegations left to be claimed",
+
+    82 CheckerProofMissing => "witness lacks the proof of a checker the pattern reads",
+    83 CheckerProofMismatch => "checker proof does not match the checker set root",
+    84 CheckerProofUnused => "witness carries checker proofs the transaction does not read",
+
+    85 SidechainConfigCheckersUnsorted => "checker lists of sidechain config are not sorted or hold duplicates",
+    86 SidechainStateCheckersUnsorted => "checker lists of sidechain state are not sorted or hold duplicates",
+
+    87 RefreshTaskLimitExceeded => "refresh carries more tasks than the sidechain allows in a transaction",
+    88 SubmitTaskLimitExceeded => "submit carries more tasks than the sidechain allows in a transaction",
+
+    89 RevealQuorumNotReached => "new random seed has fewer reveals than the sidechain requires",
+
+    90 SignedVoteMismatch => "signed vote does not match the submitted job",
+    91 SignedVoteDuplicated => "checker signed more than one vote of the job",
+
+    92 JobNotWaiting => "submitted job was never published or is settled already",
+
+    93 RevealQuorumUnreachable => "reveal quorum of sidechain config exceeds its commit threshold",
+
+    94 ProposalOutdated => "proposal was made against a sidechain config another proposal has changed since",
+    95 ProposedConfigInvalid => "proposal would leave the sidechain config without a valid threshold or rate",
+
+    96 SignedVoteSignatureInvalid => "signature of signed vote does not recover a public key",
+}
+
+pub fn find_error(code: i8) -> Option<&'static ErrorCode> {
+    CODE_CELL_ERRORS.iter().find(|error| error.code == code)
+}
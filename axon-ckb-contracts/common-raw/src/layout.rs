@@ -0,0 +1,265 @@
+/*
+Cell layouts of every pattern, shared by the code cell and off-chain transaction builders.
+
+Index 0 of inputs and outputs is always the code cell. Cell deps before PATTERN_CELL_DEPS_START
+are the environment (global config cell, contract codes and lock deps), the fixed deps of a
+pattern follow them. Variable cells (tasks) start at the first index after the fixed cells.
+ */
+
+pub const CODE_CELL: usize = 0;
+
+pub const PATTERN_CELL_DEPS_START: usize = 5;
+
+pub mod admin_create_sidechain {
+    pub const CKB_INPUT: usize = 1;
+
+    pub const SIDECHAIN_CONFIG_OUTPUT: usize = 1;
+    pub const SIDECHAIN_STATE_OUTPUT: usize = 2;
+
+    pub const INPUT_COUNT: usize = 2;
+    pub const OUTPUT_COUNT: usize = 3;
+}
+
+pub mod checker_bond_withdraw {
+    pub const BOND_INPUT: usize = 1;
+
+    pub const TOKEN_OUTPUT: usize = 1;
+
+    pub const INPUT_COUNT: usize = 2;
+    pub const OUTPUT_COUNT: usize = 2;
+}
+
+pub mod checker_join_sidechain {
+    pub const CONFIG_INPUT: usize = 1;
+    pub const CHECKER_BOND_INPUT: usize = 2;
+
+    pub const CONFIG_OUTPUT: usize = 1;
+    pub const CHECKER_BOND_OUTPUT: usize = 2;
+    pub const CHECKER_INFO_OUTPUT: usize = 3;
+
+    pub const INPUT_COUNT: usize = 3;
+    pub const OUTPUT_COUNT: usize = 4;
+}
+
+pub mod checker_quit_sidechain {
+    use super::PATTERN_CELL_DEPS_START;
+
+    pub const STATE_DEP: usize = PATTERN_CELL_DEPS_START;
+
+    pub const CONFIG_INPUT: usize = 1;
+    pub const CHECKER_BOND_INPUT: usize = 2;
+    pub const CHECKER_INFO_INPUT: usize = 3;
+
+    pub const CONFIG_OUTPUT: usize = 1;
+    pub const CHECKER_BOND_OUTPUT: usize = 2;
+    pub const CHECKER_INFO_OUTPUT: usize = 3;
+
+    pub const INPUT_COUNT: usize = 4;
+    pub const OUTPUT_COUNT: usize = 4;
+}
+
+// the sidechain config dep index is carried by the witness
+pub mod checker_vote {
+    pub const CHECKER_INFO_INPUT: usize = 1;
+    pub const TASK_INPUT: usize = 2;
+
+    pub const CHECKER_INFO_OUTPUT: usize = 1;
+    pub const TASK_OUTPUT: usize = 2;
+
+    pub const INPUT_COUNT: usize = 3;
+    pub const OUTPUT_COUNT: usize = 3;
+}
+
+// the sidechain config dep index is carried by the witness,
+// challenge tasks follow the first task output
+pub mod checker_publish_challenge {
+    pub const SIDECHAIN_STATE_INPUT: usize = 1;
+    pub const CHECKER_INFO_INPUT: usize = 2;
+    pub const FIRST_TASK_INPUT: usize = 3;
+
+    pub const SIDECHAIN_STATE_OUTPUT: usize = 1;
+    pub const CHECKER_INFO_OUTPUT: usize = 2;
+    pub const FIRST_TASK_OUTPUT: usize = 3;
+
+    pub const INPUT_COUNT: usize = 4;
+    pub const FIXED_OUTPUT_CELLS: usize = 3;
+}
+
+pub mod checker_take_beneficiary {
+    pub const CHECKER_INFO_INPUT: usize = 1;
+    pub const FEE_INPUT: usize = 2;
+
+    pub const CHECKER_INFO_OUTPUT: usize = 1;
+    pub const FEE_OUTPUT: usize = 2;
+    pub const MUSE_OUTPUT: usize = 3;
+
+    // delegation cells to the checker follow the fixed cells, each matched by an output
+    pub const DELEGATION_INPUTS_START: usize = 3;
+    pub const DELEGATION_OUTPUTS_START: usize = 4;
+
+    pub const INPUT_COUNT: usize = 3;
+    pub const OUTPUT_COUNT: usize = 4;
+}
+
+// task outputs follow the fixed outputs, one for each of commit_threshold checkers
+pub mod collator_publish_task {
+    use super::PATTERN_CELL_DEPS_START;
+
+    pub const SIDECHAIN_CONFIG_DEP: usize = PATTERN_CELL_DEPS_START;
+    pub const SIDECHAIN_BOND_DEP: usize = PATTERN_CELL_DEPS_START + 1;
+
+    pub const SIDECHAIN_STATE_INPUT: usize = 1;
+    pub const SIDECHAIN_FEE_INPUT: usize = 2;
+    pub const TOKEN_INPUT: usize = 3;
+
+    pub const SIDECHAIN_STATE_OUTPUT: usize = 1;
+    pub const SIDECHAIN_FEE_OUTPUT: usize = 2;
+
+    pub const INPUT_COUNT: usize = 4;
+    pub const FIXED_OUTPUT_CELLS: usize = 3;
+}
+
+// task inputs follow the fixed inputs and are consumed,
+// for a signed job the checker info cell of the checker of every signed vote follows them instead,
+// in inputs and outputs alike, the votes themselves are in the witness
+pub mod collator_submit_tasks {
+    pub const SIDECHAIN_CONFIG_INPUT: usize = 1;
+    pub const SIDECHAIN_STATE_INPUT: usize = 2;
+    pub const SIDECHAIN_FEE_INPUT: usize = 3;
+
+    pub const SIDECHAIN_CONFIG_OUTPUT: usize = 1;
+    pub const SIDECHAIN_STATE_OUTPUT: usize = 2;
+    pub const SIDECHAIN_FEE_OUTPUT: usize = 3;
+
+    pub const FIXED_INPUT_CELLS: usize = 4;
+    pub const OUTPUT_COUNT: usize = 4;
+
+    pub const SIGNED_VOTE_INPUTS_START: usize = FIXED_INPUT_CELLS;
+    pub const SIGNED_VOTE_OUTPUTS_START: usize = OUTPUT_COUNT;
+}
+
+// task inputs follow the fixed cells, each refreshed into the output of the same index,
+// header dep 0 is the reference block
+pub mod anyone_refresh_task {
+    pub const CONFIG_INPUT: usize = 1;
+    pub const STATE_INPUT: usize = 2;
+
+    pub const CONFIG_OUTPUT: usize = 1;
+    pub const STATE_OUTPUT: usize = 2;
+
+    pub const FIXED_CELLS: usize = 3;
+}
+
+pub mod collator_unlock_bond {
+    use super::PATTERN_CELL_DEPS_START;
+
+    pub const SIDECHAIN_CONFIG_DEP: usize = PATTERN_CELL_DEPS_START;
+    pub const SIDECHAIN_STATE_DEP: usize = PATTERN_CELL_DEPS_START + 1;
+
+    pub const SIDECHAIN_BOND_INPUT: usize = 1;
+
+    pub const SUDT_OUTPUT: usize = 1;
+
+    pub const INPUT_COUNT: usize = 2;
+    pub const OUTPUT_COUNT: usize = 2;
+}
+
+// task inputs follow the fixed inputs and are consumed
+pub mod anyone_shutdown_sidechain {
+    pub const SIDECHAIN_CONFIG_INPUT: usize = 1;
+    pub const SIDECHAIN_FEE_INPUT: usize = 2;
+
+    pub const SIDECHAIN_CONFIG_OUTPUT: usize = 1;
+    pub const SIDECHAIN_FEE_OUTPUT: usize = 2;
+
+    pub const FIXED_INPUT_CELLS: usize = 3;
+    pub const OUTPUT_COUNT: usize = 3;
+}
+
+pub mod collator_shutdown_sidechain {
+    use super::PATTERN_CELL_DEPS_START;
+
+    pub const SIDECHAIN_STATE_DEP: usize = PATTERN_CELL_DEPS_START;
+
+    pub const SIDECHAIN_CONFIG_INPUT: usize = 1;
+
+    pub const SIDECHAIN_CONFIG_OUTPUT: usize = 1;
+
+    // approval cells of other collators follow the fixed cells, each matched by an output
+    pub const APPROVAL_INPUTS_START: usize = 2;
+
+    pub const INPUT_COUNT: usize = 2;
+    pub const OUTPUT_COUNT: usize = 2;
+}
+
+pub mod checker_propose_change {
+    use super::PATTERN_CELL_DEPS_START;
+
+    pub const SIDECHAIN_CONFIG_DEP: usize = PATTERN_CELL_DEPS_START;
+
+    pub const PROPOSAL_OUTPUT: usize = 1;
+
+    pub const INPUT_COUNT: usize = 1;
+    pub const OUTPUT_COUNT: usize = 2;
+}
+
+pub mod checker_vote_proposal {
+    use super::PATTERN_CELL_DEPS_START;
+
+    pub const SIDECHAIN_CONFIG_DEP: usize = PATTERN_CELL_DEPS_START;
+
+    pub const PROPOSAL_INPUT: usize = 1;
+
+    pub const PROPOSAL_OUTPUT: usize = 1;
+
+    pub const INPUT_COUNT: usize = 2;
+    pub const OUTPUT_COUNT: usize = 2;
+}
+
+// the proposal cell is consumed
+pub mod execute_proposal {
+    pub const SIDECHAIN_CONFIG_INPUT: usize = 1;
+    pub const PROPOSAL_INPUT: usize = 2;
+
+    pub const SIDECHAIN_CONFIG_OUTPUT: usize = 1;
+
+    pub const INPUT_COUNT: usize = 3;
+    pub const OUTPUT_COUNT: usize = 2;
+}
+
+pub mod collator_handover {
+    pub const SIDECHAIN_CONFIG_INPUT: usize = 1;
+    pub const TOKEN_INPUT: usize = 2;
+
+    pub const SIDECHAIN_CONFIG_OUTPUT: usize = 1;
+    pub const SIDECHAIN_BOND_OUTPUT: usize = 2;
+
+    pub const INPUT_COUNT: usize = 3;
+    pub const OUTPUT_COUNT: usize = 3;
+}
+
+pub mod delegator_delegate {
+    pub const SIDECHAIN_CONFIG_INPUT: usize = 1;
+    pub const CHECKER_INFO_INPUT: usize = 2;
+    pub const TOKEN_INPUT: usize = 3;
+
+    pub const SIDECHAIN_CONFIG_OUTPUT: usize = 1;
+    pub const CHECKER_INFO_OUTPUT: usize = 2;
+    pub const DELEGATION_OUTPUT: usize = 3;
+
+    pub const INPUT_COUNT: usize = 4;
+    pub const OUTPUT_COUNT: usize = 4;
+}
+
+pub mod delegator_claim {
+    pub const SIDECHAIN_CONFIG_INPUT: usize = 1;
+    pub const CHECKER_INFO_INPUT: usize = 2;
+    pub const DELEGATION_INPUT: usize = 3;
+
+    pub const SIDECHAIN_CONFIG_OUTPUT: usize = 1;
+    pub const CHECKER_INFO_OUTPUT: usize = 2;
+    pub const TOKEN_OUTPUT: usize = 3;
+
+    pub const INPUT_COUNT: usize = 4;
+    pub const OUTPUT_COUNT: usize = 4;
+}
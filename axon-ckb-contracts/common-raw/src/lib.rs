@@ -1,15 +1,29 @@
 #![allow(dead_code)]
-#![no_std]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "serde")]
+extern crate alloc;
 
 use core::convert::TryFrom;
 
+pub mod beacon;
 pub mod cell;
 #[macro_use]
 pub mod common;
+pub mod error;
+pub mod error_code;
+pub mod layout;
 pub mod molecule;
 pub mod pattern;
+pub mod seed;
+#[cfg(feature = "serde")]
+pub mod serde_hex;
+pub mod smt;
 pub mod witness;
 
+pub use error::{DecodeError, DecodeField};
+pub use error_code::{find_error, ErrorCode, CODE_CELL_ERRORS};
+
 pub const SUDT_CODEHASH: [u8; 32] = [0; 32];
 pub const SUDT_HASHTYPE: u8 = 1u8;
 pub const SUDT_MUSE_ARGS: &[u8] = &[1u8];
@@ -20,36 +34,45 @@ pub const EMPTY_BIT_MAP: [u8; 32] = [0; 32];
 pub const GLOBAL_CONFIG_TYPE_HASH: [u8; 32] = [0; 32];
 
 pub trait FromRaw {
-    fn from_raw(cell_raw_data: &[u8]) -> Option<Self>
+    fn from_raw(cell_raw_data: &[u8]) -> Result<Self, DecodeError>
     where
         Self: Sized;
 }
 
+// the hash of smt nodes and seeds, blake2b on chain, left to the caller so common-raw doesn't
+// depend on a hasher
+pub trait Hasher {
+    fn hash(data: &[u8]) -> [u8; 32];
+}
+
 pub trait Serialize {
     type RawType: AsRef<[u8]>;
 
     fn serialize(&self) -> Self::RawType;
 }
 
-pub fn check_args_len(expected: usize, actual: usize) -> Option<()> {
+pub fn check_args_len(actual: usize, expected: usize) -> Result<(), DecodeError> {
     if actual != expected {
-        return None;
+        return Err(DecodeError::length(expected, actual));
     }
-    Some(())
+    Ok(())
+}
+
+// ascending by key without duplicate keys, what lists searched by binary search have to keep
+pub fn is_strictly_sorted_by_key<T, K: Ord, F: Fn(&T) -> K>(items: &[T], key: F) -> bool {
+    items.windows(2).all(|pair| key(&pair[0]) < key(&pair[1]))
 }
 
 macro_rules! SerializableNumber {
     ($type: ty, $size: expr) => {
         impl FromRaw for $type {
-            fn from_raw(raw: &[u8]) -> Option<Self> {
-                if raw.len() != $size {
-                    return None;
-                }
+            fn from_raw(raw: &[u8]) -> Result<Self, DecodeError> {
+                check_args_len(raw.len(), $size)?;
 
                 let mut buf = [0u8; $size];
 
                 buf.copy_from_slice(raw);
-                Some(<$type>::from_le_bytes(buf))
+                Ok(<$type>::from_le_bytes(buf))
             }
         }
 
@@ -69,8 +92,26 @@ SerializableNumber!(u32, 4);
 SerializableNumber!(u16, 2);
 SerializableNumber!(u8, 1);
 
+// hashes and lock args
+macro_rules! DecodableBytes {
+    ($size: expr) => {
+        impl FromRaw for [u8; $size] {
+            fn from_raw(raw: &[u8]) -> Result<Self, DecodeError> {
+                check_args_len(raw.len(), $size)?;
+
+                let mut buf = [0u8; $size];
+                buf.copy_from_slice(raw);
+                Ok(buf)
+            }
+        }
+    };
+}
+
+DecodableBytes!(20);
+DecodableBytes!(32);
+
 impl FromRaw for usize {
-    fn from_raw(raw: &[u8]) -> Option<Self> {
+    fn from_raw(raw: &[u8]) -> Result<Self, DecodeError> {
         u16::from_raw(raw).map(|v| v.into())
     }
 }
@@ -212,6 +212,9 @@ impl ::core::fmt::Display for CheckerInfoCell {
         write!(f, "{}: {}", "unpaid_fee", self.unpaid_fee())?;
         write!(f, ", {}: {}", "status", self.status())?;
         write!(f, ", {}: {}", "rpc_url", self.rpc_url())?;
+        write!(f, ", {}: {}", "commission_rate", self.commission_rate())?;
+        write!(f, ", {}: {}", "bonded_amount", self.bonded_amount())?;
+        write!(f, ", {}: {}", "delegated_amount", self.delegated_amount())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -222,13 +225,15 @@ impl ::core::fmt::Display for CheckerInfoCell {
 impl ::core::default::Default for CheckerInfoCell {
     fn default() -> Self {
         let v: Vec<u8> = vec![
-            37, 0, 0, 0, 16, 0, 0, 0, 32, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            85, 0, 0, 0, 28, 0, 0, 0, 44, 0, 0, 0, 45, 0, 0, 0, 49, 0, 0, 0, 53, 0, 0, 0, 69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0,
         ];
         CheckerInfoCell::new_unchecked(v.into())
     }
 }
 impl CheckerInfoCell {
-    pub const FIELD_COUNT: usize = 3;
+    pub const FIELD_COUNT: usize = 6;
 
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
@@ -267,11 +272,32 @@ impl CheckerInfoCell {
     pub fn rpc_url(&self) -> MolString {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        MolString::new_unchecked(self.0.slice(start..end))
+    }
+
+    pub fn commission_rate(&self) -> Uint32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Uint32::new_unchecked(self.0.slice(start..end))
+    }
+
+    pub fn bonded_amount(&self) -> Uint128 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        Uint128::new_unchecked(self.0.slice(start..end))
+    }
+
+    pub fn delegated_amount(&self) -> Uint128 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[16..]) as usize;
-            MolString::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[28..]) as usize;
+            Uint128::new_unchecked(self.0.slice(start..end))
         } else {
-            MolString::new_unchecked(self.0.slice(start..))
+            Uint128::new_unchecked(self.0.slice(start..))
         }
     }
 
@@ -313,6 +339,9 @@ impl molecule::prelude::Entity for CheckerInfoCell {
             .unpaid_fee(self.unpaid_fee())
             .status(self.status())
             .rpc_url(self.rpc_url())
+            .commission_rate(self.commission_rate())
+            .bonded_amount(self.bonded_amount())
+            .delegated_amount(self.delegated_amount())
     }
 }
 #[derive(Clone, Copy)]
@@ -337,6 +366,9 @@ impl<'r> ::core::fmt::Display for CheckerInfoCellReader<'r> {
         write!(f, "{}: {}", "unpaid_fee", self.unpaid_fee())?;
         write!(f, ", {}: {}", "status", self.status())?;
         write!(f, ", {}: {}", "rpc_url", self.rpc_url())?;
+        write!(f, ", {}: {}", "commission_rate", self.commission_rate())?;
+        write!(f, ", {}: {}", "bonded_amount", self.bonded_amount())?;
+        write!(f, ", {}: {}", "delegated_amount", self.delegated_amount())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -345,7 +377,7 @@ impl<'r> ::core::fmt::Display for CheckerInfoCellReader<'r> {
     }
 }
 impl<'r> CheckerInfoCellReader<'r> {
-    pub const FIELD_COUNT: usize = 3;
+    pub const FIELD_COUNT: usize = 6;
 
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
@@ -384,11 +416,32 @@ impl<'r> CheckerInfoCellReader<'r> {
     pub fn rpc_url(&self) -> MolStringReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        MolStringReader::new_unchecked(&self.as_slice()[start..end])
+    }
+
+    pub fn commission_rate(&self) -> Uint32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+
+    pub fn bonded_amount(&self) -> Uint128Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        Uint128Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+
+    pub fn delegated_amount(&self) -> Uint128Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[16..]) as usize;
-            MolStringReader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[28..]) as usize;
+            Uint128Reader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            MolStringReader::new_unchecked(&self.as_slice()[start..])
+            Uint128Reader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
@@ -449,17 +502,23 @@ impl<'r> molecule::prelude::Reader<'r> for CheckerInfoCellReader<'r> {
         Uint128Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
         CheckerInfoStatusReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
         MolStringReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        Uint128Reader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        Uint128Reader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
 pub struct CheckerInfoCellBuilder {
-    pub(crate) unpaid_fee: Uint128,
-    pub(crate) status:     CheckerInfoStatus,
-    pub(crate) rpc_url:    MolString,
+    pub(crate) unpaid_fee:       Uint128,
+    pub(crate) status:           CheckerInfoStatus,
+    pub(crate) rpc_url:          MolString,
+    pub(crate) commission_rate:  Uint32,
+    pub(crate) bonded_amount:    Uint128,
+    pub(crate) delegated_amount: Uint128,
 }
 impl CheckerInfoCellBuilder {
-    pub const FIELD_COUNT: usize = 3;
+    pub const FIELD_COUNT: usize = 6;
 
     pub fn unpaid_fee(mut self, v: Uint128) -> Self {
         self.unpaid_fee = v;
@@ -475,6 +534,21 @@ impl CheckerInfoCellBuilder {
         self.rpc_url = v;
         self
     }
+
+    pub fn commission_rate(mut self, v: Uint32) -> Self {
+        self.commission_rate = v;
+        self
+    }
+
+    pub fn bonded_amount(mut self, v: Uint128) -> Self {
+        self.bonded_amount = v;
+        self
+    }
+
+    pub fn delegated_amount(mut self, v: Uint128) -> Self {
+        self.delegated_amount = v;
+        self
+    }
 }
 impl molecule::prelude::Builder for CheckerInfoCellBuilder {
     type Entity = CheckerInfoCell;
@@ -486,6 +560,9 @@ impl molecule::prelude::Builder for CheckerInfoCellBuilder {
             + self.unpaid_fee.as_slice().len()
             + self.status.as_slice().len()
             + self.rpc_url.as_slice().len()
+            + self.commission_rate.as_slice().len()
+            + self.bonded_amount.as_slice().len()
+            + self.delegated_amount.as_slice().len()
     }
 
     fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
@@ -497,6 +574,12 @@ impl molecule::prelude::Builder for CheckerInfoCellBuilder {
         total_size += self.status.as_slice().len();
         offsets.push(total_size);
         total_size += self.rpc_url.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.commission_rate.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.bonded_amount.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.delegated_amount.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
@@ -504,6 +587,9 @@ impl molecule::prelude::Builder for CheckerInfoCellBuilder {
         writer.write_all(self.unpaid_fee.as_slice())?;
         writer.write_all(self.status.as_slice())?;
         writer.write_all(self.rpc_url.as_slice())?;
+        writer.write_all(self.commission_rate.as_slice())?;
+        writer.write_all(self.bonded_amount.as_slice())?;
+        writer.write_all(self.delegated_amount.as_slice())?;
         Ok(())
     }
 
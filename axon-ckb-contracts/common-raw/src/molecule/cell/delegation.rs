@@ -0,0 +1,230 @@
+// Generated by Molecule 0.7.0
+
+use super::super::common::*;
+use molecule::prelude::*;
+#[derive(Clone)]
+pub struct DelegationCellLockArgs(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for DelegationCellLockArgs {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for DelegationCellLockArgs {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for DelegationCellLockArgs {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "chain_id", self.chain_id())?;
+        write!(f, ", {}: {}", "checker_lock_arg", self.checker_lock_arg())?;
+        write!(f, ", {}: {}", "delegator_lock_arg", self.delegator_lock_arg())?;
+        write!(f, ", {}: {}", "principal", self.principal())?;
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for DelegationCellLockArgs {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        DelegationCellLockArgs::new_unchecked(v.into())
+    }
+}
+impl DelegationCellLockArgs {
+    pub const FIELD_COUNT: usize = 4;
+    pub const FIELD_SIZES: [usize; 4] = [4, 20, 20, 16];
+    pub const TOTAL_SIZE: usize = 60;
+
+    pub fn chain_id(&self) -> ChainId {
+        ChainId::new_unchecked(self.0.slice(0..4))
+    }
+
+    pub fn checker_lock_arg(&self) -> PubKeyHash {
+        PubKeyHash::new_unchecked(self.0.slice(4..24))
+    }
+
+    pub fn delegator_lock_arg(&self) -> PubKeyHash {
+        PubKeyHash::new_unchecked(self.0.slice(24..44))
+    }
+
+    pub fn principal(&self) -> Uint128 {
+        Uint128::new_unchecked(self.0.slice(44..60))
+    }
+
+    pub fn as_reader<'r>(&'r self) -> DelegationCellLockArgsReader<'r> {
+        DelegationCellLockArgsReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for DelegationCellLockArgs {
+    type Builder = DelegationCellLockArgsBuilder;
+
+    const NAME: &'static str = "DelegationCellLockArgs";
+
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        DelegationCellLockArgs(data)
+    }
+
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        DelegationCellLockArgsReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        DelegationCellLockArgsReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .chain_id(self.chain_id())
+            .checker_lock_arg(self.checker_lock_arg())
+            .delegator_lock_arg(self.delegator_lock_arg())
+            .principal(self.principal())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct DelegationCellLockArgsReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for DelegationCellLockArgsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for DelegationCellLockArgsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for DelegationCellLockArgsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "chain_id", self.chain_id())?;
+        write!(f, ", {}: {}", "checker_lock_arg", self.checker_lock_arg())?;
+        write!(f, ", {}: {}", "delegator_lock_arg", self.delegator_lock_arg())?;
+        write!(f, ", {}: {}", "principal", self.principal())?;
+        write!(f, " }}")
+    }
+}
+impl<'r> DelegationCellLockArgsReader<'r> {
+    pub const FIELD_COUNT: usize = 4;
+    pub const FIELD_SIZES: [usize; 4] = [4, 20, 20, 16];
+    pub const TOTAL_SIZE: usize = 60;
+
+    pub fn chain_id(&self) -> ChainIdReader<'r> {
+        ChainIdReader::new_unchecked(&self.as_slice()[0..4])
+    }
+
+    pub fn checker_lock_arg(&self) -> PubKeyHashReader<'r> {
+        PubKeyHashReader::new_unchecked(&self.as_slice()[4..24])
+    }
+
+    pub fn delegator_lock_arg(&self) -> PubKeyHashReader<'r> {
+        PubKeyHashReader::new_unchecked(&self.as_slice()[24..44])
+    }
+
+    pub fn principal(&self) -> Uint128Reader<'r> {
+        Uint128Reader::new_unchecked(&self.as_slice()[44..60])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for DelegationCellLockArgsReader<'r> {
+    type Entity = DelegationCellLockArgs;
+
+    const NAME: &'static str = "DelegationCellLockArgsReader";
+
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        DelegationCellLockArgsReader(slice)
+    }
+
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct DelegationCellLockArgsBuilder {
+    pub(crate) chain_id:           ChainId,
+    pub(crate) checker_lock_arg:   PubKeyHash,
+    pub(crate) delegator_lock_arg: PubKeyHash,
+    pub(crate) principal:          Uint128,
+}
+impl DelegationCellLockArgsBuilder {
+    pub const FIELD_COUNT: usize = 4;
+    pub const FIELD_SIZES: [usize; 4] = [4, 20, 20, 16];
+    pub const TOTAL_SIZE: usize = 60;
+
+    pub fn chain_id(mut self, v: ChainId) -> Self {
+        self.chain_id = v;
+        self
+    }
+
+    pub fn checker_lock_arg(mut self, v: PubKeyHash) -> Self {
+        self.checker_lock_arg = v;
+        self
+    }
+
+    pub fn delegator_lock_arg(mut self, v: PubKeyHash) -> Self {
+        self.delegator_lock_arg = v;
+        self
+    }
+
+    pub fn principal(mut self, v: Uint128) -> Self {
+        self.principal = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for DelegationCellLockArgsBuilder {
+    type Entity = DelegationCellLockArgs;
+
+    const NAME: &'static str = "DelegationCellLockArgsBuilder";
+
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        writer.write_all(self.chain_id.as_slice())?;
+        writer.write_all(self.checker_lock_arg.as_slice())?;
+        writer.write_all(self.delegator_lock_arg.as_slice())?;
+        writer.write_all(self.principal.as_slice())?;
+        Ok(())
+    }
+
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        DelegationCellLockArgs::new_unchecked(inner.into())
+    }
+}
@@ -110,6 +110,10 @@ impl ::core::fmt::Display for GlobalConfigCell {
         )?;
         write!(f, ", {}: {}", "task_cell_type_codehash", self.task_cell_type_codehash())?;
         write!(f, ", {}: {}", "task_cell_type_hashtype", self.task_cell_type_hashtype())?;
+        write!(f, ", {}: {}", "proposal_cell_type_codehash", self.proposal_cell_type_codehash())?;
+        write!(f, ", {}: {}", "proposal_cell_type_hashtype", self.proposal_cell_type_hashtype())?;
+        write!(f, ", {}: {}", "delegation_cell_lock_codehash", self.delegation_cell_lock_codehash())?;
+        write!(f, ", {}: {}", "delegation_cell_lock_hashtype", self.delegation_cell_lock_hashtype())?;
         write!(f, " }}")
     }
 }
@@ -123,15 +127,17 @@ impl ::core::default::Default for GlobalConfigCell {
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0,
         ];
         GlobalConfigCell::new_unchecked(v.into())
     }
 }
 impl GlobalConfigCell {
-    pub const FIELD_COUNT: usize = 19;
-    pub const FIELD_SIZES: [usize; 19] = [20, 32, 1, 32, 1, 32, 1, 32, 1, 32, 1, 32, 1, 32, 1, 32, 1, 32, 1];
-    pub const TOTAL_SIZE: usize = 317;
+    pub const FIELD_COUNT: usize = 23;
+    pub const FIELD_SIZES: [usize; 23] = [20, 32, 1, 32, 1, 32, 1, 32, 1, 32, 1, 32, 1, 32, 1, 32, 1, 32, 1, 32, 1, 32, 1];
+    pub const TOTAL_SIZE: usize = 383;
 
     pub fn admin_lock_arg(&self) -> PubKeyHash {
         PubKeyHash::new_unchecked(self.0.slice(0..20))
@@ -209,6 +215,22 @@ impl GlobalConfigCell {
         HashType::new_unchecked(self.0.slice(316..317))
     }
 
+    pub fn proposal_cell_type_codehash(&self) -> CodeHash {
+        CodeHash::new_unchecked(self.0.slice(317..349))
+    }
+
+    pub fn proposal_cell_type_hashtype(&self) -> HashType {
+        HashType::new_unchecked(self.0.slice(349..350))
+    }
+
+    pub fn delegation_cell_lock_codehash(&self) -> CodeHash {
+        CodeHash::new_unchecked(self.0.slice(350..382))
+    }
+
+    pub fn delegation_cell_lock_hashtype(&self) -> HashType {
+        HashType::new_unchecked(self.0.slice(382..383))
+    }
+
     pub fn as_reader<'r>(&'r self) -> GlobalConfigCellReader<'r> {
         GlobalConfigCellReader::new_unchecked(self.as_slice())
     }
@@ -263,6 +285,10 @@ impl molecule::prelude::Entity for GlobalConfigCell {
             .sidechain_state_cell_type_hashtype(self.sidechain_state_cell_type_hashtype())
             .task_cell_type_codehash(self.task_cell_type_codehash())
             .task_cell_type_hashtype(self.task_cell_type_hashtype())
+            .proposal_cell_type_codehash(self.proposal_cell_type_codehash())
+            .proposal_cell_type_hashtype(self.proposal_cell_type_hashtype())
+            .delegation_cell_lock_codehash(self.delegation_cell_lock_codehash())
+            .delegation_cell_lock_hashtype(self.delegation_cell_lock_hashtype())
     }
 }
 #[derive(Clone, Copy)]
@@ -373,13 +399,17 @@ impl<'r> ::core::fmt::Display for GlobalConfigCellReader<'r> {
         )?;
         write!(f, ", {}: {}", "task_cell_type_codehash", self.task_cell_type_codehash())?;
         write!(f, ", {}: {}", "task_cell_type_hashtype", self.task_cell_type_hashtype())?;
+        write!(f, ", {}: {}", "proposal_cell_type_codehash", self.proposal_cell_type_codehash())?;
+        write!(f, ", {}: {}", "proposal_cell_type_hashtype", self.proposal_cell_type_hashtype())?;
+        write!(f, ", {}: {}", "delegation_cell_lock_codehash", self.delegation_cell_lock_codehash())?;
+        write!(f, ", {}: {}", "delegation_cell_lock_hashtype", self.delegation_cell_lock_hashtype())?;
         write!(f, " }}")
     }
 }
 impl<'r> GlobalConfigCellReader<'r> {
-    pub const FIELD_COUNT: usize = 19;
-    pub const FIELD_SIZES: [usize; 19] = [20, 32, 1, 32, 1, 32, 1, 32, 1, 32, 1, 32, 1, 32, 1, 32, 1, 32, 1];
-    pub const TOTAL_SIZE: usize = 317;
+    pub const FIELD_COUNT: usize = 23;
+    pub const FIELD_SIZES: [usize; 23] = [20, 32, 1, 32, 1, 32, 1, 32, 1, 32, 1, 32, 1, 32, 1, 32, 1, 32, 1, 32, 1, 32, 1];
+    pub const TOTAL_SIZE: usize = 383;
 
     pub fn admin_lock_arg(&self) -> PubKeyHashReader<'r> {
         PubKeyHashReader::new_unchecked(&self.as_slice()[0..20])
@@ -456,6 +486,22 @@ impl<'r> GlobalConfigCellReader<'r> {
     pub fn task_cell_type_hashtype(&self) -> HashTypeReader<'r> {
         HashTypeReader::new_unchecked(&self.as_slice()[316..317])
     }
+
+    pub fn proposal_cell_type_codehash(&self) -> CodeHashReader<'r> {
+        CodeHashReader::new_unchecked(&self.as_slice()[317..349])
+    }
+
+    pub fn proposal_cell_type_hashtype(&self) -> HashTypeReader<'r> {
+        HashTypeReader::new_unchecked(&self.as_slice()[349..350])
+    }
+
+    pub fn delegation_cell_lock_codehash(&self) -> CodeHashReader<'r> {
+        CodeHashReader::new_unchecked(&self.as_slice()[350..382])
+    }
+
+    pub fn delegation_cell_lock_hashtype(&self) -> HashTypeReader<'r> {
+        HashTypeReader::new_unchecked(&self.as_slice()[382..383])
+    }
 }
 impl<'r> molecule::prelude::Reader<'r> for GlobalConfigCellReader<'r> {
     type Entity = GlobalConfigCell;
@@ -504,11 +550,15 @@ pub struct GlobalConfigCellBuilder {
     pub(crate) sidechain_state_cell_type_hashtype: HashType,
     pub(crate) task_cell_type_codehash: CodeHash,
     pub(crate) task_cell_type_hashtype: HashType,
+    pub(crate) proposal_cell_type_codehash: CodeHash,
+    pub(crate) proposal_cell_type_hashtype: HashType,
+    pub(crate) delegation_cell_lock_codehash: CodeHash,
+    pub(crate) delegation_cell_lock_hashtype: HashType,
 }
 impl GlobalConfigCellBuilder {
-    pub const FIELD_COUNT: usize = 19;
-    pub const FIELD_SIZES: [usize; 19] = [20, 32, 1, 32, 1, 32, 1, 32, 1, 32, 1, 32, 1, 32, 1, 32, 1, 32, 1];
-    pub const TOTAL_SIZE: usize = 317;
+    pub const FIELD_COUNT: usize = 23;
+    pub const FIELD_SIZES: [usize; 23] = [20, 32, 1, 32, 1, 32, 1, 32, 1, 32, 1, 32, 1, 32, 1, 32, 1, 32, 1, 32, 1, 32, 1];
+    pub const TOTAL_SIZE: usize = 383;
 
     pub fn admin_lock_arg(mut self, v: PubKeyHash) -> Self {
         self.admin_lock_arg = v;
@@ -604,6 +654,26 @@ impl GlobalConfigCellBuilder {
         self.task_cell_type_hashtype = v;
         self
     }
+
+    pub fn proposal_cell_type_codehash(mut self, v: CodeHash) -> Self {
+        self.proposal_cell_type_codehash = v;
+        self
+    }
+
+    pub fn proposal_cell_type_hashtype(mut self, v: HashType) -> Self {
+        self.proposal_cell_type_hashtype = v;
+        self
+    }
+
+    pub fn delegation_cell_lock_codehash(mut self, v: CodeHash) -> Self {
+        self.delegation_cell_lock_codehash = v;
+        self
+    }
+
+    pub fn delegation_cell_lock_hashtype(mut self, v: HashType) -> Self {
+        self.delegation_cell_lock_hashtype = v;
+        self
+    }
 }
 impl molecule::prelude::Builder for GlobalConfigCellBuilder {
     type Entity = GlobalConfigCell;
@@ -634,6 +704,10 @@ impl molecule::prelude::Builder for GlobalConfigCellBuilder {
         writer.write_all(self.sidechain_state_cell_type_hashtype.as_slice())?;
         writer.write_all(self.task_cell_type_codehash.as_slice())?;
         writer.write_all(self.task_cell_type_hashtype.as_slice())?;
+        writer.write_all(self.proposal_cell_type_codehash.as_slice())?;
+        writer.write_all(self.proposal_cell_type_hashtype.as_slice())?;
+        writer.write_all(self.delegation_cell_lock_codehash.as_slice())?;
+        writer.write_all(self.delegation_cell_lock_hashtype.as_slice())?;
         Ok(())
     }
 
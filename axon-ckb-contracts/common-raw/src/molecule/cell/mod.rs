@@ -1,7 +1,9 @@
 pub mod checker_bond;
 pub mod checker_info;
 pub mod code;
+pub mod delegation;
 pub mod global_config;
+pub mod proposal;
 pub mod sidechain_bond;
 pub mod sidechain_config;
 pub mod sidechain_fee;
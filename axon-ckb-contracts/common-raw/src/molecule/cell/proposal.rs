@@ -0,0 +1,731 @@
+// Generated by Molecule 0.7.0
+
+use super::super::common::*;
+use molecule::prelude::*;
+#[derive(Clone)]
+pub struct ProposalKind(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ProposalKind {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for ProposalKind {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for ProposalKind {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        let raw_data = hex_string(&self.raw_data());
+        write!(f, "{}(0x{})", Self::NAME, raw_data)
+    }
+}
+impl ::core::default::Default for ProposalKind {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![0];
+        ProposalKind::new_unchecked(v.into())
+    }
+}
+impl ProposalKind {
+    pub const ITEM_COUNT: usize = 1;
+    pub const ITEM_SIZE: usize = 1;
+    pub const TOTAL_SIZE: usize = 1;
+
+    pub fn nth0(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(0..1))
+    }
+
+    pub fn raw_data(&self) -> molecule::bytes::Bytes {
+        self.as_bytes()
+    }
+
+    pub fn as_reader<'r>(&'r self) -> ProposalKindReader<'r> {
+        ProposalKindReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for ProposalKind {
+    type Builder = ProposalKindBuilder;
+
+    const NAME: &'static str = "ProposalKind";
+
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        ProposalKind(data)
+    }
+
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ProposalKindReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ProposalKindReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().set([self.nth0()])
+    }
+}
+#[derive(Clone, Copy)]
+pub struct ProposalKindReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ProposalKindReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for ProposalKindReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for ProposalKindReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        let raw_data = hex_string(&self.raw_data());
+        write!(f, "{}(0x{})", Self::NAME, raw_data)
+    }
+}
+impl<'r> ProposalKindReader<'r> {
+    pub const ITEM_COUNT: usize = 1;
+    pub const ITEM_SIZE: usize = 1;
+    pub const TOTAL_SIZE: usize = 1;
+
+    pub fn nth0(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[0..1])
+    }
+
+    pub fn raw_data(&self) -> &'r [u8] {
+        self.as_slice()
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for ProposalKindReader<'r> {
+    type Entity = ProposalKind;
+
+    const NAME: &'static str = "ProposalKindReader";
+
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        ProposalKindReader(slice)
+    }
+
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+pub struct ProposalKindBuilder(pub(crate) [Byte; 1]);
+impl ::core::fmt::Debug for ProposalKindBuilder {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:?})", Self::NAME, &self.0[..])
+    }
+}
+impl ::core::default::Default for ProposalKindBuilder {
+    fn default() -> Self {
+        ProposalKindBuilder([Byte::default()])
+    }
+}
+impl ProposalKindBuilder {
+    pub const ITEM_COUNT: usize = 1;
+    pub const ITEM_SIZE: usize = 1;
+    pub const TOTAL_SIZE: usize = 1;
+
+    pub fn set(mut self, v: [Byte; 1]) -> Self {
+        self.0 = v;
+        self
+    }
+
+    pub fn nth0(mut self, v: Byte) -> Self {
+        self.0[0] = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for ProposalKindBuilder {
+    type Entity = ProposalKind;
+
+    const NAME: &'static str = "ProposalKindBuilder";
+
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        writer.write_all(self.0[0].as_slice())?;
+        Ok(())
+    }
+
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        ProposalKind::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct ProposalCell(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ProposalCell {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for ProposalCell {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for ProposalCell {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "kind", self.kind())?;
+        write!(f, ", {}: {}", "payload", self.payload())?;
+        write!(f, ", {}: {}", "config_nonce", self.config_nonce())?;
+        write!(f, ", {}: {}", "votes", self.votes())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for ProposalCell {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            37, 0, 0, 0, 20, 0, 0, 0, 21, 0, 0, 0, 25, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        ProposalCell::new_unchecked(v.into())
+    }
+}
+impl ProposalCell {
+    pub const FIELD_COUNT: usize = 4;
+
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+
+    pub fn kind(&self) -> ProposalKind {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        ProposalKind::new_unchecked(self.0.slice(start..end))
+    }
+
+    pub fn payload(&self) -> MolString {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        MolString::new_unchecked(self.0.slice(start..end))
+    }
+
+    pub fn config_nonce(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+
+    pub fn votes(&self) -> PubKeyHashList {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            PubKeyHashList::new_unchecked(self.0.slice(start..end))
+        } else {
+            PubKeyHashList::new_unchecked(self.0.slice(start..))
+        }
+    }
+
+    pub fn as_reader<'r>(&'r self) -> ProposalCellReader<'r> {
+        ProposalCellReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for ProposalCell {
+    type Builder = ProposalCellBuilder;
+
+    const NAME: &'static str = "ProposalCell";
+
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        ProposalCell(data)
+    }
+
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ProposalCellReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ProposalCellReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .kind(self.kind())
+            .payload(self.payload())
+            .config_nonce(self.config_nonce())
+            .votes(self.votes())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct ProposalCellReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ProposalCellReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for ProposalCellReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for ProposalCellReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "kind", self.kind())?;
+        write!(f, ", {}: {}", "payload", self.payload())?;
+        write!(f, ", {}: {}", "config_nonce", self.config_nonce())?;
+        write!(f, ", {}: {}", "votes", self.votes())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> ProposalCellReader<'r> {
+    pub const FIELD_COUNT: usize = 4;
+
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+
+    pub fn kind(&self) -> ProposalKindReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        ProposalKindReader::new_unchecked(&self.as_slice()[start..end])
+    }
+
+    pub fn payload(&self) -> MolStringReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        MolStringReader::new_unchecked(&self.as_slice()[start..end])
+    }
+
+    pub fn config_nonce(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+
+    pub fn votes(&self) -> PubKeyHashListReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            PubKeyHashListReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            PubKeyHashListReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for ProposalCellReader<'r> {
+    type Entity = ProposalCell;
+
+    const NAME: &'static str = "ProposalCellReader";
+
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        ProposalCellReader(slice)
+    }
+
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        ProposalKindReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        MolStringReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        PubKeyHashListReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct ProposalCellBuilder {
+    pub(crate) kind:         ProposalKind,
+    pub(crate) payload:      MolString,
+    pub(crate) config_nonce: Uint64,
+    pub(crate) votes:        PubKeyHashList,
+}
+impl ProposalCellBuilder {
+    pub const FIELD_COUNT: usize = 4;
+
+    pub fn kind(mut self, v: ProposalKind) -> Self {
+        self.kind = v;
+        self
+    }
+
+    pub fn payload(mut self, v: MolString) -> Self {
+        self.payload = v;
+        self
+    }
+
+    pub fn config_nonce(mut self, v: Uint64) -> Self {
+        self.config_nonce = v;
+        self
+    }
+
+    pub fn votes(mut self, v: PubKeyHashList) -> Self {
+        self.votes = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for ProposalCellBuilder {
+    type Entity = ProposalCell;
+
+    const NAME: &'static str = "ProposalCellBuilder";
+
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.kind.as_slice().len()
+            + self.payload.as_slice().len()
+            + self.config_nonce.as_slice().len()
+            + self.votes.as_slice().len()
+    }
+
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.kind.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.payload.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.config_nonce.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.votes.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.kind.as_slice())?;
+        writer.write_all(self.payload.as_slice())?;
+        writer.write_all(self.config_nonce.as_slice())?;
+        writer.write_all(self.votes.as_slice())?;
+        Ok(())
+    }
+
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        ProposalCell::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct ProposalCellTypeArgs(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ProposalCellTypeArgs {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for ProposalCellTypeArgs {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for ProposalCellTypeArgs {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "chain_id", self.chain_id())?;
+        write!(f, ", {}: {}", "proposer_lock_arg", self.proposer_lock_arg())?;
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for ProposalCellTypeArgs {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        ProposalCellTypeArgs::new_unchecked(v.into())
+    }
+}
+impl ProposalCellTypeArgs {
+    pub const FIELD_COUNT: usize = 2;
+    pub const FIELD_SIZES: [usize; 2] = [4, 20];
+    pub const TOTAL_SIZE: usize = 24;
+
+    pub fn chain_id(&self) -> ChainId {
+        ChainId::new_unchecked(self.0.slice(0..4))
+    }
+
+    pub fn proposer_lock_arg(&self) -> PubKeyHash {
+        PubKeyHash::new_unchecked(self.0.slice(4..24))
+    }
+
+    pub fn as_reader<'r>(&'r self) -> ProposalCellTypeArgsReader<'r> {
+        ProposalCellTypeArgsReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for ProposalCellTypeArgs {
+    type Builder = ProposalCellTypeArgsBuilder;
+
+    const NAME: &'static str = "ProposalCellTypeArgs";
+
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        ProposalCellTypeArgs(data)
+    }
+
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ProposalCellTypeArgsReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ProposalCellTypeArgsReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .chain_id(self.chain_id())
+            .proposer_lock_arg(self.proposer_lock_arg())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct ProposalCellTypeArgsReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ProposalCellTypeArgsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for ProposalCellTypeArgsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for ProposalCellTypeArgsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "chain_id", self.chain_id())?;
+        write!(f, ", {}: {}", "proposer_lock_arg", self.proposer_lock_arg())?;
+        write!(f, " }}")
+    }
+}
+impl<'r> ProposalCellTypeArgsReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
+    pub const FIELD_SIZES: [usize; 2] = [4, 20];
+    pub const TOTAL_SIZE: usize = 24;
+
+    pub fn chain_id(&self) -> ChainIdReader<'r> {
+        ChainIdReader::new_unchecked(&self.as_slice()[0..4])
+    }
+
+    pub fn proposer_lock_arg(&self) -> PubKeyHashReader<'r> {
+        PubKeyHashReader::new_unchecked(&self.as_slice()[4..24])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for ProposalCellTypeArgsReader<'r> {
+    type Entity = ProposalCellTypeArgs;
+
+    const NAME: &'static str = "ProposalCellTypeArgsReader";
+
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        ProposalCellTypeArgsReader(slice)
+    }
+
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct ProposalCellTypeArgsBuilder {
+    pub(crate) chain_id:          ChainId,
+    pub(crate) proposer_lock_arg: PubKeyHash,
+}
+impl ProposalCellTypeArgsBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub const FIELD_SIZES: [usize; 2] = [4, 20];
+    pub const TOTAL_SIZE: usize = 24;
+
+    pub fn chain_id(mut self, v: ChainId) -> Self {
+        self.chain_id = v;
+        self
+    }
+
+    pub fn proposer_lock_arg(mut self, v: PubKeyHash) -> Self {
+        self.proposer_lock_arg = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for ProposalCellTypeArgsBuilder {
+    type Entity = ProposalCellTypeArgs;
+
+    const NAME: &'static str = "ProposalCellTypeArgsBuilder";
+
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        writer.write_all(self.chain_id.as_slice())?;
+        writer.write_all(self.proposer_lock_arg.as_slice())?;
+        Ok(())
+    }
+
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        ProposalCellTypeArgs::new_unchecked(inner.into())
+    }
+}
@@ -191,6 +191,379 @@ impl molecule::prelude::Builder for SidechainStatusBuilder {
     }
 }
 #[derive(Clone)]
+pub struct CheckerStatus(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for CheckerStatus {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for CheckerStatus {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for CheckerStatus {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        let raw_data = hex_string(&self.raw_data());
+        write!(f, "{}(0x{})", Self::NAME, raw_data)
+    }
+}
+impl ::core::default::Default for CheckerStatus {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![0];
+        CheckerStatus::new_unchecked(v.into())
+    }
+}
+impl CheckerStatus {
+    pub const ITEM_COUNT: usize = 1;
+    pub const ITEM_SIZE: usize = 1;
+    pub const TOTAL_SIZE: usize = 1;
+
+    pub fn nth0(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(0..1))
+    }
+
+    pub fn raw_data(&self) -> molecule::bytes::Bytes {
+        self.as_bytes()
+    }
+
+    pub fn as_reader<'r>(&'r self) -> CheckerStatusReader<'r> {
+        CheckerStatusReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for CheckerStatus {
+    type Builder = CheckerStatusBuilder;
+
+    const NAME: &'static str = "CheckerStatus";
+
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        CheckerStatus(data)
+    }
+
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        CheckerStatusReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        CheckerStatusReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().set([self.nth0()])
+    }
+}
+#[derive(Clone, Copy)]
+pub struct CheckerStatusReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for CheckerStatusReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for CheckerStatusReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for CheckerStatusReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        let raw_data = hex_string(&self.raw_data());
+        write!(f, "{}(0x{})", Self::NAME, raw_data)
+    }
+}
+impl<'r> CheckerStatusReader<'r> {
+    pub const ITEM_COUNT: usize = 1;
+    pub const ITEM_SIZE: usize = 1;
+    pub const TOTAL_SIZE: usize = 1;
+
+    pub fn nth0(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[0..1])
+    }
+
+    pub fn raw_data(&self) -> &'r [u8] {
+        self.as_slice()
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for CheckerStatusReader<'r> {
+    type Entity = CheckerStatus;
+
+    const NAME: &'static str = "CheckerStatusReader";
+
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        CheckerStatusReader(slice)
+    }
+
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+pub struct CheckerStatusBuilder(pub(crate) [Byte; 1]);
+impl ::core::fmt::Debug for CheckerStatusBuilder {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:?})", Self::NAME, &self.0[..])
+    }
+}
+impl ::core::default::Default for CheckerStatusBuilder {
+    fn default() -> Self {
+        CheckerStatusBuilder([Byte::default()])
+    }
+}
+impl CheckerStatusBuilder {
+    pub const ITEM_COUNT: usize = 1;
+    pub const ITEM_SIZE: usize = 1;
+    pub const TOTAL_SIZE: usize = 1;
+
+    pub fn set(mut self, v: [Byte; 1]) -> Self {
+        self.0 = v;
+        self
+    }
+
+    pub fn nth0(mut self, v: Byte) -> Self {
+        self.0[0] = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for CheckerStatusBuilder {
+    type Entity = CheckerStatus;
+
+    const NAME: &'static str = "CheckerStatusBuilder";
+
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        writer.write_all(self.0[0].as_slice())?;
+        Ok(())
+    }
+
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        CheckerStatus::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct CheckerRecord(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for CheckerRecord {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for CheckerRecord {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for CheckerRecord {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "status", self.status())?;
+        write!(f, ", {}: {}", "weight", self.weight())?;
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for CheckerRecord {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        CheckerRecord::new_unchecked(v.into())
+    }
+}
+impl CheckerRecord {
+    pub const FIELD_COUNT: usize = 2;
+    pub const FIELD_SIZES: [usize; 2] = [1, 16];
+    pub const TOTAL_SIZE: usize = 17;
+
+    pub fn status(&self) -> CheckerStatus {
+        CheckerStatus::new_unchecked(self.0.slice(0..1))
+    }
+
+    pub fn weight(&self) -> Uint128 {
+        Uint128::new_unchecked(self.0.slice(1..17))
+    }
+
+    pub fn as_reader<'r>(&'r self) -> CheckerRecordReader<'r> {
+        CheckerRecordReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for CheckerRecord {
+    type Builder = CheckerRecordBuilder;
+
+    const NAME: &'static str = "CheckerRecord";
+
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        CheckerRecord(data)
+    }
+
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        CheckerRecordReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        CheckerRecordReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().status(self.status()).weight(self.weight())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct CheckerRecordReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for CheckerRecordReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for CheckerRecordReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for CheckerRecordReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "status", self.status())?;
+        write!(f, ", {}: {}", "weight", self.weight())?;
+        write!(f, " }}")
+    }
+}
+impl<'r> CheckerRecordReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
+    pub const FIELD_SIZES: [usize; 2] = [1, 16];
+    pub const TOTAL_SIZE: usize = 17;
+
+    pub fn status(&self) -> CheckerStatusReader<'r> {
+        CheckerStatusReader::new_unchecked(&self.as_slice()[0..1])
+    }
+
+    pub fn weight(&self) -> Uint128Reader<'r> {
+        Uint128Reader::new_unchecked(&self.as_slice()[1..17])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for CheckerRecordReader<'r> {
+    type Entity = CheckerRecord;
+
+    const NAME: &'static str = "CheckerRecordReader";
+
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        CheckerRecordReader(slice)
+    }
+
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct CheckerRecordBuilder {
+    pub(crate) status: CheckerStatus,
+    pub(crate) weight: Uint128,
+}
+impl CheckerRecordBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub const FIELD_SIZES: [usize; 2] = [1, 16];
+    pub const TOTAL_SIZE: usize = 17;
+
+    pub fn status(mut self, v: CheckerStatus) -> Self {
+        self.status = v;
+        self
+    }
+
+    pub fn weight(mut self, v: Uint128) -> Self {
+        self.weight = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for CheckerRecordBuilder {
+    type Entity = CheckerRecord;
+
+    const NAME: &'static str = "CheckerRecordBuilder";
+
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        writer.write_all(self.status.as_slice())?;
+        writer.write_all(self.weight.as_slice())?;
+        Ok(())
+    }
+
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        CheckerRecord::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
 pub struct SidechainConfigCell(molecule::bytes::Bytes);
 impl ::core::fmt::LowerHex for SidechainConfigCell {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
@@ -210,21 +583,27 @@ impl ::core::fmt::Display for SidechainConfigCell {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
         write!(f, "{}: {}", "sidechain_status", self.sidechain_status())?;
+        write!(f, ", {}: {}", "config_nonce", self.config_nonce())?;
         write!(f, ", {}: {}", "commit_threshold", self.commit_threshold())?;
         write!(f, ", {}: {}", "challenge_threshold", self.challenge_threshold())?;
         write!(f, ", {}: {}", "checker_normal_count", self.checker_normal_count())?;
         write!(f, ", {}: {}", "checker_threshold", self.checker_threshold())?;
         write!(f, ", {}: {}", "checker_total_count", self.checker_total_count())?;
         write!(f, ", {}: {}", "activated_checkers", self.activated_checkers())?;
+        write!(f, ", {}: {}", "activated_checker_weights", self.activated_checker_weights())?;
         write!(f, ", {}: {}", "jailed_checkers", self.jailed_checkers())?;
+        write!(f, ", {}: {}", "checker_set_root", self.checker_set_root())?;
         write!(f, ", {}: {}", "refresh_punish_points", self.refresh_punish_points())?;
         write!(f, ", {}: {}", "refresh_punish_release_points", self.refresh_punish_release_points())?;
         write!(f, ", {}: {}", "refresh_punish_threshold", self.refresh_punish_threshold())?;
         write!(f, ", {}: {}", "refresh_interval", self.refresh_interval())?;
+        write!(f, ", {}: {}", "reveal_punish_points", self.reveal_punish_points())?;
+        write!(f, ", {}: {}", "reveal_quorum", self.reveal_quorum())?;
         write!(f, ", {}: {}", "shutdown_timeout", self.shutdown_timeout())?;
         write!(f, ", {}: {}", "check_data_size_limit", self.check_data_size_limit())?;
         write!(f, ", {}: {}", "check_fee_rate", self.check_fee_rate())?;
         write!(f, ", {}: {}", "minimal_bond", self.minimal_bond())?;
+        write!(f, ", {}: {}", "slash_rate", self.slash_rate())?;
         write!(f, ", {}: {}", "parallel_job_upper_bond", self.parallel_job_upper_bond())?;
         write!(
             f,
@@ -232,8 +611,11 @@ impl ::core::fmt::Display for SidechainConfigCell {
             "parallel_job_maximal_height_range",
             self.parallel_job_maximal_height_range()
         )?;
+        write!(f, ", {}: {}", "refresh_task_limit", self.refresh_task_limit())?;
+        write!(f, ", {}: {}", "submit_task_limit", self.submit_task_limit())?;
         write!(f, ", {}: {}", "admin_lock_arg", self.admin_lock_arg())?;
-        write!(f, ", {}: {}", "collator_lock_arg", self.collator_lock_arg())?;
+        write!(f, ", {}: {}", "collators", self.collators())?;
+        write!(f, ", {}: {}", "collator_threshold", self.collator_threshold())?;
         write!(f, ", {}: {}", "bond_sudt_typescript_codehash", self.bond_sudt_typescript_codehash())?;
         write!(f, ", {}: {}", "bond_sudt_typescript_hashtype", self.bond_sudt_typescript_hashtype())?;
         let extra_count = self.count_extra_fields();
@@ -246,19 +628,21 @@ impl ::core::fmt::Display for SidechainConfigCell {
 impl ::core::default::Default for SidechainConfigCell {
     fn default() -> Self {
         let v: Vec<u8> = vec![
-            19, 1, 0, 0, 92, 0, 0, 0, 93, 0, 0, 0, 97, 0, 0, 0, 101, 0, 0, 0, 105, 0, 0, 0, 109, 0, 0, 0, 113, 0, 0, 0, 117, 0, 0, 0, 121,
-            0, 0, 0, 125, 0, 0, 0, 129, 0, 0, 0, 133, 0, 0, 0, 141, 0, 0, 0, 149, 0, 0, 0, 165, 0, 0, 0, 169, 0, 0, 0, 185, 0, 0, 0, 186,
-            0, 0, 0, 202, 0, 0, 0, 222, 0, 0, 0, 242, 0, 0, 0, 18, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            75, 1, 0, 0, 128, 0, 0, 0, 129, 0, 0, 0, 137, 0, 0, 0, 141, 0, 0, 0, 145, 0, 0, 0, 149, 0, 0, 0, 153, 0, 0, 0, 157, 0, 0, 0,
+            161, 0, 0, 0, 165, 0, 0, 0, 169, 0, 0, 0, 169, 0, 0, 0, 173, 0, 0, 0, 177, 0, 0, 0, 181, 0, 0, 0, 189, 0, 0, 0, 193, 0, 0, 0,
+            197, 0, 0, 0, 205, 0, 0, 0, 221, 0, 0, 0, 225, 0, 0, 0, 241, 0, 0, 0, 245, 0, 0, 0, 246, 0, 0, 0, 6, 1, 0, 0, 10, 1, 0, 0, 14,
+            1, 0, 0, 34, 1, 0, 0, 38, 1, 0, 0, 42, 1, 0, 0, 74, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         ];
         SidechainConfigCell::new_unchecked(v.into())
     }
 }
 impl SidechainConfigCell {
-    pub const FIELD_COUNT: usize = 22;
+    pub const FIELD_COUNT: usize = 31;
 
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
@@ -287,151 +671,214 @@ impl SidechainConfigCell {
         SidechainStatus::new_unchecked(self.0.slice(start..end))
     }
 
-    pub fn commit_threshold(&self) -> Uint32 {
+    pub fn config_nonce(&self) -> Uint64 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
         let end = molecule::unpack_number(&slice[12..]) as usize;
-        Uint32::new_unchecked(self.0.slice(start..end))
+        Uint64::new_unchecked(self.0.slice(start..end))
     }
 
-    pub fn challenge_threshold(&self) -> Uint32 {
+    pub fn commit_threshold(&self) -> Uint32 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[12..]) as usize;
         let end = molecule::unpack_number(&slice[16..]) as usize;
         Uint32::new_unchecked(self.0.slice(start..end))
     }
 
-    pub fn checker_normal_count(&self) -> Uint32 {
+    pub fn challenge_threshold(&self) -> Uint32 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[16..]) as usize;
         let end = molecule::unpack_number(&slice[20..]) as usize;
         Uint32::new_unchecked(self.0.slice(start..end))
     }
 
-    pub fn checker_threshold(&self) -> Uint32 {
+    pub fn checker_normal_count(&self) -> Uint32 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[20..]) as usize;
         let end = molecule::unpack_number(&slice[24..]) as usize;
         Uint32::new_unchecked(self.0.slice(start..end))
     }
 
-    pub fn checker_total_count(&self) -> Uint32 {
+    pub fn checker_threshold(&self) -> Uint32 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[24..]) as usize;
         let end = molecule::unpack_number(&slice[28..]) as usize;
         Uint32::new_unchecked(self.0.slice(start..end))
     }
 
-    pub fn activated_checkers(&self) -> PubKeyHashList {
+    pub fn checker_total_count(&self) -> Uint32 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[28..]) as usize;
         let end = molecule::unpack_number(&slice[32..]) as usize;
-        PubKeyHashList::new_unchecked(self.0.slice(start..end))
+        Uint32::new_unchecked(self.0.slice(start..end))
     }
 
-    pub fn jailed_checkers(&self) -> PubKeyHashList {
+    pub fn activated_checkers(&self) -> PubKeyHashList {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[32..]) as usize;
         let end = molecule::unpack_number(&slice[36..]) as usize;
         PubKeyHashList::new_unchecked(self.0.slice(start..end))
     }
 
-    pub fn refresh_punish_points(&self) -> Uint32 {
+    pub fn activated_checker_weights(&self) -> Uint128List {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[36..]) as usize;
         let end = molecule::unpack_number(&slice[40..]) as usize;
-        Uint32::new_unchecked(self.0.slice(start..end))
+        Uint128List::new_unchecked(self.0.slice(start..end))
     }
 
-    pub fn refresh_punish_release_points(&self) -> Uint32 {
+    pub fn jailed_checkers(&self) -> PubKeyHashList {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[40..]) as usize;
         let end = molecule::unpack_number(&slice[44..]) as usize;
-        Uint32::new_unchecked(self.0.slice(start..end))
+        PubKeyHashList::new_unchecked(self.0.slice(start..end))
     }
 
-    pub fn refresh_punish_threshold(&self) -> Uint32 {
+    pub fn checker_set_root(&self) -> MerkleHashOpt {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[44..]) as usize;
         let end = molecule::unpack_number(&slice[48..]) as usize;
-        Uint32::new_unchecked(self.0.slice(start..end))
+        MerkleHashOpt::new_unchecked(self.0.slice(start..end))
     }
 
-    pub fn refresh_interval(&self) -> Uint64 {
+    pub fn refresh_punish_points(&self) -> Uint32 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[48..]) as usize;
         let end = molecule::unpack_number(&slice[52..]) as usize;
-        Uint64::new_unchecked(self.0.slice(start..end))
+        Uint32::new_unchecked(self.0.slice(start..end))
     }
 
-    pub fn shutdown_timeout(&self) -> Uint64 {
+    pub fn refresh_punish_release_points(&self) -> Uint32 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[52..]) as usize;
         let end = molecule::unpack_number(&slice[56..]) as usize;
-        Uint64::new_unchecked(self.0.slice(start..end))
+        Uint32::new_unchecked(self.0.slice(start..end))
     }
 
-    pub fn check_data_size_limit(&self) -> Uint128 {
+    pub fn refresh_punish_threshold(&self) -> Uint32 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[56..]) as usize;
         let end = molecule::unpack_number(&slice[60..]) as usize;
-        Uint128::new_unchecked(self.0.slice(start..end))
+        Uint32::new_unchecked(self.0.slice(start..end))
     }
 
-    pub fn check_fee_rate(&self) -> Uint32 {
+    pub fn refresh_interval(&self) -> Uint64 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[60..]) as usize;
         let end = molecule::unpack_number(&slice[64..]) as usize;
-        Uint32::new_unchecked(self.0.slice(start..end))
+        Uint64::new_unchecked(self.0.slice(start..end))
     }
 
-    pub fn minimal_bond(&self) -> Uint128 {
+    pub fn reveal_punish_points(&self) -> Uint32 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[64..]) as usize;
         let end = molecule::unpack_number(&slice[68..]) as usize;
-        Uint128::new_unchecked(self.0.slice(start..end))
+        Uint32::new_unchecked(self.0.slice(start..end))
     }
 
-    pub fn parallel_job_upper_bond(&self) -> Uint8 {
+    pub fn reveal_quorum(&self) -> Uint32 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[68..]) as usize;
         let end = molecule::unpack_number(&slice[72..]) as usize;
-        Uint8::new_unchecked(self.0.slice(start..end))
+        Uint32::new_unchecked(self.0.slice(start..end))
     }
 
-    pub fn parallel_job_maximal_height_range(&self) -> BlockHeight {
+    pub fn shutdown_timeout(&self) -> Uint64 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[72..]) as usize;
         let end = molecule::unpack_number(&slice[76..]) as usize;
-        BlockHeight::new_unchecked(self.0.slice(start..end))
+        Uint64::new_unchecked(self.0.slice(start..end))
     }
 
-    pub fn admin_lock_arg(&self) -> PubKeyHash {
+    pub fn check_data_size_limit(&self) -> Uint128 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[76..]) as usize;
         let end = molecule::unpack_number(&slice[80..]) as usize;
-        PubKeyHash::new_unchecked(self.0.slice(start..end))
+        Uint128::new_unchecked(self.0.slice(start..end))
     }
 
-    pub fn collator_lock_arg(&self) -> PubKeyHash {
+    pub fn check_fee_rate(&self) -> Uint32 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[80..]) as usize;
         let end = molecule::unpack_number(&slice[84..]) as usize;
-        PubKeyHash::new_unchecked(self.0.slice(start..end))
+        Uint32::new_unchecked(self.0.slice(start..end))
     }
 
-    pub fn bond_sudt_typescript_codehash(&self) -> CodeHash {
+    pub fn minimal_bond(&self) -> Uint128 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[84..]) as usize;
         let end = molecule::unpack_number(&slice[88..]) as usize;
+        Uint128::new_unchecked(self.0.slice(start..end))
+    }
+
+    pub fn slash_rate(&self) -> Uint32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[88..]) as usize;
+        let end = molecule::unpack_number(&slice[92..]) as usize;
+        Uint32::new_unchecked(self.0.slice(start..end))
+    }
+
+    pub fn parallel_job_upper_bond(&self) -> Uint8 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[92..]) as usize;
+        let end = molecule::unpack_number(&slice[96..]) as usize;
+        Uint8::new_unchecked(self.0.slice(start..end))
+    }
+
+    pub fn parallel_job_maximal_height_range(&self) -> BlockHeight {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[96..]) as usize;
+        let end = molecule::unpack_number(&slice[100..]) as usize;
+        BlockHeight::new_unchecked(self.0.slice(start..end))
+    }
+
+    pub fn refresh_task_limit(&self) -> Uint32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[100..]) as usize;
+        let end = molecule::unpack_number(&slice[104..]) as usize;
+        Uint32::new_unchecked(self.0.slice(start..end))
+    }
+
+    pub fn submit_task_limit(&self) -> Uint32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[104..]) as usize;
+        let end = molecule::unpack_number(&slice[108..]) as usize;
+        Uint32::new_unchecked(self.0.slice(start..end))
+    }
+
+    pub fn admin_lock_arg(&self) -> PubKeyHash {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[108..]) as usize;
+        let end = molecule::unpack_number(&slice[112..]) as usize;
+        PubKeyHash::new_unchecked(self.0.slice(start..end))
+    }
+
+    pub fn collators(&self) -> PubKeyHashList {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[112..]) as usize;
+        let end = molecule::unpack_number(&slice[116..]) as usize;
+        PubKeyHashList::new_unchecked(self.0.slice(start..end))
+    }
+
+    pub fn collator_threshold(&self) -> Uint32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[116..]) as usize;
+        let end = molecule::unpack_number(&slice[120..]) as usize;
+        Uint32::new_unchecked(self.0.slice(start..end))
+    }
+
+    pub fn bond_sudt_typescript_codehash(&self) -> CodeHash {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[120..]) as usize;
+        let end = molecule::unpack_number(&slice[124..]) as usize;
         CodeHash::new_unchecked(self.0.slice(start..end))
     }
 
     pub fn bond_sudt_typescript_hashtype(&self) -> HashType {
         let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[88..]) as usize;
+        let start = molecule::unpack_number(&slice[124..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[92..]) as usize;
+            let end = molecule::unpack_number(&slice[128..]) as usize;
             HashType::new_unchecked(self.0.slice(start..end))
         } else {
             HashType::new_unchecked(self.0.slice(start..))
@@ -474,25 +921,34 @@ impl molecule::prelude::Entity for SidechainConfigCell {
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
             .sidechain_status(self.sidechain_status())
+            .config_nonce(self.config_nonce())
             .commit_threshold(self.commit_threshold())
             .challenge_threshold(self.challenge_threshold())
             .checker_normal_count(self.checker_normal_count())
             .checker_threshold(self.checker_threshold())
             .checker_total_count(self.checker_total_count())
             .activated_checkers(self.activated_checkers())
+            .activated_checker_weights(self.activated_checker_weights())
             .jailed_checkers(self.jailed_checkers())
+            .checker_set_root(self.checker_set_root())
             .refresh_punish_points(self.refresh_punish_points())
             .refresh_punish_release_points(self.refresh_punish_release_points())
             .refresh_punish_threshold(self.refresh_punish_threshold())
             .refresh_interval(self.refresh_interval())
+            .reveal_punish_points(self.reveal_punish_points())
+            .reveal_quorum(self.reveal_quorum())
             .shutdown_timeout(self.shutdown_timeout())
             .check_data_size_limit(self.check_data_size_limit())
             .check_fee_rate(self.check_fee_rate())
             .minimal_bond(self.minimal_bond())
+            .slash_rate(self.slash_rate())
             .parallel_job_upper_bond(self.parallel_job_upper_bond())
             .parallel_job_maximal_height_range(self.parallel_job_maximal_height_range())
+            .refresh_task_limit(self.refresh_task_limit())
+            .submit_task_limit(self.submit_task_limit())
             .admin_lock_arg(self.admin_lock_arg())
-            .collator_lock_arg(self.collator_lock_arg())
+            .collators(self.collators())
+            .collator_threshold(self.collator_threshold())
             .bond_sudt_typescript_codehash(self.bond_sudt_typescript_codehash())
             .bond_sudt_typescript_hashtype(self.bond_sudt_typescript_hashtype())
     }
@@ -517,21 +973,27 @@ impl<'r> ::core::fmt::Display for SidechainConfigCellReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
         write!(f, "{}: {}", "sidechain_status", self.sidechain_status())?;
+        write!(f, ", {}: {}", "config_nonce", self.config_nonce())?;
         write!(f, ", {}: {}", "commit_threshold", self.commit_threshold())?;
         write!(f, ", {}: {}", "challenge_threshold", self.challenge_threshold())?;
         write!(f, ", {}: {}", "checker_normal_count", self.checker_normal_count())?;
         write!(f, ", {}: {}", "checker_threshold", self.checker_threshold())?;
         write!(f, ", {}: {}", "checker_total_count", self.checker_total_count())?;
         write!(f, ", {}: {}", "activated_checkers", self.activated_checkers())?;
+        write!(f, ", {}: {}", "activated_checker_weights", self.activated_checker_weights())?;
         write!(f, ", {}: {}", "jailed_checkers", self.jailed_checkers())?;
+        write!(f, ", {}: {}", "checker_set_root", self.checker_set_root())?;
         write!(f, ", {}: {}", "refresh_punish_points", self.refresh_punish_points())?;
         write!(f, ", {}: {}", "refresh_punish_release_points", self.refresh_punish_release_points())?;
         write!(f, ", {}: {}", "refresh_punish_threshold", self.refresh_punish_threshold())?;
         write!(f, ", {}: {}", "refresh_interval", self.refresh_interval())?;
+        write!(f, ", {}: {}", "reveal_punish_points", self.reveal_punish_points())?;
+        write!(f, ", {}: {}", "reveal_quorum", self.reveal_quorum())?;
         write!(f, ", {}: {}", "shutdown_timeout", self.shutdown_timeout())?;
         write!(f, ", {}: {}", "check_data_size_limit", self.check_data_size_limit())?;
         write!(f, ", {}: {}", "check_fee_rate", self.check_fee_rate())?;
         write!(f, ", {}: {}", "minimal_bond", self.minimal_bond())?;
+        write!(f, ", {}: {}", "slash_rate", self.slash_rate())?;
         write!(f, ", {}: {}", "parallel_job_upper_bond", self.parallel_job_upper_bond())?;
         write!(
             f,
@@ -539,8 +1001,11 @@ impl<'r> ::core::fmt::Display for SidechainConfigCellReader<'r> {
             "parallel_job_maximal_height_range",
             self.parallel_job_maximal_height_range()
         )?;
+        write!(f, ", {}: {}", "refresh_task_limit", self.refresh_task_limit())?;
+        write!(f, ", {}: {}", "submit_task_limit", self.submit_task_limit())?;
         write!(f, ", {}: {}", "admin_lock_arg", self.admin_lock_arg())?;
-        write!(f, ", {}: {}", "collator_lock_arg", self.collator_lock_arg())?;
+        write!(f, ", {}: {}", "collators", self.collators())?;
+        write!(f, ", {}: {}", "collator_threshold", self.collator_threshold())?;
         write!(f, ", {}: {}", "bond_sudt_typescript_codehash", self.bond_sudt_typescript_codehash())?;
         write!(f, ", {}: {}", "bond_sudt_typescript_hashtype", self.bond_sudt_typescript_hashtype())?;
         let extra_count = self.count_extra_fields();
@@ -551,7 +1016,7 @@ impl<'r> ::core::fmt::Display for SidechainConfigCellReader<'r> {
     }
 }
 impl<'r> SidechainConfigCellReader<'r> {
-    pub const FIELD_COUNT: usize = 22;
+    pub const FIELD_COUNT: usize = 31;
 
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
@@ -580,151 +1045,214 @@ impl<'r> SidechainConfigCellReader<'r> {
         SidechainStatusReader::new_unchecked(&self.as_slice()[start..end])
     }
 
-    pub fn commit_threshold(&self) -> Uint32Reader<'r> {
+    pub fn config_nonce(&self) -> Uint64Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
         let end = molecule::unpack_number(&slice[12..]) as usize;
-        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
     }
 
-    pub fn challenge_threshold(&self) -> Uint32Reader<'r> {
+    pub fn commit_threshold(&self) -> Uint32Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[12..]) as usize;
         let end = molecule::unpack_number(&slice[16..]) as usize;
         Uint32Reader::new_unchecked(&self.as_slice()[start..end])
     }
 
-    pub fn checker_normal_count(&self) -> Uint32Reader<'r> {
+    pub fn challenge_threshold(&self) -> Uint32Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[16..]) as usize;
         let end = molecule::unpack_number(&slice[20..]) as usize;
         Uint32Reader::new_unchecked(&self.as_slice()[start..end])
     }
 
-    pub fn checker_threshold(&self) -> Uint32Reader<'r> {
+    pub fn checker_normal_count(&self) -> Uint32Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[20..]) as usize;
         let end = molecule::unpack_number(&slice[24..]) as usize;
         Uint32Reader::new_unchecked(&self.as_slice()[start..end])
     }
 
-    pub fn checker_total_count(&self) -> Uint32Reader<'r> {
+    pub fn checker_threshold(&self) -> Uint32Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[24..]) as usize;
         let end = molecule::unpack_number(&slice[28..]) as usize;
         Uint32Reader::new_unchecked(&self.as_slice()[start..end])
     }
 
-    pub fn activated_checkers(&self) -> PubKeyHashListReader<'r> {
+    pub fn checker_total_count(&self) -> Uint32Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[28..]) as usize;
         let end = molecule::unpack_number(&slice[32..]) as usize;
-        PubKeyHashListReader::new_unchecked(&self.as_slice()[start..end])
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
     }
 
-    pub fn jailed_checkers(&self) -> PubKeyHashListReader<'r> {
+    pub fn activated_checkers(&self) -> PubKeyHashListReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[32..]) as usize;
         let end = molecule::unpack_number(&slice[36..]) as usize;
         PubKeyHashListReader::new_unchecked(&self.as_slice()[start..end])
     }
 
-    pub fn refresh_punish_points(&self) -> Uint32Reader<'r> {
+    pub fn activated_checker_weights(&self) -> Uint128ListReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[36..]) as usize;
         let end = molecule::unpack_number(&slice[40..]) as usize;
-        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+        Uint128ListReader::new_unchecked(&self.as_slice()[start..end])
     }
 
-    pub fn refresh_punish_release_points(&self) -> Uint32Reader<'r> {
+    pub fn jailed_checkers(&self) -> PubKeyHashListReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[40..]) as usize;
         let end = molecule::unpack_number(&slice[44..]) as usize;
-        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+        PubKeyHashListReader::new_unchecked(&self.as_slice()[start..end])
     }
 
-    pub fn refresh_punish_threshold(&self) -> Uint32Reader<'r> {
+    pub fn checker_set_root(&self) -> MerkleHashOptReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[44..]) as usize;
         let end = molecule::unpack_number(&slice[48..]) as usize;
-        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+        MerkleHashOptReader::new_unchecked(&self.as_slice()[start..end])
     }
 
-    pub fn refresh_interval(&self) -> Uint64Reader<'r> {
+    pub fn refresh_punish_points(&self) -> Uint32Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[48..]) as usize;
         let end = molecule::unpack_number(&slice[52..]) as usize;
-        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
     }
 
-    pub fn shutdown_timeout(&self) -> Uint64Reader<'r> {
+    pub fn refresh_punish_release_points(&self) -> Uint32Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[52..]) as usize;
         let end = molecule::unpack_number(&slice[56..]) as usize;
-        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
     }
 
-    pub fn check_data_size_limit(&self) -> Uint128Reader<'r> {
+    pub fn refresh_punish_threshold(&self) -> Uint32Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[56..]) as usize;
         let end = molecule::unpack_number(&slice[60..]) as usize;
-        Uint128Reader::new_unchecked(&self.as_slice()[start..end])
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
     }
 
-    pub fn check_fee_rate(&self) -> Uint32Reader<'r> {
+    pub fn refresh_interval(&self) -> Uint64Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[60..]) as usize;
         let end = molecule::unpack_number(&slice[64..]) as usize;
-        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
     }
 
-    pub fn minimal_bond(&self) -> Uint128Reader<'r> {
+    pub fn reveal_punish_points(&self) -> Uint32Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[64..]) as usize;
         let end = molecule::unpack_number(&slice[68..]) as usize;
-        Uint128Reader::new_unchecked(&self.as_slice()[start..end])
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
     }
 
-    pub fn parallel_job_upper_bond(&self) -> Uint8Reader<'r> {
+    pub fn reveal_quorum(&self) -> Uint32Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[68..]) as usize;
         let end = molecule::unpack_number(&slice[72..]) as usize;
-        Uint8Reader::new_unchecked(&self.as_slice()[start..end])
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
     }
 
-    pub fn parallel_job_maximal_height_range(&self) -> BlockHeightReader<'r> {
+    pub fn shutdown_timeout(&self) -> Uint64Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[72..]) as usize;
         let end = molecule::unpack_number(&slice[76..]) as usize;
-        BlockHeightReader::new_unchecked(&self.as_slice()[start..end])
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
     }
 
-    pub fn admin_lock_arg(&self) -> PubKeyHashReader<'r> {
+    pub fn check_data_size_limit(&self) -> Uint128Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[76..]) as usize;
         let end = molecule::unpack_number(&slice[80..]) as usize;
-        PubKeyHashReader::new_unchecked(&self.as_slice()[start..end])
+        Uint128Reader::new_unchecked(&self.as_slice()[start..end])
     }
 
-    pub fn collator_lock_arg(&self) -> PubKeyHashReader<'r> {
+    pub fn check_fee_rate(&self) -> Uint32Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[80..]) as usize;
         let end = molecule::unpack_number(&slice[84..]) as usize;
-        PubKeyHashReader::new_unchecked(&self.as_slice()[start..end])
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
     }
 
-    pub fn bond_sudt_typescript_codehash(&self) -> CodeHashReader<'r> {
+    pub fn minimal_bond(&self) -> Uint128Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[84..]) as usize;
         let end = molecule::unpack_number(&slice[88..]) as usize;
+        Uint128Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+
+    pub fn slash_rate(&self) -> Uint32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[88..]) as usize;
+        let end = molecule::unpack_number(&slice[92..]) as usize;
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+
+    pub fn parallel_job_upper_bond(&self) -> Uint8Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[92..]) as usize;
+        let end = molecule::unpack_number(&slice[96..]) as usize;
+        Uint8Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+
+    pub fn parallel_job_maximal_height_range(&self) -> BlockHeightReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[96..]) as usize;
+        let end = molecule::unpack_number(&slice[100..]) as usize;
+        BlockHeightReader::new_unchecked(&self.as_slice()[start..end])
+    }
+
+    pub fn refresh_task_limit(&self) -> Uint32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[100..]) as usize;
+        let end = molecule::unpack_number(&slice[104..]) as usize;
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+
+    pub fn submit_task_limit(&self) -> Uint32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[104..]) as usize;
+        let end = molecule::unpack_number(&slice[108..]) as usize;
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+
+    pub fn admin_lock_arg(&self) -> PubKeyHashReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[108..]) as usize;
+        let end = molecule::unpack_number(&slice[112..]) as usize;
+        PubKeyHashReader::new_unchecked(&self.as_slice()[start..end])
+    }
+
+    pub fn collators(&self) -> PubKeyHashListReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[112..]) as usize;
+        let end = molecule::unpack_number(&slice[116..]) as usize;
+        PubKeyHashListReader::new_unchecked(&self.as_slice()[start..end])
+    }
+
+    pub fn collator_threshold(&self) -> Uint32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[116..]) as usize;
+        let end = molecule::unpack_number(&slice[120..]) as usize;
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+
+    pub fn bond_sudt_typescript_codehash(&self) -> CodeHashReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[120..]) as usize;
+        let end = molecule::unpack_number(&slice[124..]) as usize;
         CodeHashReader::new_unchecked(&self.as_slice()[start..end])
     }
 
     pub fn bond_sudt_typescript_hashtype(&self) -> HashTypeReader<'r> {
         let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[88..]) as usize;
+        let start = molecule::unpack_number(&slice[124..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[92..]) as usize;
+            let end = molecule::unpack_number(&slice[128..]) as usize;
             HashTypeReader::new_unchecked(&self.as_slice()[start..end])
         } else {
             HashTypeReader::new_unchecked(&self.as_slice()[start..])
@@ -786,63 +1314,86 @@ impl<'r> molecule::prelude::Reader<'r> for SidechainConfigCellReader<'r> {
             return ve!(Self, OffsetsNotMatch);
         }
         SidechainStatusReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        Uint32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
         Uint32Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
         Uint32Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
         Uint32Reader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
         Uint32Reader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
-        PubKeyHashListReader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
         PubKeyHashListReader::verify(&slice[offsets[7]..offsets[8]], compatible)?;
-        Uint32Reader::verify(&slice[offsets[8]..offsets[9]], compatible)?;
-        Uint32Reader::verify(&slice[offsets[9]..offsets[10]], compatible)?;
-        Uint32Reader::verify(&slice[offsets[10]..offsets[11]], compatible)?;
-        Uint64Reader::verify(&slice[offsets[11]..offsets[12]], compatible)?;
-        Uint64Reader::verify(&slice[offsets[12]..offsets[13]], compatible)?;
-        Uint128Reader::verify(&slice[offsets[13]..offsets[14]], compatible)?;
-        Uint32Reader::verify(&slice[offsets[14]..offsets[15]], compatible)?;
-        Uint128Reader::verify(&slice[offsets[15]..offsets[16]], compatible)?;
-        Uint8Reader::verify(&slice[offsets[16]..offsets[17]], compatible)?;
-        BlockHeightReader::verify(&slice[offsets[17]..offsets[18]], compatible)?;
-        PubKeyHashReader::verify(&slice[offsets[18]..offsets[19]], compatible)?;
-        PubKeyHashReader::verify(&slice[offsets[19]..offsets[20]], compatible)?;
-        CodeHashReader::verify(&slice[offsets[20]..offsets[21]], compatible)?;
-        HashTypeReader::verify(&slice[offsets[21]..offsets[22]], compatible)?;
+        Uint128ListReader::verify(&slice[offsets[8]..offsets[9]], compatible)?;
+        PubKeyHashListReader::verify(&slice[offsets[9]..offsets[10]], compatible)?;
+        MerkleHashOptReader::verify(&slice[offsets[10]..offsets[11]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[11]..offsets[12]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[12]..offsets[13]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[13]..offsets[14]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[14]..offsets[15]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[15]..offsets[16]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[16]..offsets[17]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[17]..offsets[18]], compatible)?;
+        Uint128Reader::verify(&slice[offsets[18]..offsets[19]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[19]..offsets[20]], compatible)?;
+        Uint128Reader::verify(&slice[offsets[20]..offsets[21]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[21]..offsets[22]], compatible)?;
+        Uint8Reader::verify(&slice[offsets[22]..offsets[23]], compatible)?;
+        BlockHeightReader::verify(&slice[offsets[23]..offsets[24]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[24]..offsets[25]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[25]..offsets[26]], compatible)?;
+        PubKeyHashReader::verify(&slice[offsets[26]..offsets[27]], compatible)?;
+        PubKeyHashListReader::verify(&slice[offsets[27]..offsets[28]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[28]..offsets[29]], compatible)?;
+        CodeHashReader::verify(&slice[offsets[29]..offsets[30]], compatible)?;
+        HashTypeReader::verify(&slice[offsets[30]..offsets[31]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
 pub struct SidechainConfigCellBuilder {
     pub(crate) sidechain_status: SidechainStatus,
+    pub(crate) config_nonce: Uint64,
     pub(crate) commit_threshold: Uint32,
     pub(crate) challenge_threshold: Uint32,
     pub(crate) checker_normal_count: Uint32,
     pub(crate) checker_threshold: Uint32,
     pub(crate) checker_total_count: Uint32,
     pub(crate) activated_checkers: PubKeyHashList,
+    pub(crate) activated_checker_weights: Uint128List,
     pub(crate) jailed_checkers: PubKeyHashList,
+    pub(crate) checker_set_root: MerkleHashOpt,
     pub(crate) refresh_punish_points: Uint32,
     pub(crate) refresh_punish_release_points: Uint32,
     pub(crate) refresh_punish_threshold: Uint32,
     pub(crate) refresh_interval: Uint64,
+    pub(crate) reveal_punish_points: Uint32,
+    pub(crate) reveal_quorum: Uint32,
     pub(crate) shutdown_timeout: Uint64,
     pub(crate) check_data_size_limit: Uint128,
     pub(crate) check_fee_rate: Uint32,
     pub(crate) minimal_bond: Uint128,
+    pub(crate) slash_rate: Uint32,
     pub(crate) parallel_job_upper_bond: Uint8,
     pub(crate) parallel_job_maximal_height_range: BlockHeight,
+    pub(crate) refresh_task_limit: Uint32,
+    pub(crate) submit_task_limit: Uint32,
     pub(crate) admin_lock_arg: PubKeyHash,
-    pub(crate) collator_lock_arg: PubKeyHash,
+    pub(crate) collators: PubKeyHashList,
+    pub(crate) collator_threshold: Uint32,
     pub(crate) bond_sudt_typescript_codehash: CodeHash,
     pub(crate) bond_sudt_typescript_hashtype: HashType,
 }
 impl SidechainConfigCellBuilder {
-    pub const FIELD_COUNT: usize = 22;
+    pub const FIELD_COUNT: usize = 31;
 
     pub fn sidechain_status(mut self, v: SidechainStatus) -> Self {
         self.sidechain_status = v;
         self
     }
 
+    pub fn config_nonce(mut self, v: Uint64) -> Self {
+        self.config_nonce = v;
+        self
+    }
+
     pub fn commit_threshold(mut self, v: Uint32) -> Self {
         self.commit_threshold = v;
         self
@@ -873,11 +1424,21 @@ impl SidechainConfigCellBuilder {
         self
     }
 
+    pub fn activated_checker_weights(mut self, v: Uint128List) -> Self {
+        self.activated_checker_weights = v;
+        self
+    }
+
     pub fn jailed_checkers(mut self, v: PubKeyHashList) -> Self {
         self.jailed_checkers = v;
         self
     }
 
+    pub fn checker_set_root(mut self, v: MerkleHashOpt) -> Self {
+        self.checker_set_root = v;
+        self
+    }
+
     pub fn refresh_punish_points(mut self, v: Uint32) -> Self {
         self.refresh_punish_points = v;
         self
@@ -898,6 +1459,16 @@ impl SidechainConfigCellBuilder {
         self
     }
 
+    pub fn reveal_punish_points(mut self, v: Uint32) -> Self {
+        self.reveal_punish_points = v;
+        self
+    }
+
+    pub fn reveal_quorum(mut self, v: Uint32) -> Self {
+        self.reveal_quorum = v;
+        self
+    }
+
     pub fn shutdown_timeout(mut self, v: Uint64) -> Self {
         self.shutdown_timeout = v;
         self
@@ -918,6 +1489,11 @@ impl SidechainConfigCellBuilder {
         self
     }
 
+    pub fn slash_rate(mut self, v: Uint32) -> Self {
+        self.slash_rate = v;
+        self
+    }
+
     pub fn parallel_job_upper_bond(mut self, v: Uint8) -> Self {
         self.parallel_job_upper_bond = v;
         self
@@ -928,13 +1504,28 @@ impl SidechainConfigCellBuilder {
         self
     }
 
+    pub fn refresh_task_limit(mut self, v: Uint32) -> Self {
+        self.refresh_task_limit = v;
+        self
+    }
+
+    pub fn submit_task_limit(mut self, v: Uint32) -> Self {
+        self.submit_task_limit = v;
+        self
+    }
+
     pub fn admin_lock_arg(mut self, v: PubKeyHash) -> Self {
         self.admin_lock_arg = v;
         self
     }
 
-    pub fn collator_lock_arg(mut self, v: PubKeyHash) -> Self {
-        self.collator_lock_arg = v;
+    pub fn collators(mut self, v: PubKeyHashList) -> Self {
+        self.collators = v;
+        self
+    }
+
+    pub fn collator_threshold(mut self, v: Uint32) -> Self {
+        self.collator_threshold = v;
         self
     }
 
@@ -956,25 +1547,34 @@ impl molecule::prelude::Builder for SidechainConfigCellBuilder {
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
             + self.sidechain_status.as_slice().len()
+            + self.config_nonce.as_slice().len()
             + self.commit_threshold.as_slice().len()
             + self.challenge_threshold.as_slice().len()
             + self.checker_normal_count.as_slice().len()
             + self.checker_threshold.as_slice().len()
             + self.checker_total_count.as_slice().len()
             + self.activated_checkers.as_slice().len()
+            + self.activated_checker_weights.as_slice().len()
             + self.jailed_checkers.as_slice().len()
+            + self.checker_set_root.as_slice().len()
             + self.refresh_punish_points.as_slice().len()
             + self.refresh_punish_release_points.as_slice().len()
             + self.refresh_punish_threshold.as_slice().len()
             + self.refresh_interval.as_slice().len()
+            + self.reveal_punish_points.as_slice().len()
+            + self.reveal_quorum.as_slice().len()
             + self.shutdown_timeout.as_slice().len()
             + self.check_data_size_limit.as_slice().len()
             + self.check_fee_rate.as_slice().len()
             + self.minimal_bond.as_slice().len()
+            + self.slash_rate.as_slice().len()
             + self.parallel_job_upper_bond.as_slice().len()
             + self.parallel_job_maximal_height_range.as_slice().len()
+            + self.refresh_task_limit.as_slice().len()
+            + self.submit_task_limit.as_slice().len()
             + self.admin_lock_arg.as_slice().len()
-            + self.collator_lock_arg.as_slice().len()
+            + self.collators.as_slice().len()
+            + self.collator_threshold.as_slice().len()
             + self.bond_sudt_typescript_codehash.as_slice().len()
             + self.bond_sudt_typescript_hashtype.as_slice().len()
     }
@@ -985,6 +1585,8 @@ impl molecule::prelude::Builder for SidechainConfigCellBuilder {
         offsets.push(total_size);
         total_size += self.sidechain_status.as_slice().len();
         offsets.push(total_size);
+        total_size += self.config_nonce.as_slice().len();
+        offsets.push(total_size);
         total_size += self.commit_threshold.as_slice().len();
         offsets.push(total_size);
         total_size += self.challenge_threshold.as_slice().len();
@@ -997,8 +1599,12 @@ impl molecule::prelude::Builder for SidechainConfigCellBuilder {
         offsets.push(total_size);
         total_size += self.activated_checkers.as_slice().len();
         offsets.push(total_size);
+        total_size += self.activated_checker_weights.as_slice().len();
+        offsets.push(total_size);
         total_size += self.jailed_checkers.as_slice().len();
         offsets.push(total_size);
+        total_size += self.checker_set_root.as_slice().len();
+        offsets.push(total_size);
         total_size += self.refresh_punish_points.as_slice().len();
         offsets.push(total_size);
         total_size += self.refresh_punish_release_points.as_slice().len();
@@ -1007,6 +1613,10 @@ impl molecule::prelude::Builder for SidechainConfigCellBuilder {
         offsets.push(total_size);
         total_size += self.refresh_interval.as_slice().len();
         offsets.push(total_size);
+        total_size += self.reveal_punish_points.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.reveal_quorum.as_slice().len();
+        offsets.push(total_size);
         total_size += self.shutdown_timeout.as_slice().len();
         offsets.push(total_size);
         total_size += self.check_data_size_limit.as_slice().len();
@@ -1015,13 +1625,21 @@ impl molecule::prelude::Builder for SidechainConfigCellBuilder {
         offsets.push(total_size);
         total_size += self.minimal_bond.as_slice().len();
         offsets.push(total_size);
+        total_size += self.slash_rate.as_slice().len();
+        offsets.push(total_size);
         total_size += self.parallel_job_upper_bond.as_slice().len();
         offsets.push(total_size);
         total_size += self.parallel_job_maximal_height_range.as_slice().len();
         offsets.push(total_size);
+        total_size += self.refresh_task_limit.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.submit_task_limit.as_slice().len();
+        offsets.push(total_size);
         total_size += self.admin_lock_arg.as_slice().len();
         offsets.push(total_size);
-        total_size += self.collator_lock_arg.as_slice().len();
+        total_size += self.collators.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.collator_threshold.as_slice().len();
         offsets.push(total_size);
         total_size += self.bond_sudt_typescript_codehash.as_slice().len();
         offsets.push(total_size);
@@ -1031,25 +1649,34 @@ impl molecule::prelude::Builder for SidechainConfigCellBuilder {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
         writer.write_all(self.sidechain_status.as_slice())?;
+        writer.write_all(self.config_nonce.as_slice())?;
         writer.write_all(self.commit_threshold.as_slice())?;
         writer.write_all(self.challenge_threshold.as_slice())?;
         writer.write_all(self.checker_normal_count.as_slice())?;
         writer.write_all(self.checker_threshold.as_slice())?;
         writer.write_all(self.checker_total_count.as_slice())?;
         writer.write_all(self.activated_checkers.as_slice())?;
+        writer.write_all(self.activated_checker_weights.as_slice())?;
         writer.write_all(self.jailed_checkers.as_slice())?;
+        writer.write_all(self.checker_set_root.as_slice())?;
         writer.write_all(self.refresh_punish_points.as_slice())?;
         writer.write_all(self.refresh_punish_release_points.as_slice())?;
         writer.write_all(self.refresh_punish_threshold.as_slice())?;
         writer.write_all(self.refresh_interval.as_slice())?;
+        writer.write_all(self.reveal_punish_points.as_slice())?;
+        writer.write_all(self.reveal_quorum.as_slice())?;
         writer.write_all(self.shutdown_timeout.as_slice())?;
         writer.write_all(self.check_data_size_limit.as_slice())?;
         writer.write_all(self.check_fee_rate.as_slice())?;
         writer.write_all(self.minimal_bond.as_slice())?;
+        writer.write_all(self.slash_rate.as_slice())?;
         writer.write_all(self.parallel_job_upper_bond.as_slice())?;
         writer.write_all(self.parallel_job_maximal_height_range.as_slice())?;
+        writer.write_all(self.refresh_task_limit.as_slice())?;
+        writer.write_all(self.submit_task_limit.as_slice())?;
         writer.write_all(self.admin_lock_arg.as_slice())?;
-        writer.write_all(self.collator_lock_arg.as_slice())?;
+        writer.write_all(self.collators.as_slice())?;
+        writer.write_all(self.collator_threshold.as_slice())?;
         writer.write_all(self.bond_sudt_typescript_codehash.as_slice())?;
         writer.write_all(self.bond_sudt_typescript_hashtype.as_slice())?;
         Ok(())
@@ -2077,6 +2077,674 @@ impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for CheckerLastAcceptTaskHeight
     }
 }
 #[derive(Clone)]
+pub struct CheckerTaskRecord(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for CheckerTaskRecord {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for CheckerTaskRecord {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for CheckerTaskRecord {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "punish_points", self.punish_points())?;
+        write!(f, ", {}: {}", "last_task_height", self.last_task_height())?;
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for CheckerTaskRecord {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        CheckerTaskRecord::new_unchecked(v.into())
+    }
+}
+impl CheckerTaskRecord {
+    pub const FIELD_COUNT: usize = 2;
+    pub const FIELD_SIZES: [usize; 2] = [4, 16];
+    pub const TOTAL_SIZE: usize = 20;
+
+    pub fn punish_points(&self) -> Uint32 {
+        Uint32::new_unchecked(self.0.slice(0..4))
+    }
+
+    pub fn last_task_height(&self) -> BlockHeight {
+        BlockHeight::new_unchecked(self.0.slice(4..20))
+    }
+
+    pub fn as_reader<'r>(&'r self) -> CheckerTaskRecordReader<'r> {
+        CheckerTaskRecordReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for CheckerTaskRecord {
+    type Builder = CheckerTaskRecordBuilder;
+
+    const NAME: &'static str = "CheckerTaskRecord";
+
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        CheckerTaskRecord(data)
+    }
+
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        CheckerTaskRecordReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        CheckerTaskRecordReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .punish_points(self.punish_points())
+            .last_task_height(self.last_task_height())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct CheckerTaskRecordReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for CheckerTaskRecordReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for CheckerTaskRecordReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for CheckerTaskRecordReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "punish_points", self.punish_points())?;
+        write!(f, ", {}: {}", "last_task_height", self.last_task_height())?;
+        write!(f, " }}")
+    }
+}
+impl<'r> CheckerTaskRecordReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
+    pub const FIELD_SIZES: [usize; 2] = [4, 16];
+    pub const TOTAL_SIZE: usize = 20;
+
+    pub fn punish_points(&self) -> Uint32Reader<'r> {
+        Uint32Reader::new_unchecked(&self.as_slice()[0..4])
+    }
+
+    pub fn last_task_height(&self) -> BlockHeightReader<'r> {
+        BlockHeightReader::new_unchecked(&self.as_slice()[4..20])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for CheckerTaskRecordReader<'r> {
+    type Entity = CheckerTaskRecord;
+
+    const NAME: &'static str = "CheckerTaskRecordReader";
+
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        CheckerTaskRecordReader(slice)
+    }
+
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct CheckerTaskRecordBuilder {
+    pub(crate) punish_points:    Uint32,
+    pub(crate) last_task_height: BlockHeight,
+}
+impl CheckerTaskRecordBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub const FIELD_SIZES: [usize; 2] = [4, 16];
+    pub const TOTAL_SIZE: usize = 20;
+
+    pub fn punish_points(mut self, v: Uint32) -> Self {
+        self.punish_points = v;
+        self
+    }
+
+    pub fn last_task_height(mut self, v: BlockHeight) -> Self {
+        self.last_task_height = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for CheckerTaskRecordBuilder {
+    type Entity = CheckerTaskRecord;
+
+    const NAME: &'static str = "CheckerTaskRecordBuilder";
+
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        writer.write_all(self.punish_points.as_slice())?;
+        writer.write_all(self.last_task_height.as_slice())?;
+        Ok(())
+    }
+
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        CheckerTaskRecord::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct SubmitProgress(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for SubmitProgress {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for SubmitProgress {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for SubmitProgress {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "job_hash", self.job_hash())?;
+        write!(f, ", {}: {}", "remaining_tasks", self.remaining_tasks())?;
+        write!(f, ", {}: {}", "remaining_challenges", self.remaining_challenges())?;
+        write!(f, ", {}: {}", "settle_count", self.settle_count())?;
+        write!(f, ", {}: {}", "shutdown_count", self.shutdown_count())?;
+        write!(f, ", {}: {}", "committed_count", self.committed_count())?;
+        write!(f, ", {}: {}", "reveal_count", self.reveal_count())?;
+        write!(f, ", {}: {}", "random_seed", self.random_seed())?;
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for SubmitProgress {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0,
+        ];
+        SubmitProgress::new_unchecked(v.into())
+    }
+}
+impl SubmitProgress {
+    pub const FIELD_COUNT: usize = 8;
+    pub const FIELD_SIZES: [usize; 8] = [32, 4, 4, 4, 4, 4, 4, 32];
+    pub const TOTAL_SIZE: usize = 88;
+
+    pub fn job_hash(&self) -> MerkleHash {
+        MerkleHash::new_unchecked(self.0.slice(0..32))
+    }
+
+    pub fn remaining_tasks(&self) -> Uint32 {
+        Uint32::new_unchecked(self.0.slice(32..36))
+    }
+
+    pub fn remaining_challenges(&self) -> Uint32 {
+        Uint32::new_unchecked(self.0.slice(36..40))
+    }
+
+    pub fn settle_count(&self) -> Uint32 {
+        Uint32::new_unchecked(self.0.slice(40..44))
+    }
+
+    pub fn shutdown_count(&self) -> Uint32 {
+        Uint32::new_unchecked(self.0.slice(44..48))
+    }
+
+    pub fn committed_count(&self) -> Uint32 {
+        Uint32::new_unchecked(self.0.slice(48..52))
+    }
+
+    pub fn reveal_count(&self) -> Uint32 {
+        Uint32::new_unchecked(self.0.slice(52..56))
+    }
+
+    pub fn random_seed(&self) -> RandomSeed {
+        RandomSeed::new_unchecked(self.0.slice(56..88))
+    }
+
+    pub fn as_reader<'r>(&'r self) -> SubmitProgressReader<'r> {
+        SubmitProgressReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for SubmitProgress {
+    type Builder = SubmitProgressBuilder;
+
+    const NAME: &'static str = "SubmitProgress";
+
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        SubmitProgress(data)
+    }
+
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SubmitProgressReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SubmitProgressReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .job_hash(self.job_hash())
+            .remaining_tasks(self.remaining_tasks())
+            .remaining_challenges(self.remaining_challenges())
+            .settle_count(self.settle_count())
+            .shutdown_count(self.shutdown_count())
+            .committed_count(self.committed_count())
+            .reveal_count(self.reveal_count())
+            .random_seed(self.random_seed())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct SubmitProgressReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for SubmitProgressReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for SubmitProgressReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for SubmitProgressReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "job_hash", self.job_hash())?;
+        write!(f, ", {}: {}", "remaining_tasks", self.remaining_tasks())?;
+        write!(f, ", {}: {}", "remaining_challenges", self.remaining_challenges())?;
+        write!(f, ", {}: {}", "settle_count", self.settle_count())?;
+        write!(f, ", {}: {}", "shutdown_count", self.shutdown_count())?;
+        write!(f, ", {}: {}", "committed_count", self.committed_count())?;
+        write!(f, ", {}: {}", "reveal_count", self.reveal_count())?;
+        write!(f, ", {}: {}", "random_seed", self.random_seed())?;
+        write!(f, " }}")
+    }
+}
+impl<'r> SubmitProgressReader<'r> {
+    pub const FIELD_COUNT: usize = 8;
+    pub const FIELD_SIZES: [usize; 8] = [32, 4, 4, 4, 4, 4, 4, 32];
+    pub const TOTAL_SIZE: usize = 88;
+
+    pub fn job_hash(&self) -> MerkleHashReader<'r> {
+        MerkleHashReader::new_unchecked(&self.as_slice()[0..32])
+    }
+
+    pub fn remaining_tasks(&self) -> Uint32Reader<'r> {
+        Uint32Reader::new_unchecked(&self.as_slice()[32..36])
+    }
+
+    pub fn remaining_challenges(&self) -> Uint32Reader<'r> {
+        Uint32Reader::new_unchecked(&self.as_slice()[36..40])
+    }
+
+    pub fn settle_count(&self) -> Uint32Reader<'r> {
+        Uint32Reader::new_unchecked(&self.as_slice()[40..44])
+    }
+
+    pub fn shutdown_count(&self) -> Uint32Reader<'r> {
+        Uint32Reader::new_unchecked(&self.as_slice()[44..48])
+    }
+
+    pub fn committed_count(&self) -> Uint32Reader<'r> {
+        Uint32Reader::new_unchecked(&self.as_slice()[48..52])
+    }
+
+    pub fn reveal_count(&self) -> Uint32Reader<'r> {
+        Uint32Reader::new_unchecked(&self.as_slice()[52..56])
+    }
+
+    pub fn random_seed(&self) -> RandomSeedReader<'r> {
+        RandomSeedReader::new_unchecked(&self.as_slice()[56..88])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for SubmitProgressReader<'r> {
+    type Entity = SubmitProgress;
+
+    const NAME: &'static str = "SubmitProgressReader";
+
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        SubmitProgressReader(slice)
+    }
+
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct SubmitProgressBuilder {
+    pub(crate) job_hash:             MerkleHash,
+    pub(crate) remaining_tasks:      Uint32,
+    pub(crate) remaining_challenges: Uint32,
+    pub(crate) settle_count:         Uint32,
+    pub(crate) shutdown_count:       Uint32,
+    pub(crate) committed_count:      Uint32,
+    pub(crate) reveal_count:         Uint32,
+    pub(crate) random_seed:          RandomSeed,
+}
+impl SubmitProgressBuilder {
+    pub const FIELD_COUNT: usize = 8;
+    pub const FIELD_SIZES: [usize; 8] = [32, 4, 4, 4, 4, 4, 4, 32];
+    pub const TOTAL_SIZE: usize = 88;
+
+    pub fn job_hash(mut self, v: MerkleHash) -> Self {
+        self.job_hash = v;
+        self
+    }
+
+    pub fn remaining_tasks(mut self, v: Uint32) -> Self {
+        self.remaining_tasks = v;
+        self
+    }
+
+    pub fn remaining_challenges(mut self, v: Uint32) -> Self {
+        self.remaining_challenges = v;
+        self
+    }
+
+    pub fn settle_count(mut self, v: Uint32) -> Self {
+        self.settle_count = v;
+        self
+    }
+
+    pub fn shutdown_count(mut self, v: Uint32) -> Self {
+        self.shutdown_count = v;
+        self
+    }
+
+    pub fn committed_count(mut self, v: Uint32) -> Self {
+        self.committed_count = v;
+        self
+    }
+
+    pub fn reveal_count(mut self, v: Uint32) -> Self {
+        self.reveal_count = v;
+        self
+    }
+
+    pub fn random_seed(mut self, v: RandomSeed) -> Self {
+        self.random_seed = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for SubmitProgressBuilder {
+    type Entity = SubmitProgress;
+
+    const NAME: &'static str = "SubmitProgressBuilder";
+
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        writer.write_all(self.job_hash.as_slice())?;
+        writer.write_all(self.remaining_tasks.as_slice())?;
+        writer.write_all(self.remaining_challenges.as_slice())?;
+        writer.write_all(self.settle_count.as_slice())?;
+        writer.write_all(self.shutdown_count.as_slice())?;
+        writer.write_all(self.committed_count.as_slice())?;
+        writer.write_all(self.reveal_count.as_slice())?;
+        writer.write_all(self.random_seed.as_slice())?;
+        Ok(())
+    }
+
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        SubmitProgress::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct SubmitProgressOpt(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for SubmitProgressOpt {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for SubmitProgressOpt {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for SubmitProgressOpt {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        if let Some(v) = self.to_opt() {
+            write!(f, "{}(Some({}))", Self::NAME, v)
+        } else {
+            write!(f, "{}(None)", Self::NAME)
+        }
+    }
+}
+impl ::core::default::Default for SubmitProgressOpt {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![];
+        SubmitProgressOpt::new_unchecked(v.into())
+    }
+}
+impl SubmitProgressOpt {
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn is_some(&self) -> bool {
+        !self.0.is_empty()
+    }
+
+    pub fn to_opt(&self) -> Option<SubmitProgress> {
+        if self.is_none() {
+            None
+        } else {
+            Some(SubmitProgress::new_unchecked(self.0.clone()))
+        }
+    }
+
+    pub fn as_reader<'r>(&'r self) -> SubmitProgressOptReader<'r> {
+        SubmitProgressOptReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for SubmitProgressOpt {
+    type Builder = SubmitProgressOptBuilder;
+
+    const NAME: &'static str = "SubmitProgressOpt";
+
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        SubmitProgressOpt(data)
+    }
+
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SubmitProgressOptReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SubmitProgressOptReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().set(self.to_opt())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct SubmitProgressOptReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for SubmitProgressOptReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for SubmitProgressOptReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for SubmitProgressOptReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        if let Some(v) = self.to_opt() {
+            write!(f, "{}(Some({}))", Self::NAME, v)
+        } else {
+            write!(f, "{}(None)", Self::NAME)
+        }
+    }
+}
+impl<'r> SubmitProgressOptReader<'r> {
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn is_some(&self) -> bool {
+        !self.0.is_empty()
+    }
+
+    pub fn to_opt(&self) -> Option<SubmitProgressReader<'r>> {
+        if self.is_none() {
+            None
+        } else {
+            Some(SubmitProgressReader::new_unchecked(self.as_slice()))
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for SubmitProgressOptReader<'r> {
+    type Entity = SubmitProgressOpt;
+
+    const NAME: &'static str = "SubmitProgressOptReader";
+
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        SubmitProgressOptReader(slice)
+    }
+
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        if !slice.is_empty() {
+            SubmitProgressReader::verify(&slice[..], compatible)?;
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct SubmitProgressOptBuilder(pub(crate) Option<SubmitProgress>);
+impl SubmitProgressOptBuilder {
+    pub fn set(mut self, v: Option<SubmitProgress>) -> Self {
+        self.0 = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for SubmitProgressOptBuilder {
+    type Entity = SubmitProgressOpt;
+
+    const NAME: &'static str = "SubmitProgressOptBuilder";
+
+    fn expected_length(&self) -> usize {
+        self.0.as_ref().map(|ref inner| inner.as_slice().len()).unwrap_or(0)
+    }
+
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        self.0
+            .as_ref()
+            .map(|ref inner| writer.write_all(inner.as_slice()))
+            .unwrap_or(Ok(()))
+    }
+
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        SubmitProgressOpt::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
 pub struct SidechainStateCell(molecule::bytes::Bytes);
 impl ::core::fmt::LowerHex for SidechainStateCell {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
@@ -2100,6 +2768,7 @@ impl ::core::fmt::Display for SidechainStateCell {
         write!(f, ", {}: {}", "waiting_jobs", self.waiting_jobs())?;
         write!(f, ", {}: {}", "random_seed", self.random_seed())?;
         write!(f, ", {}: {}", "random_offset", self.random_offset())?;
+        write!(f, ", {}: {}", "seed_epoch", self.seed_epoch())?;
         write!(f, ", {}: {}", "random_commit", self.random_commit())?;
         write!(f, ", {}: {}", "punish_checkers", self.punish_checkers())?;
         write!(f, ", {}: {}", "recent_block_headers", self.recent_block_headers())?;
@@ -2115,6 +2784,8 @@ impl ::core::fmt::Display for SidechainStateCell {
             "checker_last_task_sidechain_heights",
             self.checker_last_task_sidechain_heights()
         )?;
+        write!(f, ", {}: {}", "checker_task_root", self.checker_task_root())?;
+        write!(f, ", {}: {}", "submit_progress", self.submit_progress())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -2125,16 +2796,18 @@ impl ::core::fmt::Display for SidechainStateCell {
 impl ::core::default::Default for SidechainStateCell {
     fn default() -> Self {
         let v: Vec<u8> = vec![
-            146, 0, 0, 0, 44, 0, 0, 0, 45, 0, 0, 0, 61, 0, 0, 0, 65, 0, 0, 0, 97, 0, 0, 0, 98, 0, 0, 0, 102, 0, 0, 0, 106, 0, 0, 0, 110, 0,
-            0, 0, 142, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            205, 0, 0, 0, 56, 0, 0, 0, 57, 0, 0, 0, 73, 0, 0, 0, 77, 0, 0, 0, 109, 0, 0, 0, 117, 0, 0, 0, 125, 0, 0, 0, 129, 0, 0, 0, 133,
+            0, 0, 0, 137, 0, 0, 0, 169, 0, 0, 0, 173, 0, 0, 0, 205, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0,
         ];
         SidechainStateCell::new_unchecked(v.into())
     }
 }
 impl SidechainStateCell {
-    pub const FIELD_COUNT: usize = 10;
+    pub const FIELD_COUNT: usize = 13;
 
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
@@ -2184,49 +2857,70 @@ impl SidechainStateCell {
         RandomSeed::new_unchecked(self.0.slice(start..end))
     }
 
-    pub fn random_offset(&self) -> Uint8 {
+    pub fn random_offset(&self) -> Uint64 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[20..]) as usize;
         let end = molecule::unpack_number(&slice[24..]) as usize;
-        Uint8::new_unchecked(self.0.slice(start..end))
+        Uint64::new_unchecked(self.0.slice(start..end))
     }
 
-    pub fn random_commit(&self) -> CommittedCheckerInfos {
+    pub fn seed_epoch(&self) -> Uint64 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[24..]) as usize;
         let end = molecule::unpack_number(&slice[28..]) as usize;
-        CommittedCheckerInfos::new_unchecked(self.0.slice(start..end))
+        Uint64::new_unchecked(self.0.slice(start..end))
     }
 
-    pub fn punish_checkers(&self) -> PunishedCheckers {
+    pub fn random_commit(&self) -> CommittedCheckerInfos {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[28..]) as usize;
         let end = molecule::unpack_number(&slice[32..]) as usize;
-        PunishedCheckers::new_unchecked(self.0.slice(start..end))
+        CommittedCheckerInfos::new_unchecked(self.0.slice(start..end))
     }
 
-    pub fn recent_block_headers(&self) -> BlockHeaders {
+    pub fn punish_checkers(&self) -> PunishedCheckers {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[32..]) as usize;
         let end = molecule::unpack_number(&slice[36..]) as usize;
-        BlockHeaders::new_unchecked(self.0.slice(start..end))
+        PunishedCheckers::new_unchecked(self.0.slice(start..end))
     }
 
-    pub fn ancient_block_heard_merkle_root(&self) -> MerkleHash {
+    pub fn recent_block_headers(&self) -> BlockHeaders {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[36..]) as usize;
         let end = molecule::unpack_number(&slice[40..]) as usize;
+        BlockHeaders::new_unchecked(self.0.slice(start..end))
+    }
+
+    pub fn ancient_block_heard_merkle_root(&self) -> MerkleHash {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[40..]) as usize;
+        let end = molecule::unpack_number(&slice[44..]) as usize;
         MerkleHash::new_unchecked(self.0.slice(start..end))
     }
 
     pub fn checker_last_task_sidechain_heights(&self) -> CheckerLastAcceptTaskHeights {
         let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[40..]) as usize;
+        let start = molecule::unpack_number(&slice[44..]) as usize;
+        let end = molecule::unpack_number(&slice[48..]) as usize;
+        CheckerLastAcceptTaskHeights::new_unchecked(self.0.slice(start..end))
+    }
+
+    pub fn checker_task_root(&self) -> MerkleHash {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[48..]) as usize;
+        let end = molecule::unpack_number(&slice[52..]) as usize;
+        MerkleHash::new_unchecked(self.0.slice(start..end))
+    }
+
+    pub fn submit_progress(&self) -> SubmitProgressOpt {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[52..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[44..]) as usize;
-            CheckerLastAcceptTaskHeights::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[56..]) as usize;
+            SubmitProgressOpt::new_unchecked(self.0.slice(start..end))
         } else {
-            CheckerLastAcceptTaskHeights::new_unchecked(self.0.slice(start..))
+            SubmitProgressOpt::new_unchecked(self.0.slice(start..))
         }
     }
 
@@ -2270,11 +2964,14 @@ impl molecule::prelude::Entity for SidechainStateCell {
             .waiting_jobs(self.waiting_jobs())
             .random_seed(self.random_seed())
             .random_offset(self.random_offset())
+            .seed_epoch(self.seed_epoch())
             .random_commit(self.random_commit())
             .punish_checkers(self.punish_checkers())
             .recent_block_headers(self.recent_block_headers())
             .ancient_block_heard_merkle_root(self.ancient_block_heard_merkle_root())
             .checker_last_task_sidechain_heights(self.checker_last_task_sidechain_heights())
+            .checker_task_root(self.checker_task_root())
+            .submit_progress(self.submit_progress())
     }
 }
 #[derive(Clone, Copy)]
@@ -2301,6 +2998,7 @@ impl<'r> ::core::fmt::Display for SidechainStateCellReader<'r> {
         write!(f, ", {}: {}", "waiting_jobs", self.waiting_jobs())?;
         write!(f, ", {}: {}", "random_seed", self.random_seed())?;
         write!(f, ", {}: {}", "random_offset", self.random_offset())?;
+        write!(f, ", {}: {}", "seed_epoch", self.seed_epoch())?;
         write!(f, ", {}: {}", "random_commit", self.random_commit())?;
         write!(f, ", {}: {}", "punish_checkers", self.punish_checkers())?;
         write!(f, ", {}: {}", "recent_block_headers", self.recent_block_headers())?;
@@ -2316,6 +3014,8 @@ impl<'r> ::core::fmt::Display for SidechainStateCellReader<'r> {
             "checker_last_task_sidechain_heights",
             self.checker_last_task_sidechain_heights()
         )?;
+        write!(f, ", {}: {}", "checker_task_root", self.checker_task_root())?;
+        write!(f, ", {}: {}", "submit_progress", self.submit_progress())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -2324,7 +3024,7 @@ impl<'r> ::core::fmt::Display for SidechainStateCellReader<'r> {
     }
 }
 impl<'r> SidechainStateCellReader<'r> {
-    pub const FIELD_COUNT: usize = 10;
+    pub const FIELD_COUNT: usize = 13;
 
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
@@ -2374,49 +3074,70 @@ impl<'r> SidechainStateCellReader<'r> {
         RandomSeedReader::new_unchecked(&self.as_slice()[start..end])
     }
 
-    pub fn random_offset(&self) -> Uint8Reader<'r> {
+    pub fn random_offset(&self) -> Uint64Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[20..]) as usize;
         let end = molecule::unpack_number(&slice[24..]) as usize;
-        Uint8Reader::new_unchecked(&self.as_slice()[start..end])
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
     }
 
-    pub fn random_commit(&self) -> CommittedCheckerInfosReader<'r> {
+    pub fn seed_epoch(&self) -> Uint64Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[24..]) as usize;
         let end = molecule::unpack_number(&slice[28..]) as usize;
-        CommittedCheckerInfosReader::new_unchecked(&self.as_slice()[start..end])
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
     }
 
-    pub fn punish_checkers(&self) -> PunishedCheckersReader<'r> {
+    pub fn random_commit(&self) -> CommittedCheckerInfosReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[28..]) as usize;
         let end = molecule::unpack_number(&slice[32..]) as usize;
-        PunishedCheckersReader::new_unchecked(&self.as_slice()[start..end])
+        CommittedCheckerInfosReader::new_unchecked(&self.as_slice()[start..end])
     }
 
-    pub fn recent_block_headers(&self) -> BlockHeadersReader<'r> {
+    pub fn punish_checkers(&self) -> PunishedCheckersReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[32..]) as usize;
         let end = molecule::unpack_number(&slice[36..]) as usize;
-        BlockHeadersReader::new_unchecked(&self.as_slice()[start..end])
+        PunishedCheckersReader::new_unchecked(&self.as_slice()[start..end])
     }
 
-    pub fn ancient_block_heard_merkle_root(&self) -> MerkleHashReader<'r> {
+    pub fn recent_block_headers(&self) -> BlockHeadersReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[36..]) as usize;
         let end = molecule::unpack_number(&slice[40..]) as usize;
+        BlockHeadersReader::new_unchecked(&self.as_slice()[start..end])
+    }
+
+    pub fn ancient_block_heard_merkle_root(&self) -> MerkleHashReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[40..]) as usize;
+        let end = molecule::unpack_number(&slice[44..]) as usize;
         MerkleHashReader::new_unchecked(&self.as_slice()[start..end])
     }
 
     pub fn checker_last_task_sidechain_heights(&self) -> CheckerLastAcceptTaskHeightsReader<'r> {
         let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[40..]) as usize;
+        let start = molecule::unpack_number(&slice[44..]) as usize;
+        let end = molecule::unpack_number(&slice[48..]) as usize;
+        CheckerLastAcceptTaskHeightsReader::new_unchecked(&self.as_slice()[start..end])
+    }
+
+    pub fn checker_task_root(&self) -> MerkleHashReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[48..]) as usize;
+        let end = molecule::unpack_number(&slice[52..]) as usize;
+        MerkleHashReader::new_unchecked(&self.as_slice()[start..end])
+    }
+
+    pub fn submit_progress(&self) -> SubmitProgressOptReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[52..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[44..]) as usize;
-            CheckerLastAcceptTaskHeightsReader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[56..]) as usize;
+            SubmitProgressOptReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            CheckerLastAcceptTaskHeightsReader::new_unchecked(&self.as_slice()[start..])
+            SubmitProgressOptReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
@@ -2478,12 +3199,15 @@ impl<'r> molecule::prelude::Reader<'r> for SidechainStateCellReader<'r> {
         BlockHeightReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
         JobsReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
         RandomSeedReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
-        Uint8Reader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
-        CommittedCheckerInfosReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
-        PunishedCheckersReader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
-        BlockHeadersReader::verify(&slice[offsets[7]..offsets[8]], compatible)?;
-        MerkleHashReader::verify(&slice[offsets[8]..offsets[9]], compatible)?;
-        CheckerLastAcceptTaskHeightsReader::verify(&slice[offsets[9]..offsets[10]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
+        CommittedCheckerInfosReader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
+        PunishedCheckersReader::verify(&slice[offsets[7]..offsets[8]], compatible)?;
+        BlockHeadersReader::verify(&slice[offsets[8]..offsets[9]], compatible)?;
+        MerkleHashReader::verify(&slice[offsets[9]..offsets[10]], compatible)?;
+        CheckerLastAcceptTaskHeightsReader::verify(&slice[offsets[10]..offsets[11]], compatible)?;
+        MerkleHashReader::verify(&slice[offsets[11]..offsets[12]], compatible)?;
+        SubmitProgressOptReader::verify(&slice[offsets[12]..offsets[13]], compatible)?;
         Ok(())
     }
 }
@@ -2493,15 +3217,18 @@ pub struct SidechainStateCellBuilder {
     pub(crate) submit_sidechain_block_height: BlockHeight,
     pub(crate) waiting_jobs: Jobs,
     pub(crate) random_seed: RandomSeed,
-    pub(crate) random_offset: Uint8,
+    pub(crate) random_offset: Uint64,
+    pub(crate) seed_epoch: Uint64,
     pub(crate) random_commit: CommittedCheckerInfos,
     pub(crate) punish_checkers: PunishedCheckers,
     pub(crate) recent_block_headers: BlockHeaders,
     pub(crate) ancient_block_heard_merkle_root: MerkleHash,
     pub(crate) checker_last_task_sidechain_heights: CheckerLastAcceptTaskHeights,
+    pub(crate) checker_task_root: MerkleHash,
+    pub(crate) submit_progress: SubmitProgressOpt,
 }
 impl SidechainStateCellBuilder {
-    pub const FIELD_COUNT: usize = 10;
+    pub const FIELD_COUNT: usize = 13;
 
     pub fn version(mut self, v: Uint8) -> Self {
         self.version = v;
@@ -2523,11 +3250,16 @@ impl SidechainStateCellBuilder {
         self
     }
 
-    pub fn random_offset(mut self, v: Uint8) -> Self {
+    pub fn random_offset(mut self, v: Uint64) -> Self {
         self.random_offset = v;
         self
     }
 
+    pub fn seed_epoch(mut self, v: Uint64) -> Self {
+        self.seed_epoch = v;
+        self
+    }
+
     pub fn random_commit(mut self, v: CommittedCheckerInfos) -> Self {
         self.random_commit = v;
         self
@@ -2552,6 +3284,16 @@ impl SidechainStateCellBuilder {
         self.checker_last_task_sidechain_heights = v;
         self
     }
+
+    pub fn checker_task_root(mut self, v: MerkleHash) -> Self {
+        self.checker_task_root = v;
+        self
+    }
+
+    pub fn submit_progress(mut self, v: SubmitProgressOpt) -> Self {
+        self.submit_progress = v;
+        self
+    }
 }
 impl molecule::prelude::Builder for SidechainStateCellBuilder {
     type Entity = SidechainStateCell;
@@ -2565,11 +3307,14 @@ impl molecule::prelude::Builder for SidechainStateCellBuilder {
             + self.waiting_jobs.as_slice().len()
             + self.random_seed.as_slice().len()
             + self.random_offset.as_slice().len()
+            + self.seed_epoch.as_slice().len()
             + self.random_commit.as_slice().len()
             + self.punish_checkers.as_slice().len()
             + self.recent_block_headers.as_slice().len()
             + self.ancient_block_heard_merkle_root.as_slice().len()
             + self.checker_last_task_sidechain_heights.as_slice().len()
+            + self.checker_task_root.as_slice().len()
+            + self.submit_progress.as_slice().len()
     }
 
     fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
@@ -2586,6 +3331,8 @@ impl molecule::prelude::Builder for SidechainStateCellBuilder {
         offsets.push(total_size);
         total_size += self.random_offset.as_slice().len();
         offsets.push(total_size);
+        total_size += self.seed_epoch.as_slice().len();
+        offsets.push(total_size);
         total_size += self.random_commit.as_slice().len();
         offsets.push(total_size);
         total_size += self.punish_checkers.as_slice().len();
@@ -2595,6 +3342,10 @@ impl molecule::prelude::Builder for SidechainStateCellBuilder {
         total_size += self.ancient_block_heard_merkle_root.as_slice().len();
         offsets.push(total_size);
         total_size += self.checker_last_task_sidechain_heights.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.checker_task_root.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.submit_progress.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
@@ -2604,11 +3355,14 @@ impl molecule::prelude::Builder for SidechainStateCellBuilder {
         writer.write_all(self.waiting_jobs.as_slice())?;
         writer.write_all(self.random_seed.as_slice())?;
         writer.write_all(self.random_offset.as_slice())?;
+        writer.write_all(self.seed_epoch.as_slice())?;
         writer.write_all(self.random_commit.as_slice())?;
         writer.write_all(self.punish_checkers.as_slice())?;
         writer.write_all(self.recent_block_headers.as_slice())?;
         writer.write_all(self.ancient_block_heard_merkle_root.as_slice())?;
         writer.write_all(self.checker_last_task_sidechain_heights.as_slice())?;
+        writer.write_all(self.checker_task_root.as_slice())?;
+        writer.write_all(self.submit_progress.as_slice())?;
         Ok(())
     }
 
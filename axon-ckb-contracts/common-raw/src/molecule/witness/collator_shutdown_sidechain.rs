@@ -23,19 +23,20 @@ impl ::core::fmt::Display for CollatorShutDownSidechainWitness {
         write!(f, "{} {{ ", Self::NAME)?;
         write!(f, "{}: {}", "pattern", self.pattern())?;
         write!(f, ", {}: {}", "chain_id", self.chain_id())?;
+        write!(f, ", {}: {}", "approval_count", self.approval_count())?;
         write!(f, " }}")
     }
 }
 impl ::core::default::Default for CollatorShutDownSidechainWitness {
     fn default() -> Self {
-        let v: Vec<u8> = vec![0, 0, 0, 0, 0];
+        let v: Vec<u8> = vec![0, 0, 0, 0, 0, 0];
         CollatorShutDownSidechainWitness::new_unchecked(v.into())
     }
 }
 impl CollatorShutDownSidechainWitness {
-    pub const FIELD_COUNT: usize = 2;
-    pub const FIELD_SIZES: [usize; 2] = [1, 4];
-    pub const TOTAL_SIZE: usize = 5;
+    pub const FIELD_COUNT: usize = 3;
+    pub const FIELD_SIZES: [usize; 3] = [1, 4, 1];
+    pub const TOTAL_SIZE: usize = 6;
 
     pub fn pattern(&self) -> Uint8 {
         Uint8::new_unchecked(self.0.slice(0..1))
@@ -45,6 +46,10 @@ impl CollatorShutDownSidechainWitness {
         ChainId::new_unchecked(self.0.slice(1..5))
     }
 
+    pub fn approval_count(&self) -> Uint8 {
+        Uint8::new_unchecked(self.0.slice(5..6))
+    }
+
     pub fn as_reader<'r>(&'r self) -> CollatorShutDownSidechainWitnessReader<'r> {
         CollatorShutDownSidechainWitnessReader::new_unchecked(self.as_slice())
     }
@@ -79,7 +84,10 @@ impl molecule::prelude::Entity for CollatorShutDownSidechainWitness {
     }
 
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder().pattern(self.pattern()).chain_id(self.chain_id())
+        Self::new_builder()
+            .pattern(self.pattern())
+            .chain_id(self.chain_id())
+            .approval_count(self.approval_count())
     }
 }
 #[derive(Clone, Copy)]
@@ -103,13 +111,14 @@ impl<'r> ::core::fmt::Display for CollatorShutDownSidechainWitnessReader<'r> {
         write!(f, "{} {{ ", Self::NAME)?;
         write!(f, "{}: {}", "pattern", self.pattern())?;
         write!(f, ", {}: {}", "chain_id", self.chain_id())?;
+        write!(f, ", {}: {}", "approval_count", self.approval_count())?;
         write!(f, " }}")
     }
 }
 impl<'r> CollatorShutDownSidechainWitnessReader<'r> {
-    pub const FIELD_COUNT: usize = 2;
-    pub const FIELD_SIZES: [usize; 2] = [1, 4];
-    pub const TOTAL_SIZE: usize = 5;
+    pub const FIELD_COUNT: usize = 3;
+    pub const FIELD_SIZES: [usize; 3] = [1, 4, 1];
+    pub const TOTAL_SIZE: usize = 6;
 
     pub fn pattern(&self) -> Uint8Reader<'r> {
         Uint8Reader::new_unchecked(&self.as_slice()[0..1])
@@ -118,6 +127,10 @@ impl<'r> CollatorShutDownSidechainWitnessReader<'r> {
     pub fn chain_id(&self) -> ChainIdReader<'r> {
         ChainIdReader::new_unchecked(&self.as_slice()[1..5])
     }
+
+    pub fn approval_count(&self) -> Uint8Reader<'r> {
+        Uint8Reader::new_unchecked(&self.as_slice()[5..6])
+    }
 }
 impl<'r> molecule::prelude::Reader<'r> for CollatorShutDownSidechainWitnessReader<'r> {
     type Entity = CollatorShutDownSidechainWitness;
@@ -147,13 +160,14 @@ impl<'r> molecule::prelude::Reader<'r> for CollatorShutDownSidechainWitnessReade
 }
 #[derive(Debug, Default)]
 pub struct CollatorShutDownSidechainWitnessBuilder {
-    pub(crate) pattern:  Uint8,
-    pub(crate) chain_id: ChainId,
+    pub(crate) pattern:        Uint8,
+    pub(crate) chain_id:       ChainId,
+    pub(crate) approval_count: Uint8,
 }
 impl CollatorShutDownSidechainWitnessBuilder {
-    pub const FIELD_COUNT: usize = 2;
-    pub const FIELD_SIZES: [usize; 2] = [1, 4];
-    pub const TOTAL_SIZE: usize = 5;
+    pub const FIELD_COUNT: usize = 3;
+    pub const FIELD_SIZES: [usize; 3] = [1, 4, 1];
+    pub const TOTAL_SIZE: usize = 6;
 
     pub fn pattern(mut self, v: Uint8) -> Self {
         self.pattern = v;
@@ -164,6 +178,11 @@ impl CollatorShutDownSidechainWitnessBuilder {
         self.chain_id = v;
         self
     }
+
+    pub fn approval_count(mut self, v: Uint8) -> Self {
+        self.approval_count = v;
+        self
+    }
 }
 impl molecule::prelude::Builder for CollatorShutDownSidechainWitnessBuilder {
     type Entity = CollatorShutDownSidechainWitness;
@@ -177,6 +196,7 @@ impl molecule::prelude::Builder for CollatorShutDownSidechainWitnessBuilder {
     fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
         writer.write_all(self.pattern.as_slice())?;
         writer.write_all(self.chain_id.as_slice())?;
+        writer.write_all(self.approval_count.as_slice())?;
         Ok(())
     }
 
@@ -1,3 +1,5 @@
 pub mod anyone_shutdown_sidechain;
+pub mod batch;
+pub mod checker_proofs;
 pub mod collator_shutdown_sidechain;
 pub mod collator_submit_tasks;
@@ -1,10 +1,11 @@
-use crate::{FromRaw, Serialize};
+use crate::{DecodeError, FromRaw, Serialize};
 use core::convert::TryFrom;
 
 const PATTERN_LEN: usize = 1;
 
 #[repr(u8)]
 #[derive(Clone, Copy, Debug, PartialOrd, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Pattern {
     AdminCreateSidechain = 0u8,
 
@@ -21,6 +22,17 @@ pub enum Pattern {
     CollatorUnlockBond,
     AnyoneShutdownSidechain,
     CollatorShutdownSidechain,
+
+    Batch = 13u8,
+
+    CheckerProposeChange = 14u8,
+    CheckerVoteProposal,
+    ExecuteProposal,
+
+    CollatorHandover = 17u8,
+
+    DelegatorDelegate = 18u8,
+    DelegatorClaim,
 }
 
 impl TryFrom<u8> for Pattern {
@@ -43,14 +55,26 @@ impl TryFrom<u8> for Pattern {
             10u8 => Self::CollatorUnlockBond,
             11u8 => Self::AnyoneShutdownSidechain,
             12u8 => Self::CollatorShutdownSidechain,
+
+            13u8 => Self::Batch,
+
+            14u8 => Self::CheckerProposeChange,
+            15u8 => Self::CheckerVoteProposal,
+            16u8 => Self::ExecuteProposal,
+
+            17u8 => Self::CollatorHandover,
+
+            18u8 => Self::DelegatorDelegate,
+            19u8 => Self::DelegatorClaim,
             _ => return Err(()),
         });
     }
 }
 
 impl FromRaw for Pattern {
-    fn from_raw(raw: &[u8]) -> Option<Self> {
-        Pattern::try_from(u8::from_raw(raw)?).ok()
+    fn from_raw(raw: &[u8]) -> Result<Self, DecodeError> {
+        let pattern = u8::from_raw(raw)?;
+        Pattern::try_from(pattern).or(Err(DecodeError::unknown_variant(pattern)))
     }
 }
 
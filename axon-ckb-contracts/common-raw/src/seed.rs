@@ -0,0 +1,36 @@
+use molecule::prelude::*;
+
+use crate::{
+    common::{BlockSlice, ChainId, RandomSeed},
+    Hasher, Serialize,
+};
+
+/*
+Checkers are drawn from the random seed of the sidechain state, which only moves when a job is
+submitted. Every draw derives its own seed from it instead, bound to the sidechain, the pattern
+and the job it draws for, and to random_offset, which counts the draws made from the state seed so
+far. Draws of different sidechains, patterns or jobs never share a seed, nor do two draws of the same job.
+ */
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(u8)]
+pub enum SeedPurpose {
+    PublishTask = 1,
+    PublishChallenge = 2,
+    RefreshTask = 3,
+    SignedJob = 4,
+}
+
+const SEED_DOMAIN: &[u8] = b"axon checker seed";
+
+pub fn derive_seed<H: Hasher>(seed: &RandomSeed, offset: u64, chain_id: ChainId, purpose: SeedPurpose, job: &BlockSlice) -> RandomSeed {
+    let mut data = Vec::with_capacity(SEED_DOMAIN.len() + 32 + 8 + 4 + 1 + 16 + 16);
+    data.extend_from_slice(SEED_DOMAIN);
+    data.extend_from_slice(seed);
+    data.extend_from_slice(&offset.serialize());
+    data.extend_from_slice(&chain_id.serialize());
+    data.push(purpose as u8);
+    data.extend_from_slice(&job.from.serialize());
+    data.extend_from_slice(&job.to.serialize());
+
+    H::hash(&data)
+}
@@ -0,0 +1,116 @@
+/*
+serde helpers printing hashes, lock args and raw bytes as 0x prefixed hex, used through
+#[serde(with = "crate::serde_hex")] on the field, or serde_hex::option and serde_hex::vec for wrapped ones
+ */
+use alloc::{string::String, vec::Vec};
+use core::fmt::Write;
+
+use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+pub trait HexBytes: Sized {
+    fn bytes(&self) -> &[u8];
+
+    fn from_bytes(bytes: Vec<u8>) -> Option<Self>;
+}
+
+macro_rules! HexFixedBytes {
+    ($size: expr) => {
+        impl HexBytes for [u8; $size] {
+            fn bytes(&self) -> &[u8] {
+                self
+            }
+
+            fn from_bytes(bytes: Vec<u8>) -> Option<Self> {
+                if bytes.len() != $size {
+                    return None;
+                }
+
+                let mut buf = [0u8; $size];
+                buf.copy_from_slice(&bytes);
+                Some(buf)
+            }
+        }
+    };
+}
+
+HexFixedBytes!(20);
+HexFixedBytes!(32);
+HexFixedBytes!(65);
+
+impl HexBytes for Vec<u8> {
+    fn bytes(&self) -> &[u8] {
+        self
+    }
+
+    fn from_bytes(bytes: Vec<u8>) -> Option<Self> {
+        Some(bytes)
+    }
+}
+
+fn encode(bytes: &[u8]) -> String {
+    let mut hex = String::with_capacity(2 + bytes.len() * 2);
+    hex.push_str("0x");
+    for byte in bytes {
+        write!(hex, "{:02x}", byte).expect("write to string");
+    }
+    hex
+}
+
+fn decode<T: HexBytes, E: Error>(hex: &str) -> Result<T, E> {
+    let hex = hex.strip_prefix("0x").ok_or_else(|| E::custom("hex without 0x prefix"))?;
+    if hex.len() % 2 != 0 {
+        return Err(E::custom("odd length hex"));
+    }
+
+    let bytes = (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16))
+        .collect::<Result<Vec<u8>, _>>()
+        .map_err(|_| E::custom("invalid hex"))?;
+
+    T::from_bytes(bytes).ok_or_else(|| E::custom("wrong length of hex bytes"))
+}
+
+pub fn serialize<T: HexBytes, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&encode(value.bytes()))
+}
+
+pub fn deserialize<'de, T: HexBytes, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+    decode(&String::deserialize(deserializer)?)
+}
+
+pub mod option {
+    use super::*;
+
+    pub fn serialize<T: HexBytes, S: Serializer>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(value) => serializer.serialize_some(&encode(value.bytes())),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, T: HexBytes, D: Deserializer<'de>>(deserializer: D) -> Result<Option<T>, D::Error> {
+        match Option::<String>::deserialize(deserializer)? {
+            Some(hex) => decode(&hex).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+pub mod vec {
+    use serde::ser::SerializeSeq;
+
+    use super::*;
+
+    pub fn serialize<T: HexBytes, S: Serializer>(values: &[T], serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(values.len()))?;
+        for value in values {
+            seq.serialize_element(&encode(value.bytes()))?;
+        }
+        seq.end()
+    }
+
+    pub fn deserialize<'de, T: HexBytes, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<T>, D::Error> {
+        Vec::<String>::deserialize(deserializer)?.iter().map(|hex| decode(hex)).collect()
+    }
+}
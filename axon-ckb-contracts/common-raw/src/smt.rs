@@ -0,0 +1,195 @@
+use molecule::prelude::*;
+
+use crate::{
+    common::{Identity, MerkleHash},
+    Hasher, Serialize,
+};
+
+/*
+A sparse merkle sum tree over checker identities, a sidechain could commit to its checkers by the
+root of such a tree instead of listing them, see SidechainConfigCell::checker_set_root.
+
+Leaves are keyed by the 160 bits of the identity, from the most significant bit at the root down
+to the least significant one at the leaf. Every node carries the sum of the leaves below it and its
+hash covers the hashes and the sums of both children, thus the proof of a leaf proves the total
+weight of the tree as well as the weight of the leaves left of it, the range of the leaf in the
+drawing of checkers.
+ */
+pub const SMT_HEIGHT: usize = 160;
+
+const LEAF_DOMAIN: u8 = 0;
+const BRANCH_DOMAIN: u8 = 1;
+
+// the default node is an empty subtree, it is neither hashed nor weighted
+#[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Ord, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SmtNode {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
+    pub hash: MerkleHash,
+    pub sum:  u128,
+}
+
+impl SmtNode {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    pub fn leaf<H: Hasher>(key: &Identity, value: &[u8], sum: u128) -> Self {
+        let mut data = Vec::with_capacity(1 + key.len() + value.len());
+        data.push(LEAF_DOMAIN);
+        data.extend_from_slice(key);
+        data.extend_from_slice(value);
+
+        Self { hash: H::hash(&data), sum }
+    }
+
+    // None on overflow of the sum
+    pub fn merge<H: Hasher>(left: &Self, right: &Self) -> Option<Self> {
+        if left.is_empty() && right.is_empty() {
+            return Some(Self::default());
+        }
+
+        let sum = left.sum.checked_add(right.sum)?;
+
+        let mut data = [0u8; 97];
+        data[0] = BRANCH_DOMAIN;
+        data[1..33].copy_from_slice(&left.hash);
+        data[33..49].copy_from_slice(&left.sum.serialize());
+        data[49..81].copy_from_slice(&right.hash);
+        data[81..97].copy_from_slice(&right.sum.serialize());
+
+        Some(Self { hash: H::hash(&data), sum })
+    }
+}
+
+/*
+Siblings of a leaf from the bottom up, the empty ones left out,
+bit h of bitmap (least significant bit of byte 0 first) tells whether the sibling at height h is given
+ */
+#[derive(Debug, Clone, PartialOrd, PartialEq, Ord, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SmtProof {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
+    pub bitmap:   [u8; 20],
+    pub siblings: Vec<SmtNode>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct SmtPath {
+    pub root:   SmtNode,
+    // the sum of the leaves left of the proven one
+    pub offset: u128,
+}
+
+impl SmtProof {
+    // None if the proof is malformed or a sum overflows
+    pub fn compute<H: Hasher>(&self, key: &Identity, leaf: &SmtNode) -> Option<SmtPath> {
+        let mut siblings = self.siblings.iter();
+        let mut node = *leaf;
+        let mut offset = 0u128;
+
+        for height in 0..SMT_HEIGHT {
+            let sibling = if self.bitmap[height / 8] >> (height % 8) & 1 == 1 {
+                *siblings.next()?
+            } else {
+                SmtNode::default()
+            };
+
+            if key_bit(key, SMT_HEIGHT - 1 - height) {
+                offset = offset.checked_add(sibling.sum)?;
+                node = SmtNode::merge::<H>(&sibling, &node)?;
+            } else {
+                node = SmtNode::merge::<H>(&node, &sibling)?;
+            }
+        }
+
+        if siblings.next().is_some() {
+            return None;
+        }
+
+        Some(SmtPath { root: node, offset })
+    }
+}
+
+fn key_bit(key: &Identity, depth: usize) -> bool {
+    key[depth / 8] >> (7 - depth % 8) & 1 == 1
+}
+
+/*
+The whole tree, kept off chain by whoever builds the transactions to prove the leaves the
+patterns read and update. Contracts only ever see the root and the proofs.
+ */
+#[derive(Debug, Clone, Default)]
+pub struct SparseMerkleSumTree {
+    // sorted by key, without empty leaves
+    leaves: Vec<(Identity, SmtNode)>,
+}
+
+impl SparseMerkleSumTree {
+    pub fn get(&self, key: &Identity) -> SmtNode {
+        match self.leaves.binary_search_by(|(leaf_key, _)| leaf_key.cmp(key)) {
+            Ok(index) => self.leaves[index].1,
+            Err(_) => SmtNode::default(),
+        }
+    }
+
+    // an empty leaf removes the key
+    pub fn update(&mut self, key: Identity, leaf: SmtNode) {
+        match self.leaves.binary_search_by(|(leaf_key, _)| leaf_key.cmp(&key)) {
+            Ok(index) if leaf.is_empty() => {
+                self.leaves.remove(index);
+            }
+            Ok(index) => self.leaves[index].1 = leaf,
+            Err(_) if leaf.is_empty() => {}
+            Err(index) => self.leaves.insert(index, (key, leaf)),
+        }
+    }
+
+    pub fn root<H: Hasher>(&self) -> Option<SmtNode> {
+        subtree::<H>(&self.leaves, 0)
+    }
+
+    pub fn proof<H: Hasher>(&self, key: &Identity) -> Option<SmtProof> {
+        let mut leaves = &self.leaves[..];
+        let mut siblings = Vec::with_capacity(SMT_HEIGHT);
+
+        for depth in 0..SMT_HEIGHT {
+            let (left, right) = split(leaves, depth);
+            let (own, other) = if key_bit(key, depth) { (right, left) } else { (left, right) };
+
+            siblings.push(subtree::<H>(other, depth + 1)?);
+            leaves = own;
+        }
+
+        let mut proof = SmtProof::default();
+        for (depth, sibling) in siblings.iter().enumerate().rev() {
+            if sibling.is_empty() {
+                continue;
+            }
+
+            let height = SMT_HEIGHT - 1 - depth;
+            proof.bitmap[height / 8] |= 1 << (height % 8);
+            proof.siblings.push(*sibling);
+        }
+
+        Some(proof)
+    }
+}
+
+// leaves sharing the first depth bits, split by the next one
+fn split(leaves: &[(Identity, SmtNode)], depth: usize) -> (&[(Identity, SmtNode)], &[(Identity, SmtNode)]) {
+    let index = leaves.iter().position(|(key, _)| key_bit(key, depth)).unwrap_or(leaves.len());
+    leaves.split_at(index)
+}
+
+fn subtree<H: Hasher>(leaves: &[(Identity, SmtNode)], depth: usize) -> Option<SmtNode> {
+    if leaves.is_empty() {
+        return Some(SmtNode::default());
+    }
+    if depth == SMT_HEIGHT {
+        return Some(leaves[0].1);
+    }
+
+    let (left, right) = split(leaves, depth);
+    SmtNode::merge::<H>(&subtree::<H>(left, depth + 1)?, &subtree::<H>(right, depth + 1)?)
+}
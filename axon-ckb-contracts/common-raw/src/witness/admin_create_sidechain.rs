@@ -1,20 +1,43 @@
-use crate::{common::ChainId, pattern::Pattern, FromRaw};
+use crate::{common::ChainId, pattern::Pattern, DecodeError, DecodeField, FromRaw, Serialize};
 const ADMIN_CREATE_SIDECHAIN_WITNESS_LENGTH: usize = 5;
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AdminCreateSidechainWitness {
     pattern:      Pattern,
     pub chain_id: ChainId,
 }
 
+impl Default for AdminCreateSidechainWitness {
+    fn default() -> Self {
+        Self {
+            pattern:  Pattern::AdminCreateSidechain,
+            chain_id: ChainId::default(),
+        }
+    }
+}
+
 impl FromRaw for AdminCreateSidechainWitness {
-    fn from_raw(witness_raw_data: &[u8]) -> Option<AdminCreateSidechainWitness> {
+    fn from_raw(witness_raw_data: &[u8]) -> Result<AdminCreateSidechainWitness, DecodeError> {
         if witness_raw_data.len() != ADMIN_CREATE_SIDECHAIN_WITNESS_LENGTH {
-            return None;
+            return Err(DecodeError::length(ADMIN_CREATE_SIDECHAIN_WITNESS_LENGTH, witness_raw_data.len()));
         }
 
-        let pattern = Pattern::from_raw(&witness_raw_data[0..1])?;
-        let chain_id = ChainId::from_raw(&witness_raw_data[1..5])?;
+        let pattern = Pattern::from_raw(&witness_raw_data[0..1]).field("pattern")?;
+        let chain_id = ChainId::from_raw(&witness_raw_data[1..5]).field("chain_id")?;
+
+        Ok(AdminCreateSidechainWitness { pattern, chain_id })
+    }
+}
+
+impl Serialize for AdminCreateSidechainWitness {
+    type RawType = [u8; ADMIN_CREATE_SIDECHAIN_WITNESS_LENGTH];
+
+    fn serialize(&self) -> Self::RawType {
+        let mut buf = [0u8; ADMIN_CREATE_SIDECHAIN_WITNESS_LENGTH];
+
+        buf[0..1].copy_from_slice(&self.pattern.serialize());
+        buf[1..5].copy_from_slice(&self.chain_id.serialize());
 
-        Some(AdminCreateSidechainWitness { pattern, chain_id })
+        buf
     }
 }
@@ -1,10 +1,11 @@
 use core::default::Default;
 
-use crate::{common::ChainId, pattern::Pattern, FromRaw, Serialize};
+use crate::{common::ChainId, pattern::Pattern, DecodeError, DecodeField, FromRaw, Serialize};
 
 const COLLATOR_REFRESH_TASK_WITNESS_LEN: usize = 5;
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AnyoneRefreshTaskWitness {
     pattern:      Pattern,
     pub chain_id: ChainId,
@@ -20,14 +21,14 @@ impl Default for AnyoneRefreshTaskWitness {
 }
 
 impl FromRaw for AnyoneRefreshTaskWitness {
-    fn from_raw(witness_raw_data: &[u8]) -> Option<AnyoneRefreshTaskWitness> {
+    fn from_raw(witness_raw_data: &[u8]) -> Result<AnyoneRefreshTaskWitness, DecodeError> {
         if witness_raw_data.len() < COLLATOR_REFRESH_TASK_WITNESS_LEN {
-            return None;
+            return Err(DecodeError::length(COLLATOR_REFRESH_TASK_WITNESS_LEN, witness_raw_data.len()));
         }
 
-        let pattern = Pattern::from_raw(&witness_raw_data[0..1])?;
-        let chain_id = ChainId::from_raw(&witness_raw_data[1..5])?;
-        Some(AnyoneRefreshTaskWitness { pattern, chain_id })
+        let pattern = Pattern::from_raw(&witness_raw_data[0..1]).field("pattern")?;
+        let chain_id = ChainId::from_raw(&witness_raw_data[1..5]).field("chain_id")?;
+        Ok(AnyoneRefreshTaskWitness { pattern, chain_id })
     }
 }
 
@@ -7,15 +7,17 @@ use crate::{
         witness::anyone_shutdown_sidechain::{AnyoneShutdownSidechainWitnessBuilder, AnyoneShutdownSidechainWitnessReader},
     },
     pattern::Pattern,
-    FromRaw, Serialize,
+    DecodeError, DecodeField, FromRaw, Serialize,
 };
 
 #[derive(Debug, Clone, PartialOrd, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AnyoneShutdownSidechainWitness {
     pub pattern:         Pattern,
     pub challenge_times: usize,
     pub check_data_size: u128,
-    pub jailed_checkers: Vec<PubKeyHash>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex::vec"))]
+    pub jailed_checkers: Vec<Identity>,
 }
 
 impl Default for AnyoneShutdownSidechainWitness {
@@ -30,22 +32,23 @@ impl Default for AnyoneShutdownSidechainWitness {
 }
 
 impl FromRaw for AnyoneShutdownSidechainWitness {
-    fn from_raw(witness_raw_data: &[u8]) -> Option<Self> {
-        let pattern = Pattern::from_raw(&witness_raw_data[0..1])?;
+    fn from_raw(witness_raw_data: &[u8]) -> Result<Self, DecodeError> {
+        let pattern = Pattern::from_raw(&witness_raw_data[0..1]).field("pattern")?;
 
-        let reader = AnyoneShutdownSidechainWitnessReader::from_slice(&witness_raw_data[1..]).ok()?;
+        let reader = AnyoneShutdownSidechainWitnessReader::from_slice(&witness_raw_data[1..])?;
 
-        let challenge_times = u32::from_raw(reader.challenge_times().raw_data())? as usize; // TODO: Change to usize
+        let challenge_times = u32::from_raw(reader.challenge_times().raw_data()).field("challenge_times")? as usize; // TODO: Change to usize
 
-        let check_data_size = u128::from_raw(reader.check_data_size().raw_data())?;
+        let check_data_size = u128::from_raw(reader.check_data_size().raw_data()).field("check_data_size")?;
 
         let jailed_checkers = reader
             .jailed_checkers()
             .iter()
-            .map(|checker_reader| PubKeyHash::from_raw(checker_reader.raw_data()))
-            .collect::<Option<Vec<PubKeyHash>>>()?;
+            .map(|checker_reader| Identity::from_raw(checker_reader.raw_data()))
+            .collect::<Result<Vec<Identity>, DecodeError>>()
+            .field("jailed_checkers")?;
 
-        Some(Self {
+        Ok(Self {
             pattern,
             challenge_times,
             check_data_size,
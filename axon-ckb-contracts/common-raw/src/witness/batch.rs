@@ -0,0 +1,110 @@
+use molecule::prelude::*;
+
+use crate::{
+    molecule::{
+        common::{MolStringBuilder, Uint16Reader},
+        witness::batch::{BatchEntriesBuilder, BatchEntryBuilder, BatchEntryReader, BatchWitnessBuilder, BatchWitnessReader},
+    },
+    pattern::Pattern,
+    DecodeError, DecodeField, FromRaw, Serialize,
+};
+
+/*
+each entry is a whole code cell witness of another pattern, which owns the next input_count inputs and
+output_count outputs after the code cell, entries are laid out one after another in the order they are listed
+ */
+#[derive(Debug, Clone, PartialOrd, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BatchEntry {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
+    pub witness:      Vec<u8>,
+    pub input_count:  usize,
+    pub output_count: usize,
+}
+
+impl BatchEntry {
+    fn from_reader(reader: BatchEntryReader) -> Result<Self, DecodeError> {
+        let witness = reader.witness().raw_data().to_vec();
+        let input_count = usize::from_raw(reader.input_count().raw_data()).field("input_count")?;
+        let output_count = usize::from_raw(reader.output_count().raw_data()).field("output_count")?;
+
+        Ok(Self {
+            witness,
+            input_count,
+            output_count,
+        })
+    }
+
+    fn as_builder(&self) -> BatchEntryBuilder {
+        let mut witness = MolStringBuilder::default();
+        for &v in self.witness.iter() {
+            witness = witness.push(Byte::new(v));
+        }
+        let input_count = Uint16Reader::new_unchecked(&self.input_count.serialize()).to_entity();
+        let output_count = Uint16Reader::new_unchecked(&self.output_count.serialize()).to_entity();
+
+        BatchEntryBuilder::default()
+            .witness(witness.build())
+            .input_count(input_count)
+            .output_count(output_count)
+    }
+
+    pub fn pattern(&self) -> Result<Pattern, DecodeError> {
+        Pattern::from_raw(self.witness.get(0..1).unwrap_or(&[])).field("witness")
+    }
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BatchWitness {
+    pattern:     Pattern,
+    pub entries: Vec<BatchEntry>,
+}
+
+impl Default for BatchWitness {
+    fn default() -> Self {
+        Self {
+            pattern: Pattern::Batch,
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl FromRaw for BatchWitness {
+    fn from_raw(witness_raw_data: &[u8]) -> Result<BatchWitness, DecodeError> {
+        let pattern = Pattern::from_raw(witness_raw_data.get(0..1).unwrap_or(&[])).field("pattern")?;
+
+        let reader = BatchWitnessReader::from_slice(&witness_raw_data[1..])?;
+
+        let entries = reader
+            .entries()
+            .iter()
+            .map(|entry_reader| BatchEntry::from_reader(entry_reader))
+            .collect::<Result<Vec<BatchEntry>, DecodeError>>()
+            .field("entries")?;
+
+        Ok(BatchWitness { pattern, entries })
+    }
+}
+
+impl Serialize for BatchWitness {
+    type RawType = Vec<u8>;
+
+    fn serialize(&self) -> Self::RawType {
+        let mut entries = BatchEntriesBuilder::default();
+        for entry in &self.entries {
+            entries = entries.push(entry.as_builder().build());
+        }
+
+        let builder = BatchWitnessBuilder::default().entries(entries.build());
+
+        let mut buf = Vec::new();
+        buf.extend_from_slice(&self.pattern.serialize());
+
+        builder
+            .write(&mut buf)
+            .expect("Unable to write buffer while serializing BatchWitness");
+
+        buf
+    }
+}
@@ -1,8 +1,9 @@
-use crate::{pattern::Pattern, FromRaw, Serialize};
+use crate::{pattern::Pattern, DecodeError, DecodeField, FromRaw, Serialize};
 
 const CHECKER_BOND_WITHDRAW_WITNESS_LEN: usize = 1;
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CheckerBondWithdrawWitness {
     pattern: Pattern,
 }
@@ -16,14 +17,14 @@ impl Default for CheckerBondWithdrawWitness {
 }
 
 impl FromRaw for CheckerBondWithdrawWitness {
-    fn from_raw(witness_raw_data: &[u8]) -> Option<CheckerBondWithdrawWitness> {
+    fn from_raw(witness_raw_data: &[u8]) -> Result<CheckerBondWithdrawWitness, DecodeError> {
         if witness_raw_data.len() < CHECKER_BOND_WITHDRAW_WITNESS_LEN {
-            return None;
+            return Err(DecodeError::length(CHECKER_BOND_WITHDRAW_WITNESS_LEN, witness_raw_data.len()));
         }
 
-        let pattern = Pattern::from_raw(&witness_raw_data[0..1])?;
+        let pattern = Pattern::from_raw(&witness_raw_data[0..1]).field("pattern")?;
 
-        Some(CheckerBondWithdrawWitness { pattern })
+        Ok(CheckerBondWithdrawWitness { pattern })
     }
 }
 
@@ -1,10 +1,11 @@
 use core::default::Default;
 
-use crate::{common::ChainId, pattern::Pattern, FromRaw, Serialize};
+use crate::{common::ChainId, pattern::Pattern, DecodeError, DecodeField, FromRaw, Serialize};
 
 const CHECKER_JOIN_SIDECHAIN_WITNESS_LEN: usize = 6;
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CheckerJoinSidechainWitness {
     pattern:        Pattern,
     pub chain_id:   ChainId,
@@ -22,16 +23,16 @@ impl Default for CheckerJoinSidechainWitness {
 }
 
 impl FromRaw for CheckerJoinSidechainWitness {
-    fn from_raw(witness_raw_data: &[u8]) -> Option<CheckerJoinSidechainWitness> {
+    fn from_raw(witness_raw_data: &[u8]) -> Result<CheckerJoinSidechainWitness, DecodeError> {
         if witness_raw_data.len() < CHECKER_JOIN_SIDECHAIN_WITNESS_LEN {
-            return None;
+            return Err(DecodeError::length(CHECKER_JOIN_SIDECHAIN_WITNESS_LEN, witness_raw_data.len()));
         }
 
-        let pattern = Pattern::from_raw(&witness_raw_data[0..1])?;
-        let chain_id = ChainId::from_raw(&witness_raw_data[1..5])?;
-        let checker_id = u8::from_raw(&witness_raw_data[5..6])?;
+        let pattern = Pattern::from_raw(&witness_raw_data[0..1]).field("pattern")?;
+        let chain_id = ChainId::from_raw(&witness_raw_data[1..5]).field("chain_id")?;
+        let checker_id = u8::from_raw(&witness_raw_data[5..6]).field("checker_id")?;
 
-        Some(CheckerJoinSidechainWitness {
+        Ok(CheckerJoinSidechainWitness {
             pattern,
             chain_id,
             checker_id,
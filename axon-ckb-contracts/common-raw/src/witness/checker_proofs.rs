@@ -0,0 +1,177 @@
+use molecule::prelude::*;
+
+use crate::{
+    cell::{sidechain_config::CheckerRecord, sidechain_state::CheckerTaskRecord},
+    common::Identity,
+    molecule::{
+        cell::{sidechain_config::CheckerRecordReader, sidechain_state::CheckerTaskRecordReader},
+        common::{MerkleHashReader, PubKeyHashReader, Uint128Reader},
+        witness::checker_proofs::{
+            CheckerProofsWitnessBuilder, CheckerProofsWitnessReader, CheckerRecordOptBuilder, CheckerRecordProofBuilder,
+            CheckerRecordProofReader, CheckerRecordProofsBuilder, CheckerTaskRecordProofBuilder, CheckerTaskRecordProofReader,
+            CheckerTaskRecordProofsBuilder, SmtNodeBuilder, SmtNodesBuilder, SmtProofBuilder, SmtProofReader,
+        },
+    },
+    smt::{SmtNode, SmtProof},
+    DecodeError, DecodeField, FromRaw, Serialize,
+};
+
+/*
+proves what checker_set_root holds for checker before a pattern reads or updates it,
+record is None if the checker is not in the set
+ */
+#[derive(Debug, Clone, PartialOrd, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CheckerRecordProof {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
+    pub checker: Identity,
+    pub record:  Option<CheckerRecord>,
+    pub proof:   SmtProof,
+}
+
+impl CheckerRecordProof {
+    fn from_reader(reader: CheckerRecordProofReader) -> Result<Self, DecodeError> {
+        let checker = Identity::from_raw(reader.checker().raw_data()).field("checker")?;
+        let record = match reader.record().to_opt() {
+            Some(record_reader) => Some(CheckerRecord::from_raw(record_reader.as_slice()).field("record")?),
+            None => None,
+        };
+        let proof = proof_from_reader(reader.proof()).field("proof")?;
+
+        Ok(Self { checker, record, proof })
+    }
+
+    fn as_builder(&self) -> CheckerRecordProofBuilder {
+        let record = CheckerRecordOptBuilder::default()
+            .set(
+                self.record
+                    .as_ref()
+                    .map(|record| CheckerRecordReader::new_unchecked(&record.serialize()).to_entity()),
+            )
+            .build();
+
+        CheckerRecordProofBuilder::default()
+            .checker(PubKeyHashReader::new_unchecked(&self.checker).to_entity())
+            .record(record)
+            .proof(proof_builder(&self.proof).build())
+    }
+}
+
+// proves the CheckerTaskRecord checker_task_root holds for checker, the default one if none
+#[derive(Debug, Clone, PartialOrd, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CheckerTaskRecordProof {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
+    pub checker: Identity,
+    pub record:  CheckerTaskRecord,
+    pub proof:   SmtProof,
+}
+
+impl CheckerTaskRecordProof {
+    fn from_reader(reader: CheckerTaskRecordProofReader) -> Result<Self, DecodeError> {
+        let checker = Identity::from_raw(reader.checker().raw_data()).field("checker")?;
+        let record = CheckerTaskRecord::from_raw(reader.record().as_slice()).field("record")?;
+        let proof = proof_from_reader(reader.proof()).field("proof")?;
+
+        Ok(Self { checker, record, proof })
+    }
+
+    fn as_builder(&self) -> CheckerTaskRecordProofBuilder {
+        CheckerTaskRecordProofBuilder::default()
+            .checker(PubKeyHashReader::new_unchecked(&self.checker).to_entity())
+            .record(CheckerTaskRecordReader::new_unchecked(&self.record.serialize()).to_entity())
+            .proof(proof_builder(&self.proof).build())
+    }
+}
+
+fn proof_from_reader(reader: SmtProofReader) -> Result<SmtProof, DecodeError> {
+    let bitmap = <[u8; 20]>::from_raw(reader.bitmap().raw_data()).field("bitmap")?;
+    let siblings = reader
+        .siblings()
+        .iter()
+        .map(|node_reader| {
+            let hash = <[u8; 32]>::from_raw(node_reader.hash().raw_data())?;
+            let sum = u128::from_raw(node_reader.sum().raw_data())?;
+            Ok(SmtNode { hash, sum })
+        })
+        .collect::<Result<Vec<SmtNode>, DecodeError>>()
+        .field("siblings")?;
+
+    Ok(SmtProof { bitmap, siblings })
+}
+
+fn proof_builder(proof: &SmtProof) -> SmtProofBuilder {
+    let mut siblings = SmtNodesBuilder::default();
+    for node in &proof.siblings {
+        let node = SmtNodeBuilder::default()
+            .hash(MerkleHashReader::new_unchecked(&node.hash).to_entity())
+            .sum(Uint128Reader::new_unchecked(&node.sum.serialize()).to_entity());
+        siblings = siblings.push(node.build());
+    }
+
+    SmtProofBuilder::default()
+        .bitmap(PubKeyHashReader::new_unchecked(&proof.bitmap).to_entity())
+        .siblings(siblings.build())
+}
+
+/*
+The proofs for the checkers a transaction reads or updates, in the output_type of the witness of
+the code cell. Patterns take them one after another in the order they visit checkers, batch entries
+included, and each proof is checked against the root as left by the proofs before it.
+ */
+#[derive(Debug, Clone, PartialOrd, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CheckerProofsWitness {
+    pub record_proofs:      Vec<CheckerRecordProof>,
+    pub task_record_proofs: Vec<CheckerTaskRecordProof>,
+}
+
+impl FromRaw for CheckerProofsWitness {
+    fn from_raw(witness_raw_data: &[u8]) -> Result<CheckerProofsWitness, DecodeError> {
+        let reader = CheckerProofsWitnessReader::from_slice(witness_raw_data)?;
+
+        let record_proofs = reader
+            .record_proofs()
+            .iter()
+            .map(|proof_reader| CheckerRecordProof::from_reader(proof_reader))
+            .collect::<Result<Vec<CheckerRecordProof>, DecodeError>>()
+            .field("record_proofs")?;
+        let task_record_proofs = reader
+            .task_record_proofs()
+            .iter()
+            .map(|proof_reader| CheckerTaskRecordProof::from_reader(proof_reader))
+            .collect::<Result<Vec<CheckerTaskRecordProof>, DecodeError>>()
+            .field("task_record_proofs")?;
+
+        Ok(CheckerProofsWitness {
+            record_proofs,
+            task_record_proofs,
+        })
+    }
+}
+
+impl Serialize for CheckerProofsWitness {
+    type RawType = Vec<u8>;
+
+    fn serialize(&self) -> Self::RawType {
+        let mut record_proofs = CheckerRecordProofsBuilder::default();
+        for proof in &self.record_proofs {
+            record_proofs = record_proofs.push(proof.as_builder().build());
+        }
+
+        let mut task_record_proofs = CheckerTaskRecordProofsBuilder::default();
+        for proof in &self.task_record_proofs {
+            task_record_proofs = task_record_proofs.push(proof.as_builder().build());
+        }
+
+        let builder = CheckerProofsWitnessBuilder::default()
+            .record_proofs(record_proofs.build())
+            .task_record_proofs(task_record_proofs.build());
+
+        let mut buf = Vec::new();
+        builder
+            .write(&mut buf)
+            .expect("Unable to write buffer while serializing CheckerProofsWitness");
+        buf
+    }
+}
@@ -1,8 +1,9 @@
-use crate::{common::ChainId, pattern::Pattern, FromRaw, Serialize};
+use crate::{common::ChainId, pattern::Pattern, DecodeError, DecodeField, FromRaw, Serialize};
 
 const CHECKER_PUBLISH_CHALLENGE_WITNESS_LEN: usize = 12;
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CheckerPublishChallengeWitness {
     pattern: Pattern,
     pub chain_id: ChainId,
@@ -24,18 +25,18 @@ impl Default for CheckerPublishChallengeWitness {
 }
 
 impl FromRaw for CheckerPublishChallengeWitness {
-    fn from_raw(witness_raw_data: &[u8]) -> Option<CheckerPublishChallengeWitness> {
+    fn from_raw(witness_raw_data: &[u8]) -> Result<CheckerPublishChallengeWitness, DecodeError> {
         if witness_raw_data.len() < CHECKER_PUBLISH_CHALLENGE_WITNESS_LEN {
-            return None;
+            return Err(DecodeError::length(CHECKER_PUBLISH_CHALLENGE_WITNESS_LEN, witness_raw_data.len()));
         }
 
-        let pattern = Pattern::from_raw(&witness_raw_data[0..1])?;
-        let chain_id = ChainId::from_raw(&witness_raw_data[1..5])?;
-        let checker_id = u8::from_raw(&witness_raw_data[5..6])?;
-        let challenge_count = u32::from_raw(&witness_raw_data[6..10])?;
-        let sidechain_config_dep_index = usize::from_raw(&witness_raw_data[10..12])?;
+        let pattern = Pattern::from_raw(&witness_raw_data[0..1]).field("pattern")?;
+        let chain_id = ChainId::from_raw(&witness_raw_data[1..5]).field("chain_id")?;
+        let checker_id = u8::from_raw(&witness_raw_data[5..6]).field("checker_id")?;
+        let challenge_count = u32::from_raw(&witness_raw_data[6..10]).field("challenge_count")?;
+        let sidechain_config_dep_index = usize::from_raw(&witness_raw_data[10..12]).field("sidechain_config_dep_index")?;
 
-        Some(CheckerPublishChallengeWitness {
+        Ok(CheckerPublishChallengeWitness {
             pattern,
             chain_id,
             checker_id,
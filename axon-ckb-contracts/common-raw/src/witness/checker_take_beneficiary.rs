@@ -1,41 +1,47 @@
-use crate::{common::ChainId, pattern::Pattern, FromRaw, Serialize};
+use crate::{common::ChainId, pattern::Pattern, DecodeError, DecodeField, FromRaw, Serialize};
 
-const CHECKER_TAKE_BENEFICIARY_WITNESS_LEN: usize = 22;
+const CHECKER_TAKE_BENEFICIARY_WITNESS_LEN: usize = 23;
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CheckerTakeBeneficiaryWitness {
-    pattern:        Pattern,
-    pub chain_id:   ChainId,
-    pub checker_id: u8,
-    pub fee:        u128,
+    pattern:              Pattern,
+    pub chain_id:         ChainId,
+    pub checker_id:       u8,
+    pub fee:              u128,
+    // delegation cells to the checker after the fixed cells, each matched by an output
+    pub delegation_count: u8,
 }
 
 impl Default for CheckerTakeBeneficiaryWitness {
     fn default() -> Self {
         Self {
-            pattern:    Pattern::CheckerTakeBeneficiary,
-            chain_id:   ChainId::default(),
-            checker_id: 0,
-            fee:        0,
+            pattern:          Pattern::CheckerTakeBeneficiary,
+            chain_id:         ChainId::default(),
+            checker_id:       0,
+            fee:              0,
+            delegation_count: 0,
         }
     }
 }
 
 impl FromRaw for CheckerTakeBeneficiaryWitness {
-    fn from_raw(witness_raw_data: &[u8]) -> Option<CheckerTakeBeneficiaryWitness> {
+    fn from_raw(witness_raw_data: &[u8]) -> Result<CheckerTakeBeneficiaryWitness, DecodeError> {
         if witness_raw_data.len() != CHECKER_TAKE_BENEFICIARY_WITNESS_LEN {
-            return None;
+            return Err(DecodeError::length(CHECKER_TAKE_BENEFICIARY_WITNESS_LEN, witness_raw_data.len()));
         }
 
-        let pattern = Pattern::from_raw(&witness_raw_data[0..1])?;
-        let chain_id = ChainId::from_raw(&witness_raw_data[1..5])?;
-        let checker_id = u8::from_raw(&witness_raw_data[5..6])?;
-        let fee = u128::from_raw(&witness_raw_data[6..22])?;
+        let pattern = Pattern::from_raw(&witness_raw_data[0..1]).field("pattern")?;
+        let chain_id = ChainId::from_raw(&witness_raw_data[1..5]).field("chain_id")?;
+        let checker_id = u8::from_raw(&witness_raw_data[5..6]).field("checker_id")?;
+        let fee = u128::from_raw(&witness_raw_data[6..22]).field("fee")?;
+        let delegation_count = u8::from_raw(&witness_raw_data[22..23]).field("delegation_count")?;
 
-        Some(CheckerTakeBeneficiaryWitness {
+        Ok(CheckerTakeBeneficiaryWitness {
             pattern,
             chain_id,
             checker_id,
             fee,
+            delegation_count,
         })
     }
 }
@@ -50,6 +56,7 @@ impl Serialize for CheckerTakeBeneficiaryWitness {
         buf[1..5].copy_from_slice(&self.chain_id.serialize());
         buf[5..6].copy_from_slice(&self.checker_id.serialize());
         buf[6..22].copy_from_slice(&self.fee.serialize());
+        buf[22..23].copy_from_slice(&self.delegation_count.serialize());
 
         buf
     }
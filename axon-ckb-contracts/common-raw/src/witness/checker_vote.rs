@@ -1,8 +1,9 @@
-use crate::{common::ChainId, pattern::Pattern, FromRaw, Serialize};
+use crate::{common::ChainId, pattern::Pattern, DecodeError, DecodeField, FromRaw, Serialize};
 
 const CHECKER_VOTE_WITNESS_LEN: usize = 7;
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CheckerVoteWitness {
     pattern: Pattern,
     pub chain_id: ChainId,
@@ -20,16 +21,16 @@ impl Default for CheckerVoteWitness {
 }
 
 impl FromRaw for CheckerVoteWitness {
-    fn from_raw(witness_raw_data: &[u8]) -> Option<CheckerVoteWitness> {
+    fn from_raw(witness_raw_data: &[u8]) -> Result<CheckerVoteWitness, DecodeError> {
         if witness_raw_data.len() < CHECKER_VOTE_WITNESS_LEN {
-            return None;
+            return Err(DecodeError::length(CHECKER_VOTE_WITNESS_LEN, witness_raw_data.len()));
         }
 
-        let pattern = Pattern::from_raw(&witness_raw_data[0..1])?;
-        let chain_id = ChainId::from_raw(&witness_raw_data[1..5])?;
-        let sidechain_config_dep_index = usize::from_raw(&witness_raw_data[5..7])?;
+        let pattern = Pattern::from_raw(&witness_raw_data[0..1]).field("pattern")?;
+        let chain_id = ChainId::from_raw(&witness_raw_data[1..5]).field("chain_id")?;
+        let sidechain_config_dep_index = usize::from_raw(&witness_raw_data[5..7]).field("sidechain_config_dep_index")?;
 
-        Some(CheckerVoteWitness {
+        Ok(CheckerVoteWitness {
             pattern,
             chain_id,
             sidechain_config_dep_index,
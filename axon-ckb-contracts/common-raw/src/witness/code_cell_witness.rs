@@ -1,21 +1,22 @@
-use crate::{pattern::Pattern, FromRaw};
+use crate::{pattern::Pattern, DecodeError, DecodeField, FromRaw};
 
 const CODE_TYPE_WITNESS_LEN_MIN: usize = 1;
 
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CodeCellTypeWitness {
     pattern: Pattern,
 }
 
 impl FromRaw for CodeCellTypeWitness {
-    fn from_raw(witness_raw_data: &[u8]) -> Option<CodeCellTypeWitness> {
+    fn from_raw(witness_raw_data: &[u8]) -> Result<CodeCellTypeWitness, DecodeError> {
         if witness_raw_data.len() < CODE_TYPE_WITNESS_LEN_MIN {
-            return None;
+            return Err(DecodeError::length(CODE_TYPE_WITNESS_LEN_MIN, witness_raw_data.len()));
         }
 
-        let pattern = Pattern::from_raw(&witness_raw_data[0..1])?;
+        let pattern = Pattern::from_raw(&witness_raw_data[0..1]).field("pattern")?;
 
-        Some(CodeCellTypeWitness { pattern })
+        Ok(CodeCellTypeWitness { pattern })
     }
 }
 
@@ -0,0 +1,66 @@
+use core::default::Default;
+
+use crate::{
+    common::{ChainId, Identity},
+    pattern::Pattern,
+    DecodeError, DecodeField, FromRaw, Serialize,
+};
+
+const COLLATOR_HANDOVER_WITNESS_LEN: usize = 26;
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CollatorHandoverWitness {
+    pattern:                   Pattern,
+    pub chain_id:              ChainId,
+    // index of the outgoing collator in the collator set
+    pub collator_index:        u8,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
+    pub new_collator_lock_arg: Identity,
+}
+
+impl Default for CollatorHandoverWitness {
+    fn default() -> Self {
+        Self {
+            pattern:               Pattern::CollatorHandover,
+            chain_id:              ChainId::default(),
+            collator_index:        0,
+            new_collator_lock_arg: Identity::default(),
+        }
+    }
+}
+
+impl FromRaw for CollatorHandoverWitness {
+    fn from_raw(witness_raw_data: &[u8]) -> Result<CollatorHandoverWitness, DecodeError> {
+        if witness_raw_data.len() < COLLATOR_HANDOVER_WITNESS_LEN {
+            return Err(DecodeError::length(COLLATOR_HANDOVER_WITNESS_LEN, witness_raw_data.len()));
+        }
+
+        let pattern = Pattern::from_raw(&witness_raw_data[0..1]).field("pattern")?;
+        let chain_id = ChainId::from_raw(&witness_raw_data[1..5]).field("chain_id")?;
+        let collator_index = u8::from_raw(&witness_raw_data[5..6]).field("collator_index")?;
+        let new_collator_lock_arg = Identity::from_raw(&witness_raw_data[6..26]).field("new_collator_lock_arg")?;
+
+        Ok(CollatorHandoverWitness {
+            pattern,
+            chain_id,
+            collator_index,
+            new_collator_lock_arg,
+        })
+    }
+}
+
+impl Serialize for CollatorHandoverWitness {
+    type RawType = [u8; COLLATOR_HANDOVER_WITNESS_LEN];
+
+    fn serialize(&self) -> Self::RawType {
+        let mut buf = [0u8; COLLATOR_HANDOVER_WITNESS_LEN];
+
+        buf[0..1].copy_from_slice(&self.pattern.serialize());
+        buf[1..5].copy_from_slice(&self.chain_id.serialize());
+        buf[5..6].copy_from_slice(&self.collator_index.serialize());
+        buf[6..26].copy_from_slice(&self.new_collator_lock_arg);
+
+        buf
+    }
+}
@@ -1,12 +1,13 @@
 use crate::{
     common::{BlockHeight, ChainId},
     pattern::Pattern,
-    FromRaw, Serialize,
+    DecodeError, DecodeField, FromRaw, Serialize,
 };
 
 const COLLATOR_PUBLISH_TASK_WITNESS_LEN: usize = 53;
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CollatorPublishTaskWitness {
     pattern:             Pattern,
     pub chain_id:        ChainId,
@@ -28,18 +29,18 @@ impl Default for CollatorPublishTaskWitness {
 }
 
 impl FromRaw for CollatorPublishTaskWitness {
-    fn from_raw(witness_raw_data: &[u8]) -> Option<CollatorPublishTaskWitness> {
+    fn from_raw(witness_raw_data: &[u8]) -> Result<CollatorPublishTaskWitness, DecodeError> {
         if witness_raw_data.len() != COLLATOR_PUBLISH_TASK_WITNESS_LEN {
-            return None;
+            return Err(DecodeError::length(COLLATOR_PUBLISH_TASK_WITNESS_LEN, witness_raw_data.len()));
         }
 
-        let pattern = Pattern::from_raw(&witness_raw_data[0..1])?;
-        let chain_id = ChainId::from_raw(&witness_raw_data[1..5])?;
-        let from_height = BlockHeight::from_raw(&witness_raw_data[5..21])?;
-        let to_height = BlockHeight::from_raw(&witness_raw_data[21..37])?;
-        let check_data_size = u128::from_raw(&witness_raw_data[37..53])?;
+        let pattern = Pattern::from_raw(&witness_raw_data[0..1]).field("pattern")?;
+        let chain_id = ChainId::from_raw(&witness_raw_data[1..5]).field("chain_id")?;
+        let from_height = BlockHeight::from_raw(&witness_raw_data[5..21]).field("from_height")?;
+        let to_height = BlockHeight::from_raw(&witness_raw_data[21..37]).field("to_height")?;
+        let check_data_size = u128::from_raw(&witness_raw_data[37..53]).field("check_data_size")?;
 
-        Some(CollatorPublishTaskWitness {
+        Ok(CollatorPublishTaskWitness {
             pattern,
             chain_id,
             from_height,
@@ -7,31 +7,40 @@ use crate::{
         witness::collator_shutdown_sidechain::{CollatorShutDownSidechainWitnessBuilder, CollatorShutDownSidechainWitnessReader},
     },
     pattern::Pattern,
-    FromRaw, Serialize,
+    DecodeError, DecodeField, FromRaw, Serialize,
 };
 
 #[derive(Debug, Clone, PartialOrd, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CollatorShutdownSidechainWitness {
-    pattern:      Pattern,
-    pub chain_id: ChainId,
+    pattern:            Pattern,
+    pub chain_id:       ChainId,
+    // inputs after the fixed cells, locked by the collators approving the shutdown
+    pub approval_count: u8,
 }
 
 impl Default for CollatorShutdownSidechainWitness {
     fn default() -> Self {
         CollatorShutdownSidechainWitness {
-            pattern:  Pattern::CollatorShutdownSidechain,
-            chain_id: ChainId::default(),
+            pattern:        Pattern::CollatorShutdownSidechain,
+            chain_id:       ChainId::default(),
+            approval_count: 0,
         }
     }
 }
 
 impl FromRaw for CollatorShutdownSidechainWitness {
-    fn from_raw(witness_raw_data: &[u8]) -> Option<Self> {
-        let reader = CollatorShutDownSidechainWitnessReader::from_slice(witness_raw_data).ok()?;
-        let pattern = Pattern::from_raw(reader.pattern().raw_data())?;
-        let chain_id = ChainId::from_raw(reader.chain_id().raw_data())?;
+    fn from_raw(witness_raw_data: &[u8]) -> Result<Self, DecodeError> {
+        let reader = CollatorShutDownSidechainWitnessReader::from_slice(witness_raw_data)?;
+        let pattern = Pattern::from_raw(reader.pattern().raw_data()).field("pattern")?;
+        let chain_id = ChainId::from_raw(reader.chain_id().raw_data()).field("chain_id")?;
+        let approval_count = u8::from_raw(reader.approval_count().raw_data()).field("approval_count")?;
 
-        Some(CollatorShutdownSidechainWitness { pattern, chain_id })
+        Ok(CollatorShutdownSidechainWitness {
+            pattern,
+            chain_id,
+            approval_count,
+        })
     }
 }
 
@@ -42,8 +51,9 @@ impl Serialize for CollatorShutdownSidechainWitness {
         let builder = CollatorShutDownSidechainWitnessBuilder::default();
         let pattern = Uint8Reader::new_unchecked(&self.pattern.serialize()).to_entity();
         let chain_id = ChainIdReader::new_unchecked(&self.chain_id.serialize()).to_entity();
+        let approval_count = Uint8Reader::new_unchecked(&self.approval_count.serialize()).to_entity();
 
-        let builder = builder.pattern(pattern).chain_id(chain_id);
+        let builder = builder.pattern(pattern).chain_id(chain_id).approval_count(approval_count);
 
         let mut buf = Vec::new();
         builder
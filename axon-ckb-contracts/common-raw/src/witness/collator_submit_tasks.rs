@@ -1,36 +1,46 @@
 use molecule::prelude::*;
 
 use crate::{
+    cell::task::TaskStatus,
     common::*,
     molecule::{
+        cell::task::TaskStatusReader,
         common::{
-            CommittedHashOptBuilder, CommittedHashReader, PubKeyHashReader, RandomSeedReader, Uint128Reader, Uint32OptBuilder, Uint32Reader,
+            BlockSliceReader, ChainIdReader, CodeHashReader, CommittedHashOptBuilder, CommittedHashReader, HashTypeReader,
+            PubKeyHashReader, RandomSeedReader, Uint128Reader, Uint32OptBuilder, Uint32Reader,
         },
         witness::collator_submit_tasks::{
+            BlockSliceOptBuilder, CheckerSignatureBuilder, CheckerSignatureReader, CheckerSignaturesBuilder,
             CollatorSubmitTasksWitnessBuilder, CollatorSubmitTasksWitnessReader, ExistedCommittedCheckerInfoBuilder,
-            ExistedCommittedCheckerInfoReader, ExistedCommittedCheckerInfosBuilder,
+            ExistedCommittedCheckerInfoReader, ExistedCommittedCheckerInfosBuilder, SignatureReader, SignedVoteBuilder, SignedVoteReader,
         },
     },
     pattern::Pattern,
-    FromRaw, Serialize,
+    DecodeError, DecodeField, FromRaw, Hasher, Serialize,
 };
 
 #[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Ord, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExistedCommittedCheckerInfo {
     pub index:                 Option<usize>,
-    pub checker_lock_arg:      PubKeyHash,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
+    pub checker_lock_arg:      Identity,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex::option"))]
     pub origin_committed_hash: Option<CommittedHash>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex::option"))]
     pub new_committed_hash:    Option<CommittedHash>,
 }
 
 impl ExistedCommittedCheckerInfo {
-    fn from_reader(reader: ExistedCommittedCheckerInfoReader) -> Option<Self> {
+    fn from_reader(reader: ExistedCommittedCheckerInfoReader) -> Result<Self, DecodeError> {
         let index = reader
             .index()
             .to_opt()
-            .map_or(None, |index_reader| Some(u32::from_raw(index_reader.raw_data())? as usize));
+            .map(|index_reader| u32::from_raw(index_reader.raw_data()).map(|index| index as usize))
+            .transpose()
+            .field("index")?;
 
-        let mut checker_lock_arg: PubKeyHash = PubKeyHash::default();
+        let mut checker_lock_arg: Identity = Identity::default();
         checker_lock_arg.copy_from_slice(reader.checker_lock_arg().raw_data());
 
         let origin_committed_hash = reader.origin_committed_hash().to_opt().map(|origin_committed_hash_reader| {
@@ -45,7 +55,7 @@ impl ExistedCommittedCheckerInfo {
             buf
         });
 
-        Some(Self {
+        Ok(Self {
             index,
             checker_lock_arg,
             origin_committed_hash,
@@ -98,14 +108,174 @@ impl ExistedCommittedCheckerInfo {
     }
 }
 
+/*
+The vote a checker signs off-chain in place of voting on a task cell, the message of its signature is
+the hash of the serialized vote. The reveal is signed along, so the collator could not withhold it for
+the checker.
+ */
+#[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Ord, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SignedVote {
+    pub chain_id: ChainId,
+    pub job:      BlockSlice,
+    pub verdict:  TaskStatus,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
+    pub reveal:   RandomSeed,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
+    pub commit:   CommittedHash,
+}
+
+impl SignedVote {
+    pub fn message<H: Hasher>(&self) -> [u8; 32] {
+        H::hash(&self.serialize())
+    }
+}
+
+impl FromRaw for SignedVote {
+    fn from_raw(raw: &[u8]) -> Result<Self, DecodeError> {
+        let reader = SignedVoteReader::from_slice(raw)?;
+
+        let chain_id = ChainId::from_raw(reader.chain_id().raw_data()).field("chain_id")?;
+        let job = BlockSlice::from_raw(reader.job().as_slice()).field("job")?;
+        let verdict = TaskStatus::from_raw(reader.verdict().as_slice()).field("verdict")?;
+
+        let mut reveal = RandomSeed::default();
+        reveal.copy_from_slice(reader.reveal().raw_data());
+
+        let mut commit = CommittedHash::default();
+        commit.copy_from_slice(reader.commit().raw_data());
+
+        Ok(Self {
+            chain_id,
+            job,
+            verdict,
+            reveal,
+            commit,
+        })
+    }
+}
+
+impl Serialize for SignedVote {
+    type RawType = Vec<u8>;
+
+    fn serialize(&self) -> Self::RawType {
+        let builder = SignedVoteBuilder::default()
+            .chain_id(ChainIdReader::new_unchecked(&self.chain_id.serialize()).to_entity())
+            .job(BlockSliceReader::new_unchecked(&self.job.serialize()).to_entity())
+            .verdict(TaskStatusReader::new_unchecked(&self.verdict.serialize()).to_entity())
+            .reveal(RandomSeedReader::new_unchecked(&self.reveal).to_entity())
+            .commit(CommittedHashReader::new_unchecked(&self.commit).to_entity());
+
+        let mut buf = Vec::new();
+        builder
+            .write(&mut buf)
+            .expect("Unable to write buffer while serializing SignedVote");
+
+        buf
+    }
+}
+
+// a recoverable secp256k1 signature, r and s followed by the recovery id
+pub type Signature = [u8; 65];
+
+/*
+A signed vote as the witness carries it, its chain id and job are the ones submitted. The checker is
+locked by lock_code_hash and lock_hash_type with the blake160 of its secp256k1 public key as args, so
+the key recovered from signature tells the identity of the checker.
+ */
+#[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Ord, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CheckerSignature {
+    pub verdict:        TaskStatus,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
+    pub reveal:         RandomSeed,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
+    pub commit:         CommittedHash,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
+    pub lock_code_hash: CodeHash,
+    pub lock_hash_type: HashType,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
+    pub signature:      Signature,
+}
+
+impl Default for CheckerSignature {
+    fn default() -> Self {
+        Self {
+            verdict:        TaskStatus::default(),
+            reveal:         RandomSeed::default(),
+            commit:         CommittedHash::default(),
+            lock_code_hash: CodeHash::default(),
+            lock_hash_type: HashType::default(),
+            signature:      [0u8; 65],
+        }
+    }
+}
+
+impl CheckerSignature {
+    fn from_reader(reader: CheckerSignatureReader) -> Result<Self, DecodeError> {
+        let verdict = TaskStatus::from_raw(reader.verdict().as_slice()).field("verdict")?;
+
+        let mut reveal = RandomSeed::default();
+        reveal.copy_from_slice(reader.reveal().raw_data());
+
+        let mut commit = CommittedHash::default();
+        commit.copy_from_slice(reader.commit().raw_data());
+
+        let mut lock_code_hash = CodeHash::default();
+        lock_code_hash.copy_from_slice(reader.lock_code_hash().raw_data());
+
+        let lock_hash_type = HashType::from_raw(reader.lock_hash_type().raw_data()).field("lock_hash_type")?;
+
+        let mut signature = [0u8; 65];
+        signature.copy_from_slice(reader.signature().raw_data());
+
+        Ok(Self {
+            verdict,
+            reveal,
+            commit,
+            lock_code_hash,
+            lock_hash_type,
+            signature,
+        })
+    }
+
+    fn as_builder(&self) -> CheckerSignatureBuilder {
+        CheckerSignatureBuilder::default()
+            .verdict(TaskStatusReader::new_unchecked(&self.verdict.serialize()).to_entity())
+            .reveal(RandomSeedReader::new_unchecked(&self.reveal).to_entity())
+            .commit(CommittedHashReader::new_unchecked(&self.commit).to_entity())
+            .lock_code_hash(CodeHashReader::new_unchecked(&self.lock_code_hash).to_entity())
+            .lock_hash_type(HashTypeReader::new_unchecked(&self.lock_hash_type.serialize()).to_entity())
+            .signature(SignatureReader::new_unchecked(&self.signature).to_entity())
+    }
+
+    // the vote signed for the job of chain_id
+    pub fn vote(&self, chain_id: ChainId, job: BlockSlice) -> SignedVote {
+        SignedVote {
+            chain_id,
+            job,
+            verdict: self.verdict,
+            reveal: self.reveal,
+            commit: self.commit,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialOrd, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CollatorSubmitTasksWitness {
     pattern:                Pattern,
     pub challenge_times:    usize,
     pub check_data_size:    u128,
     pub commit:             Vec<ExistedCommittedCheckerInfo>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
     pub origin_random_seed: RandomSeed,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
     pub new_random_seed:    RandomSeed,
+    // the job of the signed votes, None if the tasks of the job are task cells
+    pub signed_job:         Option<BlockSlice>,
+    // the signed votes of the job in the order of commit, empty unless signed_job is set
+    pub signatures:         Vec<CheckerSignature>,
 }
 
 impl Default for CollatorSubmitTasksWitness {
@@ -117,26 +287,28 @@ impl Default for CollatorSubmitTasksWitness {
             commit:             Vec::new(),
             origin_random_seed: RandomSeed::default(),
             new_random_seed:    RandomSeed::default(),
+            signed_job:         None,
+            signatures:         Vec::new(),
         }
     }
 }
 
 impl FromRaw for CollatorSubmitTasksWitness {
-    fn from_raw(witness_raw_data: &[u8]) -> Option<CollatorSubmitTasksWitness> {
-        let pattern = Pattern::from_raw(&witness_raw_data[0..1])?;
+    fn from_raw(witness_raw_data: &[u8]) -> Result<CollatorSubmitTasksWitness, DecodeError> {
+        let pattern = Pattern::from_raw(&witness_raw_data[0..1]).field("pattern")?;
 
-        let reader = CollatorSubmitTasksWitnessReader::from_slice(&witness_raw_data[1..]).ok()?;
+        let reader = CollatorSubmitTasksWitnessReader::from_slice(&witness_raw_data[1..])?;
 
-        let challenge_times = u32::from_raw(reader.challenge_times().raw_data())? as usize; // TODO: Change to usize
+        let challenge_times = u32::from_raw(reader.challenge_times().raw_data()).field("challenge_times")? as usize; // TODO: Change to usize
 
-        let check_data_size = u128::from_raw(reader.check_data_size().raw_data())?;
+        let check_data_size = u128::from_raw(reader.check_data_size().raw_data()).field("check_data_size")?;
 
         let commit = reader
             .commit()
             .iter()
-            .map(|commit_reader| ExistedCommittedCheckerInfo::from_reader(commit_reader).ok_or(()))
-            .collect::<Result<Vec<ExistedCommittedCheckerInfo>, ()>>()
-            .ok()?;
+            .map(|commit_reader| ExistedCommittedCheckerInfo::from_reader(commit_reader))
+            .collect::<Result<Vec<ExistedCommittedCheckerInfo>, DecodeError>>()
+            .field("commit")?;
 
         let mut origin_random_seed = RandomSeed::default();
         origin_random_seed.copy_from_slice(reader.origin_random_seed().raw_data());
@@ -144,13 +316,29 @@ impl FromRaw for CollatorSubmitTasksWitness {
         let mut new_random_seed = RandomSeed::default();
         new_random_seed.copy_from_slice(reader.new_random_seed().raw_data());
 
-        Some(CollatorSubmitTasksWitness {
+        let signed_job = reader
+            .signed_job()
+            .to_opt()
+            .map(|job_reader| BlockSlice::from_raw(job_reader.as_slice()))
+            .transpose()
+            .field("signed_job")?;
+
+        let signatures = reader
+            .signatures()
+            .iter()
+            .map(|signature_reader| CheckerSignature::from_reader(signature_reader))
+            .collect::<Result<Vec<CheckerSignature>, DecodeError>>()
+            .field("signatures")?;
+
+        Ok(CollatorSubmitTasksWitness {
             pattern,
             challenge_times,
             check_data_size,
             commit,
             origin_random_seed,
             new_random_seed,
+            signed_job,
+            signatures,
         })
     }
 }
@@ -170,13 +358,26 @@ impl Serialize for CollatorSubmitTasksWitness {
 
         let origin_random_seed = RandomSeedReader::new_unchecked(&self.origin_random_seed).to_entity();
         let new_random_seed = RandomSeedReader::new_unchecked(&self.new_random_seed).to_entity();
+        let signed_job = BlockSliceOptBuilder::default()
+            .set(
+                self.signed_job
+                    .map(|job| BlockSliceReader::new_unchecked(&job.serialize()).to_entity()),
+            )
+            .build();
+
+        let mut signatures = CheckerSignaturesBuilder::default();
+        for signature in &self.signatures {
+            signatures = signatures.push(signature.as_builder().build());
+        }
 
         let builder = CollatorSubmitTasksWitnessBuilder::default()
             .challenge_times(challenge_times)
             .check_data_size(check_data_size)
             .commit(commit.build())
             .origin_random_seed(origin_random_seed)
-            .new_random_seed(new_random_seed);
+            .new_random_seed(new_random_seed)
+            .signed_job(signed_job)
+            .signatures(signatures.build());
 
         let mut buf = Vec::new();
         buf.extend_from_slice(&self.pattern.serialize());
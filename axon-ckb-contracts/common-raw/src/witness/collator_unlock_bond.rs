@@ -1,8 +1,9 @@
-use crate::{common::ChainId, pattern::Pattern, FromRaw, Serialize};
+use crate::{common::ChainId, pattern::Pattern, DecodeError, DecodeField, FromRaw, Serialize};
 
 const COLLATOR_UNLOCK_BOND_WITNESS_LEN: usize = 7;
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CollatorUnlockBondWitness {
     pattern: Pattern,
     pub chain_id: ChainId,
@@ -20,16 +21,16 @@ impl Default for CollatorUnlockBondWitness {
 }
 
 impl FromRaw for CollatorUnlockBondWitness {
-    fn from_raw(witness_raw_data: &[u8]) -> Option<CollatorUnlockBondWitness> {
+    fn from_raw(witness_raw_data: &[u8]) -> Result<CollatorUnlockBondWitness, DecodeError> {
         if witness_raw_data.len() != COLLATOR_UNLOCK_BOND_WITNESS_LEN {
-            return None;
+            return Err(DecodeError::length(COLLATOR_UNLOCK_BOND_WITNESS_LEN, witness_raw_data.len()));
         }
 
-        let pattern = Pattern::from_raw(&witness_raw_data[0..1])?;
-        let chain_id = ChainId::from_raw(&witness_raw_data[1..5])?;
-        let sidechain_state_dep_index = usize::from_raw(&witness_raw_data[5..7])?;
+        let pattern = Pattern::from_raw(&witness_raw_data[0..1]).field("pattern")?;
+        let chain_id = ChainId::from_raw(&witness_raw_data[1..5]).field("chain_id")?;
+        let sidechain_state_dep_index = usize::from_raw(&witness_raw_data[5..7]).field("sidechain_state_dep_index")?;
 
-        Some(CollatorUnlockBondWitness {
+        Ok(CollatorUnlockBondWitness {
             pattern,
             chain_id,
             sidechain_state_dep_index,
@@ -0,0 +1,45 @@
+use crate::{common::ChainId, pattern::Pattern, DecodeError, DecodeField, FromRaw, Serialize};
+
+const DELEGATOR_CLAIM_WITNESS_LEN: usize = 5;
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DelegatorClaimWitness {
+    pattern:      Pattern,
+    pub chain_id: ChainId,
+}
+
+impl Default for DelegatorClaimWitness {
+    fn default() -> Self {
+        Self {
+            pattern:  Pattern::DelegatorClaim,
+            chain_id: ChainId::default(),
+        }
+    }
+}
+
+impl FromRaw for DelegatorClaimWitness {
+    fn from_raw(witness_raw_data: &[u8]) -> Result<DelegatorClaimWitness, DecodeError> {
+        if witness_raw_data.len() != DELEGATOR_CLAIM_WITNESS_LEN {
+            return Err(DecodeError::length(DELEGATOR_CLAIM_WITNESS_LEN, witness_raw_data.len()));
+        }
+
+        let pattern = Pattern::from_raw(&witness_raw_data[0..1]).field("pattern")?;
+        let chain_id = ChainId::from_raw(&witness_raw_data[1..5]).field("chain_id")?;
+
+        Ok(DelegatorClaimWitness { pattern, chain_id })
+    }
+}
+
+impl Serialize for DelegatorClaimWitness {
+    type RawType = [u8; DELEGATOR_CLAIM_WITNESS_LEN];
+
+    fn serialize(&self) -> Self::RawType {
+        let mut buf = [0u8; DELEGATOR_CLAIM_WITNESS_LEN];
+
+        buf[0..1].copy_from_slice(&self.pattern.serialize());
+        buf[1..5].copy_from_slice(&self.chain_id.serialize());
+
+        buf
+    }
+}
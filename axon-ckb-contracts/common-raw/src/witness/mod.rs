@@ -1,15 +1,23 @@
 pub mod admin_create_sidechain;
 pub mod anyone_refresh_task;
 pub mod anyone_shutdown_sidechain;
+pub mod batch;
 pub mod checker_bond_withdraw;
 pub mod checker_join_sidechain;
+pub mod checker_proofs;
+pub mod checker_propose_change;
 pub mod checker_publish_challenge;
 pub mod checker_quit_sidechain;
 pub mod checker_take_beneficiary;
 pub mod checker_vote;
+pub mod checker_vote_proposal;
+pub mod collator_handover;
 pub mod collator_publish_task;
 pub mod collator_shutdown_sidechain;
 pub mod collator_submit_tasks;
 pub mod collator_unlock_bond;
+pub mod delegator_claim;
+pub mod delegator_delegate;
+pub mod execute_proposal;
 
 pub mod code_cell_witness;
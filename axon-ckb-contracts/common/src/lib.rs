@@ -1,9 +1,19 @@
 #![no_std]
 
 use ckb_std::ckb_constants::Source;
-use ckb_std::high_level::{load_cell_data, load_cell_type, load_cell_type_hash};
-
-use common_raw::{cell::global_config::GlobalConfigCellData, FromRaw};
+use ckb_std::error::SysError;
+use ckb_std::high_level::{load_cell_data, load_cell_lock, load_cell_type, load_cell_type_hash, load_header};
+
+use common_raw::{
+    beacon::RandomBeacon,
+    cell::{
+        global_config::GlobalConfigCellData,
+        sidechain_bond::SidechainBondCellLockArgs,
+        sidechain_config::{SidechainConfigCell, SidechainConfigCellTypeArgs},
+    },
+    common::ChainId,
+    FromRaw,
+};
 
 #[cfg(not(any(
     gcc_typehash = "test_gcc",
@@ -85,7 +95,7 @@ pub fn check_global_cell() -> Option<GlobalConfigCellData> {
         })?
         .ok()?;
 
-    let global_config_data = GlobalConfigCellData::from_raw(&global_config_data)?;
+    let global_config_data = GlobalConfigCellData::from_raw(&global_config_data).ok()?;
 
     Some(global_config_data)
 }
@@ -117,3 +127,122 @@ pub fn check_code_cell() -> Option<()> {
     check_type_script(0, Source::Input, &global.code_cell_type_codehash, global.code_cell_type_hashtype)?;
     check_type_script(0, Source::Output, &global.code_cell_type_codehash, global.code_cell_type_hashtype)
 }
+
+fn find_sidechain_config(global: &GlobalConfigCellData, chain_id: ChainId) -> Option<SidechainConfigCell> {
+    for i in 0.. {
+        let script = match load_cell_type(i, Source::CellDep) {
+            Ok(Some(script)) => script,
+            Ok(None) => continue,
+            Err(_) => return None,
+        };
+
+        if script.as_reader().code_hash().raw_data() != global.sidechain_config_cell_type_codehash
+            || script.as_reader().hash_type().as_slice()[0] != global.sidechain_config_cell_type_hashtype
+        {
+            continue;
+        }
+
+        let type_args = SidechainConfigCellTypeArgs::from_raw(script.as_reader().args().raw_data()).ok()?;
+        if type_args.chain_id == chain_id {
+            return SidechainConfigCell::from_raw(&load_cell_data(i, Source::CellDep).ok()?).ok();
+        }
+    }
+
+    None
+}
+
+pub fn check_bond_sudt() -> Option<()> {
+    /*
+    CollatorUnlockBond,
+    Dep:    0 Global Config Cell
+    Dep:    1 Sidechain Config Cell
+    ...
+    Sidechain Bond Cell         ->          Sudt Cell
+
+    every bond of the group is in the sudt named by the config of its sidechain
+    */
+
+    let global = check_global_cell()?;
+
+    let lock = load_cell_lock(0, Source::GroupInput).ok()?;
+    let lock_args = SidechainBondCellLockArgs::from_raw(lock.as_reader().args().raw_data()).ok()?;
+    let config = find_sidechain_config(&global, lock_args.chain_id)?;
+
+    for i in 0.. {
+        match load_cell_type(i, Source::GroupInput) {
+            Err(SysError::IndexOutOfBound) => break,
+            _ => check_type_script(
+                i,
+                Source::GroupInput,
+                &config.bond_sudt_typescript_codehash,
+                config.bond_sudt_typescript_hashtype as u8,
+            )?,
+        }
+    }
+
+    Some(())
+}
+
+pub fn load_random_beacon(chain_id: ChainId) -> Option<(usize, RandomBeacon)> {
+    /*
+    Dep:    0 Global Config Cell
+    Dep:    1 .....
+    Dep:    i Sidechain State Cell of chain_id
+
+    the index of the state cell comes along, see check_beacon_freshness
+    */
+
+    let global = check_global_cell()?;
+
+    for i in 0.. {
+        let script = match load_cell_type(i, Source::CellDep) {
+            Ok(Some(script)) => script,
+            Ok(None) => continue,
+            Err(_) => return None,
+        };
+
+        if script.as_reader().code_hash().raw_data() != global.sidechain_state_cell_type_codehash
+            || script.as_reader().hash_type().as_slice()[0] != global.sidechain_state_cell_type_hashtype
+        {
+            continue;
+        }
+
+        let beacon = RandomBeacon::from_state_cell(script.as_reader().args().raw_data(), &load_cell_data(i, Source::CellDep).ok()?).ok()?;
+        if beacon.chain_id == chain_id {
+            return Some((i, beacon));
+        }
+    }
+
+    None
+}
+
+fn load_block_number(index: usize, source: Source) -> Option<u64> {
+    let header = load_header(index, source).ok()?;
+
+    u64::from_raw(header.raw().number().as_reader().raw_data()).ok()
+}
+
+pub fn check_beacon_freshness(
+    beacon_index: usize,
+    beacon: &RandomBeacon,
+    recorded_epoch: u64,
+    index: usize,
+    source: Source,
+    max_delay: u64,
+) -> Option<()> {
+    /*
+    the beacon at cell dep beacon_index, see load_random_beacon, was renewed after the cell at index of
+    source was created, which recorded_epoch was the epoch of the beacon then. The cell has to check
+    recorded_epoch against the beacon when it is created. Both blocks must be in header deps, see
+    RandomBeacon::is_fresh
+    */
+
+    let beacon_at = load_block_number(beacon_index, Source::CellDep)?;
+    let created_at = load_block_number(index, source)?;
+
+    if !beacon.is_fresh(recorded_epoch, created_at, beacon_at, max_delay) {
+        return None;
+    }
+
+    Some(())
+}
@@ -2,8 +2,6 @@ use core::convert::TryFrom;
 
 use ckb_std::ckb_constants::Source;
 
-use ckb_std::high_level::load_header;
-use common_raw::cell::sidechain_state::{CheckerLastAcceptTaskHeight, PunishedChecker};
 use common_raw::cell::task::TaskStatus;
 use common_raw::{
     cell::{
@@ -12,29 +10,41 @@ use common_raw::{
         sidechain_state::{SidechainStateCell, SidechainStateCellTypeArgs},
         task::{TaskCell, TaskCellTypeArgs},
     },
+    common::{BlockSlice, RandomSeed},
+    layout::anyone_refresh_task as layout,
+    seed::{derive_seed, SeedPurpose},
     witness::anyone_refresh_task::AnyoneRefreshTaskWitness,
     FromRaw,
 };
 
-use crate::{cell::*, common::*, error::Error};
+use crate::{
+    cell::*,
+    checker_set::{checker_record, punish_checker, raise_last_task_height},
+    common::*,
+    error::Error,
+    jail::jail_checker,
+    sampling::CheckerSampler,
+    source::*,
+};
 
-const CONFIG_INPUT: CellOrigin = CellOrigin(1, Source::Input);
-const CONFIG_OUTPUT: CellOrigin = CellOrigin(1, Source::Output);
-const STATE_INPUT: CellOrigin = CellOrigin(2, Source::Input);
-const STATE_OUTPUT: CellOrigin = CellOrigin(2, Source::Output);
+const CONFIG_INPUT: CellOrigin = CellOrigin(layout::CONFIG_INPUT, Source::Input);
+const CONFIG_OUTPUT: CellOrigin = CellOrigin(layout::CONFIG_OUTPUT, Source::Output);
+const STATE_INPUT: CellOrigin = CellOrigin(layout::STATE_INPUT, Source::Input);
+const STATE_OUTPUT: CellOrigin = CellOrigin(layout::STATE_OUTPUT, Source::Output);
 
 pub fn load_task_header_timestamp(origin: CellOrigin) -> Result<u64, Error> {
-    let CellOrigin(index, source) = origin;
-    let raw_header = load_header(index, source).or(Err(Error::MissingHeader))?.raw();
-    let time_stamp = u64::from_raw(raw_header.timestamp().as_reader().raw_data()).ok_or(Error::MissingHeader)?;
+    let (index, source) = origin.resolve()?;
+    let raw_header = cell_source().load_header(index, source).or(Err(Error::MissingHeader))?.raw();
+    let time_stamp = u64::from_raw(raw_header.timestamp().as_reader().raw_data()).or(Err(Error::MissingHeader))?;
     Ok(time_stamp)
 }
 
 pub fn anyone_refresh_task(raw_witness: &[u8]) -> Result<(), Error> {
     /*
-    CollatorRefreshTask,
+    AnyoneRefreshTask,
 
     Dep:    0 Global Config Cell
+    Header Dep: 0 Reference Block
 
     Code Cell                   ->          Code Cell
     SidechainConfig             ->          SidechainConfig
@@ -45,7 +55,7 @@ pub fn anyone_refresh_task(raw_witness: &[u8]) -> Result<(), Error> {
     is_anyone_refresh_task()?;
     let timestamp = require_header_dep()?;
 
-    let witness = AnyoneRefreshTaskWitness::from_raw(raw_witness).ok_or(Error::Encoding)?;
+    let witness = AnyoneRefreshTaskWitness::from_raw(raw_witness).or(Err(Error::WitnessEncoding))?;
 
     let (config_input_type_args, config_input, state_input_type_args, state_input) = load_entities! {
         SidechainConfigCellTypeArgs: CONFIG_INPUT,
@@ -63,13 +73,19 @@ pub fn anyone_refresh_task(raw_witness: &[u8]) -> Result<(), Error> {
     if u32::try_from(config_input_type_args.chain_id).or(Err(Error::Encoding))? != witness.chain_id {
         return Err(Error::SidechainConfigMismatch);
     }
+    if config_input.refresh_task_limit != 0
+        && is_cell_count_greater(
+            layout::FIXED_CELLS + usize::try_from(config_input.refresh_task_limit).or(Err(Error::Encoding))?,
+            Source::Input,
+        )
+    {
+        return Err(Error::RefreshTaskLimitExceeded);
+    }
+
     let mut config_res = config_input.clone();
     let mut state_res = state_input.clone();
 
-    let mut seed = state_res.random_seed.clone();
-    seed[0] += state_res.random_offset;
-
-    for i in 3.. {
+    for i in layout::FIXED_CELLS.. {
         let task_input_origin = CellOrigin(i, Source::Input);
         let task_output_origin = CellOrigin(i, Source::Output);
 
@@ -87,6 +103,7 @@ pub fn anyone_refresh_task(raw_witness: &[u8]) -> Result<(), Error> {
             Ok(data) => data,
             Err(err) => return Err(err),
         };
+        check_continuity(task_input_origin, task_output_origin)?;
 
         let task_output_type_args = match TaskCellTypeArgs::load(task_output_origin) {
             Ok(data) => data,
@@ -95,11 +112,24 @@ pub fn anyone_refresh_task(raw_witness: &[u8]) -> Result<(), Error> {
 
         let mut task_res_type_args = task_input_type_args.clone();
 
+        // every task is drawn for with a seed of its own
+        let seed = derive_seed::<Blake2b>(
+            &state_res.random_seed,
+            state_res.random_offset,
+            witness.chain_id,
+            SeedPurpose::RefreshTask,
+            &BlockSlice {
+                from: task_input.sidechain_block_height_from,
+                to:   task_input.sidechain_block_height_to,
+            },
+        );
+        state_res.random_offset = state_res.random_offset.checked_add(1).ok_or(Error::SidechainStateMismatch)?;
+
         check_confirm_interval_and_update(
             &mut task_res_type_args,
             &config_input,
             CellOrigin(i, Source::Input),
-            &mut seed,
+            seed,
             timestamp,
         )?;
 
@@ -111,51 +141,30 @@ pub fn anyone_refresh_task(raw_witness: &[u8]) -> Result<(), Error> {
             return Err(Error::TaskMismatch);
         }
 
-        match state_res
-            .checker_last_task_sidechain_heights
-            .iter_mut()
-            .find(|checker_last_height| checker_last_height.checker_lock_arg == task_res_type_args.checker_lock_arg)
-        {
-            Some(checker_last_height) => {
-                if checker_last_height.height < task_input.sidechain_block_height_to {
-                    checker_last_height.height = task_input.sidechain_block_height_to;
-                }
-            }
+        // a checker jailed by an earlier task of this refresh is not punished any further
+        // the task goes to the drawn checker, the unresponsive one is punished
+        raise_last_task_height(
+            &config_res,
+            &mut state_res,
+            &task_res_type_args.checker_lock_arg,
+            task_input.sidechain_block_height_to,
+        )?;
 
-            None => state_res.checker_last_task_sidechain_heights.push(CheckerLastAcceptTaskHeight {
-                checker_lock_arg: task_res_type_args.checker_lock_arg,
-                height:           task_input.sidechain_block_height_to,
-            }),
+        // a checker jailed by an earlier task of this refresh is not punished any further
+        let punished_checker = task_input_type_args.checker_lock_arg;
+        if checker_record(&config_res, &punished_checker)?.map_or(false, |record| !record.is_activated()) {
+            continue;
         }
 
-        match state_res
-            .punish_checkers
-            .iter_mut()
-            .enumerate()
-            .find(|(_, checker)| checker.checker_lock_arg == task_input_type_args.checker_lock_arg)
-        {
-            Some((index, punish_checker)) => {
-                punish_checker.punish_points += config_input.refresh_punish_points;
-                let checker_lock_arg = punish_checker.checker_lock_arg;
-
-                //put checker into jail if number of punish_points is greater than threshold.
-                if punish_checker.punish_points > config_input.refresh_punish_threshold {
-                    state_res.punish_checkers.remove(index);
-                    config_res.jailed_checkers.push(checker_lock_arg);
-                }
+        //the first punishment is not checked against the threshold
+        //put checker into jail if number of punish_points is greater than threshold.
+        if let Some(punish_points) = punish_checker(&config_res, &mut state_res, &punished_checker, config_input.refresh_punish_points)? {
+            if punish_points > config_input.refresh_punish_threshold {
+                jail_checker(&mut config_res, Some(&mut state_res), &punished_checker)?;
             }
-
-            //add a new record in punished checkers
-            //do not check punish points
-            None => state_res.punish_checkers.push(PunishedChecker {
-                checker_lock_arg: task_res_type_args.checker_lock_arg.clone(),
-                punish_points:    config_input.refresh_punish_points,
-            }),
-        };
+        }
     }
 
-    //update random_offset before refresh all task in this tx.
-    state_res.random_offset += 1;
     if state_res != state_output
         || state_input_type_args != state_output_type_args
         || u32::try_from(state_input_type_args.chain_id).or(Err(Error::Encoding))? != witness.chain_id
@@ -174,7 +183,7 @@ pub fn anyone_refresh_task(raw_witness: &[u8]) -> Result<(), Error> {
 
 fn is_anyone_refresh_task() -> Result<(), Error> {
     let global = check_global_cell()?;
-    if is_cell_count_smaller(3, Source::Input) || is_cell_count_smaller(3, Source::Output) {
+    if is_cell_count_smaller(layout::FIXED_CELLS, Source::Input) || is_cell_count_smaller(layout::FIXED_CELLS, Source::Output) {
         return Err(Error::CellNumberMismatch);
     }
 
@@ -190,31 +199,34 @@ fn is_anyone_refresh_task() -> Result<(), Error> {
         },
     };
 
-    TaskCell::one_to_one_check(3, &global)
+    check_continuity! {
+        CONFIG_INPUT => CONFIG_OUTPUT,
+        STATE_INPUT => STATE_OUTPUT,
+    };
+
+    TaskCell::one_to_one_check(layout::FIXED_CELLS, &global)
 }
 
 pub fn check_confirm_interval_and_update(
     task_type_args: &mut TaskCellTypeArgs,
     config: &SidechainConfigCell,
     task_origin: CellOrigin,
-    seed: &mut [u8; 32],
+    seed: RandomSeed,
     ref_timestamp: u64,
 ) -> Result<(), Error> {
-    //compute index of chosen checker and update seed for next task in this tx;
-    *seed = Blake2b::calculate(seed);
+    // draw a checker other than the unresponsive one
     let task_timestamp = load_task_header_timestamp(task_origin)?;
-    let seed_number = u128::from_raw(&seed[0..16]).ok_or(Error::Encoding)?;
-    let checkers_count = u128::try_from(config.activated_checkers.len()).or(Err(Error::Encoding))?;
-    let index = usize::try_from(seed_number % checkers_count).or(Err(Error::Encoding))?;
 
-    let next_checker_lock_arg = config.activated_checkers.get(index).ok_or(Error::Encoding)?;
+    let mut sampler = CheckerSampler::new(config, seed)?;
+    sampler.exclude(&task_type_args.checker_lock_arg)?;
+    let next_checker_lock_arg = sampler.draw()?;
 
     // refresh limit reached, then anyone can check this task.
     if task_timestamp + config.refresh_interval > ref_timestamp {
         return Err(Error::Wrong);
     }
 
-    task_type_args.checker_lock_arg = *next_checker_lock_arg;
+    task_type_args.checker_lock_arg = next_checker_lock_arg;
 
     Ok(())
 }
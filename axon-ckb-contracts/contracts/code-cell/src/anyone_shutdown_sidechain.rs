@@ -9,19 +9,20 @@ use common_raw::{
         sidechain_fee::{SidechainFeeCell, SidechainFeeCellLockArgs},
         task::{TaskCell, TaskCellTypeArgs, TaskStatus},
     },
+    layout::anyone_shutdown_sidechain as layout,
     witness::anyone_shutdown_sidechain::AnyoneShutdownSidechainWitness,
     FromRaw,
 };
 
-use crate::{cell::*, common::*, error::Error};
+use crate::{cell::*, common::*, error::Error, jail::jail_checker};
 
-const SIDECHAIN_CONFIG_INPUT: CellOrigin = CellOrigin(1, Source::Input);
-const SIDECHAIN_FEE_INPUT: CellOrigin = CellOrigin(2, Source::Input);
+const SIDECHAIN_CONFIG_INPUT: CellOrigin = CellOrigin(layout::SIDECHAIN_CONFIG_INPUT, Source::Input);
+const SIDECHAIN_FEE_INPUT: CellOrigin = CellOrigin(layout::SIDECHAIN_FEE_INPUT, Source::Input);
 
-const SIDECHAIN_CONFIG_OUTPUT: CellOrigin = CellOrigin(1, Source::Output);
-const SIDECHAIN_FEE_OUTPUT: CellOrigin = CellOrigin(2, Source::Output);
+const SIDECHAIN_CONFIG_OUTPUT: CellOrigin = CellOrigin(layout::SIDECHAIN_CONFIG_OUTPUT, Source::Output);
+const SIDECHAIN_FEE_OUTPUT: CellOrigin = CellOrigin(layout::SIDECHAIN_FEE_OUTPUT, Source::Output);
 
-const FIXED_INPUT_CELLS: usize = 3;
+const FIXED_INPUT_CELLS: usize = layout::FIXED_INPUT_CELLS;
 
 pub fn anyone_shutdown_sidechain(raw_witness: &[u8]) -> Result<(), Error> {
     /*
@@ -33,10 +34,10 @@ pub fn anyone_shutdown_sidechain(raw_witness: &[u8]) -> Result<(), Error> {
     Sidechain Config Cell       -> ~
     Sidechain Fee Cell          -> ~
 
-    [Task Cell]         -> ~
+    [Task Cell]                 -> Null
     */
 
-    let witness = AnyoneShutdownSidechainWitness::from_raw(raw_witness).ok_or(Error::Encoding)?;
+    let witness = AnyoneShutdownSidechainWitness::from_raw(raw_witness).or(Err(Error::WitnessEncoding))?;
 
     //load inputs
     let (sidechain_config_input, sidechain_config_input_type_args) = load_entities!(
@@ -51,9 +52,11 @@ pub fn anyone_shutdown_sidechain(raw_witness: &[u8]) -> Result<(), Error> {
 
     let correct_vote_count = job_count - witness.jailed_checkers.len();
 
-    let fee = u128::try_from(correct_vote_count).or(Err(Error::Encoding))?
-        * u128::try_from(sidechain_config_input.check_fee_rate).or(Err(Error::Encoding))?
-        * witness.check_data_size;
+    let fee = u128::try_from(correct_vote_count)
+        .or(Err(Error::Encoding))?
+        .checked_mul(u128::from(sidechain_config_input.check_fee_rate))
+        .and_then(|fee| fee.checked_mul(witness.check_data_size))
+        .ok_or(Error::Encoding)?;
 
     let chain_id = sidechain_config_input_type_args.chain_id;
 
@@ -77,15 +80,9 @@ pub fn anyone_shutdown_sidechain(raw_witness: &[u8]) -> Result<(), Error> {
 
     let mut sidechain_config_res = sidechain_config_input.clone();
     sidechain_config_res.sidechain_status = SidechainStatus::Shutdown;
-    sidechain_config_res.checker_normal_count -= u32::try_from(witness.jailed_checkers.len()).or(Err(Error::Encoding))?;
-
-    sidechain_config_res.activated_checkers = sidechain_config_res
-        .activated_checkers
-        .into_iter()
-        .filter(|&lock_arg| witness.jailed_checkers.iter().find(|&&jailed| lock_arg == jailed).is_none())
-        .collect();
-
-    sidechain_config_res.jailed_checkers.extend(witness.jailed_checkers.iter());
+    for jailed_checker in witness.jailed_checkers.iter() {
+        jail_checker(&mut sidechain_config_res, None, jailed_checker)?;
+    }
 
     if sidechain_config_res != sidechain_config_output || sidechain_config_input_type_args != sidechain_config_output_type_args {
         return Err(Error::SidechainConfigMismatch);
@@ -110,8 +107,12 @@ pub fn anyone_shutdown_sidechain(raw_witness: &[u8]) -> Result<(), Error> {
         TaskCellTypeArgs: CellOrigin(FIXED_INPUT_CELLS, Source::Input),
     );
 
-    if task_first.check_data_size != witness.check_data_size || task_first_type_args.chain_id != chain_id {
-        return Err(Error::TaskMismatch);
+    if task_first_type_args.chain_id != chain_id {
+        return Err(Error::TaskChainIdMismatch);
+    }
+
+    if task_first.check_data_size != witness.check_data_size {
+        return Err(Error::TaskCheckDataSizeMismatch);
     }
 
     let mut jailed_checker_iter = witness.jailed_checkers.iter();
@@ -125,7 +126,7 @@ pub fn anyone_shutdown_sidechain(raw_witness: &[u8]) -> Result<(), Error> {
         );
 
         if match task.status {
-            TaskStatus::Idle => true,
+            TaskStatus::Idle => return Err(Error::TaskNotVoted),
             // Good checkers
             TaskStatus::ChallengePassed => match jailed_checker_opt {
                 None => false,
@@ -133,12 +134,12 @@ pub fn anyone_shutdown_sidechain(raw_witness: &[u8]) -> Result<(), Error> {
             },
             // Bad checkers
             _ => {
-                let jailed_checker = jailed_checker_opt.ok_or(Error::TaskMismatch)?;
+                let jailed_checker = jailed_checker_opt.ok_or(Error::TaskJailedCheckerMismatch)?;
                 jailed_checker_opt = jailed_checker_iter.next();
                 &task_type_args.checker_lock_arg != jailed_checker
             }
         } {
-            return Err(Error::TaskMismatch);
+            return Err(Error::TaskJailedCheckerMismatch);
         }
 
         let mut task_res = task.clone();
@@ -152,7 +153,7 @@ pub fn anyone_shutdown_sidechain(raw_witness: &[u8]) -> Result<(), Error> {
         task_res_type_args.checker_lock_arg = task_first_type_args.checker_lock_arg;
 
         if task_res != task_first || task_res_type_args != task_first_type_args {
-            return Err(Error::TaskMismatch);
+            return Err(Error::TaskJobInconsistent);
         }
     }
 
@@ -164,7 +165,7 @@ pub fn is_anyone_shutdown_sidechain(job_count: usize) -> Result<(), Error> {
 
     let len_input = FIXED_INPUT_CELLS + job_count;
 
-    if is_cell_count_not_equals(len_input, Source::Input) || is_cell_count_not_equals(3, Source::Output) {
+    if is_cell_count_not_equals(len_input, Source::Input) || is_cell_count_not_equals(layout::OUTPUT_COUNT, Source::Output) {
         return Err(Error::CellNumberMismatch);
     }
 
@@ -181,5 +182,10 @@ pub fn is_anyone_shutdown_sidechain(job_count: usize) -> Result<(), Error> {
         },
     };
 
+    check_continuity! {
+        SIDECHAIN_CONFIG_INPUT => SIDECHAIN_CONFIG_OUTPUT,
+    };
+    check_capacity_continuity(SIDECHAIN_FEE_INPUT, SIDECHAIN_FEE_OUTPUT)?;
+
     TaskCell::range_check(FIXED_INPUT_CELLS..len_input, Source::Input, &global)
 }
@@ -0,0 +1,69 @@
+use ckb_std::ckb_constants::Source;
+
+use common_raw::{common::Identity, pattern::Pattern, witness::batch::BatchWitness, FromRaw};
+
+use crate::{
+    cell::{with_cell_window, CellWindow},
+    common::*,
+    entry::dispatch,
+    error::Error,
+};
+
+pub fn batch(raw_witness: &[u8], signer: Identity) -> Result<(), Error> {
+    /*
+    Batch,
+
+    Dep:    as required by each entry
+
+    Code Cell                   ->          Code Cell
+    [Entry 0 Cells]             ->          [Entry 0 Cells]
+    [Entry 1 Cells]             ->          [Entry 1 Cells]
+    ...
+
+    */
+    let witness = BatchWitness::from_raw(raw_witness).or(Err(Error::WitnessEncoding))?;
+
+    is_batch(&witness)?;
+
+    let mut input_start = 1;
+    let mut output_start = 1;
+    for entry in &witness.entries {
+        let window = CellWindow {
+            input_start,
+            input_count: entry.input_count,
+            output_start,
+            output_count: entry.output_count,
+        };
+        with_cell_window(window, || dispatch(&entry.witness, signer))?;
+
+        input_start += entry.input_count;
+        output_start += entry.output_count;
+    }
+
+    Ok(())
+}
+
+fn is_batch(witness: &BatchWitness) -> Result<(), Error> {
+    if witness.entries.is_empty() {
+        return Err(Error::BatchPatternInvalid);
+    }
+
+    // entries have to keep the code cell alive and could not nest
+    for entry in &witness.entries {
+        match entry.pattern().or(Err(Error::WitnessEncoding))? {
+            Pattern::AdminCreateSidechain | Pattern::AnyoneShutdownSidechain | Pattern::CollatorShutdownSidechain | Pattern::Batch => {
+                return Err(Error::BatchPatternInvalid);
+            }
+            _ => {}
+        }
+    }
+
+    // every cell after the code cell belongs to exactly one entry
+    let input_count = witness.entries.iter().map(|entry| entry.input_count).sum::<usize>() + 1;
+    let output_count = witness.entries.iter().map(|entry| entry.output_count).sum::<usize>() + 1;
+    if is_cell_count_not_equals(input_count, Source::Input) || is_cell_count_not_equals(output_count, Source::Output) {
+        return Err(Error::CellNumberMismatch);
+    }
+
+    Ok(())
+}
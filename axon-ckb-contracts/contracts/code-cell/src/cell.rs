@@ -1,13 +1,14 @@
 use ckb_std::ckb_constants::Source;
-use ckb_std::high_level::{load_cell_data, load_cell_lock, load_cell_type};
 
 use common_raw::{
     cell::{
         checker_bond::{CheckerBondCell, CheckerBondCellLockArgs},
         checker_info::{CheckerInfoCell, CheckerInfoCellTypeArgs},
-        code::{CodeCell, CodeCellLockArgs},
+        code::CodeCell,
+        delegation::{DelegationCell, DelegationCellLockArgs},
         global_config::GlobalConfigCellData,
         muse_token::MuseTokenCell,
+        proposal::{ProposalCell, ProposalCellTypeArgs},
         sidechain_bond::{SidechainBondCell, SidechainBondCellLockArgs},
         sidechain_config::{SidechainConfigCell, SidechainConfigCellTypeArgs},
         sidechain_fee::{SidechainFeeCell, SidechainFeeCellLockArgs},
@@ -18,7 +19,7 @@ use common_raw::{
     FromRaw,
 };
 
-use crate::error::Error;
+use crate::{error::Error, source::*};
 
 pub const SUDT_CODEHASH: [u8; 32] = common::SUDT_TYPE_HASH;
 pub const SUDT_HASHTYPE: u8 = 0u8;
@@ -27,74 +28,207 @@ pub const SUDT_MUSE_ARGS: &[u8] = &[];
 #[derive(Debug, Copy, Clone)]
 pub struct CellOrigin(pub usize, pub Source);
 
+/*
+a batch entry only sees its own slice of inputs and outputs,
+index 0 is still the code cell and index 1.. are the cells of the slice, deps are shared
+ */
+#[derive(Debug, Copy, Clone)]
+pub struct CellWindow {
+    pub input_start:  usize,
+    pub input_count:  usize,
+    pub output_start: usize,
+    pub output_count: usize,
+}
+
+// contracts are single threaded, the window is only set by the batch dispatcher around each entry
+static mut CELL_WINDOW: Option<CellWindow> = None;
+
+pub fn with_cell_window<T, F: FnOnce() -> T>(window: CellWindow, f: F) -> T {
+    struct Reset;
+
+    impl Drop for Reset {
+        fn drop(&mut self) {
+            unsafe { CELL_WINDOW = None };
+        }
+    }
+
+    unsafe { CELL_WINDOW = Some(window) };
+    let _reset = Reset;
+
+    f()
+}
+
+impl CellOrigin {
+    pub fn resolve(self) -> Result<(usize, Source), Error> {
+        let CellOrigin(index, source) = self;
+        let window = match unsafe { CELL_WINDOW } {
+            Some(window) => window,
+            None => return Ok((index, source)),
+        };
+
+        let (start, count) = match source {
+            Source::Input => (window.input_start, window.input_count),
+            Source::Output => (window.output_start, window.output_count),
+            _ => return Ok((index, source)),
+        };
+
+        match index {
+            0 => Ok((0, source)),
+            x if x <= count => Ok((start + x - 1, source)),
+            _ => Err(Error::IndexOutOfBound),
+        }
+    }
+}
+
 pub trait LoadableCell {
+    const ENCODING_ERROR: Error;
+
+    // what an output has to hold beyond its encoding, inputs are taken as they are
+    fn check_output(&self) -> Result<(), Error> {
+        Ok(())
+    }
+
     fn load(origin: CellOrigin) -> Result<Self, Error>
     where
         Self: Sized + FromRaw,
     {
-        let CellOrigin(index, source) = origin;
-        let data = load_cell_data(index, source)?;
-        Self::from_raw(&data).ok_or(Error::Encoding)
+        let (index, source) = origin.resolve()?;
+        let data = cell_source().load_cell_data(index, source)?;
+        let cell = Self::from_raw(&data).or(Err(Self::ENCODING_ERROR))?;
+
+        if let Source::Output = source {
+            cell.check_output()?;
+        }
+        Ok(cell)
     }
 }
 
-impl LoadableCell for CheckerBondCell {}
+impl LoadableCell for CheckerBondCell {
+    const ENCODING_ERROR: Error = Error::CheckerBondEncoding;
+}
+
+impl LoadableCell for CheckerInfoCell {
+    const ENCODING_ERROR: Error = Error::CheckerInfoEncoding;
+}
+
+impl LoadableCell for DelegationCell {
+    const ENCODING_ERROR: Error = Error::DelegationEncoding;
+}
+
+impl LoadableCell for GlobalConfigCellData {
+    const ENCODING_ERROR: Error = Error::GlobalConfigEncoding;
+}
 
-impl LoadableCell for CheckerInfoCell {}
+impl LoadableCell for MuseTokenCell {
+    const ENCODING_ERROR: Error = Error::MuseTokenEncoding;
+}
 
-impl LoadableCell for GlobalConfigCellData {}
+impl LoadableCell for ProposalCell {
+    const ENCODING_ERROR: Error = Error::ProposalEncoding;
+}
 
-impl LoadableCell for MuseTokenCell {}
+impl LoadableCell for SidechainBondCell {
+    const ENCODING_ERROR: Error = Error::SidechainBondEncoding;
+}
 
-impl LoadableCell for SidechainBondCell {}
+impl LoadableCell for SidechainConfigCell {
+    const ENCODING_ERROR: Error = Error::SidechainConfigEncoding;
 
-impl LoadableCell for SidechainConfigCell {}
+    fn check_output(&self) -> Result<(), Error> {
+        if !self.has_sorted_checkers() {
+            return Err(Error::SidechainConfigCheckersUnsorted);
+        }
+        if self.reveal_quorum > self.commit_threshold {
+            return Err(Error::RevealQuorumUnreachable);
+        }
+        Ok(())
+    }
+}
 
-impl LoadableCell for SidechainFeeCell {}
+impl LoadableCell for SidechainFeeCell {
+    const ENCODING_ERROR: Error = Error::SidechainFeeEncoding;
+}
+
+impl LoadableCell for SidechainStateCell {
+    const ENCODING_ERROR: Error = Error::SidechainStateEncoding;
 
-impl LoadableCell for SidechainStateCell {}
+    fn check_output(&self) -> Result<(), Error> {
+        if !self.has_sorted_checkers() {
+            return Err(Error::SidechainStateCheckersUnsorted);
+        }
+        Ok(())
+    }
+}
 
-impl LoadableCell for SudtTokenCell {}
+impl LoadableCell for SudtTokenCell {
+    const ENCODING_ERROR: Error = Error::SudtTokenEncoding;
+}
 
-impl LoadableCell for TaskCell {}
+impl LoadableCell for TaskCell {
+    const ENCODING_ERROR: Error = Error::TaskEncoding;
+}
 
 pub trait LoadableLockArgs {
+    const ENCODING_ERROR: Error;
+
     fn load(origin: CellOrigin) -> Result<Self, Error>
     where
         Self: Sized + FromRaw,
     {
-        let CellOrigin(index, source) = origin;
-        let data = load_cell_lock(index, source)?.args();
-        Self::from_raw(data.as_reader().raw_data()).ok_or(Error::Encoding)
+        let (index, source) = origin.resolve()?;
+        let data = cell_source().load_cell_lock(index, source)?.args();
+        Self::from_raw(data.as_reader().raw_data()).or(Err(Self::ENCODING_ERROR))
     }
 }
 
-impl LoadableLockArgs for CheckerBondCellLockArgs {}
+impl LoadableLockArgs for CheckerBondCellLockArgs {
+    const ENCODING_ERROR: Error = Error::CheckerBondLockArgsEncoding;
+}
 
-impl LoadableLockArgs for CodeCellLockArgs {}
+impl LoadableLockArgs for DelegationCellLockArgs {
+    const ENCODING_ERROR: Error = Error::DelegationLockArgsEncoding;
+}
 
-impl LoadableLockArgs for SidechainBondCellLockArgs {}
+impl LoadableLockArgs for SidechainBondCellLockArgs {
+    const ENCODING_ERROR: Error = Error::SidechainBondLockArgsEncoding;
+}
 
-impl LoadableLockArgs for SidechainFeeCellLockArgs {}
+impl LoadableLockArgs for SidechainFeeCellLockArgs {
+    const ENCODING_ERROR: Error = Error::SidechainFeeLockArgsEncoding;
+}
 
 pub trait LoadableTypeArgs {
+    const ENCODING_ERROR: Error;
+
     fn load(origin: CellOrigin) -> Result<Self, Error>
     where
         Self: Sized + FromRaw,
     {
-        let CellOrigin(index, source) = origin;
-        let data = load_cell_type(index, source)?.ok_or(Error::TypeScriptMissed)?.args();
-        Self::from_raw(data.as_reader().raw_data()).ok_or(Error::Encoding)
+        let (index, source) = origin.resolve()?;
+        let data = cell_source().load_cell_type(index, source)?.ok_or(Error::TypeScriptMissed)?.args();
+        Self::from_raw(data.as_reader().raw_data()).or(Err(Self::ENCODING_ERROR))
     }
 }
 
-impl LoadableTypeArgs for CheckerInfoCellTypeArgs {}
+impl LoadableTypeArgs for CheckerInfoCellTypeArgs {
+    const ENCODING_ERROR: Error = Error::CheckerInfoTypeArgsEncoding;
+}
+
+impl LoadableTypeArgs for ProposalCellTypeArgs {
+    const ENCODING_ERROR: Error = Error::ProposalTypeArgsEncoding;
+}
 
-impl LoadableTypeArgs for SidechainConfigCellTypeArgs {}
+impl LoadableTypeArgs for SidechainConfigCellTypeArgs {
+    const ENCODING_ERROR: Error = Error::SidechainConfigTypeArgsEncoding;
+}
 
-impl LoadableTypeArgs for SidechainStateCellTypeArgs {}
+impl LoadableTypeArgs for SidechainStateCellTypeArgs {
+    const ENCODING_ERROR: Error = Error::SidechainStateTypeArgsEncoding;
+}
 
-impl LoadableTypeArgs for TaskCellTypeArgs {}
+impl LoadableTypeArgs for TaskCellTypeArgs {
+    const ENCODING_ERROR: Error = Error::TaskTypeArgsEncoding;
+}
 
 #[macro_export]
 macro_rules! load_entities {
@@ -166,8 +300,8 @@ pub trait TypedCell {
     fn type_script_info(global: &GlobalConfigCellData) -> ([u8; 32], u8);
 
     fn check(origin: CellOrigin, global: &GlobalConfigCellData) -> Result<(), Error> {
-        let CellOrigin(index, source) = origin;
-        let script = load_cell_type(index, source)?.ok_or(Error::MissingTypeScript)?;
+        let (index, source) = origin.resolve()?;
+        let script = cell_source().load_cell_type(index, source)?.ok_or(Error::MissingTypeScript)?;
 
         let (code_hash, hash_type) = Self::type_script_info(global);
 
@@ -212,8 +346,15 @@ impl TypedCell for CheckerInfoCell {
     }
 }
 
-fn check_sudt_type_script(index: usize, source: Source) -> Result<(), Error> {
-    let script = load_cell_type(index, source)?.ok_or(Error::MissingTypeScript)?;
+impl TypedCell for ProposalCell {
+    fn type_script_info(global: &GlobalConfigCellData) -> ([u8; 32], u8) {
+        (global.proposal_cell_type_codehash, global.proposal_cell_type_hashtype)
+    }
+}
+
+fn check_sudt_type_script(origin: CellOrigin) -> Result<(), Error> {
+    let (index, source) = origin.resolve()?;
+    let script = cell_source().load_cell_type(index, source)?.ok_or(Error::MissingTypeScript)?;
     check_script!(script, SUDT_CODEHASH, SUDT_HASHTYPE, SUDT_MUSE_ARGS);
 
     Ok(())
@@ -221,9 +362,7 @@ fn check_sudt_type_script(index: usize, source: Source) -> Result<(), Error> {
 
 pub trait TypedSudtCell {
     fn check(origin: CellOrigin, _: &GlobalConfigCellData) -> Result<(), Error> {
-        let CellOrigin(index, source) = origin;
-
-        check_sudt_type_script(index, source)
+        check_sudt_type_script(origin)
     }
 
     CheckableHelpers! {}
@@ -237,11 +376,10 @@ pub trait LockedTypedSudtCell {
     fn lock_script_info(global: &GlobalConfigCellData) -> ([u8; 32], u8);
 
     fn check(origin: CellOrigin, global: &GlobalConfigCellData) -> Result<(), Error> {
-        let CellOrigin(index, source) = origin;
-
-        check_sudt_type_script(index, source)?;
+        check_sudt_type_script(origin)?;
 
-        let script = load_cell_lock(index, source)?;
+        let (index, source) = origin.resolve()?;
+        let script = cell_source().load_cell_lock(index, source)?;
         let (code_hash, hash_type) = Self::lock_script_info(global);
         check_script!(script, code_hash, hash_type);
 
@@ -257,9 +395,9 @@ impl LockedTypedSudtCell for CheckerBondCell {
     }
 }
 
-impl LockedTypedSudtCell for SidechainBondCell {
+impl LockedTypedSudtCell for DelegationCell {
     fn lock_script_info(global: &GlobalConfigCellData) -> ([u8; 32], u8) {
-        (global.sidechain_bond_cell_lock_codehash, global.sidechain_bond_cell_lock_hashtype)
+        (global.delegation_cell_lock_codehash, global.delegation_cell_lock_hashtype)
     }
 }
 
@@ -268,3 +406,91 @@ impl LockedTypedSudtCell for SidechainFeeCell {
         (global.sidechain_fee_cell_lock_codehash, global.sidechain_fee_cell_lock_hashtype)
     }
 }
+
+// the token of these cells is up to the sidechain, see check_bond_type_script
+pub trait LockedCell {
+    fn lock_script_info(global: &GlobalConfigCellData) -> ([u8; 32], u8);
+
+    fn check(origin: CellOrigin, global: &GlobalConfigCellData) -> Result<(), Error> {
+        let (index, source) = origin.resolve()?;
+        let script = cell_source().load_cell_lock(index, source)?;
+        let (code_hash, hash_type) = Self::lock_script_info(global);
+        check_script!(script, code_hash, hash_type);
+
+        Ok(())
+    }
+}
+
+impl LockedCell for SidechainBondCell {
+    fn lock_script_info(global: &GlobalConfigCellData) -> ([u8; 32], u8) {
+        (global.sidechain_bond_cell_lock_codehash, global.sidechain_bond_cell_lock_hashtype)
+    }
+}
+
+// a sidechain bond is posted in the sudt its config names, instead of muse
+pub fn check_bond_type_script(origin: CellOrigin, config: &SidechainConfigCell) -> Result<(), Error> {
+    let (index, source) = origin.resolve()?;
+    let script = cell_source().load_cell_type(index, source)?.ok_or(Error::MissingTypeScript)?;
+
+    if script.as_reader().code_hash().raw_data() != config.bond_sudt_typescript_codehash
+        || script.as_reader().hash_type().as_slice()[0] != config.bond_sudt_typescript_hashtype as u8
+    {
+        return Err(Error::SidechainBondTokenMismatch);
+    }
+
+    Ok(())
+}
+
+// the bond token moves between both cells, which thus carry the very same type script
+pub fn check_same_type_script(origin: CellOrigin, other: CellOrigin) -> Result<(), Error> {
+    let (index, source) = origin.resolve()?;
+    let (other_index, other_source) = other.resolve()?;
+    let script = cell_source().load_cell_type(index, source)?.ok_or(Error::MissingTypeScript)?;
+    let other_script = cell_source()
+        .load_cell_type(other_index, other_source)?
+        .ok_or(Error::MissingTypeScript)?;
+
+    if script.as_slice() != other_script.as_slice() {
+        return Err(Error::SidechainBondTokenMismatch);
+    }
+
+    Ok(())
+}
+
+/*
+handlers compare the data and args they decode, continuity covers the rest of a cell carried through a transition,
+the capacity may be topped up as the data grows but never taken, and the lock stays the very same script
+ */
+pub fn check_continuity(input: CellOrigin, output: CellOrigin) -> Result<(), Error> {
+    check_capacity_continuity(input, output)?;
+
+    let (input_index, input_source) = input.resolve()?;
+    let (output_index, output_source) = output.resolve()?;
+    let input_lock = cell_source().load_cell_lock(input_index, input_source)?;
+    let output_lock = cell_source().load_cell_lock(output_index, output_source)?;
+    if input_lock.as_slice() != output_lock.as_slice() {
+        return Err(Error::LockScriptMismatch);
+    }
+
+    Ok(())
+}
+
+// for cells whose lock args are rewritten by the transition, the args are then up to the handler
+pub fn check_capacity_continuity(input: CellOrigin, output: CellOrigin) -> Result<(), Error> {
+    let (input_index, input_source) = input.resolve()?;
+    let (output_index, output_source) = output.resolve()?;
+    let input_capacity = cell_source().load_cell_capacity(input_index, input_source)?;
+    let output_capacity = cell_source().load_cell_capacity(output_index, output_source)?;
+    if output_capacity < input_capacity {
+        return Err(Error::CapacityDecreased);
+    }
+
+    Ok(())
+}
+
+#[macro_export]
+macro_rules! check_continuity {
+    ($($input: expr => $output: expr), * $(,)?) => {
+        $(check_continuity($input, $output)?;)*
+    }
+}
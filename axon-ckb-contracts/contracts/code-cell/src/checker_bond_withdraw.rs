@@ -1,18 +1,22 @@
 use ckb_std::ckb_constants::Source;
 
-use common_raw::cell::{
-    checker_bond::{CheckerBondCell, CheckerBondCellLockArgs},
-    code::CodeCell,
-    muse_token::MuseTokenCell,
+use common_raw::{
+    cell::{
+        checker_bond::{CheckerBondCell, CheckerBondCellLockArgs},
+        code::CodeCell,
+        muse_token::MuseTokenCell,
+    },
+    common::Identity,
+    layout::checker_bond_withdraw as layout,
 };
 
 use crate::{cell::*, common::*, error::Error};
 
-const BOND_INPUT: CellOrigin = CellOrigin(1, Source::Input);
+const BOND_INPUT: CellOrigin = CellOrigin(layout::BOND_INPUT, Source::Input);
 
-const TOKEN_OUTPUT: CellOrigin = CellOrigin(1, Source::Output);
+const TOKEN_OUTPUT: CellOrigin = CellOrigin(layout::TOKEN_OUTPUT, Source::Output);
 
-pub fn checker_bond_withdraw(signer: [u8; 20]) -> Result<(), Error> {
+pub fn checker_bond_withdraw(signer: Identity) -> Result<(), Error> {
     /*
     CheckerBondWithdraw
 
@@ -44,7 +48,7 @@ pub fn checker_bond_withdraw(signer: [u8; 20]) -> Result<(), Error> {
 fn is_checker_bond_withdraw() -> Result<(), Error> {
     let global = check_global_cell()?;
 
-    if is_cell_count_not_equals(2, Source::Input) || is_cell_count_not_equals(2, Source::Output) {
+    if is_cell_count_not_equals(layout::INPUT_COUNT, Source::Input) || is_cell_count_not_equals(layout::OUTPUT_COUNT, Source::Output) {
         return Err(Error::CellNumberMismatch);
     }
 
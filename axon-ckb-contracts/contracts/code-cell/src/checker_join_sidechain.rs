@@ -7,22 +7,24 @@ use common_raw::{
         checker_bond::{CheckerBondCell, CheckerBondCellLockArgs},
         checker_info::{CheckerInfoCell, CheckerInfoCellTypeArgs, CheckerInfoStatus},
         code::CodeCell,
-        sidechain_config::{SidechainConfigCell, SidechainConfigCellTypeArgs},
+        sidechain_config::{CheckerRecord, CheckerStatus, SidechainConfigCell, SidechainConfigCellTypeArgs},
     },
+    common::{Identity, RATE_DENOMINATOR},
+    layout::checker_join_sidechain as layout,
     witness::checker_join_sidechain::CheckerJoinSidechainWitness,
     FromRaw,
 };
 
-use crate::{cell::*, common::*, error::Error};
+use crate::{cell::*, checker_set::update_checker_record, common::*, error::Error};
 
-const CONFIG_INPUT: CellOrigin = CellOrigin(1, Source::Input);
-const CHECKER_BOND_INPUT: CellOrigin = CellOrigin(2, Source::Input);
+const CONFIG_INPUT: CellOrigin = CellOrigin(layout::CONFIG_INPUT, Source::Input);
+const CHECKER_BOND_INPUT: CellOrigin = CellOrigin(layout::CHECKER_BOND_INPUT, Source::Input);
 
-const CONFIG_OUTPUT: CellOrigin = CellOrigin(1, Source::Output);
-const CHECKER_BOND_OUTPUT: CellOrigin = CellOrigin(2, Source::Output);
-const CHECKER_INFO_OUTPUT: CellOrigin = CellOrigin(3, Source::Output);
+const CONFIG_OUTPUT: CellOrigin = CellOrigin(layout::CONFIG_OUTPUT, Source::Output);
+const CHECKER_BOND_OUTPUT: CellOrigin = CellOrigin(layout::CHECKER_BOND_OUTPUT, Source::Output);
+const CHECKER_INFO_OUTPUT: CellOrigin = CellOrigin(layout::CHECKER_INFO_OUTPUT, Source::Output);
 
-pub fn checker_join_sidechain(raw_witness: &[u8], signer: [u8; 20]) -> Result<(), Error> {
+pub fn checker_join_sidechain(raw_witness: &[u8], signer: Identity) -> Result<(), Error> {
     /*
     CheckerJoinSidechain,
 
@@ -36,7 +38,7 @@ pub fn checker_join_sidechain(raw_witness: &[u8], signer: [u8; 20]) -> Result<()
     */
     is_checker_join_sidechain()?;
 
-    let witness = CheckerJoinSidechainWitness::from_raw(raw_witness).ok_or(Error::Encoding)?;
+    let witness = CheckerJoinSidechainWitness::from_raw(raw_witness).or(Err(Error::WitnessEncoding))?;
 
     let (config_input_type_args, config_input, checker_bond_input_lock_args, checker_bond_input) = load_entities! {
         SidechainConfigCellTypeArgs: CONFIG_INPUT,
@@ -64,7 +66,17 @@ pub fn checker_join_sidechain(raw_witness: &[u8], signer: [u8; 20]) -> Result<()
     let mut config_res = config_input.clone();
     config_res.checker_total_count += 1;
     config_res.checker_normal_count += 1;
-    config_res.activated_checkers.push(signer);
+    // a checker joins once, a jailed one stays out
+    update_checker_record(&mut config_res, &signer, |record| match record {
+        Some(_) => Err(Error::SidechainConfigMismatch),
+        None => {
+            *record = Some(CheckerRecord {
+                status: CheckerStatus::Activated,
+                weight: checker_bond_input.amount,
+            });
+            Ok(())
+        }
+    })?;
 
     if config_input_type_args != config_output_type_args
         || config_res != config_output
@@ -97,11 +109,14 @@ pub fn checker_join_sidechain(raw_witness: &[u8], signer: [u8; 20]) -> Result<()
     let mut checker_info_res = checker_info_output.clone();
     checker_info_res.unpaid_fee = 0;
     checker_info_res.status = CheckerInfoStatus::Relaying;
+    checker_info_res.bonded_amount = checker_bond_input.amount;
+    checker_info_res.delegated_amount = 0;
 
     let mut checker_info_res_type_args = checker_info_output_type_args.clone();
     checker_info_res_type_args.checker_lock_arg = signer;
 
     if checker_info_res != checker_info_output
+        || checker_info_res.commission_rate > RATE_DENOMINATOR
         || checker_info_res_type_args != checker_info_output_type_args
         || checker_info_res_type_args.chain_id != witness.chain_id
     {
@@ -114,7 +129,7 @@ pub fn checker_join_sidechain(raw_witness: &[u8], signer: [u8; 20]) -> Result<()
 fn is_checker_join_sidechain() -> Result<(), Error> {
     let global = check_global_cell()?;
 
-    if is_cell_count_not_equals(3, Source::Input) || is_cell_count_not_equals(4, Source::Output) {
+    if is_cell_count_not_equals(layout::INPUT_COUNT, Source::Input) || is_cell_count_not_equals(layout::OUTPUT_COUNT, Source::Output) {
         return Err(Error::CellNumberMismatch);
     }
 
@@ -132,5 +147,10 @@ fn is_checker_join_sidechain() -> Result<(), Error> {
         },
     };
 
+    check_continuity! {
+        CONFIG_INPUT => CONFIG_OUTPUT,
+    };
+    check_capacity_continuity(CHECKER_BOND_INPUT, CHECKER_BOND_OUTPUT)?;
+
     Ok(())
 }
@@ -0,0 +1,88 @@
+use ckb_std::ckb_constants::Source;
+
+use common_raw::{
+    cell::{
+        code::CodeCell,
+        proposal::{ProposalCell, ProposalCellTypeArgs},
+        sidechain_config::{SidechainConfigCell, SidechainConfigCellTypeArgs, SidechainStatus},
+    },
+    common::Identity,
+    layout::checker_propose_change as layout,
+    witness::checker_propose_change::CheckerProposeChangeWitness,
+    FromRaw,
+};
+
+use crate::{cell::*, checker_set::is_activated, common::*, error::Error};
+
+const SIDECHAIN_CONFIG_DEP: CellOrigin = CellOrigin(layout::SIDECHAIN_CONFIG_DEP, Source::CellDep);
+
+const PROPOSAL_OUTPUT: CellOrigin = CellOrigin(layout::PROPOSAL_OUTPUT, Source::Output);
+
+pub fn checker_propose_change(raw_witness: &[u8], signer: Identity) -> Result<(), Error> {
+    /*
+    CheckerProposeChange,
+
+    Dep:    0 Global Config Cell
+    Dep:    5 Sidechain Config Cell
+
+    Code Cell                   ->          Code Cell
+    Null                        ->          Proposal Cell
+
+    */
+    is_checker_propose_change()?;
+
+    let witness = CheckerProposeChangeWitness::from_raw(raw_witness).or(Err(Error::WitnessEncoding))?;
+
+    let (config_dep_type_args, config_dep, proposal_output_type_args, proposal_output) = load_entities!(
+        SidechainConfigCellTypeArgs: SIDECHAIN_CONFIG_DEP,
+        SidechainConfigCell: SIDECHAIN_CONFIG_DEP,
+        ProposalCellTypeArgs: PROPOSAL_OUTPUT,
+        ProposalCell: PROPOSAL_OUTPUT,
+    );
+
+    if config_dep_type_args.chain_id != witness.chain_id || config_dep.sidechain_status != SidechainStatus::Relaying {
+        return Err(Error::SidechainConfigMismatch);
+    }
+
+    if !is_activated(&config_dep, &signer)? {
+        return Err(Error::CheckerNotActivated);
+    }
+
+    // the proposer votes for its own proposal, made against the current config
+    let mut proposal_res = proposal_output.clone();
+    proposal_res.config_nonce = config_dep.config_nonce;
+    proposal_res.votes = [signer].to_vec();
+
+    let proposal_res_type_args = ProposalCellTypeArgs {
+        chain_id:          witness.chain_id,
+        proposer_lock_arg: signer,
+    };
+
+    if proposal_res != proposal_output || proposal_res_type_args != proposal_output_type_args {
+        return Err(Error::ProposalMismatch);
+    }
+
+    Ok(())
+}
+
+fn is_checker_propose_change() -> Result<(), Error> {
+    let global = check_global_cell()?;
+
+    if is_cell_count_not_equals(layout::INPUT_COUNT, Source::Input) || is_cell_count_not_equals(layout::OUTPUT_COUNT, Source::Output) {
+        return Err(Error::CellNumberMismatch);
+    }
+
+    check_cells! {
+        &global,
+        {
+            SidechainConfigCell: SIDECHAIN_CONFIG_DEP,
+
+            CodeCell: CODE_INPUT,
+
+            CodeCell: CODE_OUTPUT,
+            ProposalCell: PROPOSAL_OUTPUT,
+        },
+    };
+
+    Ok(())
+}
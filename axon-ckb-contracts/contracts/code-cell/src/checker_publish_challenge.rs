@@ -7,35 +7,41 @@ use common_raw::{
         checker_info::{CheckerInfoCell, CheckerInfoCellTypeArgs},
         code::CodeCell,
         sidechain_config::{SidechainConfigCell, SidechainConfigCellTypeArgs},
-        sidechain_state::{CheckerLastAcceptTaskHeight, SidechainStateCell, SidechainStateCellTypeArgs},
+        sidechain_state::{SidechainStateCell, SidechainStateCellTypeArgs},
         task::{TaskCell, TaskCellTypeArgs, TaskMode, TaskStatus},
     },
+    common::{BlockSlice, Identity},
+    layout::checker_publish_challenge as layout,
+    seed::{derive_seed, SeedPurpose},
     witness::checker_publish_challenge::CheckerPublishChallengeWitness,
     FromRaw,
 };
 
-use crate::{cell::*, common::*, error::Error};
+use crate::{cell::*, checker_set::set_last_task_height, common::*, error::Error, sampling::CheckerSampler};
 
-const SIDECHAIN_STATE_INPUT: CellOrigin = CellOrigin(1, Source::Input);
-const CHECKER_INFO_INPUT: CellOrigin = CellOrigin(2, Source::Input);
-const FIRST_TASK_INPUT: CellOrigin = CellOrigin(3, Source::Input);
+const SIDECHAIN_STATE_INPUT: CellOrigin = CellOrigin(layout::SIDECHAIN_STATE_INPUT, Source::Input);
+const CHECKER_INFO_INPUT: CellOrigin = CellOrigin(layout::CHECKER_INFO_INPUT, Source::Input);
+const FIRST_TASK_INPUT: CellOrigin = CellOrigin(layout::FIRST_TASK_INPUT, Source::Input);
 
-const SIDECHAIN_STATE_OUTPUT: CellOrigin = CellOrigin(1, Source::Output);
-const CHECKER_INFO_OUTPUT: CellOrigin = CellOrigin(2, Source::Output);
-const FIRST_TASK_OUTPUT: CellOrigin = CellOrigin(3, Source::Output);
-pub fn checker_publish_challenge(raw_witness: &[u8], signer: [u8; 20]) -> Result<(), Error> {
+const SIDECHAIN_STATE_OUTPUT: CellOrigin = CellOrigin(layout::SIDECHAIN_STATE_OUTPUT, Source::Output);
+const CHECKER_INFO_OUTPUT: CellOrigin = CellOrigin(layout::CHECKER_INFO_OUTPUT, Source::Output);
+const FIRST_TASK_OUTPUT: CellOrigin = CellOrigin(layout::FIRST_TASK_OUTPUT, Source::Output);
+pub fn checker_publish_challenge(raw_witness: &[u8], signer: Identity) -> Result<(), Error> {
     /*
     CheckerPublishChallenge,
 
     Dep:    0 Global Config Cell
-    Dep:    1 Sidechain Config Cell
+    Dep:    sidechain_config_dep_index Sidechain Config Cell
 
     Code Cell                   ->         Code Cell
-    Task Cell                   ->          [Task Cell]
+    Sidechain State Cell        ->          Sidechain State Cell
+    Checker Info Cell           ->          Checker Info Cell
+    Task Cell                   ->          Task Cell
+    Null                        ->          [Task Cell]
 
     */
 
-    let witness = CheckerPublishChallengeWitness::from_raw(raw_witness).ok_or(Error::Encoding)?;
+    let witness = CheckerPublishChallengeWitness::from_raw(raw_witness).or(Err(Error::WitnessEncoding))?;
 
     is_checker_publish_challenge(&witness)?;
 
@@ -97,13 +103,16 @@ pub fn checker_publish_challenge(raw_witness: &[u8], signer: [u8; 20]) -> Result
 
     task_res.status = TaskStatus::Idle;
 
-    let output_count = usize::try_from(witness.challenge_count).or(Err(Error::Encoding))? + 3;
+    let output_count = usize::try_from(witness.challenge_count).or(Err(Error::Encoding))? + layout::FIXED_OUTPUT_CELLS;
     // 2 + challenge_count - 1  * Since this checker already voted
 
     let mut state_res = state_input.clone();
 
     let mut checker_info_res = checker_info_input.clone();
-    checker_info_res.unpaid_fee += task_res.check_data_size * u128::try_from(config_dep.check_fee_rate).or(Err(Error::Encoding))?;
+    checker_info_res.unpaid_fee = u128::from(config_dep.check_fee_rate)
+        .checked_mul(task_res.check_data_size)
+        .and_then(|fee| checker_info_res.unpaid_fee.checked_add(fee))
+        .ok_or(Error::CheckerInfoMismatch)?;
     if checker_info_res != checker_info_output
         || checker_info_input_type_args != checker_info_output_type_args
         || checker_info_input_type_args.checker_lock_arg != first_task_input_type_args.checker_lock_arg
@@ -112,15 +121,21 @@ pub fn checker_publish_challenge(raw_witness: &[u8], signer: [u8; 20]) -> Result
         return Err(Error::CheckerInfoMismatch);
     }
 
-    let mut seed = state_input.random_seed;
-    seed[0] += state_input.random_offset;
-    for i in 4..output_count {
-        seed = Blake2b::calculate(&seed);
-        let seed_number = u128::from_raw(&seed[0..16]).ok_or(Error::Encoding)?;
-
-        let checkers_count = u128::try_from(config_dep.activated_checkers.len()).or(Err(Error::Encoding))?;
-        let index = usize::try_from(seed_number % checkers_count).or(Err(Error::Encoding))?;
-        let checker_lock_arg = config_dep.activated_checkers.get(index).ok_or(Error::Encoding)?;
+    let seed = derive_seed::<Blake2b>(
+        &state_input.random_seed,
+        state_input.random_offset,
+        witness.chain_id,
+        SeedPurpose::PublishChallenge,
+        &BlockSlice {
+            from: task_res.sidechain_block_height_from,
+            to:   task_res.sidechain_block_height_to,
+        },
+    );
+    // the challenger has checked the job already
+    let mut sampler = CheckerSampler::new(&config_dep, seed)?;
+    sampler.exclude(&signer)?;
+    for i in (layout::FIRST_TASK_OUTPUT + 1)..output_count {
+        let checker_lock_arg = &sampler.draw()?;
 
         let (task_output, task_output_type_args) = load_entities! {
             TaskCell: CellOrigin(i, Source::Output),
@@ -133,25 +148,10 @@ pub fn checker_publish_challenge(raw_witness: &[u8], signer: [u8; 20]) -> Result
             return Err(Error::TaskMismatch);
         }
 
-        match state_res
-            .checker_last_task_sidechain_heights
-            .iter_mut()
-            .find(|info| &info.checker_lock_arg == checker_lock_arg)
-        {
-            Some(info) => {
-                info.height = task_res.sidechain_block_height_to;
-            }
-            None => {
-                state_res.checker_last_task_sidechain_heights.push(CheckerLastAcceptTaskHeight {
-                    checker_lock_arg: *checker_lock_arg,
-                    height:           task_res.sidechain_block_height_to,
-                });
-                ckb_std::debug!("-----");
-            }
-        }
+        set_last_task_height(&config_dep, &mut state_res, checker_lock_arg, task_res.sidechain_block_height_to)?;
     }
 
-    state_res.random_offset += 1;
+    state_res.random_offset = state_res.random_offset.checked_add(1).ok_or(Error::SidechainStateMismatch)?;
 
     if state_res != state_output || state_input_type_args.chain_id != witness.chain_id || state_input_type_args != state_output_type_args {
         ckb_std::debug!("{:?}, {:?}", state_res, state_output);
@@ -162,12 +162,12 @@ pub fn checker_publish_challenge(raw_witness: &[u8], signer: [u8; 20]) -> Result
 }
 
 fn is_checker_publish_challenge(witness: &CheckerPublishChallengeWitness) -> Result<(), Error> {
-    let output_count = usize::try_from(witness.challenge_count).or(Err(Error::Encoding))? + 3;
+    let output_count = usize::try_from(witness.challenge_count).or(Err(Error::Encoding))? + layout::FIXED_OUTPUT_CELLS;
     // 2 + challenge_count - 1  * Since this checker already voted
 
     let global = check_global_cell()?;
 
-    if is_cell_count_not_equals(4, Source::Input) || is_cell_count_not_equals(output_count, Source::Output) {
+    if is_cell_count_not_equals(layout::INPUT_COUNT, Source::Input) || is_cell_count_not_equals(output_count, Source::Output) {
         return Err(Error::CellNumberMismatch);
     }
 
@@ -185,5 +185,10 @@ fn is_checker_publish_challenge(witness: &CheckerPublishChallengeWitness) -> Res
         },
     };
 
-    TaskCell::range_check(3..output_count, Source::Output, &global)
+    check_continuity! {
+        SIDECHAIN_STATE_INPUT => SIDECHAIN_STATE_OUTPUT,
+        FIRST_TASK_INPUT => FIRST_TASK_OUTPUT,
+    };
+
+    TaskCell::range_check(layout::FIXED_OUTPUT_CELLS..output_count, Source::Output, &global)
 }
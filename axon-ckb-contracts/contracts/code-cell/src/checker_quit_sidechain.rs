@@ -10,27 +10,30 @@ use common_raw::{
         sidechain_config::{SidechainConfigCell, SidechainConfigCellTypeArgs, SidechainStatus},
         sidechain_state::SidechainStateCell,
     },
+    common::{Identity, RATE_DENOMINATOR},
+    layout::checker_quit_sidechain as layout,
     witness::checker_quit_sidechain::CheckerQuitSidechainWitness,
     FromRaw,
 };
 
-use crate::{cell::*, common::*, error::Error};
+use crate::{cell::*, checker_set::is_punished, common::*, error::Error, jail::remove_checker};
 
-const STATE_DEP: CellOrigin = CellOrigin(5, Source::CellDep);
+const STATE_DEP: CellOrigin = CellOrigin(layout::STATE_DEP, Source::CellDep);
 
-const CONFIG_INPUT: CellOrigin = CellOrigin(1, Source::Input);
-const CHECKER_BOND_INPUT: CellOrigin = CellOrigin(2, Source::Input);
-const CHECKER_INFO_INPUT: CellOrigin = CellOrigin(3, Source::Input);
+const CONFIG_INPUT: CellOrigin = CellOrigin(layout::CONFIG_INPUT, Source::Input);
+const CHECKER_BOND_INPUT: CellOrigin = CellOrigin(layout::CHECKER_BOND_INPUT, Source::Input);
+const CHECKER_INFO_INPUT: CellOrigin = CellOrigin(layout::CHECKER_INFO_INPUT, Source::Input);
 
-const CONFIG_OUTPUT: CellOrigin = CellOrigin(1, Source::Output);
-const CHECKER_BOND_OUTPUT: CellOrigin = CellOrigin(2, Source::Output);
-const CHECKER_INFO_OUTPUT: CellOrigin = CellOrigin(3, Source::Output);
+const CONFIG_OUTPUT: CellOrigin = CellOrigin(layout::CONFIG_OUTPUT, Source::Output);
+const CHECKER_BOND_OUTPUT: CellOrigin = CellOrigin(layout::CHECKER_BOND_OUTPUT, Source::Output);
+const CHECKER_INFO_OUTPUT: CellOrigin = CellOrigin(layout::CHECKER_INFO_OUTPUT, Source::Output);
 
-pub fn checker_quit_sidechain(raw_witness: &[u8], signer: [u8; 20]) -> Result<(), Error> {
+pub fn checker_quit_sidechain(raw_witness: &[u8], signer: Identity) -> Result<(), Error> {
     /*
     CheckerQuitSidechain
 
     Dep:    0 Global Config Cell
+    Dep:    5 Sidechain State Cell
 
     Sidechain Config Cell       ->          Sidechain Config Cell
     Checker Bond Cell           ->          Checker Bond Cell
@@ -38,9 +41,17 @@ pub fn checker_quit_sidechain(raw_witness: &[u8], signer: [u8; 20]) -> Result<()
 
     */
 
+    /*
+    Job:
+
+    1. the checker leaves the sidechain and its bond no longer participates in it
+    2. a jailed checker leaves once all delegations to it are claimed, with its bond slashed by slash_rate
+
+     */
+
     is_checker_quit_sidechain()?;
 
-    let witness = CheckerQuitSidechainWitness::from_raw(raw_witness).ok_or(Error::Encoding)?;
+    let witness = CheckerQuitSidechainWitness::from_raw(raw_witness).or(Err(Error::WitnessEncoding))?;
     let wit_chain_id = u32::try_from(witness.chain_id).or(Err(Error::Encoding))?;
     let (state_dep, state_dep_type_args) = load_entities!(SidechainStateCell: STATE_DEP, SidechainConfigCellTypeArgs: STATE_DEP,);
     let (
@@ -75,19 +86,21 @@ pub fn checker_quit_sidechain(raw_witness: &[u8], signer: [u8; 20]) -> Result<()
     };
 
     let mut config_res = config_input.clone();
-    let index = config_res
-        .activated_checkers
-        .iter()
-        .position(|checker_lock_arg| *checker_lock_arg == signer)
-        .ok_or(Error::SidechainConfigMismatch)?;
+    let record = remove_checker(&mut config_res, &signer)?;
 
-    if config_res.sidechain_status == SidechainStatus::Shutdown {
-        return Ok(());
+    if !record.is_activated() && checker_info_input.delegated_amount != 0 {
+        // delegations to a jailed checker are slashed when claimed, it leaves once they all are
+        return Err(Error::JailedCheckerDelegated);
     }
 
-    config_res.checker_total_count -= 1;
-    config_res.checker_normal_count -= 1;
-    config_res.activated_checkers.remove(index);
+    // a shut down sidechain keeps its counts and punishes no one, its checkers just leave
+    let is_shutdown = config_res.sidechain_status == SidechainStatus::Shutdown;
+    if !is_shutdown {
+        config_res.checker_total_count -= 1;
+        if record.is_activated() {
+            config_res.checker_normal_count -= 1;
+        }
+    }
     if config_res != config_output
         || config_input_type_args != config_output_type_args
         || config_output_type_args.chain_id != witness.chain_id
@@ -95,12 +108,7 @@ pub fn checker_quit_sidechain(raw_witness: &[u8], signer: [u8; 20]) -> Result<()
         return Err(Error::SidechainConfigMismatch);
     }
 
-    if state_dep
-        .punish_checkers
-        .iter()
-        .find(|checker| checker.checker_lock_arg == signer)
-        .is_some()
-    {
+    if !is_shutdown && is_punished(&config_input, &state_dep, &signer)? {
         return Err(Error::SidechainStateMismatch);
     }
 
@@ -116,9 +124,21 @@ pub fn checker_quit_sidechain(raw_witness: &[u8], signer: [u8; 20]) -> Result<()
         .ok_or(Error::CheckerBondMismatch)?;
     checker_bond_res_lock_args.participated_chain_id.remove(index);
 
+    // a jailed checker forfeits the same share of its bond as its delegators do
+    let mut checker_bond_res = checker_bond_input.clone();
+    if !record.is_activated() {
+        let slashed = pro_rata(
+            checker_bond_input.amount,
+            u128::from(config_input.slash_rate),
+            u128::from(RATE_DENOMINATOR),
+        )
+        .ok_or(Error::Encoding)?;
+        checker_bond_res.amount = checker_bond_res.amount.checked_sub(slashed).ok_or(Error::CheckerBondMismatch)?;
+    }
+
     if checker_bond_res_lock_args != checker_bond_output_lock_args
         || checker_bond_input_lock_args.checker_lock_arg != signer
-        || checker_bond_input != checker_bond_output
+        || checker_bond_res != checker_bond_output
     {
         return Err(Error::CheckerBondMismatch);
     }
@@ -140,7 +160,7 @@ pub fn checker_quit_sidechain(raw_witness: &[u8], signer: [u8; 20]) -> Result<()
 fn is_checker_quit_sidechain() -> Result<(), Error> {
     let global = check_global_cell()?;
 
-    if is_cell_count_not_equals(4, Source::Input) || is_cell_count_not_equals(4, Source::Output) {
+    if is_cell_count_not_equals(layout::INPUT_COUNT, Source::Input) || is_cell_count_not_equals(layout::OUTPUT_COUNT, Source::Output) {
         return Err(Error::CellNumberMismatch);
     }
 
@@ -161,5 +181,11 @@ fn is_checker_quit_sidechain() -> Result<(), Error> {
         },
     };
 
+    check_continuity! {
+        CONFIG_INPUT => CONFIG_OUTPUT,
+        CHECKER_INFO_INPUT => CHECKER_INFO_OUTPUT,
+    };
+    check_capacity_continuity(CHECKER_BOND_INPUT, CHECKER_BOND_OUTPUT)?;
+
     Ok(())
 }
@@ -0,0 +1,362 @@
+use core::mem;
+
+use common_raw::{
+    cell::{
+        sidechain_config::{CheckerRecord, CheckerStatus, SidechainConfigCell},
+        sidechain_state::{CheckerLastAcceptTaskHeight, CheckerTaskRecord, PunishedChecker, SidechainStateCell},
+    },
+    common::{BlockHeight, Identity, MerkleHash},
+    smt::SmtPath,
+    witness::checker_proofs::{CheckerProofsWitness, CheckerRecordProof, CheckerTaskRecordProof},
+};
+
+use crate::{common::Blake2b, error::Error};
+
+/*
+The checkers of a sidechain are either listed in its config and state cells, or committed to by
+checker_set_root and checker_task_root when the config sets the former. Patterns read and update
+checkers through this module only, which proves every committed record it visits against the root
+with the next proof of the witness, then moves the root to the updated record.
+
+Either way a checker is seen as an Option<CheckerRecord> and a CheckerTaskRecord.
+ */
+
+struct CheckerProofQueue {
+    witness:          CheckerProofsWitness,
+    next_record:      usize,
+    next_task_record: usize,
+}
+
+// like the cell window, the proofs are only set by entry::main around the dispatch
+static mut CHECKER_PROOFS: Option<CheckerProofQueue> = None;
+
+// every proof of the witness has to be taken by the patterns, no proof is left unchecked
+pub fn with_checker_proofs<F: FnOnce() -> Result<(), Error>>(witness: CheckerProofsWitness, f: F) -> Result<(), Error> {
+    struct Reset;
+
+    impl Drop for Reset {
+        fn drop(&mut self) {
+            unsafe { CHECKER_PROOFS = None };
+        }
+    }
+
+    unsafe {
+        CHECKER_PROOFS = Some(CheckerProofQueue {
+            witness,
+            next_record: 0,
+            next_task_record: 0,
+        })
+    };
+    let _reset = Reset;
+
+    f()?;
+
+    match unsafe { CHECKER_PROOFS.as_ref() } {
+        Some(queue)
+            if queue.next_record < queue.witness.record_proofs.len() || queue.next_task_record < queue.witness.task_record_proofs.len() =>
+        {
+            Err(Error::CheckerProofUnused)
+        }
+        _ => Ok(()),
+    }
+}
+
+fn next_record_proof() -> Result<CheckerRecordProof, Error> {
+    let queue = unsafe { CHECKER_PROOFS.as_mut() }.ok_or(Error::CheckerProofMissing)?;
+    let proof = queue
+        .witness
+        .record_proofs
+        .get_mut(queue.next_record)
+        .ok_or(Error::CheckerProofMissing)?;
+    queue.next_record += 1;
+
+    Ok(mem::take(proof))
+}
+
+fn next_task_record_proof() -> Result<CheckerTaskRecordProof, Error> {
+    let queue = unsafe { CHECKER_PROOFS.as_mut() }.ok_or(Error::CheckerProofMissing)?;
+    let proof = queue
+        .witness
+        .task_record_proofs
+        .get_mut(queue.next_task_record)
+        .ok_or(Error::CheckerProofMissing)?;
+    queue.next_task_record += 1;
+
+    Ok(mem::take(proof))
+}
+
+/*
+the next record proof checked against root, whichever checker it proves,
+the path tells the total weight of the set and where the checker lies in it, see CheckerSampler
+ */
+pub fn next_proven_record(root: &MerkleHash) -> Result<(CheckerRecordProof, SmtPath), Error> {
+    let proof = next_record_proof()?;
+    let leaf = CheckerRecord::leaf::<Blake2b>(proof.record.as_ref(), &proof.checker);
+    let path = proof
+        .proof
+        .compute::<Blake2b>(&proof.checker, &leaf)
+        .ok_or(Error::CheckerProofMismatch)?;
+
+    if path.root.hash != *root {
+        return Err(Error::CheckerProofMismatch);
+    }
+
+    Ok((proof, path))
+}
+
+fn proven_record(root: &MerkleHash, checker: &Identity) -> Result<CheckerRecordProof, Error> {
+    let (proof, _) = next_proven_record(root)?;
+    if proof.checker != *checker {
+        return Err(Error::CheckerProofMismatch);
+    }
+
+    Ok(proof)
+}
+
+fn proven_task_record(root: &MerkleHash, checker: &Identity) -> Result<CheckerTaskRecordProof, Error> {
+    let proof = next_task_record_proof()?;
+    let leaf = proof.record.leaf::<Blake2b>(&proof.checker);
+    let path = proof
+        .proof
+        .compute::<Blake2b>(&proof.checker, &leaf)
+        .ok_or(Error::CheckerProofMismatch)?;
+
+    if proof.checker != *checker || path.root.hash != *root {
+        return Err(Error::CheckerProofMismatch);
+    }
+
+    Ok(proof)
+}
+
+pub fn checker_record(config: &SidechainConfigCell, checker: &Identity) -> Result<Option<CheckerRecord>, Error> {
+    match &config.checker_set_root {
+        Some(root) => Ok(proven_record(root, checker)?.record),
+        None => listed_record(config, checker),
+    }
+}
+
+pub fn is_activated(config: &SidechainConfigCell, checker: &Identity) -> Result<bool, Error> {
+    Ok(checker_record(config, checker)?.map_or(false, |record| record.is_activated()))
+}
+
+// f gets the record of checker to update, None to remove the checker from the set
+pub fn update_checker_record<T, F>(config: &mut SidechainConfigCell, checker: &Identity, f: F) -> Result<T, Error>
+where
+    F: FnOnce(&mut Option<CheckerRecord>) -> Result<T, Error>,
+{
+    match config.checker_set_root {
+        Some(root) => {
+            let proof = proven_record(&root, checker)?;
+
+            let mut record = proof.record;
+            let res = f(&mut record)?;
+
+            // the proof is already checked, only the total weight could overflow
+            let leaf = CheckerRecord::leaf::<Blake2b>(record.as_ref(), checker);
+            let path = proof
+                .proof
+                .compute::<Blake2b>(checker, &leaf)
+                .ok_or(Error::CheckerWeightsMismatch)?;
+            config.checker_set_root = Some(path.root.hash);
+
+            Ok(res)
+        }
+        None => {
+            let listed = listed_record(config, checker)?;
+
+            let mut record = listed;
+            let res = f(&mut record)?;
+            update_listed_record(config, checker, listed, record)?;
+
+            Ok(res)
+        }
+    }
+}
+
+/*
+Punish points and task heights of a listed sidechain live in two lists of the state cell, sorted by
+lock arg, an entry is only added for a checker once it is punished or given a task.
+ */
+pub fn is_punished(config: &SidechainConfigCell, state: &SidechainStateCell, checker: &Identity) -> Result<bool, Error> {
+    match config.checker_set_root {
+        Some(_) => Ok(proven_task_record(&state.checker_task_root, checker)?.record.punish_points != 0),
+        None => Ok(state.punished_checker_index(checker).is_ok()),
+    }
+}
+
+// adds points to the punish points of checker, returns the new total unless it is its first
+// punishment
+pub fn punish_checker(
+    config: &SidechainConfigCell,
+    state: &mut SidechainStateCell,
+    checker: &Identity,
+    points: u32,
+) -> Result<Option<u32>, Error> {
+    if config.checker_set_root.is_some() {
+        return update_committed_task_record(state, checker, |record| {
+            let punished = record.punish_points != 0;
+            record.punish_points = record.punish_points.checked_add(points).ok_or(Error::SidechainStateMismatch)?;
+            Ok(if punished { Some(record.punish_points) } else { None })
+        });
+    }
+
+    match state.punished_checker_index(checker) {
+        Ok(index) => {
+            let punished = &mut state.punish_checkers[index];
+            punished.punish_points = punished.punish_points.checked_add(points).ok_or(Error::SidechainStateMismatch)?;
+            Ok(Some(punished.punish_points))
+        }
+        Err(index) => {
+            state.punish_checkers.insert(index, PunishedChecker {
+                checker_lock_arg: *checker,
+                punish_points:    points,
+            });
+            Ok(None)
+        }
+    }
+}
+
+// the last sidechain height checker was given a task for
+pub fn set_last_task_height(
+    config: &SidechainConfigCell,
+    state: &mut SidechainStateCell,
+    checker: &Identity,
+    height: BlockHeight,
+) -> Result<(), Error> {
+    update_last_task_height(config, state, checker, |_| height)
+}
+
+// as set_last_task_height, but a lower height leaves the last one
+pub fn raise_last_task_height(
+    config: &SidechainConfigCell,
+    state: &mut SidechainStateCell,
+    checker: &Identity,
+    height: BlockHeight,
+) -> Result<(), Error> {
+    update_last_task_height(config, state, checker, |last_height| last_height.max(height))
+}
+
+fn update_last_task_height<F: FnOnce(BlockHeight) -> BlockHeight>(
+    config: &SidechainConfigCell,
+    state: &mut SidechainStateCell,
+    checker: &Identity,
+    f: F,
+) -> Result<(), Error> {
+    if config.checker_set_root.is_some() {
+        return update_committed_task_record(state, checker, |record| {
+            record.last_task_height = f(record.last_task_height);
+            Ok(())
+        });
+    }
+
+    match state.last_task_height_index(checker) {
+        Ok(index) => {
+            let last_height = &mut state.checker_last_task_sidechain_heights[index];
+            last_height.height = f(last_height.height);
+        }
+        Err(index) => state
+            .checker_last_task_sidechain_heights
+            .insert(index, CheckerLastAcceptTaskHeight {
+                checker_lock_arg: *checker,
+                height:           f(0),
+            }),
+    }
+
+    Ok(())
+}
+
+// drops the punish points and the last task height of checker
+pub fn clear_task_record(config: &SidechainConfigCell, state: &mut SidechainStateCell, checker: &Identity) -> Result<(), Error> {
+    if config.checker_set_root.is_some() {
+        return update_committed_task_record(state, checker, |record| {
+            *record = CheckerTaskRecord::default();
+            Ok(())
+        });
+    }
+
+    if let Ok(index) = state.punished_checker_index(checker) {
+        state.punish_checkers.remove(index);
+    }
+    if let Ok(index) = state.last_task_height_index(checker) {
+        state.checker_last_task_sidechain_heights.remove(index);
+    }
+
+    Ok(())
+}
+
+fn update_committed_task_record<T, F>(state: &mut SidechainStateCell, checker: &Identity, f: F) -> Result<T, Error>
+where
+    F: FnOnce(&mut CheckerTaskRecord) -> Result<T, Error>,
+{
+    let proof = proven_task_record(&state.checker_task_root, checker)?;
+
+    let mut record = proof.record;
+    let res = f(&mut record)?;
+
+    let leaf = record.leaf::<Blake2b>(checker);
+    let path = proof.proof.compute::<Blake2b>(checker, &leaf).ok_or(Error::CheckerProofMismatch)?;
+    state.checker_task_root = path.root.hash;
+
+    Ok(res)
+}
+
+fn listed_record(config: &SidechainConfigCell, checker: &Identity) -> Result<Option<CheckerRecord>, Error> {
+    if let Some(index) = config.checker_index(checker) {
+        let weight = config.checker_weight(index).ok_or(Error::CheckerWeightsMismatch)?;
+        return Ok(Some(CheckerRecord {
+            status: CheckerStatus::Activated,
+            weight,
+        }));
+    }
+
+    if config.is_jailed(checker) {
+        return Ok(Some(CheckerRecord {
+            status: CheckerStatus::Jailed,
+            weight: 0,
+        }));
+    }
+
+    Ok(None)
+}
+
+// an activated checker keeps its place when only its weight changes, otherwise it leaves its list
+// for the new one
+fn update_listed_record(
+    config: &mut SidechainConfigCell,
+    checker: &Identity,
+    listed: Option<CheckerRecord>,
+    record: Option<CheckerRecord>,
+) -> Result<(), Error> {
+    if listed == record {
+        return Ok(());
+    }
+
+    if let (Some(listed), Some(record)) = (listed, record) {
+        if listed.is_activated() && record.is_activated() {
+            return if record.weight > listed.weight {
+                config.increase_checker_weight(checker, record.weight - listed.weight)
+            } else {
+                config.decrease_checker_weight(checker, listed.weight - record.weight)
+            }
+            .ok_or(Error::CheckerWeightsMismatch);
+        }
+    }
+
+    match listed {
+        Some(listed) if listed.is_activated() => {
+            config.deactivate_checker(checker).ok_or(Error::CheckerWeightsMismatch)?;
+        }
+        Some(_) => config.remove_jailed_checker(checker).ok_or(Error::SidechainConfigMismatch)?,
+        None => {}
+    }
+
+    match record {
+        Some(record) if record.is_activated() => config
+            .activate_checker(*checker, record.weight)
+            .ok_or(Error::CheckerWeightsMismatch)?,
+        Some(_) => config.insert_jailed_checker(*checker).ok_or(Error::SidechainConfigMismatch)?,
+        None => {}
+    }
+
+    Ok(())
+}
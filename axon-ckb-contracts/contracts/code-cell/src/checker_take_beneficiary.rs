@@ -4,23 +4,26 @@ use common_raw::{
     cell::{
         checker_info::{CheckerInfoCell, CheckerInfoCellTypeArgs},
         code::CodeCell,
+        delegation::{DelegationCell, DelegationCellLockArgs},
         muse_token::MuseTokenCell,
         sidechain_fee::{SidechainFeeCell, SidechainFeeCellLockArgs},
     },
+    common::{Identity, RATE_DENOMINATOR},
+    layout::checker_take_beneficiary as layout,
     witness::checker_take_beneficiary::CheckerTakeBeneficiaryWitness,
     FromRaw,
 };
 
 use crate::{cell::*, common::*, error::Error};
 
-const CHECKER_INFO_INPUT: CellOrigin = CellOrigin(1, Source::Input);
-const FEE_INPUT: CellOrigin = CellOrigin(2, Source::Input);
+const CHECKER_INFO_INPUT: CellOrigin = CellOrigin(layout::CHECKER_INFO_INPUT, Source::Input);
+const FEE_INPUT: CellOrigin = CellOrigin(layout::FEE_INPUT, Source::Input);
 
-const CHECKER_INFO_OUTPUT: CellOrigin = CellOrigin(1, Source::Output);
-const FEE_OUTPUT: CellOrigin = CellOrigin(2, Source::Output);
-const MUSE_OUTPUT: CellOrigin = CellOrigin(3, Source::Output);
+const CHECKER_INFO_OUTPUT: CellOrigin = CellOrigin(layout::CHECKER_INFO_OUTPUT, Source::Output);
+const FEE_OUTPUT: CellOrigin = CellOrigin(layout::FEE_OUTPUT, Source::Output);
+const MUSE_OUTPUT: CellOrigin = CellOrigin(layout::MUSE_OUTPUT, Source::Output);
 
-pub fn checker_take_beneficiary(raw_witness: &[u8], signer: [u8; 20]) -> Result<(), Error> {
+pub fn checker_take_beneficiary(raw_witness: &[u8], signer: Identity) -> Result<(), Error> {
     /*
     CheckerTakeBeneficiary,
 
@@ -30,11 +33,12 @@ pub fn checker_take_beneficiary(raw_witness: &[u8], signer: [u8; 20]) -> Result<
     Checker Info Cell           ->          Chcker Info Cell
     Sidechain Fee Cell          ->          Sidechain Fee Cell
                                 ->          Muse Token Cell
+    [Delegation Cell]           ->          [Delegation Cell]
     */
 
-    is_checker_take_beneficiary()?;
+    let witness = CheckerTakeBeneficiaryWitness::from_raw(raw_witness).or(Err(Error::WitnessEncoding))?;
 
-    let witness = CheckerTakeBeneficiaryWitness::from_raw(raw_witness).ok_or(Error::Encoding)?;
+    is_checker_take_beneficiary(&witness)?;
 
     let (checker_info_input_type_args, checker_info_input, sidechain_fee_input_lock_args, sidechain_fee_input) = load_entities! {
         CheckerInfoCellTypeArgs: CHECKER_INFO_INPUT,
@@ -77,8 +81,11 @@ pub fn checker_take_beneficiary(raw_witness: &[u8], signer: [u8; 20]) -> Result<
     {
         return Err(Error::SidechainFeeMismatch);
     }
+
+    let delegators_fee = check_delegations(&witness, &checker_info_input, signer)?;
+
     let mut muse_token_res = MuseTokenCell::default();
-    muse_token_res.amount = checker_info_input.unpaid_fee;
+    muse_token_res.amount = checker_info_input.unpaid_fee - delegators_fee;
     if muse_token_res != muse_token_output {
         return Err(Error::MuseTokenMismatch);
     }
@@ -86,10 +93,61 @@ pub fn checker_take_beneficiary(raw_witness: &[u8], signer: [u8; 20]) -> Result<
     Ok(())
 }
 
-fn is_checker_take_beneficiary() -> Result<(), Error> {
+/*
+The fee is split by stake, the delegations get their share of it less the commission of the
+checker, which is added to their delegation cells. Every delegation to the checker has to be
+present, so none is skipped. Returns the fee going to the delegators.
+ */
+fn check_delegations(witness: &CheckerTakeBeneficiaryWitness, checker_info: &CheckerInfoCell, signer: Identity) -> Result<u128, Error> {
+    let stake = checker_info
+        .bonded_amount
+        .checked_add(checker_info.delegated_amount)
+        .ok_or(Error::CheckerInfoMismatch)?;
+
+    let mut delegated_amount = 0u128;
+    let mut delegators_fee = 0u128;
+    for i in 0..usize::from(witness.delegation_count) {
+        let (delegation_input_lock_args, delegation_input, delegation_output_lock_args, delegation_output) = load_entities! {
+            DelegationCellLockArgs: CellOrigin(layout::DELEGATION_INPUTS_START + i, Source::Input),
+            DelegationCell: CellOrigin(layout::DELEGATION_INPUTS_START + i, Source::Input),
+            DelegationCellLockArgs: CellOrigin(layout::DELEGATION_OUTPUTS_START + i, Source::Output),
+            DelegationCell: CellOrigin(layout::DELEGATION_OUTPUTS_START + i, Source::Output),
+        };
+
+        let principal = delegation_input_lock_args.principal;
+        let share = pro_rata(checker_info.unpaid_fee, principal, stake).ok_or(Error::Encoding)?;
+        let commission = pro_rata(share, u128::from(checker_info.commission_rate), u128::from(RATE_DENOMINATOR)).ok_or(Error::Encoding)?;
+        let fee = share.checked_sub(commission).ok_or(Error::CheckerInfoMismatch)?;
+
+        let mut delegation_res = delegation_input.clone();
+        delegation_res.amount = delegation_res.amount.checked_add(fee).ok_or(Error::DelegationMismatch)?;
+
+        if delegation_input_lock_args.chain_id != witness.chain_id
+            || delegation_input_lock_args.checker_lock_arg != signer
+            || delegation_input_lock_args != delegation_output_lock_args
+            || delegation_res != delegation_output
+        {
+            return Err(Error::DelegationMismatch);
+        }
+
+        delegated_amount = delegated_amount.checked_add(principal).ok_or(Error::DelegationsIncomplete)?;
+        delegators_fee += fee;
+    }
+
+    if delegated_amount != checker_info.delegated_amount {
+        return Err(Error::DelegationsIncomplete);
+    }
+
+    Ok(delegators_fee)
+}
+
+fn is_checker_take_beneficiary(witness: &CheckerTakeBeneficiaryWitness) -> Result<(), Error> {
     let global = check_global_cell()?;
 
-    if is_cell_count_not_equals(3, Source::Input) || is_cell_count_not_equals(4, Source::Output) {
+    let delegation_count = usize::from(witness.delegation_count);
+    if is_cell_count_not_equals(layout::INPUT_COUNT + delegation_count, Source::Input)
+        || is_cell_count_not_equals(layout::OUTPUT_COUNT + delegation_count, Source::Output)
+    {
         return Err(Error::CellNumberMismatch);
     }
 
@@ -107,5 +165,23 @@ fn is_checker_take_beneficiary() -> Result<(), Error> {
         },
     };
 
+    check_continuity! {
+        CHECKER_INFO_INPUT => CHECKER_INFO_OUTPUT,
+    };
+    check_capacity_continuity(FEE_INPUT, FEE_OUTPUT)?;
+
+    for i in 0..delegation_count {
+        check_cells! {
+            &global,
+            {
+                DelegationCell: CellOrigin(layout::DELEGATION_INPUTS_START + i, Source::Input),
+                DelegationCell: CellOrigin(layout::DELEGATION_OUTPUTS_START + i, Source::Output),
+            },
+        };
+        check_continuity! {
+            CellOrigin(layout::DELEGATION_INPUTS_START + i, Source::Input) => CellOrigin(layout::DELEGATION_OUTPUTS_START + i, Source::Output),
+        };
+    }
+
     Ok(())
 }
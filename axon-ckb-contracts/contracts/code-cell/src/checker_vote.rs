@@ -7,31 +7,33 @@ use common_raw::{
         sidechain_config::SidechainConfigCell,
         task::{TaskCell, TaskCellTypeArgs, TaskMode, TaskStatus},
     },
+    common::Identity,
+    layout::checker_vote as layout,
     witness::checker_vote::CheckerVoteWitness,
     FromRaw,
 };
 
 use crate::{cell::*, common::*, error::Error};
 
-const CHECKER_INFO_INPUT: CellOrigin = CellOrigin(1, Source::Input);
-const TASK_INPUT: CellOrigin = CellOrigin(2, Source::Input);
+const CHECKER_INFO_INPUT: CellOrigin = CellOrigin(layout::CHECKER_INFO_INPUT, Source::Input);
+const TASK_INPUT: CellOrigin = CellOrigin(layout::TASK_INPUT, Source::Input);
 
-const CHECKER_INFO_OUTPUT: CellOrigin = CellOrigin(1, Source::Output);
-const TASK_OUTPUT: CellOrigin = CellOrigin(2, Source::Output);
+const CHECKER_INFO_OUTPUT: CellOrigin = CellOrigin(layout::CHECKER_INFO_OUTPUT, Source::Output);
+const TASK_OUTPUT: CellOrigin = CellOrigin(layout::TASK_OUTPUT, Source::Output);
 
-pub fn checker_vote(raw_witness: &[u8], signer: [u8; 20]) -> Result<(), Error> {
+pub fn checker_vote(raw_witness: &[u8], signer: Identity) -> Result<(), Error> {
     /*
     CheckerVote,
 
     Dep: 0 Global Config Cell
-    Dep: 1 Sidechain Config Cell
+    Dep: sidechain_config_dep_index Sidechain Config Cell
 
     Code Cell         -> ~
     Checker Info Cell -> ~
     Task Cell         -> ~
     */
 
-    let witness = CheckerVoteWitness::from_raw(raw_witness).ok_or(Error::Encoding)?;
+    let witness = CheckerVoteWitness::from_raw(raw_witness).or(Err(Error::WitnessEncoding))?;
 
     is_checker_vote(&witness)?;
 
@@ -52,7 +54,10 @@ pub fn checker_vote(raw_witness: &[u8], signer: [u8; 20]) -> Result<(), Error> {
     };
 
     let mut checker_info_res = checker_info_input.clone();
-    checker_info_res.unpaid_fee += u128::from(config_dep.check_fee_rate) * task_input.check_data_size;
+    checker_info_res.unpaid_fee = u128::from(config_dep.check_fee_rate)
+        .checked_mul(task_input.check_data_size)
+        .and_then(|fee| checker_info_res.unpaid_fee.checked_add(fee))
+        .ok_or(Error::CheckerInfoMismatch)?;
 
     if checker_info_input_type_args.chain_id != witness.chain_id
         || checker_info_input_type_args.checker_lock_arg != signer
@@ -65,19 +70,19 @@ pub fn checker_vote(raw_witness: &[u8], signer: [u8; 20]) -> Result<(), Error> {
     let mut task_res = task_input.clone();
 
     if task_input.status != TaskStatus::Idle {
-        return Err(Error::TaskMismatch);
+        return Err(Error::TaskAlreadyVoted);
     }
     task_res.status = task_output.status;
 
     match task_input.mode {
         TaskMode::Task => {
             if task_res.status != TaskStatus::TaskPassed {
-                return Err(Error::TaskMismatch);
+                return Err(Error::TaskStatusInconsistentWithVote);
             }
         }
         TaskMode::Challenge => {
             if task_res.status != TaskStatus::ChallengePassed && task_res.status != TaskStatus::ChallengeRejected {
-                return Err(Error::TaskMismatch);
+                return Err(Error::TaskStatusInconsistentWithVote);
             }
         }
     };
@@ -85,11 +90,15 @@ pub fn checker_vote(raw_witness: &[u8], signer: [u8; 20]) -> Result<(), Error> {
     task_res.commit.copy_from_slice(&task_output.commit);
     task_res.reveal.copy_from_slice(&task_output.reveal);
 
-    if task_input_type_args.chain_id != witness.chain_id
-        || task_input_type_args.checker_lock_arg != signer
-        || task_res != task_output
-        || task_input_type_args != task_output_type_args
-    {
+    if task_input_type_args.chain_id != witness.chain_id {
+        return Err(Error::TaskChainIdMismatch);
+    }
+
+    if task_input_type_args.checker_lock_arg != signer {
+        return Err(Error::TaskCheckerMismatch);
+    }
+
+    if task_res != task_output || task_input_type_args != task_output_type_args {
         return Err(Error::TaskMismatch);
     }
 
@@ -99,7 +108,7 @@ pub fn checker_vote(raw_witness: &[u8], signer: [u8; 20]) -> Result<(), Error> {
 fn is_checker_vote(witness: &CheckerVoteWitness) -> Result<(), Error> {
     let global = check_global_cell()?;
 
-    if is_cell_count_not_equals(3, Source::Input) || is_cell_count_not_equals(3, Source::Output) {
+    if is_cell_count_not_equals(layout::INPUT_COUNT, Source::Input) || is_cell_count_not_equals(layout::OUTPUT_COUNT, Source::Output) {
         return Err(Error::CellNumberMismatch);
     }
 
@@ -118,5 +127,10 @@ fn is_checker_vote(witness: &CheckerVoteWitness) -> Result<(), Error> {
         },
     };
 
+    check_continuity! {
+        CHECKER_INFO_INPUT => CHECKER_INFO_OUTPUT,
+        TASK_INPUT => TASK_OUTPUT,
+    };
+
     Ok(())
 }
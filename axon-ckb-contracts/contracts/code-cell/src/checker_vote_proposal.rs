@@ -0,0 +1,97 @@
+use ckb_std::ckb_constants::Source;
+
+use common_raw::{
+    cell::{
+        code::CodeCell,
+        proposal::{ProposalCell, ProposalCellTypeArgs},
+        sidechain_config::{SidechainConfigCell, SidechainConfigCellTypeArgs, SidechainStatus},
+    },
+    common::Identity,
+    layout::checker_vote_proposal as layout,
+    witness::checker_vote_proposal::CheckerVoteProposalWitness,
+    FromRaw,
+};
+
+use crate::{cell::*, checker_set::is_activated, common::*, error::Error};
+
+const SIDECHAIN_CONFIG_DEP: CellOrigin = CellOrigin(layout::SIDECHAIN_CONFIG_DEP, Source::CellDep);
+
+const PROPOSAL_INPUT: CellOrigin = CellOrigin(layout::PROPOSAL_INPUT, Source::Input);
+
+const PROPOSAL_OUTPUT: CellOrigin = CellOrigin(layout::PROPOSAL_OUTPUT, Source::Output);
+
+pub fn checker_vote_proposal(raw_witness: &[u8], signer: Identity) -> Result<(), Error> {
+    /*
+    CheckerVoteProposal,
+
+    Dep:    0 Global Config Cell
+    Dep:    5 Sidechain Config Cell
+
+    Code Cell                   ->          Code Cell
+    Proposal Cell               ->          Proposal Cell
+
+    */
+    is_checker_vote_proposal()?;
+
+    let witness = CheckerVoteProposalWitness::from_raw(raw_witness).or(Err(Error::WitnessEncoding))?;
+
+    let (config_dep_type_args, config_dep, proposal_input_type_args, proposal_input, proposal_output_type_args, proposal_output) = load_entities!(
+        SidechainConfigCellTypeArgs: SIDECHAIN_CONFIG_DEP,
+        SidechainConfigCell: SIDECHAIN_CONFIG_DEP,
+        ProposalCellTypeArgs: PROPOSAL_INPUT,
+        ProposalCell: PROPOSAL_INPUT,
+        ProposalCellTypeArgs: PROPOSAL_OUTPUT,
+        ProposalCell: PROPOSAL_OUTPUT,
+    );
+
+    if config_dep_type_args.chain_id != witness.chain_id || config_dep.sidechain_status != SidechainStatus::Relaying {
+        return Err(Error::SidechainConfigMismatch);
+    }
+
+    if !is_activated(&config_dep, &signer)? {
+        return Err(Error::CheckerNotActivated);
+    }
+
+    if proposal_input.votes.contains(&signer) {
+        return Err(Error::ProposalAlreadyVoted);
+    }
+
+    let mut proposal_res = proposal_input.clone();
+    proposal_res.votes.push(signer);
+
+    if proposal_res != proposal_output
+        || proposal_input_type_args != proposal_output_type_args
+        || proposal_input_type_args.chain_id != witness.chain_id
+    {
+        return Err(Error::ProposalMismatch);
+    }
+
+    Ok(())
+}
+
+fn is_checker_vote_proposal() -> Result<(), Error> {
+    let global = check_global_cell()?;
+
+    if is_cell_count_not_equals(layout::INPUT_COUNT, Source::Input) || is_cell_count_not_equals(layout::OUTPUT_COUNT, Source::Output) {
+        return Err(Error::CellNumberMismatch);
+    }
+
+    check_cells! {
+        &global,
+        {
+            SidechainConfigCell: SIDECHAIN_CONFIG_DEP,
+
+            CodeCell: CODE_INPUT,
+            ProposalCell: PROPOSAL_INPUT,
+
+            CodeCell: CODE_OUTPUT,
+            ProposalCell: PROPOSAL_OUTPUT,
+        },
+    };
+
+    check_continuity! {
+        PROPOSAL_INPUT => PROPOSAL_OUTPUT,
+    };
+
+    Ok(())
+}
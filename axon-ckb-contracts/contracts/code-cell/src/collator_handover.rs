@@ -0,0 +1,110 @@
+use ckb_std::ckb_constants::Source;
+
+use common_raw::{
+    cell::{
+        code::CodeCell,
+        sidechain_bond::{SidechainBondCell, SidechainBondCellLockArgs},
+        sidechain_config::{SidechainConfigCell, SidechainConfigCellTypeArgs, SidechainStatus},
+        sudt_token::SudtTokenCell,
+    },
+    common::Identity,
+    layout::collator_handover as layout,
+    witness::collator_handover::CollatorHandoverWitness,
+    FromRaw,
+};
+
+use crate::{cell::*, common::*, error::Error};
+
+const SIDECHAIN_CONFIG_INPUT: CellOrigin = CellOrigin(layout::SIDECHAIN_CONFIG_INPUT, Source::Input);
+const TOKEN_INPUT: CellOrigin = CellOrigin(layout::TOKEN_INPUT, Source::Input);
+
+const SIDECHAIN_CONFIG_OUTPUT: CellOrigin = CellOrigin(layout::SIDECHAIN_CONFIG_OUTPUT, Source::Output);
+const SIDECHAIN_BOND_OUTPUT: CellOrigin = CellOrigin(layout::SIDECHAIN_BOND_OUTPUT, Source::Output);
+
+pub fn collator_handover(raw_witness: &[u8], signer: Identity) -> Result<(), Error> {
+    /*
+    CollatorHandover,
+
+    Dep:    0 Global Config Cell
+
+    Code Cell                   ->          Code Cell
+    Sidechain Config Cell       ->          Sidechain Config Cell
+    Sudt Cell                   ->          Sidechain Bond Cell
+
+    */
+    is_collator_handover()?;
+
+    let witness = CollatorHandoverWitness::from_raw(raw_witness).or(Err(Error::WitnessEncoding))?;
+
+    let (config_input_type_args, config_input, sudt_token_input) = load_entities!(
+        SidechainConfigCellTypeArgs: SIDECHAIN_CONFIG_INPUT,
+        SidechainConfigCell: SIDECHAIN_CONFIG_INPUT,
+        SudtTokenCell: TOKEN_INPUT,
+    );
+
+    let (config_output_type_args, config_output, sidechain_bond_output_lock_args, sidechain_bond_output) = load_entities!(
+        SidechainConfigCellTypeArgs: SIDECHAIN_CONFIG_OUTPUT,
+        SidechainConfigCell: SIDECHAIN_CONFIG_OUTPUT,
+        SidechainBondCellLockArgs: SIDECHAIN_BOND_OUTPUT,
+        SidechainBondCell: SIDECHAIN_BOND_OUTPUT,
+    );
+
+    let collator_index = usize::from(witness.collator_index);
+    let outgoing_collator = config_input.collators.get(collator_index).ok_or(Error::CollatorNotInSet)?;
+
+    if signer != *outgoing_collator && signer != config_input.admin_lock_arg {
+        return Err(Error::CollatorHandoverNotAllowed);
+    }
+
+    let mut config_res = config_input.clone();
+    config_res.collators[collator_index] = witness.new_collator_lock_arg;
+
+    if config_input.sidechain_status != SidechainStatus::Relaying
+        || config_input.collators.contains(&witness.new_collator_lock_arg)
+        || config_res != config_output
+        || config_input_type_args != config_output_type_args
+        || config_input_type_args.chain_id != witness.chain_id
+    {
+        return Err(Error::SidechainConfigMismatch);
+    }
+
+    // the new collator posts its own bond, the old one stays locked until its jobs are settled
+    check_bond_type_script(SIDECHAIN_BOND_OUTPUT, &config_input)?;
+    check_same_type_script(TOKEN_INPUT, SIDECHAIN_BOND_OUTPUT)?;
+
+    if sidechain_bond_output_lock_args.collator_lock_arg != witness.new_collator_lock_arg
+        || sidechain_bond_output_lock_args.chain_id != witness.chain_id
+        || sidechain_bond_output.amount != sudt_token_input.amount
+        || sidechain_bond_output.amount < config_input.minimal_bond
+    {
+        return Err(Error::SidechainBondMismatch);
+    }
+
+    Ok(())
+}
+
+fn is_collator_handover() -> Result<(), Error> {
+    let global = check_global_cell()?;
+
+    if is_cell_count_not_equals(layout::INPUT_COUNT, Source::Input) || is_cell_count_not_equals(layout::OUTPUT_COUNT, Source::Output) {
+        return Err(Error::CellNumberMismatch);
+    }
+
+    check_cells! {
+        &global,
+        {
+            CodeCell: CODE_INPUT,
+            SidechainConfigCell: SIDECHAIN_CONFIG_INPUT,
+
+            CodeCell: CODE_OUTPUT,
+            SidechainConfigCell: SIDECHAIN_CONFIG_OUTPUT,
+            SidechainBondCell: SIDECHAIN_BOND_OUTPUT,
+        },
+    };
+
+    check_continuity! {
+        SIDECHAIN_CONFIG_INPUT => SIDECHAIN_CONFIG_OUTPUT,
+    };
+
+    Ok(())
+}
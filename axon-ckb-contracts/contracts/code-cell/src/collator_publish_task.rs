@@ -6,34 +6,36 @@ use common_raw::cell::muse_token::MuseTokenCell;
 use common_raw::cell::sidechain_bond::{SidechainBondCell, SidechainBondCellLockArgs};
 use common_raw::cell::sidechain_config::SidechainConfigCellTypeArgs;
 use common_raw::cell::sidechain_fee::{SidechainFeeCell, SidechainFeeCellLockArgs};
-use common_raw::cell::sidechain_state::{CheckerLastAcceptTaskHeight, SidechainStateCell, SidechainStateCellTypeArgs};
+use common_raw::cell::sidechain_state::{SidechainStateCell, SidechainStateCellTypeArgs};
 use common_raw::cell::task::TaskCellTypeArgs;
-use common_raw::common::BlockSlice;
+use common_raw::common::{BlockSlice, Identity};
 use common_raw::{
     cell::{code::CodeCell, sidechain_config::SidechainConfigCell, task::TaskCell},
+    layout::collator_publish_task as layout,
+    seed::{derive_seed, SeedPurpose},
     witness::collator_publish_task::CollatorPublishTaskWitness,
     FromRaw,
 };
 
-use crate::{cell::*, common::*, error::Error};
+use crate::{cell::*, checker_set::set_last_task_height, common::*, error::Error, sampling::CheckerSampler};
 
-const SIDECHAIN_CONFIG_DEP: CellOrigin = CellOrigin(5, Source::CellDep);
-const SIDECHAIN_BOND_DEP: CellOrigin = CellOrigin(6, Source::CellDep);
+const SIDECHAIN_CONFIG_DEP: CellOrigin = CellOrigin(layout::SIDECHAIN_CONFIG_DEP, Source::CellDep);
+const SIDECHAIN_BOND_DEP: CellOrigin = CellOrigin(layout::SIDECHAIN_BOND_DEP, Source::CellDep);
 
-const SIDECHAIN_STATE_INPUT: CellOrigin = CellOrigin(1, Source::Input);
-const SIDECHAIN_FEE_INPUT: CellOrigin = CellOrigin(2, Source::Input);
-const TOKEN_INPUT: CellOrigin = CellOrigin(3, Source::Input);
+const SIDECHAIN_STATE_INPUT: CellOrigin = CellOrigin(layout::SIDECHAIN_STATE_INPUT, Source::Input);
+const SIDECHAIN_FEE_INPUT: CellOrigin = CellOrigin(layout::SIDECHAIN_FEE_INPUT, Source::Input);
+const TOKEN_INPUT: CellOrigin = CellOrigin(layout::TOKEN_INPUT, Source::Input);
 
-const SIDECHAIN_STATE_OUTPUT: CellOrigin = CellOrigin(1, Source::Output);
-const SIDECHAIN_FEE_OUTPUT: CellOrigin = CellOrigin(2, Source::Output);
+const SIDECHAIN_STATE_OUTPUT: CellOrigin = CellOrigin(layout::SIDECHAIN_STATE_OUTPUT, Source::Output);
+const SIDECHAIN_FEE_OUTPUT: CellOrigin = CellOrigin(layout::SIDECHAIN_FEE_OUTPUT, Source::Output);
 
 pub fn is_collator_publish_task(sidechain_config_data: &SidechainConfigCell) -> Result<(), Error> {
     /*
     CollatorPublishTask,
 
     Dep:    0 Global Config Cell
-            1 Sidechain Config Cell
-            2 Sidechain Bond Cell
+            5 Sidechain Config Cell
+            6 Sidechain Bond Cell
     Code Cell                   ->          Code Cell
     Sidechain State Cell        ->          Sidechain State Cell
     Sidechain Fee Cell          ->          Sidechain Fee Cell
@@ -43,8 +45,11 @@ pub fn is_collator_publish_task(sidechain_config_data: &SidechainConfigCell) ->
     */
 
     let global = check_global_cell()?;
-    if is_cell_count_not_equals(4, Source::Input)
-        || is_cell_count_not_equals(3 + sidechain_config_data.commit_threshold as usize, Source::Output)
+    if is_cell_count_not_equals(layout::INPUT_COUNT, Source::Input)
+        || is_cell_count_not_equals(
+            layout::FIXED_OUTPUT_CELLS + sidechain_config_data.commit_threshold as usize,
+            Source::Output,
+        )
     {
         return Err(Error::CellNumberMismatch);
     }
@@ -63,16 +68,24 @@ pub fn is_collator_publish_task(sidechain_config_data: &SidechainConfigCell) ->
             SidechainFeeCell: SIDECHAIN_FEE_OUTPUT,
         },
     };
-    TaskCell::range_check(3.., Source::Output, &global)
+
+    check_continuity! {
+        SIDECHAIN_STATE_INPUT => SIDECHAIN_STATE_OUTPUT,
+    };
+    check_capacity_continuity(SIDECHAIN_FEE_INPUT, SIDECHAIN_FEE_OUTPUT)?;
+
+    check_bond_type_script(SIDECHAIN_BOND_DEP, sidechain_config_data)?;
+
+    TaskCell::range_check(layout::FIXED_OUTPUT_CELLS.., Source::Output, &global)
 }
 
-pub fn collator_publish_task(raw_witness: &[u8], signer: [u8; 20]) -> Result<(), Error> {
+pub fn collator_publish_task(raw_witness: &[u8], signer: Identity) -> Result<(), Error> {
     /*
     CollatorPublishTask,
 
     Dep:    0 Global Config Cell
-            1 Sidechain Config Cell
-            2 Sidechain Bond Cell
+            5 Sidechain Config Cell
+            6 Sidechain Bond Cell
     Code Cell                   ->          Code Cell
     Sidechain State Cell        ->          Sidechain State Cell
     Sidechain Fee Cell          ->          Sidechain Fee Cell
@@ -80,7 +93,7 @@ pub fn collator_publish_task(raw_witness: &[u8], signer: [u8; 20]) -> Result<(),
     Null                        ->          [Task Cell]
 
     */
-    let witness = CollatorPublishTaskWitness::from_raw(raw_witness).ok_or(Error::Encoding)?;
+    let witness = CollatorPublishTaskWitness::from_raw(raw_witness).or(Err(Error::WitnessEncoding))?;
     //load dep
     let (sidechain_config_dep, sidechain_config_dep_type_args, sidechain_bond_dep_lock_args) = load_entities!(
         SidechainConfigCell: SIDECHAIN_CONFIG_DEP,
@@ -107,8 +120,11 @@ pub fn collator_publish_task(raw_witness: &[u8], signer: [u8; 20]) -> Result<(),
         SidechainFeeCell: SIDECHAIN_FEE_OUTPUT,
     );
 
+    if sidechain_config_dep.collator_on_duty(witness.from_height) != Some(&signer) {
+        return Err(Error::CollatorNotOnDuty);
+    }
+
     if sidechain_config_dep.checker_threshold > sidechain_config_dep.checker_normal_count
-        || sidechain_config_dep.collator_lock_arg != signer
         || sidechain_config_dep_type_args.chain_id != witness.chain_id
         || sidechain_config_dep.check_data_size_limit < witness.check_data_size
     {
@@ -120,7 +136,10 @@ pub fn collator_publish_task(raw_witness: &[u8], signer: [u8; 20]) -> Result<(),
         from: witness.from_height,
         to:   witness.to_height,
     });
-    sidechain_state_res.random_offset += 1;
+    sidechain_state_res.random_offset = sidechain_state_res
+        .random_offset
+        .checked_add(1)
+        .ok_or(Error::SidechainStateMismatch)?;
 
     let unsubmitted_jobs = sidechain_state_input.waiting_jobs.clone();
     match unsubmitted_jobs.iter().max_by(|x, y| x.from.cmp(&y.from)) {
@@ -143,10 +162,11 @@ pub fn collator_publish_task(raw_witness: &[u8], signer: [u8; 20]) -> Result<(),
     let mut sidechain_fee_res = sidechain_fee_input.clone();
     let mut sidechain_fee_res_lock_args = sidechain_fee_input_lock_args.clone();
 
-    let mut max_paid = u128::try_from(sidechain_config_dep.commit_threshold).or(Err(Error::Encoding))?
-        * u128::try_from(sidechain_config_dep.challenge_threshold).or(Err(Error::Encoding))?
-        * u128::try_from(sidechain_config_dep.check_fee_rate).or(Err(Error::Encoding))?
-        * witness.check_data_size;
+    let mut max_paid = u128::from(sidechain_config_dep.commit_threshold)
+        .checked_mul(u128::from(sidechain_config_dep.challenge_threshold))
+        .and_then(|paid| paid.checked_mul(u128::from(sidechain_config_dep.check_fee_rate)))
+        .and_then(|paid| paid.checked_mul(witness.check_data_size))
+        .ok_or(Error::Encoding)?;
     if max_paid >= sidechain_fee_input_lock_args.surplus {
         sidechain_fee_res.amount += max_paid - sidechain_fee_input_lock_args.surplus;
         sidechain_fee_res_lock_args.surplus = 0;
@@ -166,17 +186,20 @@ pub fn collator_publish_task(raw_witness: &[u8], signer: [u8; 20]) -> Result<(),
         return Err(Error::SidechainFeeMismatch);
     }
 
-    let mut seed = sidechain_state_input.random_seed;
-    seed[0] += sidechain_state_input.random_offset;
-
-    for i in 3..(sidechain_config_dep.commit_threshold + 2) as usize {
-        seed = Blake2b::calculate(&seed);
-        let seed_number = u128::from_raw(&seed[0..16]).ok_or(Error::Encoding)?;
-
-        let checkers_count = u128::try_from(sidechain_config_dep.activated_checkers.len()).or(Err(Error::Encoding))?;
-        let index = usize::try_from(seed_number % checkers_count).or(Err(Error::Encoding))?;
+    let seed = derive_seed::<Blake2b>(
+        &sidechain_state_input.random_seed,
+        sidechain_state_input.random_offset,
+        witness.chain_id,
+        SeedPurpose::PublishTask,
+        &BlockSlice {
+            from: witness.from_height,
+            to:   witness.to_height,
+        },
+    );
 
-        let checker_lock_arg = sidechain_config_dep.activated_checkers.get(index).ok_or(Error::Encoding)?;
+    let mut sampler = CheckerSampler::new(&sidechain_config_dep, seed)?;
+    for i in layout::FIXED_OUTPUT_CELLS..layout::FIXED_OUTPUT_CELLS + sidechain_config_dep.commit_threshold as usize {
+        let checker_lock_arg = &sampler.draw()?;
 
         let task_output = TaskCell::load(CellOrigin(i, Source::Output))?;
         let task_output_type_args = TaskCellTypeArgs::load(CellOrigin(i, Source::Output))?;
@@ -190,23 +213,12 @@ pub fn collator_publish_task(raw_witness: &[u8], signer: [u8; 20]) -> Result<(),
             return Err(Error::TaskMismatch);
         }
 
-        match sidechain_state_res
-            .checker_last_task_sidechain_heights
-            .iter_mut()
-            .find(|info| &info.checker_lock_arg == checker_lock_arg)
-        {
-            Some(info) => {
-                info.height = task_output.sidechain_block_height_to;
-            }
-            None => {
-                sidechain_state_res
-                    .checker_last_task_sidechain_heights
-                    .push(CheckerLastAcceptTaskHeight {
-                        checker_lock_arg: *checker_lock_arg,
-                        height:           task_output.sidechain_block_height_to,
-                    });
-            }
-        }
+        set_last_task_height(
+            &sidechain_config_dep,
+            &mut sidechain_state_res,
+            checker_lock_arg,
+            task_output.sidechain_block_height_to,
+        )?;
     }
 
     if sidechain_state_input_type_args != sidechain_state_output_type_args
@@ -1,3 +1,4 @@
+use alloc::vec::Vec;
 use core::convert::TryFrom;
 
 use ckb_std::ckb_constants::Source;
@@ -8,31 +9,34 @@ use common_raw::{
         sidechain_config::{SidechainConfigCell, SidechainConfigCellTypeArgs, SidechainStatus},
         sidechain_state::{SidechainStateCell, SidechainStateCellTypeArgs},
     },
+    common::Identity,
+    layout::collator_shutdown_sidechain as layout,
     witness::collator_shutdown_sidechain::CollatorShutdownSidechainWitness,
     FromRaw,
 };
 
 use crate::{cell::*, common::*, error::Error};
 
-const SIDECHAIN_STATE_DEP: CellOrigin = CellOrigin(5, Source::CellDep);
+const SIDECHAIN_STATE_DEP: CellOrigin = CellOrigin(layout::SIDECHAIN_STATE_DEP, Source::CellDep);
 
-const SIDECHAIN_CONFIG_INPUT: CellOrigin = CellOrigin(1, Source::Input);
+const SIDECHAIN_CONFIG_INPUT: CellOrigin = CellOrigin(layout::SIDECHAIN_CONFIG_INPUT, Source::Input);
 
-const SIDECHAIN_CONFIG_OUTPUT: CellOrigin = CellOrigin(1, Source::Output);
+const SIDECHAIN_CONFIG_OUTPUT: CellOrigin = CellOrigin(layout::SIDECHAIN_CONFIG_OUTPUT, Source::Output);
 
-pub fn collator_shutdown_sidechain(raw_witness: &[u8], signer: [u8; 20]) -> Result<(), Error> {
+pub fn collator_shutdown_sidechain(raw_witness: &[u8], signer: Identity) -> Result<(), Error> {
     /*
-    AnyoneShutdownSidechain,
+    CollatorShutdownSidechain,
 
     Dep:    0 Global Config Cell
+    Dep:    5 Sidechain State Cell
 
     Code Cell                   -> ~
     Sidechain Config Cell       -> ~
-    Sidechain Fee Cell          -> ~
+    [Collator Cell]             -> [Any Cell]
     */
-    is_collator_shutdown_sidechain()?;
+    let witness = CollatorShutdownSidechainWitness::from_raw(raw_witness).or(Err(Error::WitnessEncoding))?;
 
-    let witness = CollatorShutdownSidechainWitness::from_raw(raw_witness).ok_or(Error::Encoding)?;
+    is_collator_shutdown_sidechain(&witness)?;
 
     //load entities
     let (state_dep_type_args, state_dep, config_input_type_args, config_input, config_output_type_args, config_output) = load_entities!(
@@ -50,8 +54,24 @@ pub fn collator_shutdown_sidechain(raw_witness: &[u8], signer: [u8; 20]) -> Resu
     let mut config_res = config_input.clone();
     config_res.sidechain_status = SidechainStatus::Shutdown;
 
+    // the signer and the owners of the approval cells, whose locks have been verified by ckb
+    let mut approvals = Vec::new();
+    approvals.push(signer);
+    for i in 0..usize::from(witness.approval_count) {
+        approvals.push(load_signer(CellOrigin(layout::APPROVAL_INPUTS_START + i, Source::Input))?);
+    }
+
+    let mut collators = config_input.collators.clone();
+    collators.retain(|collator| approvals.contains(collator));
+
+    if !config_input.collators.contains(&signer) {
+        return Err(Error::CollatorNotInSet);
+    }
+    if collators.len() < usize::try_from(config_input.collator_threshold).or(Err(Error::Encoding))? {
+        return Err(Error::CollatorQuorumNotReached);
+    }
+
     if config_input.sidechain_status != SidechainStatus::Relaying
-        || config_res.collator_lock_arg != signer
         || config_res != config_output
         || config_input_type_args != config_output_type_args
         || config_input_type_args.chain_id != witness.chain_id
@@ -62,10 +82,13 @@ pub fn collator_shutdown_sidechain(raw_witness: &[u8], signer: [u8; 20]) -> Resu
     Ok(())
 }
 
-fn is_collator_shutdown_sidechain() -> Result<(), Error> {
+fn is_collator_shutdown_sidechain(witness: &CollatorShutdownSidechainWitness) -> Result<(), Error> {
     let global = check_global_cell()?;
 
-    if is_cell_count_not_equals(2, Source::Input) || is_cell_count_not_equals(2, Source::Output) {
+    let approval_count = usize::from(witness.approval_count);
+    if is_cell_count_not_equals(layout::INPUT_COUNT + approval_count, Source::Input)
+        || is_cell_count_not_equals(layout::OUTPUT_COUNT + approval_count, Source::Output)
+    {
         return Err(Error::CellNumberMismatch);
     }
     check_cells! {
@@ -81,5 +104,9 @@ fn is_collator_shutdown_sidechain() -> Result<(), Error> {
         },
     };
 
+    check_continuity! {
+        SIDECHAIN_CONFIG_INPUT => SIDECHAIN_CONFIG_OUTPUT,
+    };
+
     Ok(())
 }
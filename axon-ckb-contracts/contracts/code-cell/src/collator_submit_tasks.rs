@@ -1,35 +1,53 @@
+use alloc::vec::Vec;
 use core::convert::TryFrom;
 
-use ckb_std::ckb_constants::Source;
+use ckb_std::{
+    ckb_constants::Source,
+    ckb_types::{
+        packed::{Byte, Byte32, Bytes, Script},
+        prelude::*,
+    },
+};
+use secp256k1::{recover, Message, RecoveryId, Signature as EcdsaSignature};
 
 use common_raw::{
     cell::{
+        checker_info::{CheckerInfoCell, CheckerInfoCellTypeArgs},
         code::CodeCell,
         sidechain_config::{SidechainConfigCell, SidechainConfigCellTypeArgs},
         sidechain_fee::{SidechainFeeCell, SidechainFeeCellLockArgs},
-        sidechain_state::{CommittedCheckerInfo, SidechainStateCell, SidechainStateCellTypeArgs},
+        sidechain_state::{CommittedCheckerInfo, SidechainStateCell, SidechainStateCellTypeArgs, SubmitProgress},
         task::{TaskCell, TaskCellTypeArgs, TaskMode, TaskStatus},
     },
     common::*,
-    witness::collator_submit_tasks::CollatorSubmitTasksWitness,
-    FromRaw,
+    layout::collator_submit_tasks as layout,
+    seed::{derive_seed, SeedPurpose},
+    witness::collator_submit_tasks::{CheckerSignature, CollatorSubmitTasksWitness, SignedVote},
+    FromRaw, Serialize,
 };
 
-use crate::{cell::*, common::*, error::Error};
+use crate::{
+    cell::*,
+    checker_set::{checker_record, punish_checker},
+    common::*,
+    error::Error,
+    jail::jail_checker,
+    sampling::CheckerSampler,
+};
 
-const SIDECHAIN_CONFIG_INPUT: CellOrigin = CellOrigin(1, Source::Input);
-const SIDECHAIN_STATE_INPUT: CellOrigin = CellOrigin(2, Source::Input);
-const SIDECHAIN_FEE_INPUT: CellOrigin = CellOrigin(3, Source::Input);
+const SIDECHAIN_CONFIG_INPUT: CellOrigin = CellOrigin(layout::SIDECHAIN_CONFIG_INPUT, Source::Input);
+const SIDECHAIN_STATE_INPUT: CellOrigin = CellOrigin(layout::SIDECHAIN_STATE_INPUT, Source::Input);
+const SIDECHAIN_FEE_INPUT: CellOrigin = CellOrigin(layout::SIDECHAIN_FEE_INPUT, Source::Input);
 
-const SIDECHAIN_CONFIG_OUTPUT: CellOrigin = CellOrigin(1, Source::Output);
-const SIDECHAIN_STATE_OUTPUT: CellOrigin = CellOrigin(2, Source::Output);
-const SIDECHAIN_FEE_OUTPUT: CellOrigin = CellOrigin(3, Source::Output);
+const SIDECHAIN_CONFIG_OUTPUT: CellOrigin = CellOrigin(layout::SIDECHAIN_CONFIG_OUTPUT, Source::Output);
+const SIDECHAIN_STATE_OUTPUT: CellOrigin = CellOrigin(layout::SIDECHAIN_STATE_OUTPUT, Source::Output);
+const SIDECHAIN_FEE_OUTPUT: CellOrigin = CellOrigin(layout::SIDECHAIN_FEE_OUTPUT, Source::Output);
 
-const FIXED_INPUT_CELLS: usize = 4;
+const FIXED_INPUT_CELLS: usize = layout::FIXED_INPUT_CELLS;
 
 const DEFAULT_REVEAL_VALUE: RandomSeed = [0u8; 32];
 
-pub fn collator_submit_tasks(raw_witness: &[u8], signer: [u8; 20]) -> Result<(), Error> {
+pub fn collator_submit_tasks(raw_witness: &[u8], signer: Identity) -> Result<(), Error> {
     /*
     CollatorSubmitTasks,
 
@@ -41,9 +59,24 @@ pub fn collator_submit_tasks(raw_witness: &[u8], signer: [u8; 20]) -> Result<(),
     Sidechain Fee Cell    -> ~
 
     [Task Cell]           -> Null
+
+    The tasks of a job are submitted at once or in chunks of at most submit_task_limit tasks. Every
+    chunk settles the fees and the commits of its own tasks, the state keeps the progress of the job
+    until its last chunk, see SubmitProgress. The job has to be a waiting job of the state, its last
+    chunk removes it.
+
+    A signed job has no task cells, its checkers sign their votes off-chain instead and the witness
+    carries the signatures, see CheckerSignature:
+
+    [Checker Info Cell]   -> Checker Info Cell
+
+    The key a signature recovers tells the checker of the vote. Its checkers are drawn for the job as
+    publishing it would, with their own seed, and every drawn checker has to sign it. Each is paid on
+    its checker info cell as if it had voted on a task cell. A signed job is always submitted at once
+    and only settles tasks passed by valid checkers.
     */
 
-    let witness = CollatorSubmitTasksWitness::from_raw(&raw_witness).ok_or(Error::Encoding)?;
+    let witness = CollatorSubmitTasksWitness::from_raw(&raw_witness).or(Err(Error::WitnessEncoding))?;
 
     //load inputs
     let (sidechain_config_input, sidechain_config_input_type_args) = load_entities!(
@@ -52,19 +85,33 @@ pub fn collator_submit_tasks(raw_witness: &[u8], signer: [u8; 20]) -> Result<(),
     );
 
     // prepare arguments
-    let task_count = usize::try_from(sidechain_config_input.commit_threshold).or(Err(Error::Encoding))? - witness.challenge_times;
-    let challenge_count = witness.challenge_times * usize::try_from(sidechain_config_input.challenge_threshold).or(Err(Error::Encoding))?;
-    let job_count = task_count + challenge_count;
+    let chunk_count = witness.commit.len();
+    if sidechain_config_input.submit_task_limit != 0
+        && chunk_count > usize::try_from(sidechain_config_input.submit_task_limit).or(Err(Error::Encoding))?
+    {
+        return Err(Error::SubmitTaskLimitExceeded);
+    }
 
     let correct_vote_count = witness.commit.iter().filter(|commit| commit.is_valid()).count();
 
-    let fee = u128::try_from(correct_vote_count).or(Err(Error::Encoding))?
-        * u128::try_from(sidechain_config_input.check_fee_rate).or(Err(Error::Encoding))?
-        * witness.check_data_size;
+    let fee = u128::try_from(correct_vote_count)
+        .or(Err(Error::Encoding))?
+        .checked_mul(u128::from(sidechain_config_input.check_fee_rate))
+        .and_then(|fee| fee.checked_mul(witness.check_data_size))
+        .ok_or(Error::Encoding)?;
 
     let chain_id = sidechain_config_input_type_args.chain_id;
 
-    is_collator_submit_tasks(job_count)?;
+    // the checkers of a signed job only sign the transaction, so the witness may not jail them
+    let signed = witness.signed_job.is_some();
+    if signed && (witness.challenge_times != 0 || witness.commit.iter().any(|commit| commit.is_invalid())) {
+        return Err(Error::SignedVoteMismatch);
+    }
+    if witness.signatures.len() != if signed { chunk_count } else { 0 } {
+        return Err(Error::SignedVoteMismatch);
+    }
+
+    is_collator_submit_tasks(chunk_count, signed)?;
 
     //load inputs
     let (sidechain_state_input, sidechain_state_input_type_args, sidechain_fee_input, sidechain_fee_input_lock_args) = load_entities!(
@@ -91,17 +138,129 @@ pub fn collator_submit_tasks(raw_witness: &[u8], signer: [u8; 20]) -> Result<(),
         SidechainFeeCellLockArgs: SIDECHAIN_FEE_OUTPUT,
     );
 
+    // the job is submitted by the collator on duty for it, which is the one who published it
+    let (job_task, job_task_type_args) = match witness.signed_job {
+        Some(job) => signed_job_task(&job, &witness, chain_id)?,
+        None => load_entities!(
+            TaskCell: CellOrigin(FIXED_INPUT_CELLS, Source::Input),
+            TaskCellTypeArgs: CellOrigin(FIXED_INPUT_CELLS, Source::Input),
+        ),
+    };
+    let job = BlockSlice {
+        from: job_task.sidechain_block_height_from,
+        to:   job_task.sidechain_block_height_to,
+    };
+    let job_height = job.from;
+
+    let job_index = sidechain_state_input
+        .waiting_jobs
+        .iter()
+        .position(|waiting_job| *waiting_job == job)
+        .ok_or(Error::JobNotWaiting)?;
+
+    if signed && sidechain_state_input.submit_progress.is_some() {
+        return Err(Error::TaskJobInconsistent);
+    }
+
+    let mut progress = load_progress(
+        &sidechain_config_input,
+        &sidechain_state_input,
+        &witness,
+        job_hash(&job_task, &job_task_type_args),
+    )?;
+
+    // invalid checkers are jailed, which touches both the config and the state
+    let mut sidechain_state_res = sidechain_state_input.clone();
+
+    let withheld = if signed {
+        let drawn = draw_signed_job_checkers(&sidechain_config_input, &mut sidechain_state_res, &job, chain_id)?;
+
+        let mut signers = Vec::new();
+        let withheld = check_tasks(
+            || {
+                let signature = match witness.signatures.get(signers.len()) {
+                    Some(signature) => signature,
+                    None => return Ok(None),
+                };
+
+                let result = load_signed_task(signature, &job_task, &job_task_type_args, &drawn, &signers)?;
+
+                signers.push(result.1.checker_lock_arg);
+
+                Ok(Some(result))
+            },
+            &witness,
+            &mut progress,
+            chain_id,
+        )?;
+
+        // nothing would keep a checker from signing a vote in every chunk of a job
+        if progress.remaining_tasks != 0 || progress.remaining_challenges != 0 {
+            return Err(Error::TaskCountMismatch);
+        }
+
+        check_checker_infos(&signers, &sidechain_config_input, witness.check_data_size, chain_id)?;
+
+        withheld
+    } else {
+        let mut i = FIXED_INPUT_CELLS;
+        let len_input = FIXED_INPUT_CELLS + chunk_count;
+        check_tasks(
+            || {
+                if i >= len_input {
+                    return Ok(None);
+                }
+
+                let result = load_entities!(
+                    TaskCell: CellOrigin(i, Source::Input),
+                    TaskCellTypeArgs: CellOrigin(i, Source::Input),
+                );
+
+                i += 1;
+
+                Ok(Some(result))
+            },
+            &witness,
+            &mut progress,
+            chain_id,
+        )?
+    };
+
+    // the last chunk settles the job
+    if progress.remaining_tasks == 0 && progress.remaining_challenges == 0 {
+        if progress.shutdown_count >= progress.settle_count {
+            return Err(Error::TaskChallengeMajority);
+        }
+
+        // newly committed checkers have nothing to reveal, the first job of a sidechain has no reveal at
+        // all
+        if progress.reveal_count < sidechain_config_input.reveal_quorum.min(progress.committed_count) {
+            return Err(Error::RevealQuorumNotReached);
+        }
+
+        sidechain_state_res.random_seed = progress.random_seed;
+        sidechain_state_res.seed_epoch = sidechain_state_res.seed_epoch.checked_add(1).ok_or(Error::SidechainStateMismatch)?;
+        sidechain_state_res.submit_progress = None;
+        sidechain_state_res.waiting_jobs.remove(job_index);
+    } else {
+        sidechain_state_res.submit_progress = Some(progress);
+    }
+
     check_sidechain_config(
         &sidechain_config_input,
         &sidechain_config_input_type_args,
         &sidechain_config_output,
         &sidechain_config_output_type_args,
+        &mut sidechain_state_res,
         &witness,
+        &withheld,
         &signer,
+        job_height,
     )?;
 
     check_sidechain_state(
         &sidechain_state_input,
+        sidechain_state_res,
         &sidechain_state_input_type_args,
         &sidechain_state_output,
         &sidechain_state_output_type_args,
@@ -118,67 +277,254 @@ pub fn collator_submit_tasks(raw_witness: &[u8], signer: [u8; 20]) -> Result<(),
         chain_id,
     )?;
 
-    let mut i = FIXED_INPUT_CELLS;
-    let len_input = FIXED_INPUT_CELLS + job_count;
-    check_tasks(
-        || {
-            if i >= len_input {
-                return Ok(None);
-            }
+    Ok(())
+}
 
-            let result = load_entities!(
-                TaskCell: CellOrigin(i, Source::Input),
-                TaskCellTypeArgs: CellOrigin(i, Source::Input),
-            );
+// what the tasks of a job share, their votes and checkers aside
+fn job_hash(task: &TaskCell, task_type_args: &TaskCellTypeArgs) -> MerkleHash {
+    let mut job = task.clone();
+    job.mode = TaskMode::Task;
+    job.status = TaskStatus::Idle;
+    job.reveal = RandomSeed::default();
+    job.commit = CommittedHash::default();
 
-            i += 1;
+    let mut job_type_args = task_type_args.clone();
+    job_type_args.checker_lock_arg = Identity::default();
 
-            Ok(Some(result))
-        },
-        &witness,
-        task_count,
-        challenge_count,
+    let mut blake2b = Blake2b::default();
+    blake2b.update(&job.serialize());
+    blake2b.update(&job_type_args.serialize());
+
+    let mut hash = MerkleHash::default();
+    blake2b.finalize(&mut hash);
+    hash
+}
+
+// the task cell every checker of a signed job would have got, had the job been published
+fn signed_job_task(
+    job: &BlockSlice,
+    witness: &CollatorSubmitTasksWitness,
+    chain_id: ChainId,
+) -> Result<(TaskCell, TaskCellTypeArgs), Error> {
+    if job.from > job.to {
+        return Err(Error::SignedVoteMismatch);
+    }
+
+    let task = TaskCell {
+        sidechain_block_height_from: job.from,
+        sidechain_block_height_to: job.to,
+        check_data_size: witness.check_data_size,
+        ..TaskCell::default()
+    };
+    let task_type_args = TaskCellTypeArgs {
         chain_id,
-    )?;
+        ..TaskCellTypeArgs::default()
+    };
+
+    Ok((task, task_type_args))
+}
+
+// the checkers of a signed job, drawn from the state as publishing the job draws its task checkers
+fn draw_signed_job_checkers(
+    sidechain_config_input: &SidechainConfigCell,
+    sidechain_state_res: &mut SidechainStateCell,
+    job: &BlockSlice,
+    chain_id: ChainId,
+) -> Result<Vec<Identity>, Error> {
+    let seed = derive_seed::<Blake2b>(
+        &sidechain_state_res.random_seed,
+        sidechain_state_res.random_offset,
+        chain_id,
+        SeedPurpose::SignedJob,
+        job,
+    );
+    sidechain_state_res.random_offset = sidechain_state_res
+        .random_offset
+        .checked_add(1)
+        .ok_or(Error::SidechainStateMismatch)?;
+
+    let mut sampler = CheckerSampler::new(sidechain_config_input, seed)?;
+    (0..sidechain_config_input.commit_threshold).map(|_| sampler.draw()).collect()
+}
+
+// the checker info cell of the index-th signed vote, in inputs and in outputs
+fn checker_info_cells(index: usize) -> (CellOrigin, CellOrigin) {
+    (
+        CellOrigin(layout::SIGNED_VOTE_INPUTS_START + index, Source::Input),
+        CellOrigin(layout::SIGNED_VOTE_OUTPUTS_START + index, Source::Output),
+    )
+}
+
+// the task cell a signed vote stands for, voted by the checker who signed it
+fn load_signed_task(
+    signature: &CheckerSignature,
+    job_task: &TaskCell,
+    job_task_type_args: &TaskCellTypeArgs,
+    drawn: &[Identity],
+    signers: &[Identity],
+) -> Result<(TaskCell, TaskCellTypeArgs), Error> {
+    let job = BlockSlice {
+        from: job_task.sidechain_block_height_from,
+        to:   job_task.sidechain_block_height_to,
+    };
+
+    let checker = signer_identity(signature, &signature.vote(job_task_type_args.chain_id, job))?;
+    if signers.contains(&checker) {
+        return Err(Error::SignedVoteDuplicated);
+    }
+    if !drawn.contains(&checker) {
+        return Err(Error::SignedVoteMismatch);
+    }
+
+    let mut task = job_task.clone();
+    task.status = signature.verdict;
+    task.reveal = signature.reveal;
+    task.commit = signature.commit;
+
+    let mut task_type_args = job_task_type_args.clone();
+    task_type_args.checker_lock_arg = checker;
+
+    Ok((task, task_type_args))
+}
+
+/*
+the identity of whoever signed vote, a signature made over another vote recovers another key and
+thus another identity, which is not drawn for the job
+ */
+fn signer_identity(signature: &CheckerSignature, vote: &SignedVote) -> Result<Identity, Error> {
+    let message = Message::parse(&vote.message::<Blake2b>());
+    let ecdsa_signature = EcdsaSignature::parse_slice(&signature.signature[..64]).or(Err(Error::SignedVoteSignatureInvalid))?;
+    let recovery_id = RecoveryId::parse(signature.signature[64]).or(Err(Error::SignedVoteSignatureInvalid))?;
+    let pubkey = recover(&message, &ecdsa_signature, &recovery_id).or(Err(Error::SignedVoteSignatureInvalid))?;
+
+    // the lock of the checker, with the blake160 of its key as args
+    let pubkey_hash = Blake2b::calculate(&pubkey.serialize_compressed());
+    let lock = Script::new_builder()
+        .code_hash(Byte32::new_unchecked(signature.lock_code_hash.to_vec().into()))
+        .hash_type(Byte::new(signature.lock_hash_type as u8))
+        .args(
+            Bytes::new_builder()
+                .extend(pubkey_hash[..20].iter().map(|byte| Byte::new(*byte)))
+                .build(),
+        )
+        .build();
+
+    Ok(Identity::from_lock_hash(&Blake2b::calculate(lock.as_slice())))
+}
+
+// every checker of a signed job is paid on its checker info cell, as if it had voted on its task
+// cell
+fn check_checker_infos(
+    signers: &[Identity],
+    sidechain_config_input: &SidechainConfigCell,
+    check_data_size: u128,
+    chain_id: ChainId,
+) -> Result<(), Error> {
+    for (index, checker) in signers.iter().enumerate() {
+        let (checker_info_input, checker_info_output) = checker_info_cells(index);
+
+        let (checker_info_input_type_args, checker_info_input, checker_info_output_type_args, checker_info_output) = load_entities!(
+            CheckerInfoCellTypeArgs: checker_info_input,
+            CheckerInfoCell: checker_info_input,
+            CheckerInfoCellTypeArgs: checker_info_output,
+            CheckerInfoCell: checker_info_output,
+        );
+
+        let mut checker_info_res = checker_info_input.clone();
+        checker_info_res.unpaid_fee = u128::from(sidechain_config_input.check_fee_rate)
+            .checked_mul(check_data_size)
+            .and_then(|fee| checker_info_res.unpaid_fee.checked_add(fee))
+            .ok_or(Error::CheckerInfoMismatch)?;
+
+        if checker_info_input_type_args.chain_id != chain_id
+            || checker_info_input_type_args.checker_lock_arg != *checker
+            || checker_info_input_type_args != checker_info_output_type_args
+            || checker_info_res != checker_info_output
+        {
+            return Err(Error::CheckerInfoMismatch);
+        }
+    }
 
     Ok(())
 }
 
+// the job submitted so far, or a new one if no job is being submitted
+fn load_progress(
+    sidechain_config_input: &SidechainConfigCell,
+    sidechain_state_input: &SidechainStateCell,
+    witness: &CollatorSubmitTasksWitness,
+    job_hash: MerkleHash,
+) -> Result<SubmitProgress, Error> {
+    match sidechain_state_input.submit_progress {
+        Some(progress) if progress.job_hash == job_hash => Ok(progress),
+        Some(_) => Err(Error::TaskJobInconsistent),
+        None => {
+            let challenge_times = u32::try_from(witness.challenge_times).or(Err(Error::Encoding))?;
+
+            Ok(SubmitProgress {
+                job_hash,
+                remaining_tasks: sidechain_config_input
+                    .commit_threshold
+                    .checked_sub(challenge_times)
+                    .ok_or(Error::TaskCountMismatch)?,
+                remaining_challenges: challenge_times
+                    .checked_mul(sidechain_config_input.challenge_threshold)
+                    .ok_or(Error::TaskCountMismatch)?,
+                settle_count: 0,
+                shutdown_count: 0,
+                committed_count: 0,
+                reveal_count: 0,
+                random_seed: sidechain_state_input.random_seed,
+            })
+        }
+    }
+}
+
 fn check_sidechain_config(
     sidechain_config_input: &SidechainConfigCell,
     sidechain_config_input_type_args: &SidechainConfigCellTypeArgs,
     sidechain_config_output: &SidechainConfigCell,
     sidechain_config_output_type_args: &SidechainConfigCellTypeArgs,
+    sidechain_state_res: &mut SidechainStateCell,
     witness: &CollatorSubmitTasksWitness,
-    signer: &[u8; 20],
+    withheld: &[Identity],
+    signer: &Identity,
+    job_height: BlockHeight,
 ) -> Result<(), Error> {
+    if sidechain_config_input.collator_on_duty(job_height) != Some(signer) {
+        return Err(Error::CollatorNotOnDuty);
+    }
+
     let mut sidechain_config_res = sidechain_config_input.clone();
 
-    sidechain_config_res.activated_checkers = sidechain_config_res
-        .activated_checkers
-        .into_iter()
-        .filter(|&lock_arg| {
-            witness
-                .commit
-                .iter()
-                .filter(|committed_checker| committed_checker.is_invalid())
-                .find(|invalid_checker| lock_arg == invalid_checker.checker_lock_arg)
-                .is_none()
-        })
-        .collect();
+    for invalid_checker in witness.commit.iter().filter(|committed_checker| committed_checker.is_invalid()) {
+        jail_checker(
+            &mut sidechain_config_res,
+            Some(sidechain_state_res),
+            &invalid_checker.checker_lock_arg,
+        )?;
+    }
 
-    sidechain_config_res.jailed_checkers.extend(
-        witness
-            .commit
-            .iter()
-            .filter(|committed_checker| committed_checker.is_invalid())
-            .map(|invalid_checker| invalid_checker.checker_lock_arg),
-    );
+    for checker in withheld {
+        // a checker jailed by an earlier chunk is not punished any further
+        if checker_record(&sidechain_config_res, checker)?.map_or(false, |record| !record.is_activated()) {
+            continue;
+        }
 
-    if sidechain_config_input.collator_lock_arg != *signer
-        || sidechain_config_res != *sidechain_config_output
-        || sidechain_config_input_type_args != sidechain_config_output_type_args
-    {
+        //the first punishment is not checked against the threshold, as in refresh
+        if let Some(punish_points) = punish_checker(
+            &sidechain_config_res,
+            sidechain_state_res,
+            checker,
+            sidechain_config_input.reveal_punish_points,
+        )? {
+            if punish_points > sidechain_config_input.refresh_punish_threshold {
+                jail_checker(&mut sidechain_config_res, Some(sidechain_state_res), checker)?;
+            }
+        }
+    }
+
+    if sidechain_config_res != *sidechain_config_output || sidechain_config_input_type_args != sidechain_config_output_type_args {
         return Err(Error::SidechainConfigMismatch);
     }
 
@@ -187,32 +533,26 @@ fn check_sidechain_config(
 
 fn check_sidechain_state(
     sidechain_state_input: &SidechainStateCell,
+    mut sidechain_state_res: SidechainStateCell,
     sidechain_state_input_type_args: &SidechainStateCellTypeArgs,
     sidechain_state_output: &SidechainStateCell,
     sidechain_state_output_type_args: &SidechainStateCellTypeArgs,
     witness: &CollatorSubmitTasksWitness,
     chain_id: ChainId,
 ) -> Result<(), Error> {
-    if sidechain_state_input.random_seed != witness.origin_random_seed {
-        return Err(Error::SidechainStateMismatch);
-    }
-
-    let mut sidechain_state_res = sidechain_state_input.clone();
-    sidechain_state_res.random_seed = witness.new_random_seed;
-
     // verify all existed checker
     for existed_checker in witness.commit.iter().filter(|committed_checker| committed_checker.is_existed()) {
         let index = existed_checker.index.ok_or(Error::Encoding)?;
         if index >= sidechain_state_res.random_commit.len() {
-            return Err(Error::SidechainStateMismatch);
+            return Err(Error::SidechainStateCommitIndexOutOfBound);
         }
 
-        let mut saved_commit = sidechain_state_res.random_commit[index];
+        let saved_commit = &mut sidechain_state_res.random_commit[index];
 
         if saved_commit.checker_lock_arg != existed_checker.checker_lock_arg
-            || saved_commit.committed_hash != existed_checker.origin_committed_hash.ok_or(Error::SidechainStateMismatch)?
+            || saved_commit.committed_hash != existed_checker.origin_committed_hash.ok_or(Error::Encoding)?
         {
-            return Err(Error::SidechainStateMismatch);
+            return Err(Error::SidechainStateCommitMismatch);
         }
 
         // edit valid existed checker
@@ -248,7 +588,7 @@ fn check_sidechain_state(
             .find(|commit| commit.checker_lock_arg == new_checker.checker_lock_arg)
             .is_some()
         {
-            return Err(Error::SidechainStateMismatch);
+            return Err(Error::SidechainStateCommitDuplicated);
         }
 
         // add valid new checker
@@ -294,20 +634,23 @@ fn check_sidechain_fee(
     Ok(())
 }
 
+// the tasks of the chunk, counted into progress and their reveals folded into its seed
+// submitting the job is the deadline of its reveals, the checkers who withheld theirs are returned
 fn check_tasks<T: FnMut() -> Result<Option<(TaskCell, TaskCellTypeArgs)>, Error>>(
     mut next_task: T,
     witness: &CollatorSubmitTasksWitness,
-    mut task_count: usize,
-    mut challenge_count: usize,
+    progress: &mut SubmitProgress,
     chain_id: ChainId,
-) -> Result<(), Error> {
-    let mut settle_count = 0;
-    let mut shutdown_count = 0;
+) -> Result<Vec<Identity>, Error> {
+    if progress.random_seed != witness.origin_random_seed {
+        return Err(Error::SidechainStateRandomSeedMismatch);
+    }
 
     let mut random_seed_calculator = Blake2b::default();
     random_seed_calculator.update(&witness.origin_random_seed);
 
     let mut committed_checker_iter = witness.commit.iter();
+    let mut withheld = Vec::new();
 
     let mut is_first_time = true;
     let mut task_first = TaskCell::default();
@@ -325,64 +668,74 @@ fn check_tasks<T: FnMut() -> Result<Option<(TaskCell, TaskCellTypeArgs)>, Error>
             task_first = task.clone();
             task_first_type_args = task_type_args.clone();
 
-            if task_first_type_args.chain_id != chain_id || task_first.check_data_size != witness.check_data_size {
-                return Err(Error::TaskMismatch);
+            if task_first_type_args.chain_id != chain_id {
+                return Err(Error::TaskChainIdMismatch);
+            }
+
+            if task_first.check_data_size != witness.check_data_size {
+                return Err(Error::TaskCheckDataSizeMismatch);
             }
         };
 
         match task.status {
-            TaskStatus::Idle => return Err(Error::TaskMismatch),
+            TaskStatus::Idle => return Err(Error::TaskNotVoted),
             TaskStatus::TaskPassed => {
-                task_count -= 1;
-                settle_count += 1;
+                progress.remaining_tasks = progress.remaining_tasks.checked_sub(1).ok_or(Error::TaskCountMismatch)?;
+                progress.settle_count += 1;
             }
             TaskStatus::ChallengeRejected => {
-                challenge_count -= 1;
-                settle_count += 1;
+                progress.remaining_challenges = progress.remaining_challenges.checked_sub(1).ok_or(Error::TaskCountMismatch)?;
+                progress.settle_count += 1;
             }
             TaskStatus::ChallengePassed => {
-                challenge_count -= 1;
-                shutdown_count += 1;
+                progress.remaining_challenges = progress.remaining_challenges.checked_sub(1).ok_or(Error::TaskCountMismatch)?;
+                progress.shutdown_count += 1;
             }
         }
 
-        let committed_checker = committed_checker_iter.next().ok_or(Error::TaskMismatch)?;
+        let committed_checker = committed_checker_iter.next().ok_or(Error::TaskCommitCountMismatch)?;
+        if committed_checker.is_existed() {
+            progress.committed_count += 1;
+        }
 
         if committed_checker.is_valid() {
             if committed_checker.is_new() {
                 if task.reveal != DEFAULT_REVEAL_VALUE {
-                    return Err(Error::TaskMismatch);
+                    return Err(Error::TaskRevealNotDefault);
                 }
 
                 random_seed_calculator.update(&DEFAULT_REVEAL_VALUE);
             } else {
                 let hash = committed_checker.origin_committed_hash.ok_or(Error::Encoding)?;
-                if Blake2b::calculate(&task.reveal) != hash {
-                    return Err(Error::TaskMismatch);
+                if Blake2b::calculate(&task.reveal) == hash {
+                    random_seed_calculator.update(&task.reveal);
+                    progress.reveal_count += 1;
+                } else {
+                    random_seed_calculator.update(&DEFAULT_REVEAL_VALUE);
+                    withheld.push(committed_checker.checker_lock_arg);
                 }
-
-                random_seed_calculator.update(&task.reveal);
             }
 
             if match task.mode {
                 TaskMode::Task => task.status != TaskStatus::TaskPassed,
                 TaskMode::Challenge => task.status != TaskStatus::ChallengeRejected,
             } {
-                return Err(Error::TaskMismatch);
+                return Err(Error::TaskStatusInconsistentWithVote);
             }
 
-            if task.commit != committed_checker.new_committed_hash.ok_or(Error::TaskMismatch)? {
-                return Err(Error::TaskMismatch);
+            if task.commit != committed_checker.new_committed_hash.ok_or(Error::Encoding)? {
+                return Err(Error::TaskCommitHashMismatch);
             }
         } else {
             let hash = committed_checker.origin_committed_hash.ok_or(Error::Encoding)?;
 
             if Blake2b::calculate(&task.reveal) == hash {
                 if task.mode != TaskMode::Challenge || task.status != TaskStatus::ChallengePassed {
-                    return Err(Error::TaskMismatch);
+                    return Err(Error::TaskStatusInconsistentWithVote);
                 }
 
                 random_seed_calculator.update(&task.reveal);
+                progress.reveal_count += 1;
             } else {
                 random_seed_calculator.update(&DEFAULT_REVEAL_VALUE);
             }
@@ -398,40 +751,49 @@ fn check_tasks<T: FnMut() -> Result<Option<(TaskCell, TaskCellTypeArgs)>, Error>
 
         task_res_type_args.checker_lock_arg = task_first_type_args.checker_lock_arg;
 
-        if task_type_args.checker_lock_arg != committed_checker.checker_lock_arg
-            || task_res != task_first
-            || task_res_type_args != task_first_type_args
-        {
-            return Err(Error::TaskMismatch);
+        if task_type_args.checker_lock_arg != committed_checker.checker_lock_arg {
+            return Err(Error::TaskCheckerMismatch);
+        }
+
+        if task_res != task_first || task_res_type_args != task_first_type_args {
+            return Err(Error::TaskJobInconsistent);
         }
     }
 
-    if task_count != 0 || challenge_count != 0 || shutdown_count >= settle_count || committed_checker_iter.next().is_some() {
-        return Err(Error::TaskMismatch);
+    if committed_checker_iter.next().is_some() {
+        return Err(Error::TaskCommitCountMismatch);
     }
 
     let mut random_seed_res = RandomSeed::default();
     random_seed_calculator.finalize(&mut random_seed_res);
     if random_seed_res != witness.new_random_seed {
-        return Err(Error::TaskMismatch);
+        return Err(Error::RandomSeedMismatch);
     }
+    progress.random_seed = random_seed_res;
 
-    Ok(())
+    Ok(withheld)
 }
 
-fn is_collator_submit_tasks(job_count: usize) -> Result<(), Error> {
+fn is_collator_submit_tasks(chunk_count: usize, signed: bool) -> Result<(), Error> {
     let global = check_global_cell()?;
 
-    let len_input = FIXED_INPUT_CELLS + job_count;
+    let (len_input, len_output) = if signed {
+        (
+            layout::SIGNED_VOTE_INPUTS_START + chunk_count,
+            layout::SIGNED_VOTE_OUTPUTS_START + chunk_count,
+        )
+    } else {
+        (FIXED_INPUT_CELLS + chunk_count, layout::OUTPUT_COUNT)
+    };
 
-    if is_cell_count_not_equals(len_input, Source::Input) || is_cell_count_not_equals(4, Source::Output) {
+    if is_cell_count_not_equals(len_input, Source::Input) || is_cell_count_not_equals(len_output, Source::Output) {
         return Err(Error::CellNumberMismatch);
     }
     check_cells! {
         &global,
         {
             CodeCell: CODE_INPUT,
-            SidechainConfigCell: SIDECHAIN_STATE_INPUT,
+            SidechainConfigCell: SIDECHAIN_CONFIG_INPUT,
             SidechainStateCell: SIDECHAIN_STATE_INPUT,
             SidechainFeeCell: SIDECHAIN_FEE_INPUT,
 
@@ -442,7 +804,26 @@ fn is_collator_submit_tasks(job_count: usize) -> Result<(), Error> {
         },
     };
 
-    TaskCell::range_check(FIXED_INPUT_CELLS..len_input, Source::Input, &global)?;
+    check_continuity! {
+        SIDECHAIN_CONFIG_INPUT => SIDECHAIN_CONFIG_OUTPUT,
+        SIDECHAIN_STATE_INPUT => SIDECHAIN_STATE_OUTPUT,
+    };
+    check_capacity_continuity(SIDECHAIN_FEE_INPUT, SIDECHAIN_FEE_OUTPUT)?;
+
+    if signed {
+        for index in 0..chunk_count {
+            let (checker_info_input, checker_info_output) = checker_info_cells(index);
+
+            CheckerInfoCell::check(checker_info_input, &global)?;
+            CheckerInfoCell::check(checker_info_output, &global)?;
+
+            check_continuity! {
+                checker_info_input => checker_info_output,
+            };
+        }
+    } else {
+        TaskCell::range_check(FIXED_INPUT_CELLS..len_input, Source::Input, &global)?;
+    }
 
     Ok(())
 }
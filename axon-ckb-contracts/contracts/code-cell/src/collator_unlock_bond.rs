@@ -5,39 +5,40 @@ use ckb_std::ckb_constants::Source;
 use common_raw::{
     cell::{
         code::CodeCell,
-        muse_token::MuseTokenCell,
         sidechain_bond::{SidechainBondCell, SidechainBondCellLockArgs},
         sidechain_config::{SidechainConfigCell, SidechainConfigCellTypeArgs, SidechainStatus},
         sidechain_state::{SidechainStateCell, SidechainStateCellTypeArgs},
         sudt_token::SudtTokenCell,
     },
+    common::Identity,
+    layout::collator_unlock_bond as layout,
     witness::collator_unlock_bond::CollatorUnlockBondWitness,
     FromRaw,
 };
 
 use crate::{cell::*, common::*, error::Error};
 
-const SIDECHAIN_CONFIG_DEP: CellOrigin = CellOrigin(5, Source::CellDep);
-const SIDECHAIN_STATE_DEP: CellOrigin = CellOrigin(6, Source::CellDep);
+const SIDECHAIN_CONFIG_DEP: CellOrigin = CellOrigin(layout::SIDECHAIN_CONFIG_DEP, Source::CellDep);
+const SIDECHAIN_STATE_DEP: CellOrigin = CellOrigin(layout::SIDECHAIN_STATE_DEP, Source::CellDep);
 
-const SIDECHAIN_BOND_INPUT: CellOrigin = CellOrigin(1, Source::Input);
+const SIDECHAIN_BOND_INPUT: CellOrigin = CellOrigin(layout::SIDECHAIN_BOND_INPUT, Source::Input);
 
-const SUDT_OUTPUT: CellOrigin = CellOrigin(1, Source::Output);
+const SUDT_OUTPUT: CellOrigin = CellOrigin(layout::SUDT_OUTPUT, Source::Output);
 
-pub fn collator_unlock_bond(raw_witness: &[u8], signer: [u8; 20]) -> Result<(), Error> {
+pub fn collator_unlock_bond(raw_witness: &[u8], signer: Identity) -> Result<(), Error> {
     /*
     CollatorUnlockBond,
 
     Dep:    0 Global Config Cell
-    Dep:    1 Sidechain Config Cell
-    Dep:    2 Sidechain State Cell
+    Dep:    5 Sidechain Config Cell
+    Dep:    6 Sidechain State Cell
 
     Code Cell                   ->          Code Cell
     Sidechain Bond Cell         ->          Sudt Cell
 
     */
 
-    let witness = CollatorUnlockBondWitness::from_raw(raw_witness).ok_or(Error::Encoding)?;
+    let witness = CollatorUnlockBondWitness::from_raw(raw_witness).or(Err(Error::WitnessEncoding))?;
 
     is_collator_unlock_bond()?;
 
@@ -48,7 +49,7 @@ pub fn collator_unlock_bond(raw_witness: &[u8], signer: [u8; 20]) -> Result<(),
         state_dep,
         sidechain_bond_input_lock_args,
         sidechain_bond_input,
-        muse_token_output,
+        sudt_token_output,
     ) = load_entities! {
         SidechainConfigCellTypeArgs: SIDECHAIN_CONFIG_DEP,
         SidechainConfigCell: SIDECHAIN_CONFIG_DEP,
@@ -56,21 +57,44 @@ pub fn collator_unlock_bond(raw_witness: &[u8], signer: [u8; 20]) -> Result<(),
         SidechainStateCell: SIDECHAIN_STATE_DEP,
         SidechainBondCellLockArgs: SIDECHAIN_BOND_INPUT,
         SidechainBondCell: SIDECHAIN_BOND_INPUT,
-        MuseTokenCell: SUDT_OUTPUT,
+        SudtTokenCell: SUDT_OUTPUT,
     };
-    if config_dep_type_args.chain_id != witness.chain_id || config_dep.sidechain_status != SidechainStatus::Shutdown {
+    if config_dep_type_args.chain_id != witness.chain_id {
         return Err(Error::SidechainConfigMismatch);
     }
 
-    if state_dep_type_args.chain_id != u32::try_from(witness.chain_id).or(Err(Error::Encoding))?
-        || state_dep.submit_sidechain_block_height > sidechain_bond_input_lock_args.unlock_sidechain_height
-    {
+    check_bond_type_script(SIDECHAIN_BOND_INPUT, &config_dep)?;
+    check_same_type_script(SIDECHAIN_BOND_INPUT, SUDT_OUTPUT)?;
+
+    if state_dep_type_args.chain_id != u32::try_from(witness.chain_id).or(Err(Error::Encoding))? {
         return Err(Error::SidechainStateMismatch);
     }
 
+    match config_dep.sidechain_status {
+        SidechainStatus::Shutdown => {
+            if state_dep.submit_sidechain_block_height > sidechain_bond_input_lock_args.unlock_sidechain_height {
+                return Err(Error::SidechainStateMismatch);
+            }
+        }
+        // a collator which left the collator set gets its bond back once no waiting job is covered by it
+        SidechainStatus::Relaying => {
+            if config_dep.collators.contains(&sidechain_bond_input_lock_args.collator_lock_arg) {
+                return Err(Error::SidechainConfigMismatch);
+            }
+
+            if state_dep
+                .waiting_jobs
+                .iter()
+                .any(|job| job.from <= sidechain_bond_input_lock_args.unlock_sidechain_height)
+            {
+                return Err(Error::CollatorJobsPending);
+            }
+        }
+    }
+
     if signer != sidechain_bond_input_lock_args.collator_lock_arg
         || sidechain_bond_input_lock_args.chain_id != witness.chain_id
-        || sidechain_bond_input.amount != muse_token_output.amount
+        || sidechain_bond_input.amount != sudt_token_output.amount
     {
         return Err(Error::SidechainBondMismatch);
     }
@@ -81,7 +105,7 @@ pub fn collator_unlock_bond(raw_witness: &[u8], signer: [u8; 20]) -> Result<(),
 fn is_collator_unlock_bond() -> Result<(), Error> {
     let global = check_global_cell()?;
 
-    if is_cell_count_not_equals(2, Source::Input) || is_cell_count_not_equals(2, Source::Output) {
+    if is_cell_count_not_equals(layout::INPUT_COUNT, Source::Input) || is_cell_count_not_equals(layout::OUTPUT_COUNT, Source::Output) {
         return Err(Error::CellNumberMismatch);
     }
 
@@ -95,7 +119,6 @@ fn is_collator_unlock_bond() -> Result<(), Error> {
             SidechainBondCell: SIDECHAIN_BOND_INPUT,
 
             CodeCell: CODE_OUTPUT,
-            SudtTokenCell: SUDT_OUTPUT,
         },
     };
 
@@ -1,22 +1,27 @@
 use ckb_std::ckb_constants::Source;
-use ckb_std::high_level::{load_cell_capacity, load_header};
 
 use common_raw::cell::global_config::GlobalConfigCellData;
-use common_raw::FromRaw;
+use common_raw::common::{Identity, SignerIdentity};
+use common_raw::layout::CODE_CELL;
+use common_raw::{FromRaw, Hasher};
 
-use crate::{cell::CellOrigin, error::Error};
+use crate::{cell::CellOrigin, error::Error, source::*};
 
-pub const CODE_INPUT: CellOrigin = CellOrigin(0, Source::Input);
-pub const CODE_OUTPUT: CellOrigin = CellOrigin(0, Source::Output);
+pub const CODE_INPUT: CellOrigin = CellOrigin(CODE_CELL, Source::Input);
+pub const CODE_OUTPUT: CellOrigin = CellOrigin(CODE_CELL, Source::Output);
 
 pub const CKB_HASH_PERSONALIZATION: &[u8] = b"ckb-default-hash";
 
 pub fn is_cell_count_greater(n: usize, source: Source) -> bool {
-    load_cell_capacity(n, source).is_ok()
+    CellOrigin(n, source)
+        .resolve()
+        .map_or(false, |(index, source)| cell_source().load_cell_capacity(index, source).is_ok())
 }
 
 pub fn is_cell_count_smaller(n: usize, source: Source) -> bool {
-    load_cell_capacity(n - 1, source).is_err()
+    CellOrigin(n - 1, source)
+        .resolve()
+        .map_or(true, |(index, source)| cell_source().load_cell_capacity(index, source).is_err())
 }
 
 pub fn is_cell_count_not_equals(n: usize, source: Source) -> bool {
@@ -30,15 +35,45 @@ macro_rules! check_cells {
     }
 }
 
+// the lookup of common::check_global_cell, read through the cell source
 pub fn check_global_cell() -> Result<GlobalConfigCellData, Error> {
-    common::check_global_cell().ok_or(Error::GlobalConfigMissed)
+    for i in 0.. {
+        match cell_source().load_cell_type_hash(i, Source::CellDep) {
+            Ok(Some(type_hash)) if type_hash == common::GLOBAL_CONFIG_TYPE_HASH => {
+                let data = cell_source()
+                    .load_cell_data(i, Source::CellDep)
+                    .or(Err(Error::GlobalConfigMissed))?;
+                return GlobalConfigCellData::from_raw(&data).or(Err(Error::GlobalConfigEncoding));
+            }
+            Ok(_) => {}
+            Err(_) => break,
+        }
+    }
+
+    Err(Error::GlobalConfigMissed)
+}
+
+/*
+the signer is whoever unlocks the cell, identified by its lock script hash,
+thus any lock (secp256k1, multisig, omni-lock, ...) could be a signer
+ */
+pub fn load_signer(origin: CellOrigin) -> Result<Identity, Error> {
+    let (index, source) = origin.resolve()?;
+    let lock_hash = cell_source().load_cell_lock_hash(index, source)?;
+
+    Ok(Identity::from_lock_hash(&lock_hash))
 }
 
 pub fn require_header_dep() -> Result<u64, Error> {
-    let header = load_header(0, Source::HeaderDep).map_err(|_| Error::MissingHeader)?;
+    let header = cell_source().load_header(0, Source::HeaderDep).map_err(|_| Error::MissingHeader)?;
     let raw_header = header.raw();
 
-    u64::from_raw(raw_header.timestamp().as_reader().raw_data()).ok_or(Error::MissingHeader)
+    u64::from_raw(raw_header.timestamp().as_reader().raw_data()).or(Err(Error::MissingHeader))
+}
+
+// the part/whole share of an amount, rounded down, None on overflow or an empty whole
+pub fn pro_rata(amount: u128, part: u128, whole: u128) -> Option<u128> {
+    amount.checked_mul(part)?.checked_div(whole)
 }
 
 pub struct Blake2b {
@@ -72,3 +107,9 @@ impl Blake2b {
         result
     }
 }
+
+impl Hasher for Blake2b {
+    fn hash(data: &[u8]) -> [u8; 32] {
+        Self::calculate(data)
+    }
+}
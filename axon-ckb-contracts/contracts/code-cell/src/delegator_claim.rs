@@ -0,0 +1,161 @@
+use ckb_std::ckb_constants::Source;
+
+use common_raw::{
+    cell::{
+        checker_info::{CheckerInfoCell, CheckerInfoCellTypeArgs, CheckerInfoStatus},
+        code::CodeCell,
+        delegation::{DelegationCell, DelegationCellLockArgs},
+        muse_token::MuseTokenCell,
+        sidechain_config::{SidechainConfigCell, SidechainConfigCellTypeArgs},
+    },
+    common::{Identity, RATE_DENOMINATOR},
+    layout::delegator_claim as layout,
+    witness::delegator_claim::DelegatorClaimWitness,
+    FromRaw,
+};
+
+use crate::{cell::*, checker_set::update_checker_record, common::*, error::Error};
+
+const SIDECHAIN_CONFIG_INPUT: CellOrigin = CellOrigin(layout::SIDECHAIN_CONFIG_INPUT, Source::Input);
+const CHECKER_INFO_INPUT: CellOrigin = CellOrigin(layout::CHECKER_INFO_INPUT, Source::Input);
+const DELEGATION_INPUT: CellOrigin = CellOrigin(layout::DELEGATION_INPUT, Source::Input);
+
+const SIDECHAIN_CONFIG_OUTPUT: CellOrigin = CellOrigin(layout::SIDECHAIN_CONFIG_OUTPUT, Source::Output);
+const CHECKER_INFO_OUTPUT: CellOrigin = CellOrigin(layout::CHECKER_INFO_OUTPUT, Source::Output);
+const TOKEN_OUTPUT: CellOrigin = CellOrigin(layout::TOKEN_OUTPUT, Source::Output);
+
+pub fn delegator_claim(raw_witness: &[u8], signer: Identity) -> Result<(), Error> {
+    /*
+    DelegatorClaim,
+
+    Dep:    0 Global Config Cell
+
+    Code Cell                   ->          Code Cell
+    Sidechain Config Cell       ->          Sidechain Config Cell
+    Checker Info Cell           ->          Checker Info Cell
+    Delegation Cell             ->          Muse Token Cell
+
+    */
+
+    /*
+    Job:
+
+    1. the delegator takes its principal and the fee accrued to it
+    2. the principal no longer weighs for a checker still activated
+    3. the principal delegated to a jailed checker is slashed by slash_rate
+
+     */
+
+    is_delegator_claim()?;
+
+    let witness = DelegatorClaimWitness::from_raw(raw_witness).or(Err(Error::WitnessEncoding))?;
+
+    let (
+        config_input_type_args,
+        config_input,
+        checker_info_input_type_args,
+        checker_info_input,
+        delegation_input_lock_args,
+        delegation_input,
+    ) = load_entities! {
+        SidechainConfigCellTypeArgs: SIDECHAIN_CONFIG_INPUT,
+        SidechainConfigCell: SIDECHAIN_CONFIG_INPUT,
+        CheckerInfoCellTypeArgs: CHECKER_INFO_INPUT,
+        CheckerInfoCell: CHECKER_INFO_INPUT,
+        DelegationCellLockArgs: DELEGATION_INPUT,
+        DelegationCell: DELEGATION_INPUT,
+    };
+    let (config_output_type_args, config_output, checker_info_output_type_args, checker_info_output, token_output) = load_entities! {
+        SidechainConfigCellTypeArgs: SIDECHAIN_CONFIG_OUTPUT,
+        SidechainConfigCell: SIDECHAIN_CONFIG_OUTPUT,
+        CheckerInfoCellTypeArgs: CHECKER_INFO_OUTPUT,
+        CheckerInfoCell: CHECKER_INFO_OUTPUT,
+        MuseTokenCell: TOKEN_OUTPUT,
+    };
+
+    let checker = delegation_input_lock_args.checker_lock_arg;
+    let principal = delegation_input_lock_args.principal;
+
+    if delegation_input_lock_args.chain_id != witness.chain_id || delegation_input_lock_args.delegator_lock_arg != signer {
+        return Err(Error::DelegationMismatch);
+    }
+
+    let relaying = checker_info_input.status == CheckerInfoStatus::Relaying;
+    let mut config_res = config_input.clone();
+    let jailed = update_checker_record(&mut config_res, &checker, |record| match record {
+        Some(record) if record.is_activated() => {
+            if relaying {
+                record.weight = record.weight.checked_sub(principal).ok_or(Error::CheckerWeightsMismatch)?;
+            }
+            Ok(false)
+        }
+        Some(_) => Ok(true),
+        None => Ok(false),
+    })?;
+
+    if config_res != config_output
+        || config_input_type_args != config_output_type_args
+        || config_input_type_args.chain_id != witness.chain_id
+    {
+        return Err(Error::SidechainConfigMismatch);
+    }
+
+    let mut checker_info_res = checker_info_input.clone();
+    checker_info_res.delegated_amount = checker_info_res
+        .delegated_amount
+        .checked_sub(principal)
+        .ok_or(Error::CheckerInfoMismatch)?;
+
+    if checker_info_input_type_args.checker_lock_arg != checker
+        || checker_info_input_type_args.chain_id != witness.chain_id
+        || checker_info_input_type_args != checker_info_output_type_args
+        || checker_info_res != checker_info_output
+    {
+        return Err(Error::CheckerInfoMismatch);
+    }
+
+    // delegators share the fate of the checker in proportion to what they put in
+    let slashed = if jailed {
+        pro_rata(principal, u128::from(config_input.slash_rate), u128::from(RATE_DENOMINATOR)).ok_or(Error::Encoding)?
+    } else {
+        0
+    };
+
+    let mut token_res = MuseTokenCell::default();
+    token_res.amount = delegation_input.amount.checked_sub(slashed).ok_or(Error::DelegationMismatch)?;
+    if token_res != token_output {
+        return Err(Error::MuseTokenMismatch);
+    }
+
+    Ok(())
+}
+
+fn is_delegator_claim() -> Result<(), Error> {
+    let global = check_global_cell()?;
+
+    if is_cell_count_not_equals(layout::INPUT_COUNT, Source::Input) || is_cell_count_not_equals(layout::OUTPUT_COUNT, Source::Output) {
+        return Err(Error::CellNumberMismatch);
+    }
+
+    check_cells! {
+        &global,
+        {
+            CodeCell: CODE_INPUT,
+            SidechainConfigCell: SIDECHAIN_CONFIG_INPUT,
+            CheckerInfoCell: CHECKER_INFO_INPUT,
+            DelegationCell: DELEGATION_INPUT,
+
+            CodeCell: CODE_OUTPUT,
+            SidechainConfigCell: SIDECHAIN_CONFIG_OUTPUT,
+            CheckerInfoCell: CHECKER_INFO_OUTPUT,
+            MuseTokenCell: TOKEN_OUTPUT,
+        },
+    };
+
+    check_continuity! {
+        SIDECHAIN_CONFIG_INPUT => SIDECHAIN_CONFIG_OUTPUT,
+        CHECKER_INFO_INPUT => CHECKER_INFO_OUTPUT,
+    };
+
+    Ok(())
+}
@@ -0,0 +1,144 @@
+use ckb_std::ckb_constants::Source;
+
+use common_raw::{
+    cell::{
+        checker_info::{CheckerInfoCell, CheckerInfoCellTypeArgs, CheckerInfoStatus},
+        code::CodeCell,
+        delegation::{DelegationCell, DelegationCellLockArgs},
+        muse_token::MuseTokenCell,
+        sidechain_config::{SidechainConfigCell, SidechainConfigCellTypeArgs, SidechainStatus},
+    },
+    common::Identity,
+    layout::delegator_delegate as layout,
+    witness::delegator_delegate::DelegatorDelegateWitness,
+    FromRaw,
+};
+
+use crate::{cell::*, checker_set::update_checker_record, common::*, error::Error};
+
+const SIDECHAIN_CONFIG_INPUT: CellOrigin = CellOrigin(layout::SIDECHAIN_CONFIG_INPUT, Source::Input);
+const CHECKER_INFO_INPUT: CellOrigin = CellOrigin(layout::CHECKER_INFO_INPUT, Source::Input);
+const TOKEN_INPUT: CellOrigin = CellOrigin(layout::TOKEN_INPUT, Source::Input);
+
+const SIDECHAIN_CONFIG_OUTPUT: CellOrigin = CellOrigin(layout::SIDECHAIN_CONFIG_OUTPUT, Source::Output);
+const CHECKER_INFO_OUTPUT: CellOrigin = CellOrigin(layout::CHECKER_INFO_OUTPUT, Source::Output);
+const DELEGATION_OUTPUT: CellOrigin = CellOrigin(layout::DELEGATION_OUTPUT, Source::Output);
+
+pub fn delegator_delegate(raw_witness: &[u8], signer: Identity) -> Result<(), Error> {
+    /*
+    DelegatorDelegate,
+
+    Dep:    0 Global Config Cell
+
+    Code Cell                   ->          Code Cell
+    Sidechain Config Cell       ->          Sidechain Config Cell
+    Checker Info Cell           ->          Checker Info Cell
+    Muse Token Cell             ->          Delegation Cell
+
+    */
+
+    is_delegator_delegate()?;
+
+    let witness = DelegatorDelegateWitness::from_raw(raw_witness).or(Err(Error::WitnessEncoding))?;
+
+    let (config_input_type_args, config_input, checker_info_input_type_args, checker_info_input, token_input) = load_entities! {
+        SidechainConfigCellTypeArgs: SIDECHAIN_CONFIG_INPUT,
+        SidechainConfigCell: SIDECHAIN_CONFIG_INPUT,
+        CheckerInfoCellTypeArgs: CHECKER_INFO_INPUT,
+        CheckerInfoCell: CHECKER_INFO_INPUT,
+        MuseTokenCell: TOKEN_INPUT,
+    };
+    let (
+        config_output_type_args,
+        config_output,
+        checker_info_output_type_args,
+        checker_info_output,
+        delegation_output_lock_args,
+        delegation_output,
+    ) = load_entities! {
+        SidechainConfigCellTypeArgs: SIDECHAIN_CONFIG_OUTPUT,
+        SidechainConfigCell: SIDECHAIN_CONFIG_OUTPUT,
+        CheckerInfoCellTypeArgs: CHECKER_INFO_OUTPUT,
+        CheckerInfoCell: CHECKER_INFO_OUTPUT,
+        DelegationCellLockArgs: DELEGATION_OUTPUT,
+        DelegationCell: DELEGATION_OUTPUT,
+    };
+
+    let checker = checker_info_input_type_args.checker_lock_arg;
+    let amount = token_input.amount;
+
+    // the delegation weighs in the drawing of the checker from now on
+    let mut config_res = config_input.clone();
+    update_checker_record(&mut config_res, &checker, |record| match record {
+        Some(record) if record.is_activated() => {
+            record.weight = record.weight.checked_add(amount).ok_or(Error::CheckerWeightsMismatch)?;
+            Ok(())
+        }
+        _ => Err(Error::CheckerNotActivated),
+    })?;
+
+    if config_input.sidechain_status != SidechainStatus::Relaying
+        || config_res != config_output
+        || config_input_type_args != config_output_type_args
+        || config_input_type_args.chain_id != witness.chain_id
+    {
+        return Err(Error::SidechainConfigMismatch);
+    }
+
+    let mut checker_info_res = checker_info_input.clone();
+    checker_info_res.delegated_amount = checker_info_res
+        .delegated_amount
+        .checked_add(amount)
+        .ok_or(Error::CheckerInfoMismatch)?;
+
+    if checker_info_input.status != CheckerInfoStatus::Relaying
+        || checker_info_res != checker_info_output
+        || checker_info_input_type_args != checker_info_output_type_args
+        || checker_info_input_type_args.chain_id != witness.chain_id
+    {
+        return Err(Error::CheckerInfoMismatch);
+    }
+
+    let delegation_res_lock_args = DelegationCellLockArgs {
+        chain_id:           witness.chain_id,
+        checker_lock_arg:   checker,
+        delegator_lock_arg: signer,
+        principal:          amount,
+    };
+
+    if amount == 0 || delegation_output.amount != amount || delegation_res_lock_args != delegation_output_lock_args {
+        return Err(Error::DelegationMismatch);
+    }
+
+    Ok(())
+}
+
+fn is_delegator_delegate() -> Result<(), Error> {
+    let global = check_global_cell()?;
+
+    if is_cell_count_not_equals(layout::INPUT_COUNT, Source::Input) || is_cell_count_not_equals(layout::OUTPUT_COUNT, Source::Output) {
+        return Err(Error::CellNumberMismatch);
+    }
+
+    check_cells! {
+        &global,
+        {
+            CodeCell: CODE_INPUT,
+            SidechainConfigCell: SIDECHAIN_CONFIG_INPUT,
+            CheckerInfoCell: CHECKER_INFO_INPUT,
+            MuseTokenCell: TOKEN_INPUT,
+
+            CodeCell: CODE_OUTPUT,
+            SidechainConfigCell: SIDECHAIN_CONFIG_OUTPUT,
+            CheckerInfoCell: CHECKER_INFO_OUTPUT,
+            DelegationCell: DELEGATION_OUTPUT,
+        },
+    };
+
+    check_continuity! {
+        SIDECHAIN_CONFIG_INPUT => SIDECHAIN_CONFIG_OUTPUT,
+        CHECKER_INFO_INPUT => CHECKER_INFO_OUTPUT,
+    };
+
+    Ok(())
+}
@@ -2,41 +2,62 @@
 use core::result::Result;
 
 use ckb_std::ckb_constants::Source;
-use ckb_std::{ckb_types::prelude::*, high_level::load_witness_args};
+use ckb_std::ckb_types::prelude::*;
 
 use common_raw::{
-    cell::{code::CodeCellLockArgs, sidechain_config::SidechainConfigCellTypeArgs, sidechain_state::SidechainStateCellTypeArgs},
+    cell::{sidechain_config::SidechainConfigCellTypeArgs, sidechain_state::SidechainStateCellTypeArgs},
+    common::Identity,
+    layout::admin_create_sidechain as layout,
     pattern::Pattern,
-    witness::{admin_create_sidechain::AdminCreateSidechainWitness, code_cell_witness::CodeCellTypeWitness},
+    witness::{
+        admin_create_sidechain::AdminCreateSidechainWitness, checker_proofs::CheckerProofsWitness, code_cell_witness::CodeCellTypeWitness,
+    },
     FromRaw,
 };
 
+use crate::common::{load_signer, CODE_INPUT};
 use crate::pattern::is_admin_create_sidechain;
 use crate::{
-    anyone_refresh_task::anyone_refresh_task, anyone_shutdown_sidechain::anyone_shutdown_sidechain, cell::*,
+    anyone_refresh_task::anyone_refresh_task, anyone_shutdown_sidechain::anyone_shutdown_sidechain, batch::batch, cell::*,
     checker_bond_withdraw::checker_bond_withdraw, checker_join_sidechain::checker_join_sidechain,
-    checker_publish_challenge::checker_publish_challenge, checker_quit_sidechain::checker_quit_sidechain,
-    checker_take_beneficiary::checker_take_beneficiary, checker_vote::checker_vote, collator_publish_task::collator_publish_task,
-    collator_shutdown_sidechain::collator_shutdown_sidechain, collator_submit_tasks::collator_submit_tasks,
-    collator_unlock_bond::collator_unlock_bond, error::Error,
+    checker_propose_change::checker_propose_change, checker_publish_challenge::checker_publish_challenge,
+    checker_quit_sidechain::checker_quit_sidechain, checker_set::with_checker_proofs, checker_take_beneficiary::checker_take_beneficiary,
+    checker_vote::checker_vote, checker_vote_proposal::checker_vote_proposal, collator_handover::collator_handover,
+    collator_publish_task::collator_publish_task, collator_shutdown_sidechain::collator_shutdown_sidechain,
+    collator_submit_tasks::collator_submit_tasks, collator_unlock_bond::collator_unlock_bond, delegator_claim::delegator_claim,
+    delegator_delegate::delegator_delegate, error::Error, execute_proposal::execute_proposal, source::*,
 };
 
-const CODE_INPUT: CellOrigin = CellOrigin(0, Source::Input);
-
 pub fn main() -> Result<(), Error> {
     /*
     the unlocker of code cell is the owner/signer of code cell
-    thus code cell's lock script must be secp256k1
+    the signer is identified by the lock script hash of code cell, whatever the lock is,
+    which admin/collator/checker lock args refer to
      */
     // of cause, the signer is correct
-    let signer = CodeCellLockArgs::load(CODE_INPUT)?.lock_arg;
+    let signer = load_signer(CODE_INPUT)?;
 
-    let witness = load_witness_args(0, Source::GroupInput)?;
-    let witness = witness.input_type().to_opt().ok_or(Error::MissingWitness)?;
+    let witness_args = cell_source().load_witness_args(0, Source::GroupInput)?;
+    let witness = witness_args.input_type().to_opt().ok_or(Error::MissingWitness)?;
     let raw_witness = witness.as_reader().raw_data();
 
-    let witness = CodeCellTypeWitness::from_raw(raw_witness).ok_or(Error::Encoding)?;
+    // proofs of committed checker sets, only sidechains with a checker_set_root need them
+    let checker_proofs = match witness_args.output_type().to_opt() {
+        Some(proofs) => CheckerProofsWitness::from_raw(proofs.as_reader().raw_data()).or(Err(Error::WitnessEncoding))?,
+        None => CheckerProofsWitness::default(),
+    };
+
+    with_checker_proofs(checker_proofs, || dispatch(raw_witness, signer))
+}
+
+pub fn dispatch(raw_witness: &[u8], signer: Identity) -> Result<(), Error> {
+    let witness = CodeCellTypeWitness::from_raw(raw_witness).or(Err(Error::WitnessEncoding))?;
 
+    /*
+    Dep 0 is the global config cell, the environment takes the deps before
+    layout::PATTERN_CELL_DEPS_START (5) and the fixed deps of a pattern follow it, a dep of
+    sidechain_config_dep_index is the one the witness points to. See common_raw::layout.
+     */
     match witness.pattern() {
         /*
         CheckerBondWithdraw
@@ -65,11 +86,12 @@ pub fn main() -> Result<(), Error> {
         CheckerQuitSidechain
 
         Dep:    0 Global Config Cell
+        Dep:    5 Sidechain State Cell
 
         Code Cell                   ->          Code Cell
         Sidechain Config Cell       ->          Sidechain Config Cell
         Checker Bond Cell           ->          Checker Bond Cell
-        Checker Info Cell           ->          Null
+        Checker Info Cell           ->          Checker Info Cell
         */
         Pattern::CheckerQuitSidechain => checker_quit_sidechain(raw_witness, signer),
 
@@ -77,11 +99,11 @@ pub fn main() -> Result<(), Error> {
         CheckerVote,
 
         Dep:    0 Global Config Cell
-        Dep:    1 Sidechain Config Cell
+        Dep:    sidechain_config_dep_index Sidechain Config Cell
 
         Code Cell                   ->         Code Cell
         Checker Info Cell           ->          Checker Info Cell
-        Task Cell                   ->          Null
+        Task Cell                   ->          Task Cell
 
         */
         Pattern::CheckerVote => checker_vote(raw_witness, signer),
@@ -89,11 +111,13 @@ pub fn main() -> Result<(), Error> {
         CheckerPublishChallenge,
 
         Dep:    0 Global Config Cell
-        Dep:    1 Sidechain Config Cell
+        Dep:    sidechain_config_dep_index Sidechain Config Cell
 
         Code Cell                   ->         Code Cell
+        Sidechain State Cell        ->          Sidechain State Cell
         Checker Info Cell           ->          Checker Info Cell
-        Task Cell                   ->          [Task Cell]
+        Task Cell                   ->          Task Cell
+        Null                        ->          [Task Cell]
 
         */
         Pattern::CheckerPublishChallenge => checker_publish_challenge(raw_witness, signer),
@@ -105,7 +129,8 @@ pub fn main() -> Result<(), Error> {
         Code Cell                   ->         Code Cell
         Checker Info Cell           ->          Checker Info Cell
         Sidechain Fee Cell          ->          Sidechain Fee Cell
-        Muse Token Cell             ->          Muse Token Cell
+        Null                        ->          Muse Token Cell
+        [Delegation Cell]           ->          [Delegation Cell]
 
         */
         Pattern::CheckerTakeBeneficiary => checker_take_beneficiary(raw_witness, signer),
@@ -129,25 +154,56 @@ pub fn main() -> Result<(), Error> {
         CollatorPublishTask,
 
         Dep:    0 Global Config Cell
-        Dep:    1 Sidechain Config Cell
+        Dep:    5 Sidechain Config Cell
+        Dep:    6 Sidechain Bond Cell
 
         Code Cell                   ->          Code Cell
         Sidechain State Cell        ->          Sidechain State Cell
-        Sidechain Bond Cell/Sudt    ->          Sidechain Bond Cell
+        Sidechain Fee Cell          ->          Sidechain Fee Cell
+        Muse Token Cell             ->          Null
         Null                        ->          [Task Cell]
 
         */
         Pattern::CollatorPublishTask => collator_publish_task(raw_witness, signer),
+        /*
+        CollatorSubmitTasks,
+
+        Dep:    0 Global Config Cell
+
+        Code Cell                   ->          Code Cell
+        Sidechain Config Cell       ->          Sidechain Config Cell
+        Sidechain State Cell        ->          Sidechain State Cell
+        Sidechain Fee Cell          ->          Sidechain Fee Cell
+        [Task Cell]                 ->          Null
+
+        or for a signed job, with the signed votes in the witness
+
+        [Checker Info Cell]         ->          [Checker Info Cell]
+
+        */
         Pattern::CollatorSubmitTasks => collator_submit_tasks(raw_witness, signer),
+        /*
+        AnyoneShutdownSidechain,
+
+        Dep:    0 Global Config Cell
+
+        Code Cell                   ->          Code Cell
+        Sidechain Config Cell       ->          Sidechain Config Cell
+        Sidechain Fee Cell          ->          Sidechain Fee Cell
+        [Task Cell]                 ->          Null
+
+        */
         Pattern::AnyoneShutdownSidechain => anyone_shutdown_sidechain(raw_witness),
 
         /*
-        RefreshTask,
+        AnyoneRefreshTask,
 
         Dep:    0 Global Config Cell
-        Dep:    1 Sidechain Config Cell
+        Header Dep: 0 Reference Block
 
         Code Cell                   ->          Code Cell
+        Sidechain Config Cell       ->          Sidechain Config Cell
+        Sidechain State Cell        ->          Sidechain State Cell
         [Task Cell]                 ->          [Task Cell]
 
         */
@@ -157,8 +213,8 @@ pub fn main() -> Result<(), Error> {
         CollatorUnlockBond,
 
         Dep:    0 Global Config Cell
-        Dep:    1 Sidechain Config Cell
-        Dep:    2 Sidechain State Cell
+        Dep:    5 Sidechain Config Cell
+        Dep:    6 Sidechain State Cell
 
         Code Cell                   ->          Code Cell
         Sidechain Bond Cell         ->          Sudt Cell
@@ -166,19 +222,103 @@ pub fn main() -> Result<(), Error> {
         */
         Pattern::CollatorUnlockBond => collator_unlock_bond(raw_witness, signer),
         /*
-        AnyoneShutdownSidechain,
+        CollatorShutdownSidechain,
 
         Dep:    0 Global Config Cell
+        Dep:    5 Sidechain State Cell
+
+        Code Cell                   ->          Code Cell
+        Sidechain Config Cell       ->          Sidechain Config Cell
+        [Approval Cell]             ->          [Any Cell]
 
-        Code Cell                   -> ~
-        Sidechain Config Cell       -> ~
-        Sidechain Fee Cell          -> ~
         */
         Pattern::CollatorShutdownSidechain => collator_shutdown_sidechain(raw_witness, signer),
+
+        /*
+        Batch,
+
+        Code Cell                   ->          Code Cell
+        [Entry 0 Cells]             ->          [Entry 0 Cells]
+        [Entry 1 Cells]             ->          [Entry 1 Cells]
+        ...
+
+        */
+        Pattern::Batch => batch(raw_witness, signer),
+
+        /*
+        CheckerProposeChange,
+
+        Dep:    0 Global Config Cell
+        Dep:    5 Sidechain Config Cell
+
+        Code Cell                   ->          Code Cell
+        Null                        ->          Proposal Cell
+
+        */
+        Pattern::CheckerProposeChange => checker_propose_change(raw_witness, signer),
+        /*
+        CheckerVoteProposal,
+
+        Dep:    0 Global Config Cell
+        Dep:    5 Sidechain Config Cell
+
+        Code Cell                   ->          Code Cell
+        Proposal Cell               ->          Proposal Cell
+
+        */
+        Pattern::CheckerVoteProposal => checker_vote_proposal(raw_witness, signer),
+        /*
+        ExecuteProposal,
+
+        Dep:    0 Global Config Cell
+
+        Code Cell                   ->          Code Cell
+        Sidechain Config Cell       ->          Sidechain Config Cell
+        Proposal Cell               ->          Null
+
+        */
+        Pattern::ExecuteProposal => execute_proposal(raw_witness),
+
+        /*
+        CollatorHandover,
+
+        Dep:    0 Global Config Cell
+
+        Code Cell                   ->          Code Cell
+        Sidechain Config Cell       ->          Sidechain Config Cell
+        Sudt Cell                   ->          Sidechain Bond Cell
+
+        */
+        Pattern::CollatorHandover => collator_handover(raw_witness, signer),
+
+        /*
+        DelegatorDelegate,
+
+        Dep:    0 Global Config Cell
+
+        Code Cell                   ->          Code Cell
+        Sidechain Config Cell       ->          Sidechain Config Cell
+        Checker Info Cell           ->          Checker Info Cell
+        Muse Token Cell             ->          Delegation Cell
+
+        */
+        Pattern::DelegatorDelegate => delegator_delegate(raw_witness, signer),
+        /*
+        DelegatorClaim,
+
+        Dep:    0 Global Config Cell
+
+        Code Cell                   ->          Code Cell
+        Sidechain Config Cell       ->          Sidechain Config Cell
+        Checker Info Cell           ->          Checker Info Cell
+        Delegation Cell             ->          Muse Token Cell
+
+        */
+        Pattern::DelegatorClaim => delegator_claim(raw_witness, signer),
     }
 }
 
-fn admin_create_sidechain(_signer: [u8; 20]) -> Result<(), Error> {
+fn admin_create_sidechain(_signer: Identity) -> Result<(), Error> {
     /*
     AdminCreateSidechain,
 
@@ -189,13 +329,13 @@ fn admin_create_sidechain(_signer: [u8; 20]) -> Result<(), Error> {
     Null                        ->          Sidechain State Cell
 
     */
-    let witness = load_witness_args(0, Source::Input)?;
+    let witness = cell_source().load_witness_args(0, Source::Input)?;
     let witness = witness.input_type().to_opt().ok_or(Error::MissingWitness)?;
-    let witness = AdminCreateSidechainWitness::from_raw(&witness.as_slice()[..]).ok_or(Error::Encoding)?;
+    let witness = AdminCreateSidechainWitness::from_raw(&witness.as_slice()[..]).or(Err(Error::WitnessEncoding))?;
 
-    let sidechain_config_output_type_args = SidechainConfigCellTypeArgs::load(CellOrigin(1, Source::Output))?;
+    let sidechain_config_output_type_args = SidechainConfigCellTypeArgs::load(CellOrigin(layout::SIDECHAIN_CONFIG_OUTPUT, Source::Output))?;
 
-    let _sidechain_state_output_type_args = SidechainStateCellTypeArgs::load(CellOrigin(2, Source::Output))?;
+    let _sidechain_state_output_type_args = SidechainStateCellTypeArgs::load(CellOrigin(layout::SIDECHAIN_STATE_OUTPUT, Source::Output))?;
 
     if sidechain_config_output_type_args.chain_id != witness.chain_id {
         return Err(Error::Wrong);
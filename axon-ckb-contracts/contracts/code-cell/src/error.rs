@@ -1,8 +1,12 @@
+use core::fmt;
+
 use ckb_std::error::SysError;
 
+use common_raw::find_error;
+
 /// Error
 #[repr(i8)]
-#[derive(Debug)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum Error {
     IndexOutOfBound = 1,
     ItemMissing,
@@ -37,6 +41,119 @@ pub enum Error {
     MuseTokenMismatch,
     TaskMismatch,
     MissingHeader,
+    BatchPatternInvalid,
+
+    // the entity which could not be decoded
+    WitnessEncoding,
+    GlobalConfigEncoding,
+    CodeCellLockArgsEncoding,
+    CheckerBondEncoding,
+    CheckerBondLockArgsEncoding,
+    CheckerInfoEncoding,
+    CheckerInfoTypeArgsEncoding,
+    MuseTokenEncoding,
+    SidechainBondEncoding,
+    SidechainBondLockArgsEncoding,
+    SidechainConfigEncoding,
+    SidechainConfigTypeArgsEncoding,
+    SidechainFeeEncoding,
+    SidechainFeeLockArgsEncoding,
+    SidechainStateEncoding,
+    SidechainStateTypeArgsEncoding,
+    SudtTokenEncoding,
+    TaskEncoding,
+    TaskTypeArgsEncoding,
+
+    // the check of the pattern which failed
+    TaskChainIdMismatch,
+    TaskCheckDataSizeMismatch,
+    TaskNotVoted,
+    TaskAlreadyVoted,
+    TaskStatusInconsistentWithVote,
+    TaskRevealHashMismatch,
+    TaskRevealNotDefault,
+    TaskCommitHashMismatch,
+    TaskCheckerMismatch,
+    TaskJailedCheckerMismatch,
+    TaskJobInconsistent,
+    TaskCommitCountMismatch,
+    TaskCountMismatch,
+    TaskChallengeMajority,
+    RandomSeedMismatch,
+    SidechainStateRandomSeedMismatch,
+    SidechainStateCommitIndexOutOfBound,
+    SidechainStateCommitMismatch,
+    SidechainStateCommitDuplicated,
+
+    // governance of the sidechain by its checkers
+    ProposalMismatch,
+    ProposalEncoding,
+    ProposalTypeArgsEncoding,
+    CheckerNotActivated,
+    ProposalAlreadyVoted,
+    ProposalQuorumNotReached,
+
+    // collator handover
+    CollatorHandoverNotAllowed,
+    CollatorJobsPending,
+
+    // collator set
+    CollatorNotInSet,
+    CollatorNotOnDuty,
+    CollatorQuorumNotReached,
+
+    // stake weighted checker selection
+    CheckerWeightsMismatch,
+    CheckerSampleExhausted,
+
+    // stake delegation
+    DelegationMismatch,
+    DelegationEncoding,
+    DelegationLockArgsEncoding,
+    DelegationsIncomplete,
+
+    // cell continuity
+    CapacityDecreased,
+    LockScriptMismatch,
+
+    // sidechain bond token
+    SidechainBondTokenMismatch,
+
+    // jail
+    JailedCheckerDelegated,
+
+    // checker set commitment
+    CheckerProofMissing,
+    CheckerProofMismatch,
+    CheckerProofUnused,
+
+    // sorted checker lists
+    SidechainConfigCheckersUnsorted,
+    SidechainStateCheckersUnsorted,
+
+    // per transaction limits
+    RefreshTaskLimitExceeded,
+    SubmitTaskLimitExceeded,
+
+    // commit reveal
+    RevealQuorumNotReached,
+
+    // signed votes
+    SignedVoteMismatch,
+    SignedVoteDuplicated,
+
+    // waiting jobs
+    JobNotWaiting,
+
+    // commit reveal
+    RevealQuorumUnreachable,
+
+    // governance of the sidechain by its checkers
+    ProposalOutdated,
+    ProposedConfigInvalid,
+
+    // signatures of signed votes
+    SignedVoteSignatureInvalid,
 }
 
 impl From<SysError> for Error {
@@ -51,3 +168,12 @@ impl From<SysError> for Error {
         }
     }
 }
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // messages live in common-raw so off-chain tools can decode exit codes too
+        let reason = find_error(*self as i8).map_or("unknown error", |error| error.message);
+
+        write!(f, "{} (code {})", reason, *self as i8)
+    }
+}
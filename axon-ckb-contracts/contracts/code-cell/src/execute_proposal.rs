@@ -0,0 +1,154 @@
+use core::convert::TryFrom;
+
+use ckb_std::ckb_constants::Source;
+
+use common_raw::{
+    cell::{
+        code::CodeCell,
+        proposal::{ProposalCell, ProposalCellTypeArgs, ProposalChange},
+        sidechain_config::{SidechainConfigCell, SidechainConfigCellTypeArgs, SidechainStatus},
+    },
+    common::RATE_DENOMINATOR,
+    layout::execute_proposal as layout,
+    witness::execute_proposal::ExecuteProposalWitness,
+    FromRaw,
+};
+
+use crate::{cell::*, checker_set::is_activated, common::*, error::Error};
+
+const SIDECHAIN_CONFIG_INPUT: CellOrigin = CellOrigin(layout::SIDECHAIN_CONFIG_INPUT, Source::Input);
+const PROPOSAL_INPUT: CellOrigin = CellOrigin(layout::PROPOSAL_INPUT, Source::Input);
+
+const SIDECHAIN_CONFIG_OUTPUT: CellOrigin = CellOrigin(layout::SIDECHAIN_CONFIG_OUTPUT, Source::Output);
+
+pub fn execute_proposal(raw_witness: &[u8]) -> Result<(), Error> {
+    /*
+    ExecuteProposal,
+
+    Dep:    0 Global Config Cell
+
+    Code Cell                   ->          Code Cell
+    Sidechain Config Cell       ->          Sidechain Config Cell
+    Proposal Cell               ->          Null
+
+    */
+    is_execute_proposal()?;
+
+    let witness = ExecuteProposalWitness::from_raw(raw_witness).or(Err(Error::WitnessEncoding))?;
+
+    let (config_input_type_args, config_input, proposal_input_type_args, proposal_input, config_output_type_args, config_output) = load_entities!(
+        SidechainConfigCellTypeArgs: SIDECHAIN_CONFIG_INPUT,
+        SidechainConfigCell: SIDECHAIN_CONFIG_INPUT,
+        ProposalCellTypeArgs: PROPOSAL_INPUT,
+        ProposalCell: PROPOSAL_INPUT,
+        SidechainConfigCellTypeArgs: SIDECHAIN_CONFIG_OUTPUT,
+        SidechainConfigCell: SIDECHAIN_CONFIG_OUTPUT,
+    );
+
+    if proposal_input_type_args.chain_id != witness.chain_id {
+        return Err(Error::ProposalMismatch);
+    }
+
+    // a proposal made before another one was executed would undo it
+    if proposal_input.config_nonce != config_input.config_nonce {
+        return Err(Error::ProposalOutdated);
+    }
+
+    // only votes of checkers still activated count, a checker which quit or got jailed lost its say
+    for (i, vote) in proposal_input.votes.iter().enumerate() {
+        if proposal_input.votes[..i].contains(vote) {
+            return Err(Error::ProposalAlreadyVoted);
+        }
+    }
+    let mut votes = 0;
+    for vote in proposal_input.votes.iter() {
+        if is_activated(&config_input, vote)? {
+            votes += 1;
+        }
+    }
+    if votes < usize::try_from(config_input.checker_threshold).or(Err(Error::Encoding))? {
+        return Err(Error::ProposalQuorumNotReached);
+    }
+
+    let mut config_res = config_input.clone();
+    match proposal_input.change {
+        ProposalChange::UpdateConfig(proposed) => {
+            config_res = SidechainConfigCell {
+                sidechain_status: config_input.sidechain_status,
+                config_nonce: config_input.config_nonce,
+                checker_normal_count: config_input.checker_normal_count,
+                checker_total_count: config_input.checker_total_count,
+                activated_checkers: config_input.activated_checkers.clone(),
+                activated_checker_weights: config_input.activated_checker_weights.clone(),
+                jailed_checkers: config_input.jailed_checkers.clone(),
+                checker_set_root: config_input.checker_set_root,
+                admin_lock_arg: config_input.admin_lock_arg,
+                collators: config_input.collators.clone(),
+                bond_sudt_typescript_codehash: config_input.bond_sudt_typescript_codehash,
+                bond_sudt_typescript_hashtype: config_input.bond_sudt_typescript_hashtype,
+                ..proposed
+            };
+            check_proposed_config(&config_res)?;
+        }
+        ProposalChange::ReplaceCollators(collators) => {
+            // an empty collator set could publish nothing anymore
+            if collators.is_empty() {
+                return Err(Error::ProposalMismatch);
+            }
+            config_res.collators = collators;
+            check_proposed_config(&config_res)?;
+        }
+        ProposalChange::Shutdown => config_res.sidechain_status = SidechainStatus::Shutdown,
+    }
+    config_res.config_nonce = config_res.config_nonce.checked_add(1).ok_or(Error::SidechainConfigMismatch)?;
+
+    if config_input.sidechain_status != SidechainStatus::Relaying
+        || config_res != config_output
+        || config_input_type_args != config_output_type_args
+        || config_input_type_args.chain_id != witness.chain_id
+    {
+        return Err(Error::SidechainConfigMismatch);
+    }
+
+    Ok(())
+}
+
+// a proposal leaves the sidechain with quorums it can reach and a slash rate of at most the whole
+fn check_proposed_config(config: &SidechainConfigCell) -> Result<(), Error> {
+    if config.checker_threshold == 0
+        || usize::try_from(config.collator_threshold).or(Err(Error::Encoding))? > config.collators.len()
+        || config.commit_threshold == 0
+        || config.reveal_quorum > config.commit_threshold
+        || config.slash_rate > RATE_DENOMINATOR
+    {
+        return Err(Error::ProposedConfigInvalid);
+    }
+
+    Ok(())
+}
+
+fn is_execute_proposal() -> Result<(), Error> {
+    let global = check_global_cell()?;
+
+    if is_cell_count_not_equals(layout::INPUT_COUNT, Source::Input) || is_cell_count_not_equals(layout::OUTPUT_COUNT, Source::Output) {
+        return Err(Error::CellNumberMismatch);
+    }
+
+    check_cells! {
+        &global,
+        {
+            CodeCell: CODE_INPUT,
+            SidechainConfigCell: SIDECHAIN_CONFIG_INPUT,
+            ProposalCell: PROPOSAL_INPUT,
+
+            CodeCell: CODE_OUTPUT,
+            SidechainConfigCell: SIDECHAIN_CONFIG_OUTPUT,
+        },
+    };
+
+    check_continuity! {
+        SIDECHAIN_CONFIG_INPUT => SIDECHAIN_CONFIG_OUTPUT,
+    };
+
+    Ok(())
+}
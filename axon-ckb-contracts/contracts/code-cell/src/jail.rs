@@ -0,0 +1,52 @@
+use common_raw::{
+    cell::{
+        sidechain_config::{CheckerRecord, CheckerStatus, SidechainConfigCell},
+        sidechain_state::SidechainStateCell,
+    },
+    common::Identity,
+};
+
+use crate::{
+    checker_set::{clear_task_record, update_checker_record},
+    error::Error,
+};
+
+/*
+A jailed checker leaves the drawing for good: its weight is dropped from the activated checkers,
+it no longer counts as a normal checker and is kept as jailed. Its punish points and last accepted
+height are cleared from the state, when the pattern carries the state cell.
+Jailing an already jailed checker changes nothing.
+ */
+pub fn jail_checker(config: &mut SidechainConfigCell, state: Option<&mut SidechainStateCell>, checker: &Identity) -> Result<(), Error> {
+    let jailed = CheckerRecord {
+        status: CheckerStatus::Jailed,
+        weight: 0,
+    };
+    let was_activated = update_checker_record(config, checker, |record| match record {
+        Some(record) if !record.is_activated() => Ok(None),
+        _ => Ok(Some(record.replace(jailed).is_some())),
+    })?;
+
+    let was_activated = match was_activated {
+        Some(was_activated) => was_activated,
+        None => return Ok(()),
+    };
+    if was_activated {
+        config.checker_normal_count = config.checker_normal_count.checked_sub(1).ok_or(Error::SidechainConfigMismatch)?;
+    }
+
+    if let Some(state) = state {
+        clear_task_record(config, state, checker)?;
+    }
+
+    Ok(())
+}
+
+/*
+There is no unjailing, a jailed checker only leaves jail by quitting the sidechain: like any
+quitting checker it is removed from the set whatever its status, and checker_quit_sidechain slashes
+its bond by slash_rate. Returns the record the checker had.
+ */
+pub fn remove_checker(config: &mut SidechainConfigCell, checker: &Identity) -> Result<CheckerRecord, Error> {
+    update_checker_record(config, checker, |record| record.take().ok_or(Error::SidechainConfigMismatch))
+}
@@ -0,0 +1,46 @@
+//! Validation logic of the code cell.
+//!
+//! `main.rs` runs it on chain. Every read of the transaction goes through `source::CellSource`,
+//! with the `native` feature the same handlers could run against an in-memory transaction, see
+//! `native`.
+
+#![no_std]
+// core::hint::spin_loop is still unstable on the pinned toolchain
+#![cfg_attr(feature = "native", feature(renamed_spin_loop))]
+
+extern crate alloc;
+
+// define modules
+#[macro_use]
+mod cell;
+mod checker_set;
+#[macro_use]
+mod common;
+pub mod entry;
+pub mod error;
+mod jail;
+#[cfg(feature = "native")]
+pub mod native;
+mod pattern;
+mod sampling;
+pub mod source;
+
+mod anyone_refresh_task;
+mod anyone_shutdown_sidechain;
+mod batch;
+mod checker_bond_withdraw;
+mod checker_join_sidechain;
+mod checker_propose_change;
+mod checker_publish_challenge;
+mod checker_quit_sidechain;
+mod checker_take_beneficiary;
+mod checker_vote;
+mod checker_vote_proposal;
+mod collator_handover;
+mod collator_publish_task;
+mod collator_shutdown_sidechain;
+mod collator_submit_tasks;
+mod collator_unlock_bond;
+mod delegator_claim;
+mod delegator_delegate;
+mod execute_proposal;
@@ -12,27 +12,7 @@
 
 use ckb_std::{debug, default_alloc};
 
-// define modules
-#[macro_use]
-mod cell;
-#[macro_use]
-mod common;
-mod entry;
-mod error;
-mod pattern;
-
-mod anyone_refresh_task;
-mod anyone_shutdown_sidechain;
-mod checker_bond_withdraw;
-mod checker_join_sidechain;
-mod checker_publish_challenge;
-mod checker_quit_sidechain;
-mod checker_take_beneficiary;
-mod checker_vote;
-mod collator_publish_task;
-mod collator_shutdown_sidechain;
-mod collator_submit_tasks;
-mod collator_unlock_bond;
+use code_cell::entry;
 
 ckb_std::entry!(program_entry);
 default_alloc!();
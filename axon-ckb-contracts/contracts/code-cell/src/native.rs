@@ -0,0 +1,176 @@
+use alloc::vec::Vec;
+use core::{
+    hint::spin_loop,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use ckb_std::{
+    ckb_constants::Source,
+    ckb_types::{packed::WitnessArgs, prelude::*},
+    error::SysError,
+};
+
+use common_raw::{
+    cell::sidechain_config::SidechainConfigCell,
+    common::{Identity, RandomSeed},
+    layout::CODE_CELL,
+};
+
+use crate::{
+    common::Blake2b,
+    entry::main,
+    error::Error,
+    sampling::CheckerSampler,
+    source::{with_cell_source, CellSource},
+};
+
+pub use ckb_std::ckb_types::packed::{Header, Script};
+
+/*
+a resolved cell of an in-memory transaction,
+header is the block the cell is committed in, only required if the pattern loads it
+ */
+#[derive(Debug, Clone)]
+pub struct NativeCell {
+    pub capacity: u64,
+    pub lock:     Script,
+    pub type_:    Option<Script>,
+    pub data:     Vec<u8>,
+    pub header:   Option<Header>,
+}
+
+/*
+a transaction with every input and dep resolved, the code cell is verified as the script group
+of the type script of input CODE_CELL
+ */
+#[derive(Debug, Clone, Default)]
+pub struct NativeTransaction {
+    pub cell_deps:   Vec<NativeCell>,
+    pub header_deps: Vec<Header>,
+    pub inputs:      Vec<NativeCell>,
+    pub outputs:     Vec<NativeCell>,
+    pub witnesses:   Vec<Vec<u8>>,
+}
+
+// the handlers keep their state in globals as on chain, thus verifications could not overlap
+static VERIFYING: AtomicBool = AtomicBool::new(false);
+
+/*
+runs the code cell against tx without a vm, the result is the same as on chain,
+use Display of the error for a human-readable reason
+ */
+pub fn verify(tx: &NativeTransaction) -> Result<(), Error> {
+    // released on unwinding as well, a panicking verification would block every later one otherwise
+    struct Release;
+
+    impl Drop for Release {
+        fn drop(&mut self) {
+            VERIFYING.store(false, Ordering::Release);
+        }
+    }
+
+    while VERIFYING
+        .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+        .is_err()
+    {
+        spin_loop();
+    }
+    let _release = Release;
+
+    with_cell_source(tx, main)
+}
+
+/*
+the first count checkers drawn from config with seed, in the order the task cells of a publish
+take them. A committed checker set is not supported, its draws read the record proofs of a witness
+ */
+pub fn draw_checkers(config: &SidechainConfigCell, seed: RandomSeed, count: usize) -> Result<Vec<Identity>, Error> {
+    let mut sampler = CheckerSampler::new(config, seed)?;
+
+    (0..count).map(|_| sampler.draw()).collect()
+}
+
+impl NativeTransaction {
+    // indices of cells sharing the type script of the code cell
+    fn group(&self, cells: &[NativeCell]) -> Option<Vec<usize>> {
+        let code_type = self.inputs.get(CODE_CELL)?.type_.as_ref()?.as_slice();
+
+        let group = cells
+            .iter()
+            .enumerate()
+            .filter(|(_, cell)| cell.type_.as_ref().map_or(false, |script| script.as_slice() == code_type))
+            .map(|(index, _)| index)
+            .collect();
+
+        Some(group)
+    }
+
+    fn resolve(&self, index: usize, source: Source) -> Result<(usize, Source), SysError> {
+        let group = match source {
+            Source::GroupInput => (self.group(&self.inputs), Source::Input),
+            Source::GroupOutput => (self.group(&self.outputs), Source::Output),
+            _ => return Ok((index, source)),
+        };
+
+        match group {
+            (Some(group), source) => group.get(index).map(|&index| (index, source)).ok_or(SysError::IndexOutOfBound),
+            (None, _) => Err(SysError::IndexOutOfBound),
+        }
+    }
+
+    fn cell(&self, index: usize, source: Source) -> Result<&NativeCell, SysError> {
+        let (index, source) = self.resolve(index, source)?;
+        let cells = match source {
+            Source::Input => &self.inputs,
+            Source::Output => &self.outputs,
+            Source::CellDep => &self.cell_deps,
+            _ => return Err(SysError::IndexOutOfBound),
+        };
+
+        cells.get(index).ok_or(SysError::IndexOutOfBound)
+    }
+}
+
+impl CellSource for NativeTransaction {
+    fn load_cell_data(&self, index: usize, source: Source) -> Result<Vec<u8>, SysError> {
+        Ok(self.cell(index, source)?.data.clone())
+    }
+
+    fn load_cell_capacity(&self, index: usize, source: Source) -> Result<u64, SysError> {
+        Ok(self.cell(index, source)?.capacity)
+    }
+
+    fn load_cell_lock(&self, index: usize, source: Source) -> Result<Script, SysError> {
+        Ok(self.cell(index, source)?.lock.clone())
+    }
+
+    fn load_cell_lock_hash(&self, index: usize, source: Source) -> Result<[u8; 32], SysError> {
+        Ok(Blake2b::calculate(self.cell(index, source)?.lock.as_slice()))
+    }
+
+    fn load_cell_type(&self, index: usize, source: Source) -> Result<Option<Script>, SysError> {
+        Ok(self.cell(index, source)?.type_.clone())
+    }
+
+    fn load_cell_type_hash(&self, index: usize, source: Source) -> Result<Option<[u8; 32]>, SysError> {
+        Ok(self
+            .cell(index, source)?
+            .type_
+            .as_ref()
+            .map(|script| Blake2b::calculate(script.as_slice())))
+    }
+
+    fn load_header(&self, index: usize, source: Source) -> Result<Header, SysError> {
+        match source {
+            Source::HeaderDep => self.header_deps.get(index).cloned().ok_or(SysError::IndexOutOfBound),
+            _ => self.cell(index, source)?.header.clone().ok_or(SysError::ItemMissing),
+        }
+    }
+
+    fn load_witness_args(&self, index: usize, source: Source) -> Result<WitnessArgs, SysError> {
+        let (index, _) = self.resolve(index, source)?;
+        let witness = self.witnesses.get(index).ok_or(SysError::IndexOutOfBound)?;
+
+        WitnessArgs::from_slice(witness).map_err(|_| SysError::Encoding)
+    }
+}
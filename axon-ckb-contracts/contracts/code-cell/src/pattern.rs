@@ -1,6 +1,9 @@
 use ckb_std::ckb_constants::Source;
 
-use common_raw::cell::{code::CodeCell, sidechain_config::SidechainConfigCell, sidechain_state::SidechainStateCell};
+use common_raw::{
+    cell::{code::CodeCell, sidechain_config::SidechainConfigCell, sidechain_state::SidechainStateCell},
+    layout::admin_create_sidechain as layout,
+};
 
 use crate::{
     cell::{CellOrigin, TypedCell},
@@ -22,17 +25,17 @@ pub fn is_admin_create_sidechain() -> Result<(), Error> {
 
     let global = check_global_cell()?;
 
-    if is_cell_count_not_equals(2, Source::Input) || is_cell_count_not_equals(3, Source::Output) {
+    if is_cell_count_not_equals(layout::INPUT_COUNT, Source::Input) || is_cell_count_not_equals(layout::OUTPUT_COUNT, Source::Output) {
         return Err(Error::CellNumberMismatch);
     }
 
     check_cells! {
         &global,
         {
-            CodeCell: CellOrigin(0, Source::Input),
-            CodeCell: CellOrigin(0, Source::Output),
-            SidechainConfigCell: CellOrigin(1, Source::Output),
-            SidechainStateCell: CellOrigin(2, Source::Output),
+            CodeCell: CODE_INPUT,
+            CodeCell: CODE_OUTPUT,
+            SidechainConfigCell: CellOrigin(layout::SIDECHAIN_CONFIG_OUTPUT, Source::Output),
+            SidechainStateCell: CellOrigin(layout::SIDECHAIN_STATE_OUTPUT, Source::Output),
         },
     };
 
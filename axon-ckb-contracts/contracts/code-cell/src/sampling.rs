@@ -0,0 +1,159 @@
+use alloc::vec::Vec;
+
+use common_raw::{
+    cell::sidechain_config::{CheckerRecord, SidechainConfigCell},
+    common::{Identity, MerkleHash, RandomSeed},
+    FromRaw,
+};
+
+use crate::{checker_set::next_proven_record, common::Blake2b, error::Error};
+
+/*
+Draws distinct activated checkers in proportion to their bonds.
+
+Every draw hashes the seed and reads a u128 from it, numbers of the last incomplete multiple of
+the weight are rejected and drawn again, so the modulo doesn't favour the first checkers. The point
+is drawn from the weight of the checkers not taken yet, a drawn or excluded checker being taken,
+and moved past the ranges of the taken checkers lying before it, which makes it a point of the
+whole set that never falls on a taken checker. Thus no job is assigned twice to the same checker
+and every draw hashes the seed a bounded number of times, whatever the weights are.
+
+A listed sidechain looks the point up in the cumulative weights of its config. A committed checker
+set is drawn from with the record proofs of the witness instead: each draw takes the next proof,
+which has to prove the checker whose range covers the point, and excluding a checker takes a proof
+of it to learn its range.
+ */
+pub struct CheckerSampler<'a> {
+    config:       &'a SidechainConfigCell,
+    seed:         RandomSeed,
+    // weight ranges of the taken checkers as offset and weight, sorted by offset
+    taken:        Vec<(u128, u128)>,
+    taken_weight: u128,
+}
+
+impl<'a> CheckerSampler<'a> {
+    pub fn new(config: &'a SidechainConfigCell, seed: RandomSeed) -> Result<Self, Error> {
+        if config.activated_checker_weights.len() != config.activated_checkers.len() {
+            return Err(Error::CheckerWeightsMismatch);
+        }
+
+        Ok(Self {
+            config,
+            seed,
+            taken: Vec::new(),
+            taken_weight: 0,
+        })
+    }
+
+    pub fn exclude(&mut self, checker: &Identity) -> Result<(), Error> {
+        if let Some(root) = self.config.checker_set_root {
+            let (proof, path) = next_proven_record(&root)?;
+            if proof.checker != *checker {
+                return Err(Error::CheckerProofMismatch);
+            }
+            return self.take(path.offset, activated_weight(proof.record));
+        }
+
+        if let Some(index) = self.config.checker_index(checker) {
+            self.take_listed(index)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn draw(&mut self) -> Result<Identity, Error> {
+        match self.config.checker_set_root {
+            Some(root) => self.draw_committed(&root),
+            None => self.draw_listed(),
+        }
+    }
+
+    fn draw_listed(&mut self) -> Result<Identity, Error> {
+        let weight = self.remaining_weight(self.config.total_checker_weight())?;
+        let point = self.next_point(weight)?;
+        let index = self
+            .config
+            .checker_at(self.skip_taken(point))
+            .ok_or(Error::CheckerWeightsMismatch)?;
+
+        self.take_listed(index)?;
+        Ok(self.config.activated_checkers[index])
+    }
+
+    fn draw_committed(&mut self, root: &MerkleHash) -> Result<Identity, Error> {
+        if *root == MerkleHash::default() {
+            return Err(Error::CheckerSampleExhausted);
+        }
+
+        let (proof, path) = next_proven_record(root)?;
+        let weight = self.remaining_weight(path.root.sum)?;
+        let point = self.next_point(weight)?;
+        let point = self.skip_taken(point);
+
+        let weight = activated_weight(proof.record);
+        if point < path.offset || point - path.offset >= weight {
+            return Err(Error::CheckerProofMismatch);
+        }
+
+        self.take(path.offset, weight)?;
+        Ok(proof.checker)
+    }
+
+    // the weight left to draw from out of total
+    fn remaining_weight(&self, total: u128) -> Result<u128, Error> {
+        match total.checked_sub(self.taken_weight) {
+            Some(0) => Err(Error::CheckerSampleExhausted),
+            Some(weight) => Ok(weight),
+            None => Err(Error::CheckerWeightsMismatch),
+        }
+    }
+
+    // the point counted over the checkers not taken yet as a point of the whole set
+    fn skip_taken(&self, mut point: u128) -> u128 {
+        for (offset, weight) in &self.taken {
+            if *offset > point {
+                break;
+            }
+            point += weight;
+        }
+
+        point
+    }
+
+    // 2^128 % weight numbers at the top of the range are rejected
+    fn next_point(&mut self, weight: u128) -> Result<u128, Error> {
+        let rejected = (u128::MAX % weight + 1) % weight;
+        loop {
+            self.seed = Blake2b::calculate(&self.seed);
+            let seed_number = u128::from_raw(&self.seed[0..16]).or(Err(Error::Encoding))?;
+
+            if seed_number <= u128::MAX - rejected {
+                return Ok(seed_number % weight);
+            }
+        }
+    }
+
+    fn take_listed(&mut self, index: usize) -> Result<(), Error> {
+        let weight = self.config.checker_weight(index).ok_or(Error::CheckerWeightsMismatch)?;
+        self.take(self.config.activated_checker_weights[index] - weight, weight)
+    }
+
+    fn take(&mut self, offset: u128, weight: u128) -> Result<(), Error> {
+        if weight == 0 {
+            return Ok(());
+        }
+
+        let index = match self.taken.binary_search_by_key(&offset, |(offset, _)| *offset) {
+            Ok(_) => return Ok(()),
+            Err(index) => index,
+        };
+        self.taken_weight = self.taken_weight.checked_add(weight).ok_or(Error::CheckerWeightsMismatch)?;
+        self.taken.insert(index, (offset, weight));
+
+        Ok(())
+    }
+}
+
+fn activated_weight(record: Option<CheckerRecord>) -> u128 {
+    record.filter(|record| record.is_activated()).map_or(0, |record| record.weight)
+}
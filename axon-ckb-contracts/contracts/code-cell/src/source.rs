@@ -0,0 +1,101 @@
+use alloc::vec::Vec;
+
+use ckb_std::{
+    ckb_constants::Source,
+    ckb_types::packed::{Header, Script, WitnessArgs},
+    error::SysError,
+    high_level,
+};
+
+/*
+everything the code cell reads from its transaction goes through a CellSource,
+on chain it is served by syscalls, off chain by an in-memory transaction (see native.rs),
+the handlers could not tell the difference
+ */
+pub trait CellSource {
+    fn load_cell_data(&self, index: usize, source: Source) -> Result<Vec<u8>, SysError>;
+
+    fn load_cell_capacity(&self, index: usize, source: Source) -> Result<u64, SysError>;
+
+    fn load_cell_lock(&self, index: usize, source: Source) -> Result<Script, SysError>;
+
+    fn load_cell_lock_hash(&self, index: usize, source: Source) -> Result<[u8; 32], SysError>;
+
+    fn load_cell_type(&self, index: usize, source: Source) -> Result<Option<Script>, SysError>;
+
+    fn load_cell_type_hash(&self, index: usize, source: Source) -> Result<Option<[u8; 32]>, SysError>;
+
+    fn load_header(&self, index: usize, source: Source) -> Result<Header, SysError>;
+
+    fn load_witness_args(&self, index: usize, source: Source) -> Result<WitnessArgs, SysError>;
+}
+
+pub struct Syscalls;
+
+impl CellSource for Syscalls {
+    fn load_cell_data(&self, index: usize, source: Source) -> Result<Vec<u8>, SysError> {
+        high_level::load_cell_data(index, source)
+    }
+
+    fn load_cell_capacity(&self, index: usize, source: Source) -> Result<u64, SysError> {
+        high_level::load_cell_capacity(index, source)
+    }
+
+    fn load_cell_lock(&self, index: usize, source: Source) -> Result<Script, SysError> {
+        high_level::load_cell_lock(index, source)
+    }
+
+    fn load_cell_lock_hash(&self, index: usize, source: Source) -> Result<[u8; 32], SysError> {
+        high_level::load_cell_lock_hash(index, source)
+    }
+
+    fn load_cell_type(&self, index: usize, source: Source) -> Result<Option<Script>, SysError> {
+        high_level::load_cell_type(index, source)
+    }
+
+    fn load_cell_type_hash(&self, index: usize, source: Source) -> Result<Option<[u8; 32]>, SysError> {
+        high_level::load_cell_type_hash(index, source)
+    }
+
+    fn load_header(&self, index: usize, source: Source) -> Result<Header, SysError> {
+        high_level::load_header(index, source)
+    }
+
+    fn load_witness_args(&self, index: usize, source: Source) -> Result<WitnessArgs, SysError> {
+        high_level::load_witness_args(index, source)
+    }
+}
+
+// on chain the source is always the syscalls, resolved statically
+#[cfg(not(feature = "native"))]
+pub fn cell_source() -> &'static Syscalls {
+    &Syscalls
+}
+
+// like the cell window, the source is only set around a verification, see native::verify
+#[cfg(feature = "native")]
+static mut CELL_SOURCE: Option<&'static dyn CellSource> = None;
+
+#[cfg(feature = "native")]
+pub fn cell_source() -> &'static dyn CellSource {
+    unsafe { CELL_SOURCE }.unwrap_or(&Syscalls)
+}
+
+#[cfg(feature = "native")]
+pub fn with_cell_source<T, F: FnOnce() -> T>(source: &dyn CellSource, f: F) -> T {
+    struct Reset;
+
+    impl Drop for Reset {
+        fn drop(&mut self) {
+            unsafe { CELL_SOURCE = None };
+        }
+    }
+
+    // SAFETY: the 'static borrow is only reachable through CELL_SOURCE, which _reset clears when it
+    // drops at the end of this call, on return or unwind, before source can go out of scope. The
+    // VERIFYING lock of native::verify keeps other threads from setting or reading it meanwhile
+    unsafe { CELL_SOURCE = Some(core::mem::transmute::<&dyn CellSource, &'static dyn CellSource>(source)) };
+    let _reset = Reset;
+
+    f()
+}
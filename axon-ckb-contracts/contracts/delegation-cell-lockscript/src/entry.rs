@@ -0,0 +1,10 @@
+use core::result::Result;
+
+use common::check_code_cell;
+
+use crate::error::Error;
+
+pub fn main() -> Result<(), Error> {
+    check_code_cell().ok_or(Error::CodeCellMissing)?;
+    Ok(())
+}
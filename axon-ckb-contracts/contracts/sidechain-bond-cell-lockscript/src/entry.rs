@@ -1,6 +1,6 @@
 use core::result::Result;
 
-use common::check_code_cell;
+use common::{check_bond_sudt, check_code_cell};
 
 use crate::error::Error;
 
@@ -16,12 +16,14 @@ pub fn main() -> Result<(), Error> {
     CollatorUnlockBond,
 
     Dep:    0 Global Config Cell
-    Dep:    1 Sidechain State Cell
+    Dep:    1 Sidechain Config Cell
+    Dep:    2 Sidechain State Cell
 
-    Sidechain Bond Cell         ->          Muse Token Cell
+    Sidechain Bond Cell         ->          Sudt Cell
 
     */
     check_code_cell().ok_or(Error::CodeCellMissing)?;
+    check_bond_sudt().ok_or(Error::BondSudtMismatch)?;
 
     Ok(())
 }
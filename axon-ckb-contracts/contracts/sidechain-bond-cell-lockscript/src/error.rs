@@ -8,6 +8,7 @@ pub enum Error {
     LengthNotEnough,
     Encoding,
     CodeCellMissing,
+    BondSudtMismatch,
 }
 
 impl From<SysError> for Error {
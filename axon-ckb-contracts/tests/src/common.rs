@@ -1,4 +1,12 @@
-use ckb_tool::ckb_types::{bytes::Bytes, packed, packed::*, prelude::*};
+use ckb_testtool::context::Context;
+use ckb_tool::ckb_hash::blake2b_256;
+use ckb_tool::ckb_types::{bytes::Bytes, core::TransactionView, packed, packed::*, prelude::*};
+
+use code_cell::native::{Header, NativeCell, NativeTransaction, Script as NativeScript};
+use common_raw::{
+    common::{Identity, ScriptHash, SignerIdentity},
+    Hasher,
+};
 
 pub fn new_cell_output(capacity: u64, script: &Script) -> CellOutput {
     CellOutput::new_builder().capacity(capacity.pack()).lock(script.clone()).build()
@@ -12,6 +20,55 @@ pub fn new_type_cell_output(capacity: u64, lock: &Script, type_: &Script) -> Cel
         .build()
 }
 
+pub fn lock_identity(lock: &Script) -> Identity {
+    let mut lock_hash = ScriptHash::default();
+    lock_hash.copy_from_slice(lock.calc_script_hash().as_slice());
+
+    Identity::from_lock_hash(&lock_hash)
+}
+
+pub struct Blake2b;
+
+impl Hasher for Blake2b {
+    fn hash(data: &[u8]) -> [u8; 32] {
+        blake2b_256(data)
+    }
+}
+
+fn native_cell(output: &CellOutput, data: &[u8]) -> NativeCell {
+    NativeCell {
+        capacity: output.capacity().unpack(),
+        lock:     NativeScript::from_slice(output.lock().as_slice()).expect("lock"),
+        type_:    output
+            .type_()
+            .to_opt()
+            .map(|script| NativeScript::from_slice(script.as_slice()).expect("type")),
+        data:     data.to_vec(),
+        header:   None,
+    }
+}
+
+// resolves the cells of tx from context, the same way the vm does
+pub fn native_transaction(context: &Context, tx: &TransactionView) -> NativeTransaction {
+    let resolve = |out_point: OutPoint| {
+        let (output, data) = context.get_cell(&out_point).expect("cell");
+        native_cell(&output, &data)
+    };
+
+    NativeTransaction {
+        cell_deps:   tx.cell_deps().into_iter().map(|dep| resolve(dep.out_point())).collect(),
+        header_deps: Vec::<Header>::new(),
+        inputs:      tx.inputs().into_iter().map(|input| resolve(input.previous_output())).collect(),
+        outputs:     tx
+            .outputs()
+            .into_iter()
+            .zip(tx.outputs_data().into_iter())
+            .map(|(output, data)| native_cell(&output, &data.raw_data()))
+            .collect(),
+        witnesses:   tx.witnesses().into_iter().map(|witness| witness.raw_data().to_vec()).collect(),
+    }
+}
+
 pub trait SerializableRef {
     fn serialize(&self) -> Bytes;
 }
@@ -22,6 +22,9 @@ pub struct AxonScripts {
 
     pub code_cell_code:   OutPoint,
     pub code_cell_script: Script,
+
+    // identity of the code cell signer, derived from secp256k1_script
+    pub signer: [u8; 20],
 }
 
 impl Default for EnvironmentBuilder {
@@ -63,6 +66,20 @@ impl EnvironmentBuilder {
     }
 
     pub fn bootstrap(self, lock_args: Vec<u8>) -> (Self, AxonScripts) {
+        self.bootstrap_with_signer_lock(with_secp256k1_cell_deps, lock_args)
+    }
+
+    // the code cell is locked by secp256k1 multisig, lock_args is blake160(multisig script) maybe
+    // followed by a since, the signer is the identity of the whole lock script hash and not
+    // lock_args
+    pub fn bootstrap_multisig(self, lock_args: Vec<u8>) -> (Self, AxonScripts) {
+        self.bootstrap_with_signer_lock(with_secp256k1_multisig_cell_deps, lock_args)
+    }
+
+    fn bootstrap_with_signer_lock<F>(self, with_lock_cell_deps: F, lock_args: Vec<u8>) -> (Self, AxonScripts)
+    where
+        F: FnOnce(Self) -> (Self, OutPoint),
+    {
         let mut global_config = GlobalConfigCellData::default();
 
         let (self_, code_cell_code, code_cell_script) = self.load_script("code-cell");
@@ -81,6 +98,8 @@ impl EnvironmentBuilder {
         global_config.sidechain_fee_cell_lock_codehash.copy_from_slice(a_s_codehash);
         global_config.sidechain_state_cell_type_codehash.copy_from_slice(a_s_codehash);
         global_config.task_cell_type_codehash.copy_from_slice(a_s_codehash);
+        global_config.proposal_cell_type_codehash.copy_from_slice(a_s_codehash);
+        global_config.delegation_cell_lock_codehash.copy_from_slice(a_s_codehash);
 
         let global_config_dep = self_.create_dep(
             new_type_cell_output(1000, &always_success_script, &always_success_script),
@@ -89,13 +108,15 @@ impl EnvironmentBuilder {
 
         let self_ = self_.cell_dep(global_config_dep);
 
-        let (mut self_, secp256k1_code) = with_secp256k1_cell_deps(self_);
+        let (mut self_, secp256k1_code) = with_lock_cell_deps(self_);
         let secp256k1_script = self_.context.build_script(&secp256k1_code, lock_args.into()).expect("script");
 
         let code_cell_input = self_.create_input(new_type_cell_output(1000, &secp256k1_script, &code_cell_script), Bytes::new());
 
         let self_ = self_.input(code_cell_input);
 
+        let signer = lock_identity(&secp256k1_script);
+
         (self_, AxonScripts {
             always_success_code,
             always_success_script,
@@ -103,6 +124,7 @@ impl EnvironmentBuilder {
             secp256k1_script,
             code_cell_code,
             code_cell_script,
+            signer,
         })
     }
 
@@ -18,27 +18,63 @@ mod test_always_success;
 #[cfg(test)]
 mod test_anyone_shutdown_sidechain;
 #[cfg(test)]
+mod test_batch;
+#[cfg(test)]
+mod test_beacon;
+#[cfg(test)]
+mod test_checker_set;
+#[cfg(test)]
 mod test_checker_vote;
 #[cfg(test)]
 mod test_checker_withdraw;
 #[cfg(test)]
+mod test_collator_handover;
+#[cfg(test)]
 mod test_collator_publish_task;
 #[cfg(test)]
 mod test_collator_shutdown_sidechain;
 #[cfg(test)]
+mod test_collator_submit_signed_tasks;
+#[cfg(test)]
 mod test_collator_submit_tasks;
 #[cfg(test)]
 mod test_collator_unlock;
 #[cfg(test)]
+mod test_decode;
+#[cfg(test)]
+mod test_delegator_claim;
+#[cfg(test)]
+mod test_delegator_delegate;
+#[cfg(test)]
+mod test_error_code;
+#[cfg(test)]
+mod test_execute_proposal;
+#[cfg(test)]
 mod test_join_sidechain;
 #[cfg(test)]
+mod test_multisig_signer;
+#[cfg(test)]
+mod test_native;
+#[cfg(test)]
+mod test_propose_change;
+#[cfg(test)]
 mod test_publish_challenge;
 #[cfg(test)]
 mod test_quit_sidechain;
 #[cfg(test)]
 mod test_refresh_task;
 #[cfg(test)]
+mod test_sdk;
+#[cfg(test)]
+mod test_seed;
+#[cfg(test)]
+mod test_serde;
+#[cfg(test)]
+mod test_sidechain_config;
+#[cfg(test)]
 mod test_take_beneficiary;
+#[cfg(test)]
+mod test_vote_proposal;
 
 const TEST_ENV_VAR: &str = "CAPSULE_TEST_ENV";
 
@@ -31,7 +31,7 @@ pub fn get_dummy_witness_builder() -> WitnessArgsBuilder {
     WitnessArgsBuilder::default().lock(zero_lock.pack_some())
 }
 
-pub fn sign_tx_with_witnesses(tx: TransactionView, witnesses: BytesVec, key: &Privkey) -> Option<Vec<packed::Bytes>> {
+fn signing_message(tx: &TransactionView, witnesses: &BytesVec) -> Option<H256> {
     let witnesses_len = witnesses.len();
 
     let mut blake2b = new_blake2b();
@@ -55,24 +55,65 @@ pub fn sign_tx_with_witnesses(tx: TransactionView, witnesses: BytesVec, key: &Pr
 
     let mut message = [0u8; 32];
     blake2b.finalize(&mut message);
-    let message = H256::from(message);
-
-    let sig = key.sign_recoverable(&message).expect("sign");
+    Some(H256::from(message))
+}
 
+fn replace_first_witness_lock(witnesses: BytesVec, lock: Bytes) -> Option<Vec<packed::Bytes>> {
     let mut signed_witnesses: Vec<packed::Bytes> = Vec::new();
     signed_witnesses.push(
-        WitnessArgs::new_unchecked(signature_witness)
+        WitnessArgs::new_unchecked(witnesses.get(0)?.raw_data())
             .as_builder()
-            .lock(Bytes::from(sig.serialize()).pack_some())
+            .lock(lock.pack_some())
             .pack(),
     );
-    for i in 1..witnesses_len {
+    for i in 1..witnesses.len() {
         signed_witnesses.push(witnesses.get(i)?);
     }
 
     Some(signed_witnesses)
 }
 
+pub fn sign_tx_with_witnesses(tx: TransactionView, witnesses: BytesVec, key: &Privkey) -> Option<Vec<packed::Bytes>> {
+    let message = signing_message(&tx, &witnesses)?;
+
+    let sig = key.sign_recoverable(&message).expect("sign");
+
+    replace_first_witness_lock(witnesses, Bytes::from(sig.serialize()))
+}
+
+/*
+secp256k1 multisig script: reserved(0) | require_first_n | threshold | pubkeys count | blake160(pubkey) * pubkeys count
+the lock args of multisig cells are blake160(multisig script)
+ */
+pub fn multisig_script(require_first_n: u8, threshold: u8, pubkey_hashes: &[[u8; 20]]) -> Bytes {
+    let mut script = vec![0u8, require_first_n, threshold, pubkey_hashes.len() as u8];
+    pubkey_hashes.iter().for_each(|pubkey_hash| script.extend_from_slice(pubkey_hash));
+
+    script.into()
+}
+
+pub fn get_dummy_multisig_witness_builder(multisig_script: &Bytes, threshold: usize) -> WitnessArgsBuilder {
+    let mut zero_lock = multisig_script.to_vec();
+    zero_lock.resize(multisig_script.len() + SIGNATURE_SIZE * threshold, 0);
+
+    WitnessArgsBuilder::default().lock(Bytes::from(zero_lock).pack_some())
+}
+
+pub fn multisig_sign_tx_with_witnesses(
+    tx: TransactionView,
+    witnesses: BytesVec,
+    multisig_script: &Bytes,
+    keys: &[&Privkey],
+) -> Option<Vec<packed::Bytes>> {
+    let message = signing_message(&tx, &witnesses)?;
+
+    let mut lock = multisig_script.to_vec();
+    keys.iter()
+        .for_each(|key| lock.extend_from_slice(&key.sign_recoverable(&message).expect("sign").serialize()));
+
+    replace_first_witness_lock(witnesses, lock.into())
+}
+
 pub fn with_secp256k1_cell_deps(mut builder: EnvironmentBuilder) -> (EnvironmentBuilder, OutPoint) {
     let secp256k1_bin = BUNDLED_CELL.get("specs/cells/secp256k1_blake160_sighash_all").unwrap();
     let secp256k1_out_point = builder.context.deploy_cell(secp256k1_bin.serialize());
@@ -84,3 +125,15 @@ pub fn with_secp256k1_cell_deps(mut builder: EnvironmentBuilder) -> (Environment
 
     (builder.cell_dep(secp256k1_dep).cell_dep(secp256k1_data_dep), secp256k1_out_point)
 }
+
+pub fn with_secp256k1_multisig_cell_deps(mut builder: EnvironmentBuilder) -> (EnvironmentBuilder, OutPoint) {
+    let multisig_bin = BUNDLED_CELL.get("specs/cells/secp256k1_blake160_multisig_all").unwrap();
+    let multisig_out_point = builder.context.deploy_cell(multisig_bin.serialize());
+    let multisig_dep = CellDep::new_builder().out_point(multisig_out_point.clone()).build();
+
+    let secp256k1_data_bin = BUNDLED_CELL.get("specs/cells/secp256k1_data").unwrap();
+    let secp256k1_data_out_point = builder.context.deploy_cell(secp256k1_data_bin.serialize());
+    let secp256k1_data_dep = CellDep::new_builder().out_point(secp256k1_data_out_point).build();
+
+    (builder.cell_dep(multisig_dep).cell_dep(secp256k1_data_dep), multisig_out_point)
+}
@@ -113,6 +113,7 @@ fn test_success() {
         REJECT_CHALLENGE_CHECKER,
         PASS_TASK_CHECKER,
     ];
+    sidechain_config_input_data.activated_checker_weights = vec![1, 2, 3, 4, 5];
 
     let sidechain_config_input_out_point = builder.context.create_cell(
         new_type_cell_output(1000, &always_success, &sidechain_config_type_script),
@@ -178,6 +179,7 @@ fn test_success() {
     sidechain_config_output_data.sidechain_status = SidechainStatus::Shutdown;
     sidechain_config_output_data.checker_normal_count = 3;
     sidechain_config_output_data.activated_checkers = vec![GOOD_CHECKER_1, GOOD_CHECKER_2, GOOD_CHECKER_3];
+    sidechain_config_output_data.activated_checker_weights = vec![1, 2, 3];
     sidechain_config_output_data.jailed_checkers = vec![REJECT_CHALLENGE_CHECKER, PASS_TASK_CHECKER];
 
     let sidechain_fee_output_data = SidechainFeeCell::default();
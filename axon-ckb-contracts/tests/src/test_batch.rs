@@ -0,0 +1,193 @@
+use ckb_testtool::context::Context;
+use ckb_tool::ckb_crypto::secp::Generator;
+use ckb_tool::ckb_types::{bytes::Bytes, core::TransactionView, packed::CellDep, prelude::*};
+
+use code_cell::{error::Error, native::verify};
+use common_raw::{
+    cell::{
+        checker_info::{CheckerInfoCell, CheckerInfoCellTypeArgs},
+        sidechain_config::{SidechainConfigCell, SidechainConfigCellTypeArgs},
+        task::{TaskCell, TaskCellTypeArgs, TaskStatus},
+    },
+    pattern::Pattern,
+    witness::{
+        batch::{BatchEntry, BatchWitness},
+        checker_vote::CheckerVoteWitness,
+    },
+    Serialize,
+};
+
+use crate::common::*;
+use crate::environment_builder::{AxonScripts, EnvironmentBuilder};
+use crate::secp256k1::*;
+
+const MAX_CYCLES: u64 = 10_000_000;
+
+// two votes of the same checker batched in one transaction, entries may change the witness
+fn batch_tx<F: FnOnce(&mut Vec<BatchEntry>)>(entries: F) -> (Context, TransactionView) {
+    // generate key pair
+    let privkey = Generator::random_privkey();
+    let pubkey = privkey.pubkey().expect("pubkey");
+    let pubkey_hash = blake160(&pubkey.serialize());
+
+    // deploy contract
+    let (
+        mut builder,
+        AxonScripts {
+            always_success_code,
+            always_success_script: always_success,
+            code_cell_script,
+            signer,
+            ..
+        },
+    ) = EnvironmentBuilder::default().bootstrap(pubkey_hash.to_vec());
+
+    // prepare scripts
+    let config_type_args = SidechainConfigCellTypeArgs::default();
+    let config_script = builder
+        .context
+        .build_script(&always_success_code, config_type_args.serialize())
+        .expect("script");
+
+    let mut task_type_args = TaskCellTypeArgs::default();
+    task_type_args.checker_lock_arg.copy_from_slice(&signer);
+
+    let task_script = builder
+        .context
+        .build_script(&always_success_code, task_type_args.serialize())
+        .expect("script");
+
+    let mut checker_info_type_args = CheckerInfoCellTypeArgs::default();
+    checker_info_type_args.checker_lock_arg.copy_from_slice(&signer);
+
+    let checker_info_script = builder
+        .context
+        .build_script(&always_success_code, checker_info_type_args.serialize())
+        .expect("script");
+
+    // prepare cell deps
+    let mut config_dep_data = SidechainConfigCell::default();
+    config_dep_data.check_fee_rate = 100;
+
+    let config_dep_out_point = builder.context.create_cell(
+        new_type_cell_output(1000, &always_success, &config_script),
+        config_dep_data.serialize(),
+    );
+    let config_dep = CellDep::new_builder().out_point(config_dep_out_point).build();
+    let mut builder = builder.cell_dep(config_dep);
+
+    // prepare inputs, two votes of the same checker
+    let checker_info_input_data = CheckerInfoCell::default();
+
+    let mut task_input_data = TaskCell::default();
+    task_input_data.check_data_size = 100;
+
+    for _ in 0..2 {
+        let checker_info_input = builder.create_input(
+            new_type_cell_output(1000, &always_success, &checker_info_script),
+            checker_info_input_data.serialize(),
+        );
+        let task_input = builder.create_input(
+            new_type_cell_output(1000, &always_success, &task_script),
+            task_input_data.serialize(),
+        );
+        builder = builder.input(checker_info_input).input(task_input);
+    }
+
+    // prepare outputs
+    let mut checker_info_output = checker_info_input_data.clone();
+    checker_info_output.unpaid_fee = 10000;
+
+    let mut task_output = task_input_data.clone();
+    task_output.status = TaskStatus::TaskPassed;
+    task_output.commit[0] = 1;
+    task_output.reveal[0] = 1;
+
+    let outputs = vec![
+        new_type_cell_output(1000, &always_success, &code_cell_script),
+        new_type_cell_output(1000, &always_success, &checker_info_script),
+        new_type_cell_output(1000, &always_success, &task_script),
+        new_type_cell_output(1000, &always_success, &checker_info_script),
+        new_type_cell_output(1000, &always_success, &task_script),
+    ];
+    let outputs_data: Vec<Bytes> = vec![
+        Bytes::new(),
+        checker_info_output.serialize(),
+        task_output.serialize(),
+        checker_info_output.serialize(),
+        task_output.serialize(),
+    ];
+
+    let mut vote_witness = CheckerVoteWitness::default();
+    vote_witness.sidechain_config_dep_index = EnvironmentBuilder::BOOTSTRAP_CELL_DEPS_LENGTH;
+
+    let mut witness = BatchWitness::default();
+    for _ in 0..2 {
+        witness.entries.push(BatchEntry {
+            witness:      vote_witness.serialize().to_vec(),
+            input_count:  2,
+            output_count: 2,
+        });
+    }
+    entries(&mut witness.entries);
+    let witnesses = [get_dummy_witness_builder().input_type(witness.serialize().pack_some()).as_bytes()];
+
+    // build transaction
+    let builder = builder.outputs(outputs).outputs_data(outputs_data.pack());
+    let tx = builder.builder.build();
+    let tx = tx
+        .as_advanced_builder()
+        .set_witnesses(sign_tx_with_witnesses(tx, witnesses.pack(), &privkey).unwrap())
+        .build();
+
+    (builder.context, tx)
+}
+
+#[test]
+fn test_success() {
+    let (context, tx) = batch_tx(|_| {});
+
+    context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+}
+
+#[test]
+fn test_forbidden_pattern() {
+    for &pattern in &[
+        Pattern::AdminCreateSidechain,
+        Pattern::AnyoneShutdownSidechain,
+        Pattern::CollatorShutdownSidechain,
+        Pattern::Batch,
+    ] {
+        let (context, tx) = batch_tx(|entries| entries[1].witness = vec![pattern as u8]);
+
+        assert_eq!(
+            verify(&native_transaction(&context, &tx)),
+            Err(Error::BatchPatternInvalid),
+            "{:?}",
+            pattern
+        );
+    }
+}
+
+#[test]
+fn test_entry_count_mismatch() {
+    // the entries leave a cell of the transaction to no one
+    let (context, tx) = batch_tx(|entries| entries[1].input_count = 1);
+    assert_eq!(verify(&native_transaction(&context, &tx)), Err(Error::CellNumberMismatch));
+
+    // or claim one more than the transaction has
+    let (context, tx) = batch_tx(|entries| entries[1].output_count = 3);
+    assert_eq!(verify(&native_transaction(&context, &tx)), Err(Error::CellNumberMismatch));
+}
+
+#[test]
+fn test_overlapping_windows() {
+    // windows follow each other, an entry reaching into the cells of the next one takes them away
+    // from it, here the first vote sees the checker info cell of the second one
+    let (context, tx) = batch_tx(|entries| {
+        entries[0].input_count = 3;
+        entries[1].input_count = 1;
+    });
+
+    assert_eq!(verify(&native_transaction(&context, &tx)), Err(Error::CellNumberMismatch));
+}
@@ -0,0 +1,68 @@
+use common_raw::{
+    beacon::RandomBeacon,
+    cell::sidechain_state::{SidechainStateCell, SidechainStateCellTypeArgs},
+    Serialize,
+};
+
+#[test]
+fn test_success() {
+    let type_args = SidechainStateCellTypeArgs { chain_id: 3 };
+
+    let mut state = SidechainStateCell::default();
+    state.random_seed = [9u8; 32];
+    state.random_offset = 5;
+    state.seed_epoch = 2;
+
+    let beacon = RandomBeacon::from_state_cell(&type_args.serialize(), &state.serialize()).expect("beacon");
+    assert_eq!(beacon, RandomBeacon {
+        chain_id: 3,
+        epoch:    2,
+        seed:     [9u8; 32],
+    });
+
+    // a cell which is not a state cell is no beacon
+    assert!(RandomBeacon::from_state_cell(&type_args.serialize(), &[0u8; 4]).is_err());
+}
+
+#[test]
+fn test_freshness() {
+    let beacon = RandomBeacon {
+        chain_id: 3,
+        epoch:    2,
+        seed:     [9u8; 32],
+    };
+
+    // created at block 100 while the epoch was 1, the state cell of epoch 2 is at block 110
+    assert!(beacon.is_fresh(1, 100, 110, 10));
+    assert!(!beacon.is_fresh(1, 100, 111, 10));
+
+    // a state cell rewritten after the cell was created, by a publish or a refresh, keeps the seed
+    assert!(!beacon.is_fresh(2, 100, 110, 10));
+    assert!(!beacon.is_fresh(3, 100, 110, 10));
+
+    // the state cell can't predate a cell recording an earlier epoch
+    assert!(!beacon.is_fresh(1, 110, 100, 10));
+}
+
+#[test]
+fn test_seed_mismatch() {
+    let type_args = SidechainStateCellTypeArgs { chain_id: 3 };
+
+    let mut state = SidechainStateCell::default();
+    state.random_seed = [9u8; 32];
+    state.seed_epoch = 2;
+    let beacon = RandomBeacon::from_state_cell(&type_args.serialize(), &state.serialize()).expect("beacon");
+
+    // a state cell of the same epoch carrying another seed is not the same beacon
+    state.random_seed = [8u8; 32];
+    let other = RandomBeacon::from_state_cell(&type_args.serialize(), &state.serialize()).expect("beacon");
+    assert_eq!(other.epoch, beacon.epoch);
+    assert_ne!(other, beacon);
+
+    // nor is the state cell of another sidechain with the same seed
+    state.random_seed = [9u8; 32];
+    let other_chain =
+        RandomBeacon::from_state_cell(&SidechainStateCellTypeArgs { chain_id: 4 }.serialize(), &state.serialize()).expect("beacon");
+    assert_eq!(other_chain.seed, beacon.seed);
+    assert_ne!(other_chain, beacon);
+}
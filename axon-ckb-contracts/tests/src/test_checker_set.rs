@@ -0,0 +1,147 @@
+use ckb_tool::bytes::Bytes;
+use ckb_tool::ckb_crypto::secp::Generator;
+use ckb_tool::ckb_types::prelude::*;
+
+use common_raw::cell::checker_info::{CheckerInfoCell, CheckerInfoCellTypeArgs};
+use common_raw::cell::delegation::{DelegationCell, DelegationCellLockArgs};
+use common_raw::cell::muse_token::MuseTokenCell;
+use common_raw::cell::sidechain_config::{CheckerRecord, CheckerStatus, SidechainConfigCell, SidechainConfigCellTypeArgs};
+use common_raw::smt::SparseMerkleSumTree;
+use common_raw::witness::checker_proofs::{CheckerProofsWitness, CheckerRecordProof};
+use common_raw::witness::delegator_delegate::DelegatorDelegateWitness;
+
+use crate::common::*;
+use crate::environment_builder::{AxonScripts, EnvironmentBuilder};
+use crate::secp256k1::*;
+
+const MAX_CYCLES: u64 = 10_000_000;
+
+#[test]
+fn test_success() {
+    // generate key pair
+    let privkey = Generator::random_privkey();
+    let pubkey = privkey.pubkey().expect("pubkey");
+    let pubkey_hash = blake160(&pubkey.serialize());
+
+    // deploy contract
+    let (
+        mut builder,
+        AxonScripts {
+            always_success_code,
+            always_success_script: always_success,
+            code_cell_script,
+            signer,
+            ..
+        },
+    ) = EnvironmentBuilder::default().bootstrap(pubkey_hash.to_vec());
+
+    let checker = [1u8; 20];
+
+    // prepare scripts
+    let config_type_args = SidechainConfigCellTypeArgs::default();
+    let config_type_script = builder
+        .context
+        .build_script(&always_success_code, config_type_args.serialize())
+        .expect("script");
+
+    let mut checker_info_type_args = CheckerInfoCellTypeArgs::default();
+    checker_info_type_args.checker_lock_arg = checker;
+    let checker_info_type_script = builder
+        .context
+        .build_script(&always_success_code, checker_info_type_args.serialize())
+        .expect("script");
+
+    let mut delegation_lock_args = DelegationCellLockArgs::default();
+    delegation_lock_args.checker_lock_arg = checker;
+    delegation_lock_args.delegator_lock_arg = signer;
+    delegation_lock_args.principal = 50;
+    let delegation_lock_script = builder
+        .context
+        .build_script(&always_success_code, delegation_lock_args.serialize())
+        .expect("script");
+
+    // prepare inputs
+    let mut record = CheckerRecord {
+        status: CheckerStatus::Activated,
+        weight: 100,
+    };
+    let mut checker_set = SparseMerkleSumTree::default();
+    checker_set.update(checker, CheckerRecord::leaf::<Blake2b>(Some(&record), &checker));
+    let proof = CheckerRecordProof {
+        checker,
+        record: Some(record),
+        proof: checker_set.proof::<Blake2b>(&checker).expect("proof"),
+    };
+
+    let mut config_input_data = SidechainConfigCell::default();
+    config_input_data.checker_set_root = Some(checker_set.root::<Blake2b>().expect("root").hash);
+
+    let config_input = builder.create_input(
+        new_type_cell_output(1000, &always_success, &config_type_script),
+        config_input_data.serialize(),
+    );
+    let mut builder = builder.input(config_input);
+
+    let mut checker_info_input_data = CheckerInfoCell::default();
+    checker_info_input_data.bonded_amount = 100;
+    let checker_info_input = builder.create_input(
+        new_type_cell_output(1000, &always_success, &checker_info_type_script),
+        checker_info_input_data.serialize(),
+    );
+    let mut builder = builder.input(checker_info_input);
+
+    let mut muse_token_input_data = MuseTokenCell::default();
+    muse_token_input_data.amount = 50;
+    let muse_token_input = builder.create_input(
+        new_type_cell_output(1000, &always_success, &always_success),
+        muse_token_input_data.serialize(),
+    );
+    let builder = builder.input(muse_token_input);
+
+    // prepare outputs
+    let outputs = vec![
+        new_type_cell_output(1000, &always_success, &code_cell_script),
+        new_type_cell_output(1000, &always_success, &config_type_script),
+        new_type_cell_output(1000, &always_success, &checker_info_type_script),
+        new_type_cell_output(1000, &delegation_lock_script, &always_success),
+    ];
+
+    record.weight = 150;
+    checker_set.update(checker, CheckerRecord::leaf::<Blake2b>(Some(&record), &checker));
+
+    let mut config_output_data = config_input_data.clone();
+    config_output_data.checker_set_root = Some(checker_set.root::<Blake2b>().expect("root").hash);
+
+    let mut checker_info_output_data = checker_info_input_data.clone();
+    checker_info_output_data.delegated_amount = 50;
+
+    let mut delegation_output_data = DelegationCell::default();
+    delegation_output_data.amount = 50;
+
+    let outputs_data = vec![
+        Bytes::new(),
+        config_output_data.serialize(),
+        checker_info_output_data.serialize(),
+        delegation_output_data.serialize(),
+    ];
+
+    let builder = builder.outputs(outputs).outputs_data(outputs_data.pack());
+
+    let witness = DelegatorDelegateWitness::default();
+    let mut checker_proofs = CheckerProofsWitness::default();
+    checker_proofs.record_proofs.push(proof);
+    let witnesses = [get_dummy_witness_builder()
+        .input_type(witness.serialize().pack_some())
+        .output_type(checker_proofs.serialize().pack_some())
+        .as_bytes()];
+
+    // build transaction
+    let tx = builder.builder.build();
+    let tx = tx
+        .as_advanced_builder()
+        .set_witnesses(sign_tx_with_witnesses(tx, witnesses.pack(), &privkey).unwrap())
+        .build();
+
+    // run
+    builder.context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+}
@@ -30,6 +30,7 @@ fn test_success() {
             always_success_code,
             always_success_script: always_success,
             code_cell_script,
+            signer,
             ..
         },
     ) = EnvironmentBuilder::default().bootstrap(pubkey_hash.to_vec());
@@ -42,7 +43,7 @@ fn test_success() {
         .expect("script");
 
     let mut task_type_args = TaskCellTypeArgs::default();
-    task_type_args.checker_lock_arg.copy_from_slice(&pubkey_hash);
+    task_type_args.checker_lock_arg.copy_from_slice(&signer);
 
     let task_script = builder
         .context
@@ -50,7 +51,7 @@ fn test_success() {
         .expect("script");
 
     let mut checker_info_type_args = CheckerInfoCellTypeArgs::default();
-    checker_info_type_args.checker_lock_arg.copy_from_slice(&pubkey_hash);
+    checker_info_type_args.checker_lock_arg.copy_from_slice(&signer);
 
     let checker_info_script = builder
         .context
@@ -29,6 +29,7 @@ fn test_success() {
             always_success_code,
             always_success_script: always_success,
             code_cell_script,
+            signer,
             ..
         },
     ) = EnvironmentBuilder::default().bootstrap(pubkey_hash.to_vec());
@@ -36,7 +37,7 @@ fn test_success() {
     println!("{:?}", always_success.calc_script_hash().as_slice());
     // prepare scripts
     let mut checker_bond_lock_args = CheckerBondCellLockArgs::default();
-    checker_bond_lock_args.checker_lock_arg.copy_from_slice(&pubkey_hash);
+    checker_bond_lock_args.checker_lock_arg.copy_from_slice(&signer);
 
     let checker_bond_lock_input_script = builder
         .context
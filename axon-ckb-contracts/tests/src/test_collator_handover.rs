@@ -0,0 +1,141 @@
+use ckb_testtool::context::Context;
+use ckb_tool::bytes::Bytes;
+use ckb_tool::ckb_crypto::secp::Generator;
+use ckb_tool::ckb_types::core::TransactionView;
+use ckb_tool::ckb_types::prelude::*;
+
+use code_cell::{error::Error, native::verify};
+
+use common_raw::cell::muse_token::MuseTokenCell;
+use common_raw::cell::sidechain_bond::{SidechainBondCell, SidechainBondCellLockArgs};
+use common_raw::cell::sidechain_config::{SidechainConfigCell, SidechainConfigCellTypeArgs};
+use common_raw::witness::collator_handover::CollatorHandoverWitness;
+use common_raw::FromRaw;
+
+use crate::common::*;
+use crate::environment_builder::{AxonScripts, EnvironmentBuilder};
+use crate::secp256k1::*;
+
+const MAX_CYCLES: u64 = 10_000_000;
+
+// the only collator hands its seat over to [1; 20]
+fn collator_handover_tx() -> (Context, TransactionView) {
+    // generate key pair
+    let privkey = Generator::random_privkey();
+    let pubkey = privkey.pubkey().expect("pubkey");
+    let pubkey_hash = blake160(&pubkey.serialize());
+
+    // deploy contract
+    let (
+        mut builder,
+        AxonScripts {
+            always_success_code,
+            always_success_script: always_success,
+            code_cell_script,
+            signer,
+            ..
+        },
+    ) = EnvironmentBuilder::default().bootstrap(pubkey_hash.to_vec());
+
+    let new_collator = [1u8; 20];
+
+    // prepare scripts
+    let config_type_args = SidechainConfigCellTypeArgs::default();
+    let config_type_script = builder
+        .context
+        .build_script(&always_success_code, config_type_args.serialize())
+        .expect("script");
+
+    let mut sidechain_bond_lock_args = SidechainBondCellLockArgs::default();
+    sidechain_bond_lock_args.collator_lock_arg = new_collator;
+    sidechain_bond_lock_args.unlock_sidechain_height = 100;
+    let sidechain_bond_lock_script = builder
+        .context
+        .build_script(&always_success_code, sidechain_bond_lock_args.serialize())
+        .expect("script");
+
+    // prepare inputs
+    let mut config_input_data = SidechainConfigCell::default();
+    config_input_data.collators = vec![signer];
+    config_input_data.minimal_bond = 100;
+    config_input_data
+        .bond_sudt_typescript_codehash
+        .copy_from_slice(always_success.as_reader().code_hash().raw_data());
+
+    let config_input = builder.create_input(
+        new_type_cell_output(1000, &always_success, &config_type_script),
+        config_input_data.serialize(),
+    );
+    let mut builder = builder.input(config_input);
+
+    let mut muse_token_input_data = MuseTokenCell::default();
+    muse_token_input_data.amount = 100;
+    let muse_token_input = builder.create_input(
+        new_type_cell_output(1000, &always_success, &always_success),
+        muse_token_input_data.serialize(),
+    );
+    let builder = builder.input(muse_token_input);
+
+    // prepare outputs
+    let outputs = vec![
+        new_type_cell_output(1000, &always_success, &code_cell_script),
+        new_type_cell_output(1000, &always_success, &config_type_script),
+        new_type_cell_output(1000, &sidechain_bond_lock_script, &always_success),
+    ];
+
+    let mut config_output_data = config_input_data.clone();
+    config_output_data.collators = vec![new_collator];
+
+    let mut sidechain_bond_output_data = SidechainBondCell::default();
+    sidechain_bond_output_data.amount = 100;
+
+    let outputs_data = vec![Bytes::new(), config_output_data.serialize(), sidechain_bond_output_data.serialize()];
+
+    let builder = builder.outputs(outputs).outputs_data(outputs_data.pack());
+
+    let mut witness = CollatorHandoverWitness::default();
+    witness.new_collator_lock_arg = new_collator;
+    let witnesses = [get_dummy_witness_builder().input_type(witness.serialize().pack_some()).as_bytes()];
+
+    // build transaction
+    let tx = builder.builder.build();
+    let tx = tx
+        .as_advanced_builder()
+        .set_witnesses(sign_tx_with_witnesses(tx, witnesses.pack(), &privkey).unwrap())
+        .build();
+
+    (builder.context, tx)
+}
+
+#[test]
+fn test_success() {
+    let (context, tx) = collator_handover_tx();
+
+    context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+}
+
+#[test]
+fn test_signer_not_collator() {
+    let (context, tx) = collator_handover_tx();
+
+    // the seat belongs to another collator, the signer is neither it nor the admin
+    let mut tx = native_transaction(&context, &tx);
+    let mut config_input_data = SidechainConfigCell::from_raw(&tx.inputs[1].data).expect("config");
+    config_input_data.collators = vec![[2u8; 20]];
+    tx.inputs[1].data = config_input_data.serialize().to_vec();
+
+    assert_eq!(verify(&tx), Err(Error::CollatorHandoverNotAllowed));
+}
+
+#[test]
+fn test_collator_not_in_set() {
+    let (context, tx) = collator_handover_tx();
+
+    // there is no seat to hand over
+    let mut tx = native_transaction(&context, &tx);
+    let mut config_input_data = SidechainConfigCell::from_raw(&tx.inputs[1].data).expect("config");
+    config_input_data.collators = Vec::new();
+    tx.inputs[1].data = config_input_data.serialize().to_vec();
+
+    assert_eq!(verify(&tx), Err(Error::CollatorNotInSet));
+}
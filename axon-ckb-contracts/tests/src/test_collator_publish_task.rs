@@ -1,19 +1,26 @@
+use ckb_testtool::context::Context;
 use ckb_tool::bytes::Bytes;
 use ckb_tool::ckb_crypto::secp::Generator;
+use ckb_tool::ckb_types::core::TransactionView;
 use ckb_tool::ckb_types::packed::{CellDep, CellInput};
 use ckb_tool::ckb_types::prelude::*;
 
+use code_cell::{
+    error::Error,
+    native::{draw_checkers, verify},
+};
 use common_raw::cell::muse_token::MuseTokenCell;
 use common_raw::cell::sidechain_config::{SidechainConfigCell, SidechainConfigCellTypeArgs};
 use common_raw::cell::sidechain_fee::{SidechainFeeCell, SidechainFeeCellLockArgs};
-use common_raw::cell::sidechain_state::CheckerLastAcceptTaskHeight;
 use common_raw::cell::task::{TaskCell, TaskCellTypeArgs};
 use common_raw::cell::{
     sidechain_bond::{SidechainBondCell, SidechainBondCellLockArgs},
-    sidechain_state::{SidechainStateCell, SidechainStateCellTypeArgs},
+    sidechain_state::{CheckerLastAcceptTaskHeight, SidechainStateCell, SidechainStateCellTypeArgs},
 };
-use common_raw::common::BlockSlice;
+use common_raw::common::{BlockSlice, Identity};
+use common_raw::seed::{derive_seed, SeedPurpose};
 use common_raw::witness::collator_publish_task::CollatorPublishTaskWitness;
+use common_raw::FromRaw;
 
 use crate::common::*;
 use crate::environment_builder::{AxonScripts, EnvironmentBuilder};
@@ -22,8 +29,9 @@ use crate::secp256k1::*;
 const MAX_CYCLES: u64 = 10_000_000;
 const SIDECHAIN_BOND_UNLOCK_HEIGHT: u128 = 1000;
 
-#[test]
-fn test_success() {
+// publishes job 1..=2 to the two checkers drawn out of two with the cumulative checker_weights,
+// task_checkers may replace them
+fn collator_publish_task_tx<F: FnOnce(&mut Vec<Identity>)>(checker_weights: Vec<u128>, task_checkers: F) -> (Context, TransactionView) {
     // generate key pair
     let privkey = Generator::random_privkey();
     let pubkey = privkey.pubkey().expect("pubkey");
@@ -36,6 +44,7 @@ fn test_success() {
             always_success_code,
             always_success_script: always_success,
             code_cell_script,
+            signer,
             ..
         },
     ) = EnvironmentBuilder::default().bootstrap(pubkey_hash.to_vec());
@@ -47,12 +56,6 @@ fn test_success() {
         .build_script(&always_success_code, config_type_args.serialize())
         .expect("script");
 
-    let task_type_args = TaskCellTypeArgs::default();
-    let task_type_script = builder
-        .context
-        .build_script(&always_success_code, task_type_args.serialize())
-        .expect("script");
-
     let sidechain_state_type_args = SidechainStateCellTypeArgs::default();
     let sidechain_state_type_script = builder
         .context
@@ -60,7 +63,7 @@ fn test_success() {
         .expect("script");
 
     let mut sidechain_bond_lock_args_dep = SidechainBondCellLockArgs::default();
-    sidechain_bond_lock_args_dep.collator_lock_arg.copy_from_slice(&pubkey_hash);
+    sidechain_bond_lock_args_dep.collator_lock_arg.copy_from_slice(&signer);
     sidechain_bond_lock_args_dep.unlock_sidechain_height = SIDECHAIN_BOND_UNLOCK_HEIGHT;
     let sidechain_bond_lock_script_dep = builder
         .context
@@ -75,14 +78,18 @@ fn test_success() {
 
     //prepare dep
     let mut sidechain_config_data_dep = SidechainConfigCell::default();
-    sidechain_config_data_dep.checker_total_count = 1;
-    sidechain_config_data_dep.checker_normal_count = 1;
-    sidechain_config_data_dep.activated_checkers.push([0; 20]);
+    sidechain_config_data_dep.checker_total_count = 2;
+    sidechain_config_data_dep.checker_normal_count = 2;
+    sidechain_config_data_dep
+        .bond_sudt_typescript_codehash
+        .copy_from_slice(always_success.as_reader().code_hash().raw_data());
+    sidechain_config_data_dep.activated_checkers = vec![[1; 20], [2; 20]];
+    sidechain_config_data_dep.activated_checker_weights = checker_weights;
     sidechain_config_data_dep.challenge_threshold = 1;
-    sidechain_config_data_dep.commit_threshold = 1;
+    sidechain_config_data_dep.commit_threshold = 2;
     sidechain_config_data_dep.check_fee_rate = 1;
     sidechain_config_data_dep.check_data_size_limit = 2;
-    sidechain_config_data_dep.collator_lock_arg.copy_from_slice(&pubkey_hash);
+    sidechain_config_data_dep.collators = vec![signer];
     let sidechain_config_dep_out_point = builder.context.create_cell(
         new_type_cell_output(1000, &always_success, &config_type_script),
         sidechain_config_data_dep.serialize(),
@@ -99,10 +106,7 @@ fn test_success() {
     let mut builder = builder.cell_dep(sidechain_bond_dep);
 
     //prepare input
-    let mut sidechain_state_data_input = SidechainStateCell::default();
-    let mut info = CheckerLastAcceptTaskHeight::default();
-    info.height = 2;
-    sidechain_state_data_input.checker_last_task_sidechain_heights.push(info);
+    let sidechain_state_data_input = SidechainStateCell::default();
 
     let output = new_type_cell_output(1000, &always_success, &sidechain_state_type_script);
     let sidechain_state_input_outpoint = builder.context.create_cell(output, sidechain_state_data_input.serialize());
@@ -120,11 +124,11 @@ fn test_success() {
     let mut builder = builder.input(sidechain_fee_input);
 
     let mut muse_token_data_input = MuseTokenCell::default();
-    muse_token_data_input.amount = 1;
+    muse_token_data_input.amount = 2;
     let output = new_type_cell_output(1000, &always_success, &always_success);
     let muse_token_input_outpoint = builder.context.create_cell(output, muse_token_data_input.serialize());
     let muse_token_input = CellInput::new_builder().previous_output(muse_token_input_outpoint.clone()).build();
-    let builder = builder.input(muse_token_input);
+    let mut builder = builder.input(muse_token_input);
 
     //prepare output
     let mut outputs = vec![
@@ -137,8 +141,30 @@ fn test_success() {
     sidechain_state_data_output.random_offset += 1;
     sidechain_state_data_output.waiting_jobs.push(BlockSlice { from: 1, to: 2 });
 
+    // every drawn checker takes a task of the job
+    let seed = derive_seed::<Blake2b>(
+        &sidechain_state_data_input.random_seed,
+        sidechain_state_data_input.random_offset,
+        0,
+        SeedPurpose::PublishTask,
+        &BlockSlice { from: 1, to: 2 },
+    );
+    let mut checkers = draw_checkers(&sidechain_config_data_dep, seed, 2).expect("draw");
+    for checker in &checkers {
+        sidechain_state_data_output
+            .checker_last_task_sidechain_heights
+            .push(CheckerLastAcceptTaskHeight {
+                checker_lock_arg: *checker,
+                height:           2,
+            });
+    }
+    sidechain_state_data_output
+        .checker_last_task_sidechain_heights
+        .sort_by_key(|last_height| last_height.checker_lock_arg);
+    task_checkers(&mut checkers);
+
     let mut sidechain_fee_data_output = SidechainFeeCell::default();
-    sidechain_fee_data_output.amount = 1;
+    sidechain_fee_data_output.amount = 2;
     let mut outputs_data = vec![
         Bytes::new(),
         sidechain_state_data_output.serialize(),
@@ -148,9 +174,18 @@ fn test_success() {
     let mut task_data_output = TaskCell::default();
     task_data_output.sidechain_block_height_from = 1;
     task_data_output.sidechain_block_height_to = 2;
-    let output = new_type_cell_output(1000, &always_success, &task_type_script);
-    outputs.push(output);
-    outputs_data.push(task_data_output.serialize());
+    task_data_output.check_data_size = 1;
+    for checker in checkers {
+        let mut task_type_args = TaskCellTypeArgs::default();
+        task_type_args.checker_lock_arg = checker;
+        let task_type_script = builder
+            .context
+            .build_script(&always_success_code, task_type_args.serialize())
+            .expect("script");
+
+        outputs.push(new_type_cell_output(1000, &always_success, &task_type_script));
+        outputs_data.push(task_data_output.serialize());
+    }
 
     let mut witness = CollatorPublishTaskWitness::default();
     witness.from_height = 1;
@@ -165,6 +200,46 @@ fn test_success() {
         .set_witnesses(sign_tx_with_witnesses(tx, witnesses.pack(), &privkey).unwrap())
         .build();
 
-    // run
-    builder.context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+    (builder.context, tx)
+}
+
+#[test]
+fn test_success() {
+    let (context, tx) = collator_publish_task_tx(vec![100, 200], |_| {});
+
+    context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+}
+
+#[test]
+fn test_last_task_checker_mismatch() {
+    // the first checker takes the last task as well
+    let (context, tx) = collator_publish_task_tx(vec![100, 200], |checkers| checkers[1] = checkers[0]);
+
+    assert_eq!(verify(&native_transaction(&context, &tx)), Err(Error::TaskMismatch));
+}
+
+#[test]
+fn test_skewed_weights() {
+    let (context, tx) = collator_publish_task_tx(vec![100, 200], |_| {});
+    let even_cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+
+    // the light checker is drawn second from what is left, not by redrawing the heavy one
+    let (context, tx) = collator_publish_task_tx(vec![1_000_000, 1_000_001], |_| {});
+    let skewed_cycles = context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+
+    assert!(skewed_cycles < even_cycles + 100_000, "{} cycles", skewed_cycles);
+}
+
+#[test]
+fn test_duplicated_state_checkers() {
+    let (context, tx) = collator_publish_task_tx(vec![100, 200], |_| {});
+
+    // the task height of the first checker is listed twice
+    let mut tx = native_transaction(&context, &tx);
+    let mut sidechain_state_output_data = SidechainStateCell::from_raw(&tx.outputs[1].data).expect("state");
+    let last_heights = &mut sidechain_state_output_data.checker_last_task_sidechain_heights;
+    last_heights[1] = last_heights[0];
+    tx.outputs[1].data = sidechain_state_output_data.serialize().to_vec();
+
+    assert_eq!(verify(&tx), Err(Error::SidechainStateCheckersUnsorted));
 }
@@ -27,6 +27,7 @@ fn test_success() {
             always_success_code,
             always_success_script: always_success,
             code_cell_script,
+            signer,
             ..
         },
     ) = EnvironmentBuilder::default().bootstrap(pubkey_hash.to_vec());
@@ -55,30 +56,37 @@ fn test_success() {
 
     let mut builder = builder.cell_dep(state_dep);
     //prepare inputs
+    // the second collator approves with a cell of its own
     let mut config_input_data = SidechainConfigCell::default();
-    config_input_data.collator_lock_arg = pubkey_hash;
+    config_input_data.collators = vec![signer, lock_identity(&always_success)];
+    config_input_data.collator_threshold = 2;
 
     let config_input_out_point = builder.create_input(
         new_type_cell_output(1000, &always_success, &config_type_script),
         config_input_data.serialize(),
     );
 
-    let builder = builder.input(config_input_out_point);
+    let mut builder = builder.input(config_input_out_point);
+
+    let approval_input = builder.create_input(new_cell_output(1000, &always_success), Bytes::new());
+    let builder = builder.input(approval_input);
 
     //prepare outputs
     let outputs = vec![
         new_type_cell_output(1000, &always_success, &code_cell_script),
         new_type_cell_output(1000, &always_success, &config_type_script),
+        new_cell_output(1000, &always_success),
     ];
 
     let mut config_output_data = config_input_data.clone();
     config_output_data.sidechain_status = SidechainStatus::Shutdown;
 
-    let outputs_data = vec![Bytes::new(), config_output_data.serialize()];
+    let outputs_data = vec![Bytes::new(), config_output_data.serialize(), Bytes::new()];
 
     let builder = builder.outputs(outputs).outputs_data(outputs_data.pack());
 
-    let witness = CollatorShutdownSidechainWitness::default();
+    let mut witness = CollatorShutdownSidechainWitness::default();
+    witness.approval_count = 1;
     let witnesses = [get_dummy_witness_builder().input_type(witness.serialize().pack_some()).as_bytes()];
 
     // build transaction
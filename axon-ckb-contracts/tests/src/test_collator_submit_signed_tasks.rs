@@ -0,0 +1,347 @@
+use ckb_testtool::context::Context;
+use ckb_tool::ckb_crypto::secp::{Generator, Privkey};
+use ckb_tool::ckb_hash::new_blake2b;
+use ckb_tool::ckb_types::{bytes::Bytes, core::TransactionView, packed::WitnessArgs, prelude::*, H256};
+
+use code_cell::{
+    error::Error,
+    native::{draw_checkers, verify, NativeTransaction},
+};
+use common_raw::{
+    cell::{
+        checker_info::{CheckerInfoCell, CheckerInfoCellTypeArgs},
+        sidechain_config::{SidechainConfigCell, SidechainConfigCellTypeArgs},
+        sidechain_fee::{SidechainFeeCell, SidechainFeeCellLockArgs},
+        sidechain_state::{CommittedCheckerInfo, SidechainStateCell, SidechainStateCellTypeArgs},
+        task::TaskStatus,
+    },
+    common::*,
+    seed::{derive_seed, SeedPurpose},
+    witness::collator_submit_tasks::{CheckerSignature, CollatorSubmitTasksWitness, ExistedCommittedCheckerInfo, SignedVote},
+    FromRaw,
+};
+
+use crate::common::*;
+use crate::environment_builder::{AxonScripts, EnvironmentBuilder};
+use crate::secp256k1::*;
+
+const MAX_CYCLES: u64 = 10_000_000;
+
+const CHECKED_SIZE: u128 = 1;
+const FEE_RATE: u32 = 1;
+
+const JOB: BlockSlice = BlockSlice { from: 1, to: 2 };
+
+const BLANK_HASH: [u8; 32] = [
+    38, 108, 236, 151, 203, 237, 226, 207, 188, 231, 54, 102, 240, 141, 238, 217, 86, 11, 223, 120, 65, 167, 165, 165, 27, 58, 63, 9, 218,
+    36, 158, 33,
+]; // Blake2b hash for [0u8; 32]
+
+/*
+signs JOB with the two checkers drawn out of three, waiting_jobs are the jobs of the state input.
+signers may replace the drawn checkers and their keys, the second argument is the undrawn one
+ */
+fn collator_submit_signed_tasks_tx<F>(waiting_jobs: Vec<BlockSlice>, signers: F) -> (Context, TransactionView)
+where
+    F: FnOnce(&mut Vec<(Identity, Privkey)>, &(Identity, Privkey)),
+{
+    // generate key pair
+    let privkey = Generator::random_privkey();
+    let pubkey = privkey.pubkey().expect("pubkey");
+    let pubkey_hash = blake160(&pubkey.serialize());
+
+    // deploy contract
+    let (
+        mut builder,
+        AxonScripts {
+            always_success_code,
+            always_success_script: always_success,
+            code_cell_script,
+            signer,
+            ..
+        },
+    ) = EnvironmentBuilder::default().bootstrap(pubkey_hash.to_vec());
+
+    // prepare scripts
+    let sidechain_config_type_args = SidechainConfigCellTypeArgs::default();
+    let sidechain_config_type_script = builder
+        .context
+        .build_script(&always_success_code, sidechain_config_type_args.serialize())
+        .expect("script");
+
+    let sidechain_state_type_args = SidechainStateCellTypeArgs::default();
+    let sidechain_state_type_script = builder
+        .context
+        .build_script(&always_success_code, sidechain_state_type_args.serialize())
+        .expect("script");
+
+    let mut sidechain_fee_lock_args = SidechainFeeCellLockArgs::default();
+    let sidechain_fee_output_lock_script = builder
+        .context
+        .build_script(&always_success_code, sidechain_fee_lock_args.serialize())
+        .expect("script");
+
+    sidechain_fee_lock_args.surplus = 2 * CHECKED_SIZE * FEE_RATE as u128;
+    let sidechain_fee_input_lock_script = builder
+        .context
+        .build_script(&always_success_code, sidechain_fee_lock_args.serialize())
+        .expect("script");
+
+    // every checker is locked by the blake160 of its key
+    let checker_lock = builder.context.build_script(&always_success_code, Bytes::new()).expect("script");
+    let mut checkers = (0..3)
+        .map(|_| {
+            let privkey = Generator::random_privkey();
+            let pubkey_hash = blake160(&privkey.pubkey().expect("pubkey").serialize());
+            let lock = checker_lock.as_builder().args(pubkey_hash.to_vec().pack()).build();
+            (lock_identity(&lock), privkey)
+        })
+        .collect::<Vec<_>>();
+    checkers.sort_by_key(|(checker, _)| *checker);
+
+    // prepare inputs
+    let mut sidechain_config_input_data = SidechainConfigCell::default();
+    sidechain_config_input_data.commit_threshold = 2;
+    sidechain_config_input_data.collators = vec![signer];
+    sidechain_config_input_data.check_fee_rate = FEE_RATE;
+    sidechain_config_input_data.checker_normal_count = 3;
+    sidechain_config_input_data.activated_checkers = checkers.iter().map(|(checker, _)| *checker).collect();
+    sidechain_config_input_data.activated_checker_weights = vec![1, 2, 3];
+    // no checker has committed before, so the first job settles without a reveal
+    sidechain_config_input_data.reveal_quorum = 2;
+
+    let sidechain_config_input = builder.create_input(
+        new_type_cell_output(1000, &always_success, &sidechain_config_type_script),
+        sidechain_config_input_data.serialize(),
+    );
+
+    let mut sidechain_state_input_data = SidechainStateCell::default();
+    sidechain_state_input_data.waiting_jobs = waiting_jobs;
+    let sidechain_state_input = builder.create_input(
+        new_type_cell_output(1000, &always_success, &sidechain_state_type_script),
+        sidechain_state_input_data.serialize(),
+    );
+
+    let sidechain_fee_input_data = SidechainFeeCell::default();
+    let sidechain_fee_input = builder.create_input(
+        new_type_cell_output(1000, &sidechain_fee_input_lock_script, &always_success),
+        sidechain_fee_input_data.serialize(),
+    );
+
+    let mut builder = builder
+        .input(sidechain_config_input)
+        .input(sidechain_state_input)
+        .input(sidechain_fee_input);
+
+    // the job is signed by the checkers drawn for it
+    let seed = derive_seed::<Blake2b>(
+        &sidechain_state_input_data.random_seed,
+        sidechain_state_input_data.random_offset,
+        0,
+        SeedPurpose::SignedJob,
+        &JOB,
+    );
+    let drawn = draw_checkers(&sidechain_config_input_data, seed, 2).expect("draw");
+    checkers.sort_by_key(|(checker, _)| {
+        drawn
+            .iter()
+            .position(|drawn_checker| drawn_checker == checker)
+            .unwrap_or(drawn.len())
+    });
+    let undrawn = checkers.pop().expect("undrawn checker");
+    signers(&mut checkers, &undrawn);
+
+    let checker_info_type_scripts = checkers
+        .iter()
+        .map(|(checker, _)| {
+            let mut checker_info_type_args = CheckerInfoCellTypeArgs::default();
+            checker_info_type_args.checker_lock_arg = *checker;
+            builder
+                .context
+                .build_script(&always_success_code, checker_info_type_args.serialize())
+                .expect("script")
+        })
+        .collect::<Vec<_>>();
+
+    let checker_info_input_data = CheckerInfoCell::default();
+    for checker_info_type_script in &checker_info_type_scripts {
+        let checker_info_input = builder.create_input(
+            new_type_cell_output(1000, &always_success, checker_info_type_script),
+            checker_info_input_data.serialize(),
+        );
+        builder = builder.input(checker_info_input);
+    }
+
+    // prepare outputs
+    let vote = SignedVote {
+        chain_id: 0,
+        job:      JOB,
+        verdict:  TaskStatus::TaskPassed,
+        reveal:   [0u8; 32],
+        commit:   BLANK_HASH,
+    };
+
+    let mut checker_info_output_data = checker_info_input_data.clone();
+    checker_info_output_data.unpaid_fee = CHECKED_SIZE * FEE_RATE as u128;
+
+    // both checkers are newly committed, thus reveal the default value
+    let mut random_seed = new_blake2b();
+    random_seed.update(&sidechain_state_input_data.random_seed);
+    for _ in &checkers {
+        random_seed.update(&[0u8; 32]);
+    }
+
+    let mut sidechain_state_output_data = sidechain_state_input_data.clone();
+    random_seed.finalize(&mut sidechain_state_output_data.random_seed);
+    sidechain_state_output_data.random_offset = 1;
+    sidechain_state_output_data.seed_epoch = 1;
+    sidechain_state_output_data.waiting_jobs.retain(|job| *job != JOB);
+    sidechain_state_output_data.random_commit = checkers
+        .iter()
+        .map(|(checker, _)| CommittedCheckerInfo {
+            checker_lock_arg: *checker,
+            committed_hash:   BLANK_HASH,
+        })
+        .collect();
+
+    let mut outputs = vec![
+        new_type_cell_output(1000, &always_success, &code_cell_script),
+        new_type_cell_output(1000, &always_success, &sidechain_config_type_script),
+        new_type_cell_output(1000, &always_success, &sidechain_state_type_script),
+        new_type_cell_output(1000, &sidechain_fee_output_lock_script, &always_success),
+    ];
+    let mut outputs_data = vec![
+        Bytes::new(),
+        sidechain_config_input_data.serialize(),
+        sidechain_state_output_data.serialize(),
+        SidechainFeeCell::default().serialize(),
+    ];
+
+    for checker_info_type_script in &checker_info_type_scripts {
+        outputs.push(new_type_cell_output(1000, &always_success, checker_info_type_script));
+        outputs_data.push(checker_info_output_data.serialize());
+    }
+
+    let mut witness = CollatorSubmitTasksWitness::default();
+    witness.commit = checkers
+        .iter()
+        .map(|(checker, _)| ExistedCommittedCheckerInfo {
+            index:                 None,
+            checker_lock_arg:      *checker,
+            origin_committed_hash: None,
+            new_committed_hash:    Some(BLANK_HASH),
+        })
+        .collect();
+    witness.check_data_size = CHECKED_SIZE;
+    witness.new_random_seed = sidechain_state_output_data.random_seed;
+    witness.signed_job = Some(JOB);
+    witness.signatures = checkers
+        .iter()
+        .map(|(_, privkey)| {
+            let signature = privkey.sign_recoverable(&H256::from(vote.message::<Blake2b>())).expect("sign");
+
+            let mut checker_signature = CheckerSignature {
+                verdict: vote.verdict,
+                reveal: vote.reveal,
+                commit: vote.commit,
+                ..CheckerSignature::default()
+            };
+            checker_signature
+                .lock_code_hash
+                .copy_from_slice(checker_lock.as_reader().code_hash().raw_data());
+            checker_signature.lock_hash_type = HashType::from_raw(checker_lock.as_reader().hash_type().as_slice()).expect("hash type");
+            checker_signature.signature.copy_from_slice(&signature.serialize());
+            checker_signature
+        })
+        .collect();
+
+    let witnesses = [get_dummy_witness_builder().input_type(witness.serialize().pack_some()).as_bytes()];
+
+    // build transaction
+    let builder = builder.outputs(outputs).outputs_data(outputs_data.pack());
+    let tx = builder.builder.build();
+    let tx = tx
+        .as_advanced_builder()
+        .set_witnesses(sign_tx_with_witnesses(tx, witnesses.pack(), &privkey).unwrap())
+        .build();
+
+    (builder.context, tx)
+}
+
+fn update_witness<F: FnOnce(&mut CollatorSubmitTasksWitness)>(tx: &mut NativeTransaction, f: F) {
+    let witness_args = WitnessArgs::from_slice(&tx.witnesses[0]).expect("witness args");
+    let mut witness =
+        CollatorSubmitTasksWitness::from_raw(&witness_args.input_type().to_opt().expect("input type").raw_data()).expect("witness");
+    f(&mut witness);
+
+    tx.witnesses[0] = witness_args
+        .as_builder()
+        .input_type(witness.serialize().pack_some())
+        .build()
+        .as_slice()
+        .to_vec();
+}
+
+#[test]
+fn test_success() {
+    let (context, tx) = collator_submit_signed_tasks_tx(vec![JOB], |_, _| {});
+
+    context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+}
+
+#[test]
+fn test_signed_vote_duplicated() {
+    let (context, tx) = collator_submit_signed_tasks_tx(vec![JOB], |signers, _| signers[1] = signers[0].clone());
+
+    assert_eq!(verify(&native_transaction(&context, &tx)), Err(Error::SignedVoteDuplicated));
+}
+
+#[test]
+fn test_checker_not_drawn() {
+    // the witness carries a valid signature of the checker who was not drawn
+    let (context, tx) = collator_submit_signed_tasks_tx(vec![JOB], |signers, undrawn| signers[1] = undrawn.clone());
+
+    assert_eq!(verify(&native_transaction(&context, &tx)), Err(Error::SignedVoteMismatch));
+}
+
+#[test]
+fn test_signed_vote_tampered() {
+    let (context, tx) = collator_submit_signed_tasks_tx(vec![JOB], |_, _| {});
+
+    // the collator passes a vote its checker did not sign, whose signature recovers another key
+    let mut tx = native_transaction(&context, &tx);
+    update_witness(&mut tx, |witness| witness.signatures[0].verdict = TaskStatus::ChallengeRejected);
+
+    assert_eq!(verify(&tx), Err(Error::SignedVoteMismatch));
+}
+
+#[test]
+fn test_signature_invalid() {
+    let (context, tx) = collator_submit_signed_tasks_tx(vec![JOB], |_, _| {});
+
+    // a recovery id out of range recovers no key at all
+    let mut tx = native_transaction(&context, &tx);
+    update_witness(&mut tx, |witness| witness.signatures[0].signature[64] = 4);
+
+    assert_eq!(verify(&tx), Err(Error::SignedVoteSignatureInvalid));
+}
+
+#[test]
+fn test_job_not_waiting() {
+    let (context, tx) = collator_submit_signed_tasks_tx(vec![BlockSlice { from: 1, to: 3 }], |_, _| {});
+
+    assert_eq!(verify(&native_transaction(&context, &tx)), Err(Error::JobNotWaiting));
+}
+
+#[test]
+fn test_reveal_quorum_unreachable() {
+    let (context, tx) = collator_submit_signed_tasks_tx(vec![JOB], |_, _| {});
+
+    // more reveals than checkers in a job
+    let mut tx = native_transaction(&context, &tx);
+    let mut sidechain_config_output_data = SidechainConfigCell::from_raw(&tx.outputs[1].data).expect("config");
+    sidechain_config_output_data.reveal_quorum = 3;
+    tx.outputs[1].data = sidechain_config_output_data.serialize().to_vec();
+
+    assert_eq!(verify(&tx), Err(Error::RevealQuorumUnreachable));
+}
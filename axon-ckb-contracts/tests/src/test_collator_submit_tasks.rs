@@ -1,15 +1,28 @@
+use ckb_testtool::context::Context;
 use ckb_tool::ckb_types::prelude::*;
-use ckb_tool::{bytes::Bytes, ckb_crypto::secp::Generator, ckb_types::packed::CellInput};
+use ckb_tool::{
+    bytes::Bytes,
+    ckb_crypto::secp::Generator,
+    ckb_types::{
+        core::TransactionView,
+        packed::{CellInput, Script, WitnessArgs},
+    },
+};
 
+use code_cell::{
+    error::Error,
+    native::{draw_checkers, verify, NativeCell, NativeTransaction, Script as NativeScript},
+};
 use common_raw::{
     cell::{
         sidechain_config::{SidechainConfigCell, SidechainConfigCellTypeArgs},
         sidechain_fee::{SidechainFeeCell, SidechainFeeCellLockArgs},
-        sidechain_state::{CommittedCheckerInfo, SidechainStateCell, SidechainStateCellTypeArgs},
+        sidechain_state::{CommittedCheckerInfo, SidechainStateCell, SidechainStateCellTypeArgs, SubmitProgress},
         task::{TaskCell, TaskCellTypeArgs, TaskMode, TaskStatus},
     },
     common::*,
     witness::collator_submit_tasks::{CollatorSubmitTasksWitness, ExistedCommittedCheckerInfo},
+    FromRaw, Hasher,
 };
 
 use crate::common::*;
@@ -37,8 +50,11 @@ const INVALID_CHECKER_LOCK_ARG: PubKeyHash = [2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0
 const VALID_CHALLENGE_CHECKER_LOCK_ARG: PubKeyHash = [3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
 const INVALID_CHALLENGE_CHECKER_LOCK_ARG: PubKeyHash = [4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
 
-#[test]
-fn test_success() {
+// submits the five tasks of the job 0..=0 at once, jailing the two checkers who voted wrong, the
+// existed valid checker reveals reveal against its saved commit and commits to new_commit
+fn collator_submit_tasks_tx(reveal: RandomSeed, new_commit: CommittedHash) -> (Context, TransactionView) {
+    let origin_commit = Blake2b::hash(&reveal);
+
     // generate key pair
     let privkey = Generator::random_privkey();
     let pubkey = privkey.pubkey().expect("pubkey");
@@ -51,6 +67,7 @@ fn test_success() {
             always_success_code,
             always_success_script: always_success,
             code_cell_script,
+            signer,
             ..
         },
     ) = EnvironmentBuilder::default().bootstrap(pubkey_hash.to_vec());
@@ -114,15 +131,17 @@ fn test_success() {
     let mut sidechain_config_input_data = SidechainConfigCell::default();
     sidechain_config_input_data.commit_threshold = COMMIT_THRESHOLD;
     sidechain_config_input_data.challenge_threshold = CHALLENGE_THRESHOLD;
-    sidechain_config_input_data.collator_lock_arg.copy_from_slice(&pubkey_hash);
+    sidechain_config_input_data.collators = vec![signer];
     sidechain_config_input_data.check_fee_rate = FEE_RATE;
+    sidechain_config_input_data.checker_normal_count = 4;
     sidechain_config_input_data.activated_checkers = vec![
         VALID_CHECKER_LOCK_ARG,
         NEW_CHECKER_LOCK_ARG,
         INVALID_CHECKER_LOCK_ARG,
         VALID_CHALLENGE_CHECKER_LOCK_ARG,
-        INVALID_CHECKER_LOCK_ARG,
     ];
+    sidechain_config_input_data.activated_checker_weights = vec![1, 2, 3, 4];
+    sidechain_config_input_data.reveal_quorum = 1;
 
     let sidechain_config_input_outpoint = builder.context.create_cell(
         new_type_cell_output(1000, &always_success, &sidechain_config_type_script),
@@ -133,11 +152,13 @@ fn test_success() {
         .build();
     let mut builder = builder.input(sidechain_config_input);
 
+    // the tasks check the job 0..=0
     let mut sidechain_state_input_data = SidechainStateCell::default();
+    sidechain_state_input_data.waiting_jobs = vec![BlockSlice::default()];
 
-    let existed_checker_info = CommittedCheckerInfo {
+    let mut existed_checker_info = CommittedCheckerInfo {
         checker_lock_arg: VALID_CHECKER_LOCK_ARG,
-        committed_hash:   BLANK_HASH,
+        committed_hash:   origin_commit,
     };
     let invalid_checker_info = CommittedCheckerInfo {
         checker_lock_arg: INVALID_CHECKER_LOCK_ARG,
@@ -182,7 +203,8 @@ fn test_success() {
     task_input_data.check_data_size = CHECKED_SIZE;
     task_input_data.mode = TaskMode::Task;
     task_input_data.status = TaskStatus::TaskPassed;
-    task_input_data.commit = BLANK_HASH;
+    task_input_data.commit = new_commit;
+    task_input_data.reveal = reveal;
 
     let existed_task_input_outpoint = builder.context.create_cell(
         new_type_cell_output(1000, &always_success, &existed_task_type_script),
@@ -193,6 +215,9 @@ fn test_success() {
         .build();
     let mut builder = builder.input(existed_task_input);
 
+    task_input_data.commit = BLANK_HASH;
+    task_input_data.reveal = RandomSeed::default();
+
     let new_task_input_outpoint = builder.context.create_cell(
         new_type_cell_output(1000, &always_success, &new_task_type_script),
         task_input_data.serialize(),
@@ -238,19 +263,24 @@ fn test_success() {
     //prepare outputs
     let mut sidechain_config_output_data = sidechain_config_input_data.clone();
     sidechain_config_output_data.activated_checkers = vec![VALID_CHECKER_LOCK_ARG, NEW_CHECKER_LOCK_ARG, VALID_CHALLENGE_CHECKER_LOCK_ARG];
+    sidechain_config_output_data.activated_checker_weights = vec![1, 2, 3];
+    sidechain_config_output_data.checker_normal_count = 3;
     sidechain_config_output_data.jailed_checkers = vec![INVALID_CHECKER_LOCK_ARG, INVALID_CHALLENGE_CHECKER_LOCK_ARG];
 
     let mut sidechain_state_data_output = sidechain_state_input_data.clone();
-    sidechain_state_data_output.random_seed = [
-        221, 69, 216, 101, 62, 143, 232, 10, 142, 65, 192, 13, 1, 143, 107, 149, 92, 153, 26, 231, 162, 9, 76, 81, 63, 187, 104, 92, 156,
-        86, 150, 48,
-    ];
+    // the origin seed and the reveal of every task, the invalid checker withheld its reveal
+    let mut seed_data = [0u8; 32 * 6];
+    seed_data[32..64].copy_from_slice(&reveal);
+    sidechain_state_data_output.random_seed = Blake2b::hash(&seed_data);
+    sidechain_state_data_output.seed_epoch = 1;
+    sidechain_state_data_output.waiting_jobs = Vec::new();
 
     let new_checker_info = CommittedCheckerInfo {
         checker_lock_arg: NEW_CHECKER_LOCK_ARG,
         committed_hash:   BLANK_HASH,
     };
 
+    existed_checker_info.committed_hash = new_commit;
     sidechain_state_data_output.random_commit = vec![existed_checker_info, valid_challenge_checker_info, new_checker_info];
 
     let sidechain_fee_data_output = SidechainFeeCell::default();
@@ -273,8 +303,8 @@ fn test_success() {
     let existed_checker_info = ExistedCommittedCheckerInfo {
         index:                 Some(0),
         checker_lock_arg:      VALID_CHECKER_LOCK_ARG,
-        origin_committed_hash: Some(BLANK_HASH),
-        new_committed_hash:    Some(BLANK_HASH),
+        origin_committed_hash: Some(origin_commit),
+        new_committed_hash:    Some(new_commit),
     };
     let new_checker_info = ExistedCommittedCheckerInfo {
         index:                 None,
@@ -322,6 +352,272 @@ fn test_success() {
         .set_witnesses(sign_tx_with_witnesses(tx, witnesses.pack(), &privkey).unwrap())
         .build();
 
-    // run
-    builder.context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+    (builder.context, tx)
+}
+
+#[test]
+fn test_success() {
+    let (context, tx) = collator_submit_tasks_tx(RandomSeed::default(), BLANK_HASH);
+
+    context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+}
+
+#[test]
+fn test_submit_task_limit_exceeded() {
+    let (context, tx) = collator_submit_tasks_tx(RandomSeed::default(), BLANK_HASH);
+
+    // one task more than a submit may carry
+    let mut tx = native_transaction(&context, &tx);
+    let mut sidechain_config_input_data = SidechainConfigCell::from_raw(&tx.inputs[1].data).expect("config");
+    sidechain_config_input_data.submit_task_limit = 4;
+    tx.inputs[1].data = sidechain_config_input_data.serialize().to_vec();
+
+    assert_eq!(verify(&tx), Err(Error::SubmitTaskLimitExceeded));
+}
+
+#[test]
+fn test_chunk_of_another_job() {
+    let (context, tx) = collator_submit_tasks_tx(RandomSeed::default(), BLANK_HASH);
+
+    // earlier chunks submitted tasks of another job
+    let mut tx = native_transaction(&context, &tx);
+    let mut sidechain_state_input_data = SidechainStateCell::from_raw(&tx.inputs[2].data).expect("state");
+    sidechain_state_input_data.submit_progress = Some(SubmitProgress {
+        job_hash: [1u8; 32],
+        ..SubmitProgress::default()
+    });
+    tx.inputs[2].data = sidechain_state_input_data.serialize().to_vec();
+
+    assert_eq!(verify(&tx), Err(Error::TaskJobInconsistent));
+}
+
+#[test]
+fn test_rotated_commit() {
+    // the first job rotates the commit of the existed valid checker
+    let (context, tx) = collator_submit_tasks_tx(RandomSeed::default(), Blake2b::hash(&[1u8; 32]));
+    context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+
+    let first_state_output = SidechainStateCell::from_raw(&tx.outputs_data().get(2).expect("state").raw_data()).expect("state");
+    assert_eq!(first_state_output.random_commit[0].committed_hash, Blake2b::hash(&[1u8; 32]));
+
+    // the second job reveals against the rotated commit
+    let (context, tx) = collator_submit_tasks_tx([1u8; 32], BLANK_HASH);
+    let second_state_input = SidechainStateCell::from_raw(&native_transaction(&context, &tx).inputs[2].data).expect("state");
+    assert_eq!(second_state_input.random_commit[0], first_state_output.random_commit[0]);
+    context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+}
+
+#[test]
+fn test_jailed_checker_kept_activated() {
+    let (context, tx) = collator_submit_tasks_tx(RandomSeed::default(), BLANK_HASH);
+
+    // the invalid checker keeps its weight in the activated checkers instead of being jailed
+    let mut tx = native_transaction(&context, &tx);
+    let sidechain_config_input_data = SidechainConfigCell::from_raw(&tx.inputs[1].data).expect("config");
+    let mut sidechain_config_output_data = SidechainConfigCell::from_raw(&tx.outputs[1].data).expect("config");
+    sidechain_config_output_data.activated_checkers = sidechain_config_input_data.activated_checkers;
+    sidechain_config_output_data.activated_checker_weights = sidechain_config_input_data.activated_checker_weights;
+    sidechain_config_output_data.jailed_checkers = vec![INVALID_CHALLENGE_CHECKER_LOCK_ARG];
+    tx.outputs[1].data = sidechain_config_output_data.serialize().to_vec();
+
+    assert_eq!(verify(&tx), Err(Error::SidechainConfigMismatch));
+}
+
+#[test]
+fn test_jailed_checker_not_drawn() {
+    let (context, tx) = collator_submit_tasks_tx(RandomSeed::default(), BLANK_HASH);
+    context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+
+    // the jailed checkers are out of the drawing, the three left are all there is to draw
+    let sidechain_config_output_data =
+        SidechainConfigCell::from_raw(&tx.outputs_data().get(1).expect("config").raw_data()).expect("config");
+    for seed in 0..8u8 {
+        let mut checkers = draw_checkers(&sidechain_config_output_data, [seed; 32], 3).expect("draw");
+        checkers.sort();
+        assert_eq!(checkers, vec![
+            VALID_CHECKER_LOCK_ARG,
+            NEW_CHECKER_LOCK_ARG,
+            VALID_CHALLENGE_CHECKER_LOCK_ARG
+        ]);
+    }
+    assert_eq!(
+        draw_checkers(&sidechain_config_output_data, [0u8; 32], 4),
+        Err(Error::CheckerSampleExhausted)
+    );
+}
+
+#[test]
+fn test_unsorted_config_checkers() {
+    let (context, tx) = collator_submit_tasks_tx(RandomSeed::default(), BLANK_HASH);
+
+    let mut tx = native_transaction(&context, &tx);
+    let mut sidechain_config_output_data = SidechainConfigCell::from_raw(&tx.outputs[1].data).expect("config");
+    sidechain_config_output_data.activated_checkers.swap(0, 1);
+    tx.outputs[1].data = sidechain_config_output_data.serialize().to_vec();
+
+    assert_eq!(verify(&tx), Err(Error::SidechainConfigCheckersUnsorted));
+}
+
+#[test]
+fn test_config_type_mismatch() {
+    let (context, tx) = collator_submit_tasks_tx(RandomSeed::default(), BLANK_HASH);
+
+    // the config input keeps its type args under the code of another script
+    let mut tx = native_transaction(&context, &tx);
+    let config_type = Script::from_slice(tx.inputs[1].type_.as_ref().expect("type").as_slice()).expect("type");
+    let other_type = Script::from_slice(tx.inputs[0].lock.as_slice())
+        .expect("lock")
+        .as_builder()
+        .args(config_type.args())
+        .build();
+    tx.inputs[1].type_ = Some(NativeScript::from_slice(other_type.as_slice()).expect("type"));
+
+    assert_eq!(verify(&tx), Err(Error::CodeHashMismatch));
+}
+
+fn update_witness<F: FnOnce(&mut CollatorSubmitTasksWitness)>(tx: &mut NativeTransaction, f: F) {
+    let witness_args = WitnessArgs::from_slice(&tx.witnesses[0]).expect("witness args");
+    let mut witness =
+        CollatorSubmitTasksWitness::from_raw(&witness_args.input_type().to_opt().expect("input type").raw_data()).expect("witness");
+    f(&mut witness);
+
+    tx.witnesses[0] = witness_args
+        .as_builder()
+        .input_type(witness.serialize().pack_some())
+        .build()
+        .as_slice()
+        .to_vec();
+}
+
+fn set_fee_surplus(cell: &mut NativeCell, surplus: u128) {
+    let lock = Script::from_slice(cell.lock.as_slice()).expect("lock");
+    let mut lock_args = SidechainFeeCellLockArgs::from_raw(&lock.args().raw_data()).expect("lock args");
+    lock_args.surplus = surplus;
+
+    let lock = lock.as_builder().args(lock_args.serialize().pack()).build();
+    cell.lock = NativeScript::from_slice(lock.as_slice()).expect("lock");
+}
+
+// the job of collator_submit_tasks_tx in two chunks, the tasks of the existed and the new checker
+// first and the three others then
+fn two_chunks_txs() -> (NativeTransaction, NativeTransaction) {
+    let (context, tx) = collator_submit_tasks_tx(RandomSeed::default(), BLANK_HASH);
+    let tx = native_transaction(&context, &tx);
+
+    let sidechain_state_input_data = SidechainStateCell::from_raw(&tx.inputs[2].data).expect("state");
+
+    // the origin seed and the reveals of the existed and the new checker, all blank
+    let first_random_seed = Blake2b::hash(&[0u8; 32 * 3]);
+    // the first chunk seed and the reveals of the three other checkers, the invalid one withheld its
+    // reveal and both challengers revealed a blank one
+    let mut seed_data = [0u8; 32 * 4];
+    seed_data[0..32].copy_from_slice(&first_random_seed);
+    let last_random_seed = Blake2b::hash(&seed_data);
+
+    let mut job = TaskCell::from_raw(&tx.inputs[4].data).expect("task");
+    job.status = TaskStatus::Idle;
+    job.reveal = RandomSeed::default();
+    job.commit = CommittedHash::default();
+    let mut job_data = job.serialize().to_vec();
+    job_data.extend_from_slice(&TaskCellTypeArgs::default().serialize());
+
+    // 3 tasks and 2 challenges, two tasks of which are settled, the existed checker revealed
+    let mut sidechain_state_chunk_data = sidechain_state_input_data.clone();
+    sidechain_state_chunk_data.random_commit.push(CommittedCheckerInfo {
+        checker_lock_arg: NEW_CHECKER_LOCK_ARG,
+        committed_hash:   BLANK_HASH,
+    });
+    sidechain_state_chunk_data.submit_progress = Some(SubmitProgress {
+        job_hash:             Blake2b::hash(&job_data),
+        remaining_tasks:      1,
+        remaining_challenges: 2,
+        settle_count:         2,
+        shutdown_count:       0,
+        committed_count:      1,
+        reveal_count:         1,
+        random_seed:          first_random_seed,
+    });
+
+    // each valid vote is paid, two in the first chunk and one in the last
+    let mut first_chunk = tx.clone();
+    first_chunk.inputs.truncate(6);
+    first_chunk.outputs[1].data = first_chunk.inputs[1].data.clone();
+    first_chunk.outputs[2].data = sidechain_state_chunk_data.serialize().to_vec();
+    set_fee_surplus(&mut first_chunk.outputs[3], 1);
+    update_witness(&mut first_chunk, |witness| {
+        witness.commit.truncate(2);
+        witness.new_random_seed = first_random_seed;
+    });
+
+    let mut last_chunk = tx;
+    last_chunk.inputs.drain(4..6);
+    last_chunk.inputs[2].data = sidechain_state_chunk_data.serialize().to_vec();
+    set_fee_surplus(&mut last_chunk.inputs[3], 1);
+    update_witness(&mut last_chunk, |witness| {
+        witness.commit.drain(0..2);
+        witness.origin_random_seed = first_random_seed;
+        witness.new_random_seed = last_random_seed;
+    });
+
+    let mut sidechain_state_output_data = SidechainStateCell::from_raw(&last_chunk.outputs[2].data).expect("state");
+    sidechain_state_output_data.random_seed = last_random_seed;
+    last_chunk.outputs[2].data = sidechain_state_output_data.serialize().to_vec();
+
+    (first_chunk, last_chunk)
+}
+
+#[test]
+fn test_two_chunks() {
+    let (first_chunk, last_chunk) = two_chunks_txs();
+
+    verify(&first_chunk).expect("pass verification");
+    verify(&last_chunk).expect("pass verification");
+}
+
+#[test]
+fn test_chunk_progress_dropped() {
+    let (mut first_chunk, mut last_chunk) = two_chunks_txs();
+
+    // the first chunk could not leave the job without its progress
+    let mut sidechain_state_output_data = SidechainStateCell::from_raw(&first_chunk.outputs[2].data).expect("state");
+    sidechain_state_output_data.submit_progress = None;
+    first_chunk.outputs[2].data = sidechain_state_output_data.serialize().to_vec();
+    assert_eq!(verify(&first_chunk), Err(Error::SidechainStateMismatch));
+
+    // nor could the last chunk keep it
+    let mut sidechain_state_output_data = SidechainStateCell::from_raw(&last_chunk.outputs[2].data).expect("state");
+    sidechain_state_output_data.submit_progress = SidechainStateCell::from_raw(&last_chunk.inputs[2].data)
+        .expect("state")
+        .submit_progress;
+    last_chunk.outputs[2].data = sidechain_state_output_data.serialize().to_vec();
+    assert_eq!(verify(&last_chunk), Err(Error::SidechainStateMismatch));
+}
+
+#[test]
+fn test_chunk_progress_lost() {
+    let (_, mut last_chunk) = two_chunks_txs();
+
+    // without the progress the last chunk would start the job over from the seed of the state
+    let mut sidechain_state_input_data = SidechainStateCell::from_raw(&last_chunk.inputs[2].data).expect("state");
+    sidechain_state_input_data.submit_progress = None;
+    last_chunk.inputs[2].data = sidechain_state_input_data.serialize().to_vec();
+
+    assert_eq!(verify(&last_chunk), Err(Error::SidechainStateRandomSeedMismatch));
+}
+
+#[test]
+fn test_collator_not_on_duty() {
+    let (context, tx) = collator_submit_tasks_tx(RandomSeed::default(), BLANK_HASH);
+
+    // the job 0..=0 belongs to the first collator, the signer takes the second seat
+    let mut tx = native_transaction(&context, &tx);
+    let mut sidechain_config_input_data = SidechainConfigCell::from_raw(&tx.inputs[1].data).expect("config");
+    sidechain_config_input_data.collators.insert(0, [9u8; 20]);
+    tx.inputs[1].data = sidechain_config_input_data.serialize().to_vec();
+
+    let mut sidechain_config_output_data = SidechainConfigCell::from_raw(&tx.outputs[1].data).expect("config");
+    sidechain_config_output_data.collators.insert(0, [9u8; 20]);
+    tx.outputs[1].data = sidechain_config_output_data.serialize().to_vec();
+
+    assert_eq!(verify(&tx), Err(Error::CollatorNotOnDuty));
 }
@@ -31,6 +31,7 @@ fn test_success() {
             always_success_code,
             always_success_script: always_success,
             code_cell_script,
+            signer,
             ..
         },
     ) = EnvironmentBuilder::default().bootstrap(pubkey_hash.to_vec());
@@ -44,7 +45,7 @@ fn test_success() {
         .expect("script");
 
     let mut sidechain_bond_lock_args = SidechainBondCellLockArgs::default();
-    sidechain_bond_lock_args.collator_lock_arg.copy_from_slice(&pubkey_hash);
+    sidechain_bond_lock_args.collator_lock_arg.copy_from_slice(&signer);
 
     let sidechain_bond_lock_input_script = builder
         .context
@@ -60,6 +61,9 @@ fn test_success() {
     // prepare cell deps
     let mut config_dep_data = SidechainConfigCell::default();
     config_dep_data.sidechain_status = SidechainStatus::Shutdown;
+    config_dep_data
+        .bond_sudt_typescript_codehash
+        .copy_from_slice(always_success.as_reader().code_hash().raw_data());
     let config_dep_out_point = builder.context.create_cell(
         new_type_cell_output(1000, &always_success, &config_type_script),
         config_dep_data.serialize(),
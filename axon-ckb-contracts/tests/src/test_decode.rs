@@ -0,0 +1,22 @@
+use common_raw::{cell::sidechain_config::SidechainConfigCell, DecodeError, FromRaw, Serialize};
+
+#[test]
+fn test_success() {
+    let data = SidechainConfigCell::default().serialize();
+
+    // sidechain_status is the first field, located by the first offset of the molecule table
+    let mut unknown_status = data.clone();
+    let offset = u32::from_le_bytes([data[4], data[5], data[6], data[7]]) as usize;
+    unknown_status[offset] = 7;
+
+    let err = SidechainConfigCell::from_raw(&unknown_status).unwrap_err();
+    assert!(matches!(err, DecodeError::UnknownVariant { value: 7, .. }));
+    assert_eq!(err.to_string(), "unknown variant 7 at sidechain_status");
+
+    // a truncated cell fails on its molecule header
+    let err = SidechainConfigCell::from_raw(&data[..data.len() - 1]).unwrap_err();
+    assert!(matches!(err, DecodeError::Molecule { .. }));
+    assert!(err.path().is_empty());
+
+    assert_eq!(SidechainConfigCell::from_raw(&data), Ok(SidechainConfigCell::default()));
+}
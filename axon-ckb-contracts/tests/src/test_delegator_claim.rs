@@ -0,0 +1,167 @@
+use ckb_testtool::context::Context;
+use ckb_tool::bytes::Bytes;
+use ckb_tool::ckb_crypto::secp::Generator;
+use ckb_tool::ckb_types::{core::TransactionView, prelude::*};
+
+use code_cell::{error::Error, native::verify};
+
+use common_raw::cell::checker_info::{CheckerInfoCell, CheckerInfoCellTypeArgs};
+use common_raw::cell::delegation::{DelegationCell, DelegationCellLockArgs};
+use common_raw::cell::muse_token::MuseTokenCell;
+use common_raw::cell::sidechain_config::{SidechainConfigCell, SidechainConfigCellTypeArgs};
+use common_raw::witness::delegator_claim::DelegatorClaimWitness;
+use common_raw::FromRaw;
+
+use crate::common::*;
+use crate::environment_builder::{AxonScripts, EnvironmentBuilder};
+use crate::secp256k1::*;
+
+const MAX_CYCLES: u64 = 10_000_000;
+
+// claims a principal of 50 out of 60 tokens, from a jailed checker or from an activated one
+fn delegator_claim_tx(jailed: bool) -> (Context, TransactionView) {
+    // generate key pair
+    let privkey = Generator::random_privkey();
+    let pubkey = privkey.pubkey().expect("pubkey");
+    let pubkey_hash = blake160(&pubkey.serialize());
+
+    // deploy contract
+    let (
+        mut builder,
+        AxonScripts {
+            always_success_code,
+            always_success_script: always_success,
+            code_cell_script,
+            signer,
+            ..
+        },
+    ) = EnvironmentBuilder::default().bootstrap(pubkey_hash.to_vec());
+
+    let checker = [1u8; 20];
+
+    // prepare scripts
+    let config_type_args = SidechainConfigCellTypeArgs::default();
+    let config_type_script = builder
+        .context
+        .build_script(&always_success_code, config_type_args.serialize())
+        .expect("script");
+
+    let mut checker_info_type_args = CheckerInfoCellTypeArgs::default();
+    checker_info_type_args.checker_lock_arg = checker;
+    let checker_info_type_script = builder
+        .context
+        .build_script(&always_success_code, checker_info_type_args.serialize())
+        .expect("script");
+
+    let mut delegation_lock_args = DelegationCellLockArgs::default();
+    delegation_lock_args.checker_lock_arg = checker;
+    delegation_lock_args.delegator_lock_arg = signer;
+    delegation_lock_args.principal = 50;
+    let delegation_lock_script = builder
+        .context
+        .build_script(&always_success_code, delegation_lock_args.serialize())
+        .expect("script");
+
+    // prepare inputs
+    let mut config_input_data = SidechainConfigCell::default();
+    config_input_data.slash_rate = 1000;
+    if jailed {
+        config_input_data.jailed_checkers = vec![checker];
+    } else {
+        config_input_data.checker_normal_count = 1;
+        config_input_data.activated_checkers = vec![checker];
+        config_input_data.activated_checker_weights = vec![150];
+    }
+
+    let config_input = builder.create_input(
+        new_type_cell_output(1000, &always_success, &config_type_script),
+        config_input_data.serialize(),
+    );
+    let mut builder = builder.input(config_input);
+
+    let mut checker_info_input_data = CheckerInfoCell::default();
+    checker_info_input_data.bonded_amount = 100;
+    checker_info_input_data.delegated_amount = 50;
+    let checker_info_input = builder.create_input(
+        new_type_cell_output(1000, &always_success, &checker_info_type_script),
+        checker_info_input_data.serialize(),
+    );
+    let mut builder = builder.input(checker_info_input);
+
+    let mut delegation_input_data = DelegationCell::default();
+    delegation_input_data.amount = 60;
+    let delegation_input = builder.create_input(
+        new_type_cell_output(1000, &delegation_lock_script, &always_success),
+        delegation_input_data.serialize(),
+    );
+    let builder = builder.input(delegation_input);
+
+    // prepare outputs
+    let outputs = vec![
+        new_type_cell_output(1000, &always_success, &code_cell_script),
+        new_type_cell_output(1000, &always_success, &config_type_script),
+        new_type_cell_output(1000, &always_success, &checker_info_type_script),
+        new_type_cell_output(1000, &always_success, &always_success),
+    ];
+
+    // the principal no longer weighs for an activated checker
+    let mut config_output_data = config_input_data.clone();
+    if !jailed {
+        config_output_data.activated_checker_weights = vec![100];
+    }
+
+    let mut checker_info_output_data = checker_info_input_data.clone();
+    checker_info_output_data.delegated_amount = 0;
+
+    // a tenth of the principal is slashed as the checker is jailed
+    let mut muse_token_output_data = MuseTokenCell::default();
+    muse_token_output_data.amount = if jailed { 55 } else { 60 };
+
+    let outputs_data = vec![
+        Bytes::new(),
+        config_output_data.serialize(),
+        checker_info_output_data.serialize(),
+        muse_token_output_data.serialize(),
+    ];
+
+    let builder = builder.outputs(outputs).outputs_data(outputs_data.pack());
+
+    let witness = DelegatorClaimWitness::default();
+    let witnesses = [get_dummy_witness_builder().input_type(witness.serialize().pack_some()).as_bytes()];
+
+    // build transaction
+    let tx = builder.builder.build();
+    let tx = tx
+        .as_advanced_builder()
+        .set_witnesses(sign_tx_with_witnesses(tx, witnesses.pack(), &privkey).unwrap())
+        .build();
+
+    (builder.context, tx)
+}
+
+#[test]
+fn test_success() {
+    let (context, tx) = delegator_claim_tx(true);
+
+    context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+}
+
+#[test]
+fn test_success_activated() {
+    let (context, tx) = delegator_claim_tx(false);
+
+    context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+}
+
+#[test]
+fn test_activated_slashed() {
+    let (context, tx) = delegator_claim_tx(false);
+
+    // the principal delegated to a checker which is not jailed is never slashed
+    let mut tx = native_transaction(&context, &tx);
+    let mut muse_token_output_data = MuseTokenCell::from_raw(&tx.outputs[3].data).expect("token");
+    muse_token_output_data.amount = 55;
+    tx.outputs[3].data = muse_token_output_data.serialize().to_vec();
+
+    assert_eq!(verify(&tx), Err(Error::MuseTokenMismatch));
+}
@@ -0,0 +1,70 @@
+use code_cell::error::Error;
+use common_raw::{find_error, CODE_CELL_ERRORS};
+
+// lists every variant of Error, the match fails to compile once a variant is missing
+macro_rules! assert_names {
+    ($($variant: ident,)*) => {
+        fn name(error: Error) -> &'static str {
+            match error {
+                $(Error::$variant => stringify!($variant),)*
+            }
+        }
+
+        $(assert_eq!(find_error(Error::$variant as i8).unwrap().name, name(Error::$variant));)*
+    };
+}
+
+#[test]
+fn test_success() {
+    // codes are contiguous from 1, so no variant of the contract is missing from the table
+    for (i, error) in CODE_CELL_ERRORS.iter().enumerate() {
+        assert_eq!(error.code as usize, i + 1, "{}", error.name);
+    }
+
+    assert_eq!(
+        Error::TaskRevealHashMismatch.to_string(),
+        format!("task reveal hash mismatch (code {})", Error::TaskRevealHashMismatch as i8)
+    );
+
+    // every variant has the code of its name in the table
+    assert_names! {
+        IndexOutOfBound, ItemMissing, LengthNotEnough, Encoding,
+        MissingTypeScript, CodeHashMismatch, HashTypeMismatch, CellNumberMismatch,
+        GlobalConfigMissed, MissingWitness, TypeScriptMissed, Wrong, SidechainBondMismatch, SidechainConfigMismatch,
+        SidechainFeeMismatch, SidechainStateMismatch, CheckerBondMismatch, CheckerInfoMismatch, MuseTokenMismatch, TaskMismatch,
+        MissingHeader, BatchPatternInvalid,
+        WitnessEncoding, GlobalConfigEncoding, CodeCellLockArgsEncoding, CheckerBondEncoding, CheckerBondLockArgsEncoding,
+        CheckerInfoEncoding, CheckerInfoTypeArgsEncoding, MuseTokenEncoding, SidechainBondEncoding, SidechainBondLockArgsEncoding,
+        SidechainConfigEncoding, SidechainConfigTypeArgsEncoding, SidechainFeeEncoding, SidechainFeeLockArgsEncoding,
+        SidechainStateEncoding, SidechainStateTypeArgsEncoding, SudtTokenEncoding, TaskEncoding, TaskTypeArgsEncoding,
+        TaskChainIdMismatch, TaskCheckDataSizeMismatch, TaskNotVoted, TaskAlreadyVoted, TaskStatusInconsistentWithVote,
+        TaskRevealHashMismatch, TaskRevealNotDefault, TaskCommitHashMismatch, TaskCheckerMismatch, TaskJailedCheckerMismatch,
+        TaskJobInconsistent, TaskCommitCountMismatch, TaskCountMismatch, TaskChallengeMajority, RandomSeedMismatch,
+        SidechainStateRandomSeedMismatch, SidechainStateCommitIndexOutOfBound, SidechainStateCommitMismatch,
+        SidechainStateCommitDuplicated,
+        ProposalMismatch, ProposalEncoding, ProposalTypeArgsEncoding, CheckerNotActivated, ProposalAlreadyVoted,
+        ProposalQuorumNotReached,
+        CollatorHandoverNotAllowed, CollatorJobsPending,
+        CollatorNotInSet, CollatorNotOnDuty, CollatorQuorumNotReached,
+        CheckerWeightsMismatch, CheckerSampleExhausted,
+        DelegationMismatch, DelegationEncoding, DelegationLockArgsEncoding, DelegationsIncomplete,
+        CapacityDecreased, LockScriptMismatch,
+        SidechainBondTokenMismatch,
+        JailedCheckerDelegated,
+        CheckerProofMissing, CheckerProofMismatch, CheckerProofUnused,
+        SidechainConfigCheckersUnsorted, SidechainStateCheckersUnsorted,
+        RefreshTaskLimitExceeded, SubmitTaskLimitExceeded,
+        RevealQuorumNotReached,
+        SignedVoteMismatch, SignedVoteDuplicated,
+        JobNotWaiting,
+        RevealQuorumUnreachable,
+        ProposalOutdated, ProposedConfigInvalid,
+        SignedVoteSignatureInvalid,
+    }
+
+    assert_eq!(
+        find_error(Error::SignedVoteSignatureInvalid as i8).unwrap().code as usize,
+        CODE_CELL_ERRORS.len()
+    );
+    assert!(find_error(0).is_none());
+}
@@ -0,0 +1,159 @@
+use ckb_testtool::context::Context;
+use ckb_tool::bytes::Bytes;
+use ckb_tool::ckb_crypto::secp::Generator;
+use ckb_tool::ckb_types::{core::TransactionView, prelude::*};
+
+use code_cell::{error::Error, native::verify};
+
+use common_raw::cell::proposal::{ProposalCell, ProposalCellTypeArgs, ProposalChange};
+use common_raw::cell::sidechain_config::{SidechainConfigCell, SidechainConfigCellTypeArgs};
+use common_raw::common::RATE_DENOMINATOR;
+use common_raw::witness::execute_proposal::ExecuteProposalWitness;
+
+use crate::common::*;
+use crate::environment_builder::{AxonScripts, EnvironmentBuilder};
+use crate::secp256k1::*;
+
+const MAX_CYCLES: u64 = 10_000_000;
+
+/*
+executes a proposal of two out of three checkers raising check_fee_rate, proposal may change the
+proposal cell before it is created
+ */
+fn execute_proposal_tx<F: FnOnce(&mut ProposalCell)>(proposal: F) -> (Context, TransactionView) {
+    // generate key pair
+    let privkey = Generator::random_privkey();
+    let pubkey = privkey.pubkey().expect("pubkey");
+    let pubkey_hash = blake160(&pubkey.serialize());
+
+    // deploy contract
+    let (
+        mut builder,
+        AxonScripts {
+            always_success_code,
+            always_success_script: always_success,
+            code_cell_script,
+            ..
+        },
+    ) = EnvironmentBuilder::default().bootstrap(pubkey_hash.to_vec());
+
+    let checkers = [[1u8; 20], [2u8; 20], [3u8; 20]];
+
+    // prepare scripts
+    let config_type_args = SidechainConfigCellTypeArgs::default();
+    let config_type_script = builder
+        .context
+        .build_script(&always_success_code, config_type_args.serialize())
+        .expect("script");
+
+    let mut proposal_type_args = ProposalCellTypeArgs::default();
+    proposal_type_args.proposer_lock_arg = checkers[0];
+    let proposal_type_script = builder
+        .context
+        .build_script(&always_success_code, proposal_type_args.serialize())
+        .expect("script");
+
+    // prepare inputs
+    let mut config_input_data = SidechainConfigCell::default();
+    config_input_data.checker_threshold = 2;
+    config_input_data.commit_threshold = 1;
+    config_input_data.activated_checkers = checkers.to_vec();
+    config_input_data.activated_checker_weights = vec![100, 200, 300];
+    config_input_data.check_fee_rate = 100;
+
+    let config_input = builder.create_input(
+        new_type_cell_output(1000, &always_success, &config_type_script),
+        config_input_data.serialize(),
+    );
+    let mut builder = builder.input(config_input);
+
+    // the proposed config tries to drop a checker as well, which is not adopted
+    let mut proposed = config_input_data.clone();
+    proposed.check_fee_rate = 200;
+    proposed.activated_checkers.pop();
+    proposed.activated_checker_weights.pop();
+
+    let mut proposal_input_data = ProposalCell {
+        change:       ProposalChange::UpdateConfig(proposed),
+        config_nonce: 0,
+        votes:        vec![checkers[0], checkers[2]],
+    };
+    proposal(&mut proposal_input_data);
+    let proposal_input = builder.create_input(
+        new_type_cell_output(1000, &always_success, &proposal_type_script),
+        proposal_input_data.serialize(),
+    );
+    let builder = builder.input(proposal_input);
+
+    // prepare outputs
+    let outputs = vec![
+        new_type_cell_output(1000, &always_success, &code_cell_script),
+        new_type_cell_output(1000, &always_success, &config_type_script),
+    ];
+
+    let mut config_output_data = config_input_data.clone();
+    config_output_data.check_fee_rate = 200;
+    config_output_data.config_nonce = 1;
+
+    let outputs_data = vec![Bytes::new(), config_output_data.serialize()];
+
+    let builder = builder.outputs(outputs).outputs_data(outputs_data.pack());
+
+    let witness = ExecuteProposalWitness::default();
+    let witnesses = [get_dummy_witness_builder().input_type(witness.serialize().pack_some()).as_bytes()];
+
+    // build transaction
+    let tx = builder.builder.build();
+    let tx = tx
+        .as_advanced_builder()
+        .set_witnesses(sign_tx_with_witnesses(tx, witnesses.pack(), &privkey).unwrap())
+        .build();
+
+    (builder.context, tx)
+}
+
+#[test]
+fn test_success() {
+    let (context, tx) = execute_proposal_tx(|_| {});
+
+    context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+}
+
+#[test]
+fn test_quorum_not_reached() {
+    let (context, tx) = execute_proposal_tx(|proposal| {
+        proposal.votes.pop();
+    });
+
+    assert_eq!(verify(&native_transaction(&context, &tx)), Err(Error::ProposalQuorumNotReached));
+}
+
+#[test]
+fn test_proposal_outdated() {
+    // made before another proposal was executed
+    let (context, tx) = execute_proposal_tx(|proposal| proposal.config_nonce = 1);
+
+    assert_eq!(verify(&native_transaction(&context, &tx)), Err(Error::ProposalOutdated));
+}
+
+#[test]
+fn test_checker_threshold_dropped() {
+    let (context, tx) = execute_proposal_tx(|proposal| {
+        if let ProposalChange::UpdateConfig(proposed) = &mut proposal.change {
+            proposed.checker_threshold = 0;
+        }
+    });
+
+    assert_eq!(verify(&native_transaction(&context, &tx)), Err(Error::ProposedConfigInvalid));
+}
+
+#[test]
+fn test_slash_rate_overflow() {
+    let (context, tx) = execute_proposal_tx(|proposal| {
+        if let ProposalChange::UpdateConfig(proposed) = &mut proposal.change {
+            proposed.slash_rate = RATE_DENOMINATOR + 1;
+        }
+    });
+
+    assert_eq!(verify(&native_transaction(&context, &tx)), Err(Error::ProposedConfigInvalid));
+}
@@ -30,13 +30,14 @@ fn test_success() {
             always_success_code,
             always_success_script: always_success,
             code_cell_script,
+            signer,
             ..
         },
     ) = EnvironmentBuilder::default().bootstrap(pubkey_hash.to_vec());
 
     // prepare scripts
     let mut checker_bond_input_lock_args = CheckerBondCellLockArgs::default();
-    checker_bond_input_lock_args.checker_lock_arg.copy_from_slice(&pubkey_hash);
+    checker_bond_input_lock_args.checker_lock_arg.copy_from_slice(&signer);
 
     let mut checker_bond_output_lock_args = checker_bond_input_lock_args.clone();
     checker_bond_output_lock_args.participated_chain_id.push(0);
@@ -52,7 +53,7 @@ fn test_success() {
         .expect("script");
 
     let mut checker_info_type_args = CheckerInfoCellTypeArgs::default();
-    checker_info_type_args.checker_lock_arg.copy_from_slice(&pubkey_hash);
+    checker_info_type_args.checker_lock_arg.copy_from_slice(&signer);
 
     let checker_info_script = builder
         .context
@@ -89,9 +90,11 @@ fn test_success() {
     let mut config_output = config_input_data.clone();
     config_output.checker_total_count = 1;
     config_output.checker_normal_count = 1;
-    config_output.activated_checkers.push(pubkey_hash);
+    config_output.activated_checkers.push(signer);
+    config_output.activated_checker_weights.push(100);
     let checker_bond_output = checker_bond_input_data.clone();
-    let checker_info_output = CheckerInfoCell::default();
+    let mut checker_info_output = CheckerInfoCell::default();
+    checker_info_output.bonded_amount = 100;
 
     let outputs = vec![
         new_type_cell_output(1000, &always_success, &code_cell_script),
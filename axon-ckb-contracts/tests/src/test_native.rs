@@ -0,0 +1,161 @@
+use ckb_testtool::context::Context;
+use ckb_tool::ckb_crypto::secp::Generator;
+use ckb_tool::ckb_types::{bytes::Bytes, core::TransactionView, packed::CellDep, prelude::*};
+
+use code_cell::{error::Error, native::verify};
+use common_raw::{
+    cell::{
+        checker_info::{CheckerInfoCell, CheckerInfoCellTypeArgs},
+        sidechain_config::{SidechainConfigCell, SidechainConfigCellTypeArgs},
+        task::{TaskCell, TaskCellTypeArgs, TaskStatus},
+    },
+    witness::checker_vote::CheckerVoteWitness,
+};
+
+use crate::common::*;
+use crate::environment_builder::{AxonScripts, EnvironmentBuilder};
+use crate::secp256k1::*;
+
+// a checker vote, the checker info output is paid with unpaid_fee
+fn checker_vote_tx(unpaid_fee: u128) -> (Context, TransactionView) {
+    // generate key pair
+    let privkey = Generator::random_privkey();
+    let pubkey = privkey.pubkey().expect("pubkey");
+    let pubkey_hash = blake160(&pubkey.serialize());
+
+    // deploy contract
+    let (
+        mut builder,
+        AxonScripts {
+            always_success_code,
+            always_success_script: always_success,
+            code_cell_script,
+            signer,
+            ..
+        },
+    ) = EnvironmentBuilder::default().bootstrap(pubkey_hash.to_vec());
+
+    // prepare scripts
+    let config_type_args = SidechainConfigCellTypeArgs::default();
+    let config_script = builder
+        .context
+        .build_script(&always_success_code, config_type_args.serialize())
+        .expect("script");
+
+    let mut task_type_args = TaskCellTypeArgs::default();
+    task_type_args.checker_lock_arg.copy_from_slice(&signer);
+
+    let task_script = builder
+        .context
+        .build_script(&always_success_code, task_type_args.serialize())
+        .expect("script");
+
+    let mut checker_info_type_args = CheckerInfoCellTypeArgs::default();
+    checker_info_type_args.checker_lock_arg.copy_from_slice(&signer);
+
+    let checker_info_script = builder
+        .context
+        .build_script(&always_success_code, checker_info_type_args.serialize())
+        .expect("script");
+
+    // prepare cell deps
+    let mut config_dep_data = SidechainConfigCell::default();
+    config_dep_data.check_fee_rate = 100;
+
+    let config_dep_out_point = builder.context.create_cell(
+        new_type_cell_output(1000, &always_success, &config_script),
+        config_dep_data.serialize(),
+    );
+    let config_dep = CellDep::new_builder().out_point(config_dep_out_point).build();
+    let mut builder = builder.cell_dep(config_dep);
+
+    // prepare inputs
+    let checker_info_input_data = CheckerInfoCell::default();
+    let checker_info_input = builder.create_input(
+        new_type_cell_output(1000, &always_success, &checker_info_script),
+        checker_info_input_data.serialize(),
+    );
+
+    let mut task_input_data = TaskCell::default();
+    task_input_data.check_data_size = 100;
+
+    let task_input = builder.create_input(
+        new_type_cell_output(1000, &always_success, &task_script),
+        task_input_data.serialize(),
+    );
+
+    let builder = builder.input(checker_info_input).input(task_input);
+
+    // prepare outputs
+    let mut checker_info_output = checker_info_input_data.clone();
+    checker_info_output.unpaid_fee = unpaid_fee;
+
+    let mut task_output = task_input_data.clone();
+    task_output.status = TaskStatus::TaskPassed;
+    task_output.commit[0] = 1;
+    task_output.reveal[0] = 1;
+
+    let outputs = vec![
+        new_type_cell_output(1000, &always_success, &code_cell_script),
+        new_type_cell_output(1000, &always_success, &checker_info_script),
+        new_type_cell_output(1000, &always_success, &task_script),
+    ];
+    let outputs_data: Vec<Bytes> = vec![Bytes::new(), checker_info_output.serialize(), task_output.serialize()];
+
+    // locks are not run natively, the signature is left out
+    let mut witness = CheckerVoteWitness::default();
+    witness.sidechain_config_dep_index = EnvironmentBuilder::BOOTSTRAP_CELL_DEPS_LENGTH;
+    let witnesses = [get_dummy_witness_builder().input_type(witness.serialize().pack_some()).as_bytes()];
+
+    // build transaction
+    let builder = builder.outputs(outputs).outputs_data(outputs_data.pack());
+    let tx = builder.builder.witnesses(witnesses.pack()).build();
+
+    (builder.context, tx)
+}
+
+#[test]
+fn test_success() {
+    let (context, tx) = checker_vote_tx(10000);
+
+    verify(&native_transaction(&context, &tx)).expect("pass verification");
+}
+
+#[test]
+fn test_checker_info_mismatch() {
+    let (context, tx) = checker_vote_tx(1);
+
+    let err = verify(&native_transaction(&context, &tx)).unwrap_err();
+    assert_eq!(err, Error::CheckerInfoMismatch);
+    assert!(err.to_string().starts_with("checker info cell mismatch"));
+}
+
+#[test]
+fn test_task_encoding() {
+    let (context, tx) = checker_vote_tx(10000);
+
+    let mut tx = native_transaction(&context, &tx);
+    tx.outputs[2].data = vec![0xff];
+
+    assert_eq!(verify(&tx).unwrap_err(), Error::TaskEncoding);
+}
+
+#[test]
+fn test_capacity_decreased() {
+    let (context, tx) = checker_vote_tx(10000);
+
+    let mut tx = native_transaction(&context, &tx);
+    tx.outputs[1].capacity -= 1;
+
+    assert_eq!(verify(&tx).unwrap_err(), Error::CapacityDecreased);
+}
+
+#[test]
+fn test_lock_script_mismatch() {
+    let (context, tx) = checker_vote_tx(10000);
+
+    let mut tx = native_transaction(&context, &tx);
+    tx.outputs[2].lock = tx.outputs[2].type_.clone().expect("type");
+
+    assert_eq!(verify(&tx).unwrap_err(), Error::LockScriptMismatch);
+}
@@ -34,6 +34,7 @@ fn test_success() {
             always_success_code,
             always_success_script: always_success,
             code_cell_script,
+            signer,
             ..
         },
     ) = EnvironmentBuilder::default().bootstrap(pubkey_hash.to_vec());
@@ -52,14 +53,14 @@ fn test_success() {
         .expect("script");
 
     let mut checker_info_type_args = CheckerInfoCellTypeArgs::default();
-    checker_info_type_args.checker_lock_arg = pubkey_hash;
+    checker_info_type_args.checker_lock_arg = signer;
     let checker_info_script = builder
         .context
         .build_script(&always_success_code, checker_info_type_args.serialize())
         .expect("script");
 
     let mut task_type_args = TaskCellTypeArgs::default();
-    task_type_args.checker_lock_arg = pubkey_hash;
+    task_type_args.checker_lock_arg = signer;
     let task_script = builder
         .context
         .build_script(&always_success_code, task_type_args.serialize())
@@ -68,7 +69,7 @@ fn test_success() {
     //prepare dep
     let mut config_dep_data = SidechainConfigCell::default();
     config_dep_data.challenge_threshold = 1;
-    config_dep_data.collator_lock_arg.copy_from_slice(&pubkey_hash);
+    config_dep_data.collators = vec![signer];
 
     let config_dep_out_point = builder.context.create_cell(
         new_type_cell_output(1000, &always_success, &config_script),
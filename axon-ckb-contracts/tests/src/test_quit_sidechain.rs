@@ -1,5 +1,8 @@
+use ckb_testtool::context::Context;
 use ckb_tool::ckb_crypto::secp::Generator;
-use ckb_tool::ckb_types::{bytes::Bytes, core, packed::*, prelude::*};
+use ckb_tool::ckb_types::{bytes::Bytes, core, core::TransactionView, packed::*, prelude::*};
+
+use code_cell::{error::Error, native::verify};
 
 use common_raw::cell::checker_info::CheckerInfoStatus;
 use common_raw::cell::sidechain_state::{SidechainStateCell, SidechainStateCellTypeArgs};
@@ -7,9 +10,10 @@ use common_raw::{
     cell::{
         checker_bond::{CheckerBondCell, CheckerBondCellLockArgs},
         checker_info::{CheckerInfoCell, CheckerInfoCellTypeArgs},
-        sidechain_config::{SidechainConfigCell, SidechainConfigCellTypeArgs},
+        sidechain_config::{SidechainConfigCell, SidechainConfigCellTypeArgs, SidechainStatus},
     },
     witness::checker_quit_sidechain::CheckerQuitSidechainWitness,
+    FromRaw,
 };
 
 use crate::common::*;
@@ -27,8 +31,7 @@ fn with_time_header(mut builder: EnvironmentBuilder, timestamp: u64) -> (Environ
     (builder, header)
 }
 
-#[test]
-fn test_success() {
+fn checker_quit_sidechain_tx(sidechain_status: SidechainStatus, jailed: bool) -> (Context, TransactionView) {
     // generate key pair
     let privkey = Generator::random_privkey();
     let pubkey = privkey.pubkey().expect("pubkey");
@@ -41,6 +44,7 @@ fn test_success() {
             always_success_code,
             always_success_script: always_success,
             code_cell_script,
+            signer,
             ..
         },
     ) = EnvironmentBuilder::default().bootstrap(pubkey_hash.to_vec());
@@ -57,11 +61,11 @@ fn test_success() {
         .expect("script");
 
     let mut checker_bond_input_lock_args = CheckerBondCellLockArgs::default();
-    checker_bond_input_lock_args.checker_lock_arg.copy_from_slice(&pubkey_hash);
+    checker_bond_input_lock_args.checker_lock_arg.copy_from_slice(&signer);
     checker_bond_input_lock_args.participated_chain_id.push(0);
 
     let mut checker_bond_output_lock_args = CheckerBondCellLockArgs::default();
-    checker_bond_output_lock_args.checker_lock_arg.copy_from_slice(&pubkey_hash);
+    checker_bond_output_lock_args.checker_lock_arg.copy_from_slice(&signer);
 
     let checker_bond_lock_input_script = builder
         .context
@@ -74,7 +78,7 @@ fn test_success() {
         .expect("script");
 
     let mut checker_info_type_args = CheckerInfoCellTypeArgs::default();
-    checker_info_type_args.checker_lock_arg.copy_from_slice(&pubkey_hash);
+    checker_info_type_args.checker_lock_arg.copy_from_slice(&signer);
 
     let checker_info_script = builder
         .context
@@ -100,9 +104,16 @@ fn test_success() {
     // prepare inputs
     let mut config_input_data = SidechainConfigCell::default();
     config_input_data.checker_total_count = 1;
-    config_input_data.checker_normal_count = 1;
     config_input_data.minimal_bond = 100;
-    config_input_data.activated_checkers.push(pubkey_hash.clone());
+    config_input_data.slash_rate = 1000;
+    config_input_data.sidechain_status = sidechain_status;
+    if jailed {
+        config_input_data.jailed_checkers.push(signer.clone());
+    } else {
+        config_input_data.checker_normal_count = 1;
+        config_input_data.activated_checkers.push(signer.clone());
+        config_input_data.activated_checker_weights.push(100);
+    }
 
     let config_input_out_point = builder.context.create_cell(
         new_type_cell_output(1000, &always_success, &config_script),
@@ -130,9 +141,20 @@ fn test_success() {
     let builder = builder.input(config_input).input(checker_bond_input).input(checker_info_input);
 
     // prepare outputs
-    let mut config_output = SidechainConfigCell::default();
-    config_output.minimal_bond = 100;
-    let checker_bond_output = checker_bond_input_data.clone();
+    let mut config_output = config_input_data.clone();
+    config_output.activated_checkers.clear();
+    config_output.activated_checker_weights.clear();
+    config_output.jailed_checkers.clear();
+    // a shut down sidechain keeps its counts
+    if sidechain_status != SidechainStatus::Shutdown {
+        config_output.checker_total_count = 0;
+        config_output.checker_normal_count = 0;
+    }
+    // a tenth of the bond of a jailed checker is slashed
+    let mut checker_bond_output = checker_bond_input_data.clone();
+    if jailed {
+        checker_bond_output.amount = 90;
+    }
 
     let mut checker_info_output = CheckerInfoCell::default();
     checker_info_output.status = CheckerInfoStatus::Quit;
@@ -161,6 +183,80 @@ fn test_success() {
         .set_witnesses(sign_tx_with_witnesses(tx, witnesses.pack(), &privkey).unwrap())
         .build();
 
-    // run
-    builder.context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+    (builder.context, tx)
+}
+
+#[test]
+fn test_success() {
+    let (context, tx) = checker_quit_sidechain_tx(SidechainStatus::Relaying, false);
+
+    context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+}
+
+#[test]
+fn test_success_shutdown() {
+    let (context, tx) = checker_quit_sidechain_tx(SidechainStatus::Shutdown, false);
+
+    context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+}
+
+#[test]
+fn test_shutdown_bond_mismatch() {
+    let (context, tx) = checker_quit_sidechain_tx(SidechainStatus::Shutdown, false);
+
+    // the bond of a shut down sidechain is still checked
+    let mut tx = native_transaction(&context, &tx);
+    let mut checker_bond_output_data = CheckerBondCell::from_raw(&tx.outputs[2].data).expect("bond");
+    checker_bond_output_data.amount += 1;
+    tx.outputs[2].data = checker_bond_output_data.serialize().to_vec();
+
+    assert_eq!(verify(&tx), Err(Error::CheckerBondMismatch));
+}
+
+#[test]
+fn test_success_jailed() {
+    let (context, tx) = checker_quit_sidechain_tx(SidechainStatus::Relaying, true);
+
+    context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+}
+
+#[test]
+fn test_jailed_full_bond() {
+    let (context, tx) = checker_quit_sidechain_tx(SidechainStatus::Relaying, true);
+
+    // a jailed checker can't take its whole bond back
+    let mut tx = native_transaction(&context, &tx);
+    let mut checker_bond_output_data = CheckerBondCell::from_raw(&tx.outputs[2].data).expect("bond");
+    checker_bond_output_data.amount = 100;
+    tx.outputs[2].data = checker_bond_output_data.serialize().to_vec();
+
+    assert_eq!(verify(&tx), Err(Error::CheckerBondMismatch));
+}
+
+#[test]
+fn test_jailed_slash_rate() {
+    let (context, tx) = checker_quit_sidechain_tx(SidechainStatus::Relaying, true);
+
+    // a fifth of the bond is slashed at a slash_rate of 2000, not a tenth
+    let mut tx = native_transaction(&context, &tx);
+    for cell in &mut [&mut tx.inputs[1], &mut tx.outputs[1]] {
+        let mut config_data = SidechainConfigCell::from_raw(&cell.data).expect("config");
+        config_data.slash_rate = 2000;
+        cell.data = config_data.serialize().to_vec();
+    }
+
+    assert_eq!(verify(&tx), Err(Error::CheckerBondMismatch));
+}
+
+#[test]
+fn test_jailed_kept() {
+    let (context, tx) = checker_quit_sidechain_tx(SidechainStatus::Relaying, true);
+
+    // a quitting checker leaves jail along with the sidechain
+    let mut tx = native_transaction(&context, &tx);
+    let mut config_output_data = SidechainConfigCell::from_raw(&tx.outputs[1].data).expect("config");
+    config_output_data.jailed_checkers = SidechainConfigCell::from_raw(&tx.inputs[1].data).expect("config").jailed_checkers;
+    tx.outputs[1].data = config_output_data.serialize().to_vec();
+
+    assert_eq!(verify(&tx), Err(Error::SidechainConfigMismatch));
 }
@@ -1,6 +1,9 @@
+use ckb_testtool::context::Context;
 use ckb_tool::ckb_crypto::secp::Generator;
 use ckb_tool::ckb_types::{bytes::Bytes, core, packed::*, prelude::*};
 
+use code_cell::{error::Error, native::verify};
+
 use common_raw::cell::sidechain_state::{CheckerLastAcceptTaskHeight, PunishedChecker, SidechainStateCell, SidechainStateCellTypeArgs};
 use common_raw::common::PubKeyHash;
 use common_raw::{
@@ -9,6 +12,7 @@ use common_raw::{
         task::{TaskCell, TaskCellTypeArgs},
     },
     witness::anyone_refresh_task::AnyoneRefreshTaskWitness,
+    FromRaw,
 };
 
 use crate::common::*;
@@ -25,8 +29,8 @@ fn with_time_header(mut builder: EnvironmentBuilder, timestamp: u64) -> (Environ
     (builder, header)
 }
 
-#[test]
-fn test_success() {
+// refreshes the task of the first checker to the other one
+fn refresh_task_tx() -> (Context, core::TransactionView) {
     // generate key pair
     let privkey = Generator::random_privkey();
     let pubkey = privkey.pubkey().expect("pubkey");
@@ -65,7 +69,9 @@ fn test_success() {
         .build_script(&always_success_code, task_input_type_args.serialize())
         .expect("script");
 
-    let task_output_type_args = TaskCellTypeArgs::default();
+    // the task moves to the other checker
+    let mut task_output_type_args = TaskCellTypeArgs::default();
+    task_output_type_args.checker_lock_arg = [1u8; 20];
     let task_output_script = builder
         .context
         .build_script(&always_success_code, task_output_type_args.serialize())
@@ -73,7 +79,8 @@ fn test_success() {
 
     // prepare inputs
     let mut config_cell_data = SidechainConfigCell::default();
-    config_cell_data.activated_checkers.push(PubKeyHash::default());
+    config_cell_data.activated_checkers = vec![PubKeyHash::default(), [1u8; 20]];
+    config_cell_data.activated_checker_weights = vec![100, 200];
     config_cell_data.refresh_punish_threshold = PUNISH_THREAD;
     config_cell_data.refresh_interval = 90;
     let config_cell_outpoint = builder.context.create_cell(
@@ -104,7 +111,8 @@ fn test_success() {
     let task_cell_data = TaskCell::default();
 
     let mut config_cell_data = SidechainConfigCell::default();
-    config_cell_data.activated_checkers.push(PubKeyHash::default());
+    config_cell_data.activated_checkers = vec![PubKeyHash::default(), [1u8; 20]];
+    config_cell_data.activated_checker_weights = vec![100, 200];
     config_cell_data.refresh_punish_threshold = PUNISH_THREAD;
     config_cell_data.refresh_interval = 90;
 
@@ -112,7 +120,10 @@ fn test_success() {
     state_cell_output_data.punish_checkers.push(PunishedChecker::default());
     state_cell_output_data
         .checker_last_task_sidechain_heights
-        .push(CheckerLastAcceptTaskHeight::default());
+        .push(CheckerLastAcceptTaskHeight {
+            checker_lock_arg: [1u8; 20],
+            height:           0,
+        });
     state_cell_output_data.random_offset = 1;
 
     let outputs = vec![
@@ -139,6 +150,31 @@ fn test_success() {
         .set_witnesses(sign_tx_with_witnesses(tx, witnesses.pack(), &privkey).unwrap())
         .build();
 
-    // run
-    builder.context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+    (builder.context, tx)
+}
+
+#[test]
+fn test_success() {
+    let (context, tx) = refresh_task_tx();
+
+    context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+}
+
+#[test]
+fn test_refresh_task_limit_exceeded() {
+    let (context, tx) = refresh_task_tx();
+
+    // a refresh may carry its one task
+    let mut tx = native_transaction(&context, &tx);
+    let mut config_input_data = SidechainConfigCell::from_raw(&tx.inputs[1].data).expect("config");
+    config_input_data.refresh_task_limit = 1;
+    tx.inputs[1].data = config_input_data.serialize().to_vec();
+    tx.outputs[1].data = config_input_data.serialize().to_vec();
+    verify(&tx).expect("pass verification");
+
+    // but not a second one
+    let task_input = tx.inputs[3].clone();
+    tx.inputs.push(task_input);
+
+    assert_eq!(verify(&tx), Err(Error::RefreshTaskLimitExceeded));
 }
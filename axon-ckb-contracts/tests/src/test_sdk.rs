@@ -0,0 +1,143 @@
+use ckb_tool::ckb_crypto::secp::Generator;
+use ckb_tool::ckb_types::{bytes::Bytes, packed::CellDep, prelude::*};
+
+use axon_sdk::{checker_vote::CheckerVote, Cell, Environment, PatternTx, Transition};
+use common_raw::{
+    cell::{
+        checker_info::{CheckerInfoCell, CheckerInfoCellTypeArgs},
+        sidechain_config::{SidechainConfigCell, SidechainConfigCellTypeArgs},
+        task::{TaskCell, TaskCellTypeArgs, TaskStatus},
+    },
+    witness::checker_vote::CheckerVoteWitness,
+};
+
+use crate::common::*;
+use crate::environment_builder::{AxonScripts, EnvironmentBuilder};
+use crate::secp256k1::*;
+
+const MAX_CYCLES: u64 = 10_000_000;
+
+#[test]
+fn test_success() {
+    // generate key pair
+    let privkey = Generator::random_privkey();
+    let pubkey = privkey.pubkey().expect("pubkey");
+    let pubkey_hash = blake160(&pubkey.serialize());
+
+    // deploy contract
+    let (
+        mut builder,
+        AxonScripts {
+            always_success_code,
+            always_success_script: always_success,
+            code_cell_script,
+            signer,
+            ..
+        },
+    ) = EnvironmentBuilder::default().bootstrap(pubkey_hash.to_vec());
+
+    // take the environment from the bootstrapped transaction
+    let bootstrap = builder.builder.clone().build();
+    let env = Environment {
+        cell_deps: bootstrap.cell_deps().into_iter().collect(),
+        code:      Transition {
+            input:  bootstrap.inputs().get(0).expect("code cell"),
+            output: Cell {
+                output: new_type_cell_output(1000, &always_success, &code_cell_script),
+                data:   Bytes::new(),
+            },
+        },
+    };
+
+    // prepare scripts
+    let config_type_args = SidechainConfigCellTypeArgs::default();
+    let config_script = builder
+        .context
+        .build_script(&always_success_code, config_type_args.serialize())
+        .expect("script");
+
+    let mut task_type_args = TaskCellTypeArgs::default();
+    task_type_args.checker_lock_arg.copy_from_slice(&signer);
+
+    let task_script = builder
+        .context
+        .build_script(&always_success_code, task_type_args.serialize())
+        .expect("script");
+
+    let mut checker_info_type_args = CheckerInfoCellTypeArgs::default();
+    checker_info_type_args.checker_lock_arg.copy_from_slice(&signer);
+
+    let checker_info_script = builder
+        .context
+        .build_script(&always_success_code, checker_info_type_args.serialize())
+        .expect("script");
+
+    // prepare cell deps
+    let mut config_dep_data = SidechainConfigCell::default();
+    config_dep_data.check_fee_rate = 100;
+
+    let config_dep_out_point = builder.context.create_cell(
+        new_type_cell_output(1000, &always_success, &config_script),
+        config_dep_data.serialize(),
+    );
+    let config_dep = CellDep::new_builder().out_point(config_dep_out_point).build();
+
+    // prepare cells
+    let checker_info_input_data = CheckerInfoCell::default();
+    let checker_info_input = builder.create_input(
+        new_type_cell_output(1000, &always_success, &checker_info_script),
+        checker_info_input_data.serialize(),
+    );
+
+    let mut task_input_data = TaskCell::default();
+    task_input_data.check_data_size = 100;
+
+    let task_input = builder.create_input(
+        new_type_cell_output(1000, &always_success, &task_script),
+        task_input_data.serialize(),
+    );
+
+    let mut checker_info_output = checker_info_input_data.clone();
+    checker_info_output.unpaid_fee = 10000;
+
+    let mut task_output = task_input_data.clone();
+    task_output.status = TaskStatus::TaskPassed;
+    task_output.commit[0] = 1;
+    task_output.reveal[0] = 1;
+
+    let mut witness = CheckerVoteWitness::default();
+    witness.sidechain_config_dep_index = EnvironmentBuilder::BOOTSTRAP_CELL_DEPS_LENGTH;
+
+    // build transaction
+    let vote = CheckerVote {
+        env,
+        sidechain_config_dep: config_dep,
+        checker_info: Transition {
+            input:  checker_info_input,
+            output: Cell {
+                output: new_type_cell_output(1000, &always_success, &checker_info_script),
+                data:   checker_info_output.serialize(),
+            },
+        },
+        task: Transition {
+            input:  task_input,
+            output: Cell {
+                output: new_type_cell_output(1000, &always_success, &task_script),
+                data:   task_output.serialize(),
+            },
+        },
+        witness,
+    };
+
+    let tx = vote.build().expect("build");
+    let witnesses = [get_dummy_witness_builder()
+        .input_type(vote.witness().expect("witness").pack_some())
+        .as_bytes()];
+    let tx = tx
+        .as_advanced_builder()
+        .set_witnesses(sign_tx_with_witnesses(tx, witnesses.pack(), &privkey).unwrap())
+        .build();
+
+    // run
+    builder.context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+}
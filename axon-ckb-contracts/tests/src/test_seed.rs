@@ -0,0 +1,29 @@
+use common_raw::{
+    common::BlockSlice,
+    seed::{derive_seed, SeedPurpose},
+};
+
+use crate::common::Blake2b;
+
+#[test]
+fn test_success() {
+    let seed = [7u8; 32];
+    let job = BlockSlice { from: 10, to: 20 };
+    let derived = derive_seed::<Blake2b>(&seed, 0, 1, SeedPurpose::PublishTask, &job);
+
+    // the same draw derives the same seed, any other draw another one
+    assert_eq!(derive_seed::<Blake2b>(&seed, 0, 1, SeedPurpose::PublishTask, &job), derived);
+    assert_ne!(derive_seed::<Blake2b>(&seed, 1, 1, SeedPurpose::PublishTask, &job), derived);
+    assert_ne!(derive_seed::<Blake2b>(&seed, 0, 2, SeedPurpose::PublishTask, &job), derived);
+    assert_ne!(derive_seed::<Blake2b>(&seed, 0, 1, SeedPurpose::PublishChallenge, &job), derived);
+    assert_ne!(
+        derive_seed::<Blake2b>(&seed, 0, 1, SeedPurpose::PublishTask, &BlockSlice { from: 10, to: 21 }),
+        derived
+    );
+
+    // offsets past the old u8 range keep the seeds apart
+    assert_ne!(
+        derive_seed::<Blake2b>(&seed, 256, 1, SeedPurpose::PublishTask, &job),
+        derive_seed::<Blake2b>(&seed, 0, 1, SeedPurpose::PublishTask, &job)
+    );
+}
@@ -0,0 +1,53 @@
+use common_raw::{
+    cell::{
+        sidechain_state::{PunishedChecker, SidechainStateCell},
+        task::{TaskCell, TaskStatus},
+    },
+    witness::collator_submit_tasks::{CollatorSubmitTasksWitness, ExistedCommittedCheckerInfo},
+};
+
+#[test]
+fn test_success() {
+    // hashes and lock args are hex strings
+    let mut task = TaskCell::default();
+    task.status = TaskStatus::TaskPassed;
+    task.commit[0] = 0xab;
+    task.sidechain_block_header.push([1u8; 32]);
+
+    let json = serde_json::to_value(&task).expect("serialize");
+    assert_eq!(json["status"], "TaskPassed");
+    assert_eq!(json["commit"], format!("0xab{}", "00".repeat(31)));
+    assert_eq!(json["sidechain_block_header"][0], format!("0x{}", "01".repeat(32)));
+    assert_eq!(serde_json::from_value::<TaskCell>(json).expect("deserialize"), task);
+
+    let mut state = SidechainStateCell::default();
+    state.punish_checkers.push(PunishedChecker {
+        checker_lock_arg: [2u8; 20],
+        punish_points:    1,
+    });
+
+    let json = serde_json::to_value(&state).expect("serialize");
+    assert_eq!(json["punish_checkers"][0]["checker_lock_arg"], format!("0x{}", "02".repeat(20)));
+    assert_eq!(serde_json::from_value::<SidechainStateCell>(json).expect("deserialize"), state);
+
+    // absent committed hashes are null
+    let mut witness = CollatorSubmitTasksWitness::default();
+    witness.commit.push(ExistedCommittedCheckerInfo {
+        index:                 Some(0),
+        checker_lock_arg:      [3u8; 20],
+        origin_committed_hash: None,
+        new_committed_hash:    Some([4u8; 32]),
+    });
+
+    let json = serde_json::to_value(&witness).expect("serialize");
+    assert!(json["commit"][0]["origin_committed_hash"].is_null());
+    assert_eq!(
+        serde_json::from_value::<CollatorSubmitTasksWitness>(json).expect("deserialize"),
+        witness
+    );
+
+    // malformed hex is rejected
+    let mut json = serde_json::to_value(&task).expect("serialize");
+    json["commit"] = "0xab".into();
+    assert!(serde_json::from_value::<TaskCell>(json).is_err());
+}
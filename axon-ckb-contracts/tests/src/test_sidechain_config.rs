@@ -0,0 +1,62 @@
+use common_raw::cell::sidechain_config::SidechainConfigCell;
+
+#[test]
+fn test_checker_at() {
+    // the first checker weighs 100 and the second 200
+    let mut config = SidechainConfigCell::default();
+    config.activated_checkers = vec![[1; 20], [2; 20]];
+    config.activated_checker_weights = vec![100, 300];
+
+    assert_eq!(config.checker_at(0), Some(0));
+    assert_eq!(config.checker_at(99), Some(0));
+    assert_eq!(config.checker_at(100), Some(1));
+    assert_eq!(config.checker_at(299), Some(1));
+    assert_eq!(config.checker_at(300), None);
+
+    assert_eq!(SidechainConfigCell::default().checker_at(0), None);
+}
+
+#[test]
+fn test_activate_checker() {
+    let mut config = SidechainConfigCell::default();
+    config.activate_checker([2; 20], 200).expect("activate");
+    config.activate_checker([3; 20], 300).expect("activate");
+    config.activate_checker([1; 20], 100).expect("activate");
+
+    // inserted in order of lock arg with the weights of the checkers after it moved up
+    assert_eq!(config.activated_checkers, vec![[1; 20], [2; 20], [3; 20]]);
+    assert_eq!(config.activated_checker_weights, vec![100, 300, 600]);
+    assert!(config.has_sorted_checkers());
+
+    // an activated checker is not activated twice
+    assert_eq!(config.activate_checker([2; 20], 200), None);
+    assert_eq!(config.activated_checkers.len(), 3);
+
+    config.increase_checker_weight(&[2; 20], 50).expect("increase");
+    assert_eq!(config.activated_checker_weights, vec![100, 350, 650]);
+    assert_eq!(config.checker_weight(1), Some(250));
+
+    assert_eq!(config.increase_checker_weight(&[4; 20], 50), None);
+    assert_eq!(config.increase_checker_weight(&[3; 20], u128::MAX), None);
+}
+
+#[test]
+fn test_unsorted_checkers() {
+    let mut config = SidechainConfigCell::default();
+    config.activated_checkers = vec![[1; 20], [3; 20]];
+    config.jailed_checkers = vec![[2; 20], [4; 20]];
+    assert!(config.has_sorted_checkers());
+
+    let mut unsorted = config.clone();
+    unsorted.activated_checkers = vec![[3; 20], [1; 20]];
+    assert!(!unsorted.has_sorted_checkers());
+
+    let mut duplicated = config.clone();
+    duplicated.jailed_checkers = vec![[2; 20], [2; 20]];
+    assert!(!duplicated.has_sorted_checkers());
+
+    // a checker is either activated or jailed
+    let mut both = config.clone();
+    both.jailed_checkers = vec![[2; 20], [3; 20]];
+    assert!(!both.has_sorted_checkers());
+}
@@ -4,6 +4,7 @@ use ckb_tool::ckb_types::{bytes::Bytes, prelude::*};
 use common_raw::{
     cell::{
         checker_info::{CheckerInfoCell, CheckerInfoCellTypeArgs},
+        delegation::{DelegationCell, DelegationCellLockArgs},
         muse_token::MuseTokenCell,
         sidechain_fee::{SidechainFeeCell, SidechainFeeCellLockArgs},
     },
@@ -30,6 +31,7 @@ fn test_success() {
             always_success_code,
             always_success_script: always_success,
             code_cell_script,
+            signer,
             ..
         },
     ) = EnvironmentBuilder::default().bootstrap(pubkey_hash.to_vec());
@@ -42,16 +44,28 @@ fn test_success() {
         .expect("script");
 
     let mut checker_info_type_args = CheckerInfoCellTypeArgs::default();
-    checker_info_type_args.checker_lock_arg.copy_from_slice(&pubkey_hash);
+    checker_info_type_args.checker_lock_arg.copy_from_slice(&signer);
 
     let checker_info_script = builder
         .context
         .build_script(&always_success_code, checker_info_type_args.serialize())
         .expect("script");
 
+    let mut delegation_lock_args = DelegationCellLockArgs::default();
+    delegation_lock_args.checker_lock_arg.copy_from_slice(&signer);
+    delegation_lock_args.principal = 100;
+
+    let delegation_script = builder
+        .context
+        .build_script(&always_success_code, delegation_lock_args.serialize())
+        .expect("script");
+
     // prepare inputs
     let mut checker_info_input_data = CheckerInfoCell::default();
     checker_info_input_data.unpaid_fee = 100;
+    checker_info_input_data.commission_rate = 1000;
+    checker_info_input_data.bonded_amount = 100;
+    checker_info_input_data.delegated_amount = 100;
 
     let checker_info_input = builder.create_input(
         new_type_cell_output(1000, &always_success, &checker_info_script),
@@ -66,7 +80,15 @@ fn test_success() {
         sidechain_fee_input_data.serialize(),
     );
 
-    let builder = builder.input(checker_info_input).input(sidechain_fee_input);
+    let mut delegation_input_data = DelegationCell::default();
+    delegation_input_data.amount = 100;
+
+    let delegation_input = builder.create_input(
+        new_type_cell_output(1000, &delegation_script, &always_success),
+        delegation_input_data.serialize(),
+    );
+
+    let builder = builder.input(checker_info_input).input(sidechain_fee_input).input(delegation_input);
 
     // prepare outputs
     let mut checker_info_output = checker_info_input_data.clone();
@@ -75,24 +97,31 @@ fn test_success() {
     let mut sidechain_fee_output = sidechain_fee_input_data.clone();
     sidechain_fee_output.amount = 0;
 
+    // the delegation holds half of the stake and pays a tenth of its share as commission
     let mut muse_output = MuseTokenCell::default();
-    muse_output.amount = 100;
+    muse_output.amount = 55;
+
+    let mut delegation_output = delegation_input_data.clone();
+    delegation_output.amount = 145;
 
     let outputs = vec![
         new_type_cell_output(1000, &always_success, &code_cell_script),
         new_type_cell_output(1000, &always_success, &checker_info_script),
         new_type_cell_output(1000, &sidechain_fee_script, &always_success),
         new_type_cell_output(1000, &always_success, &always_success),
+        new_type_cell_output(1000, &delegation_script, &always_success),
     ];
     let outputs_data: Vec<Bytes> = vec![
         Bytes::new(),
         checker_info_output.serialize(),
         sidechain_fee_output.serialize(),
         muse_output.serialize(),
+        delegation_output.serialize(),
     ];
 
     let mut witness = CheckerTakeBeneficiaryWitness::default();
     witness.fee = 100;
+    witness.delegation_count = 1;
 
     let witnesses = [get_dummy_witness_builder().input_type(witness.serialize().pack_some()).as_bytes()];
 
@@ -0,0 +1,99 @@
+use ckb_tool::bytes::Bytes;
+use ckb_tool::ckb_crypto::secp::Generator;
+use ckb_tool::ckb_types::packed::CellDep;
+use ckb_tool::ckb_types::prelude::*;
+
+use common_raw::cell::proposal::{ProposalCell, ProposalCellTypeArgs, ProposalChange};
+use common_raw::cell::sidechain_config::{SidechainConfigCell, SidechainConfigCellTypeArgs};
+use common_raw::witness::checker_vote_proposal::CheckerVoteProposalWitness;
+
+use crate::common::*;
+use crate::environment_builder::{AxonScripts, EnvironmentBuilder};
+use crate::secp256k1::*;
+
+const MAX_CYCLES: u64 = 10_000_000;
+
+#[test]
+fn test_success() {
+    // generate key pair
+    let privkey = Generator::random_privkey();
+    let pubkey = privkey.pubkey().expect("pubkey");
+    let pubkey_hash = blake160(&pubkey.serialize());
+
+    // deploy contract
+    let (
+        mut builder,
+        AxonScripts {
+            always_success_code,
+            always_success_script: always_success,
+            code_cell_script,
+            signer,
+            ..
+        },
+    ) = EnvironmentBuilder::default().bootstrap(pubkey_hash.to_vec());
+
+    let proposer = [1u8; 20];
+
+    // prepare scripts
+    let config_type_args = SidechainConfigCellTypeArgs::default();
+    let config_type_script = builder
+        .context
+        .build_script(&always_success_code, config_type_args.serialize())
+        .expect("script");
+
+    let mut proposal_type_args = ProposalCellTypeArgs::default();
+    proposal_type_args.proposer_lock_arg = proposer;
+    let proposal_type_script = builder
+        .context
+        .build_script(&always_success_code, proposal_type_args.serialize())
+        .expect("script");
+
+    // prepare deps
+    let mut config_dep_data = SidechainConfigCell::default();
+    config_dep_data.activated_checkers = vec![proposer, signer];
+
+    let config_dep_out_point = builder.context.create_cell(
+        new_type_cell_output(1000, &always_success, &config_type_script),
+        config_dep_data.serialize(),
+    );
+    let config_dep = CellDep::new_builder().out_point(config_dep_out_point).build();
+    let mut builder = builder.cell_dep(config_dep);
+
+    // prepare inputs
+    let proposal_input_data = ProposalCell {
+        change:       ProposalChange::Shutdown,
+        config_nonce: 0,
+        votes:        vec![proposer],
+    };
+    let proposal_input = builder.create_input(
+        new_type_cell_output(1000, &always_success, &proposal_type_script),
+        proposal_input_data.serialize(),
+    );
+    let builder = builder.input(proposal_input);
+
+    // prepare outputs
+    let outputs = vec![
+        new_type_cell_output(1000, &always_success, &code_cell_script),
+        new_type_cell_output(1000, &always_success, &proposal_type_script),
+    ];
+
+    let mut proposal_output_data = proposal_input_data.clone();
+    proposal_output_data.votes.push(signer);
+
+    let outputs_data = vec![Bytes::new(), proposal_output_data.serialize()];
+
+    let builder = builder.outputs(outputs).outputs_data(outputs_data.pack());
+
+    let witness = CheckerVoteProposalWitness::default();
+    let witnesses = [get_dummy_witness_builder().input_type(witness.serialize().pack_some()).as_bytes()];
+
+    // build transaction
+    let tx = builder.builder.build();
+    let tx = tx
+        .as_advanced_builder()
+        .set_witnesses(sign_tx_with_witnesses(tx, witnesses.pack(), &privkey).unwrap())
+        .build();
+
+    // run
+    builder.context.verify_tx(&tx, MAX_CYCLES).expect("pass verification");
+}